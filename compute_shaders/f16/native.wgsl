@@ -0,0 +1,15 @@
+enable f16;
+
+// Prelude of the f16 kernels used when the device supports SHADER_F16.
+// Two f16 values are packed in each u32 word, the low half holds the first value.
+
+alias half = f16;
+alias half2 = vec2<f16>;
+
+fn load_half2(word: u32) -> half2 {
+    return bitcast<vec2<f16>>(word);
+}
+
+fn store_half2(value: half2) -> u32 {
+    return bitcast<u32>(value);
+}
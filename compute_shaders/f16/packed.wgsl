@@ -0,0 +1,30 @@
+// Prelude of the packed f16 kernels used when the device lacks SHADER_F16.
+// Two f16 values are packed in each u32 word, the low half holds the first value.
+// Values are unpacked to f32, computed and rounded back to f16 when packed.
+
+alias half = f32;
+alias half2 = vec2<f32>;
+
+fn load_half2(word: u32) -> half2 {
+    return unpack2x16float(word);
+}
+
+// pack2x16float is unspecified outside the finite f16 range, overflow and NaN are fixed up here
+fn fix_half_bits(value: f32, bits: u32) -> u32 {
+    let magnitude = bitcast<u32>(value) & 0x7fffffffu;
+    if magnitude > 0x7f800000u {
+        return 0x7e00u;
+    }
+    // 65520 is the smallest magnitude that rounds to infinity
+    if bitcast<f32>(magnitude) >= 65520.0 {
+        return select(0x7c00u, 0xfc00u, value < 0.0);
+    }
+    return bits;
+}
+
+fn store_half2(value: half2) -> u32 {
+    let packed = pack2x16float(value);
+    let low = fix_half_bits(value.x, packed & 0xffffu);
+    let high = fix_half_bits(value.y, packed >> 16u);
+    return low | (high << 16u);
+}
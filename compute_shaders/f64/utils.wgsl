@@ -0,0 +1,258 @@
+// 64 bit floats are stored with their exact IEEE bits as vec2<u32>, x holds the low word.
+// Arithmetic converts them to double-single values, a vec2<f32> of an unevaluated sum
+// hi + lo with |lo| <= ulp(hi) / 2, which carries 48 bits of significand.
+// Needs add_64 and sub_64 from compute_shaders/u64/utils.wgsl.
+
+// Shader compilers may reassociate float math, which cancels the rounding errors the exact
+// transformations below recover. Sums and products pass through `opaque`, the compiler can
+// not see through it since workgroup memory is only zero initialized at runtime.
+var<workgroup> opaque_zero: u32;
+
+fn opaque(value: f32) -> f32 {
+    return bitcast<f32>(bitcast<u32>(value) | opaque_zero);
+}
+
+fn is_finite_f32(value: f32) -> bool {
+    return (bitcast<u32>(value) & 0x7f800000u) != 0x7f800000u;
+}
+
+fn nan_f32() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+// 2^exponent for exponent in [-126, 127]
+fn pow2_f32(exponent: i32) -> f32 {
+    return bitcast<f32>(u32(exponent + 127) << 23u);
+}
+
+fn two_sum(left: f32, right: f32) -> vec2<f32> {
+    let sum = opaque(left + right);
+    let right_part = opaque(sum - left);
+    let error = (left - (sum - right_part)) + (right - right_part);
+    return vec2<f32>(sum, error);
+}
+
+fn quick_two_sum(left: f32, right: f32) -> vec2<f32> {
+    let sum = opaque(left + right);
+    return vec2<f32>(sum, right - (sum - left));
+}
+
+// Dekker split into two halves of 12 bits, large values are scaled to avoid overflow
+fn split_f32(value: f32) -> vec2<f32> {
+    if abs(value) > 1e30 {
+        let scaled = split_f32_unscaled(value * 0x1p-28f);
+        return scaled * 0x1p28f;
+    }
+    return split_f32_unscaled(value);
+}
+
+fn split_f32_unscaled(value: f32) -> vec2<f32> {
+    let temp = opaque(4097.0 * value);
+    let high = opaque(temp - (temp - value));
+    return vec2<f32>(high, value - high);
+}
+
+fn two_prod(left: f32, right: f32) -> vec2<f32> {
+    let product = opaque(left * right);
+    let l = split_f32(left);
+    let r = split_f32(right);
+    let error = ((l.x * r.x - product) + l.x * r.y + l.y * r.x) + l.y * r.y;
+    return vec2<f32>(product, error);
+}
+
+fn ds_add(left: vec2<f32>, right: vec2<f32>) -> vec2<f32> {
+    let high = two_sum(left.x, right.x);
+    if !is_finite_f32(high.x) {
+        return vec2<f32>(high.x, 0.0);
+    }
+    let low = two_sum(left.y, right.y);
+    let result = quick_two_sum(high.x, high.y + low.x);
+    return quick_two_sum(result.x, result.y + low.y);
+}
+
+fn ds_sub(left: vec2<f32>, right: vec2<f32>) -> vec2<f32> {
+    return ds_add(left, -right);
+}
+
+fn ds_mul(left: vec2<f32>, right: vec2<f32>) -> vec2<f32> {
+    let product = two_prod(left.x, right.x);
+    if !is_finite_f32(product.x) {
+        return vec2<f32>(product.x, 0.0);
+    }
+    return quick_two_sum(product.x, product.y + (left.x * right.y + left.y * right.x));
+}
+
+// f32 division may be off by a few ulp, the two correction steps recover the ds precision
+fn ds_div(left: vec2<f32>, right: vec2<f32>) -> vec2<f32> {
+    let q1 = left.x / right.x;
+    if !is_finite_f32(q1) || q1 == 0.0 {
+        return vec2<f32>(q1, 0.0);
+    }
+    let r1 = ds_sub(left, ds_mul(right, vec2<f32>(q1, 0.0)));
+    let q2 = r1.x / right.x;
+    let r2 = ds_sub(r1, ds_mul(right, vec2<f32>(q2, 0.0)));
+    let q3 = r2.x / right.x;
+    return ds_add(quick_two_sum(q1, q2), vec2<f32>(q3, 0.0));
+}
+
+fn ds_sqrt(value: vec2<f32>) -> vec2<f32> {
+    if value.x < 0.0 {
+        return vec2<f32>(nan_f32(), 0.0);
+    }
+    if value.x == 0.0 || !is_finite_f32(value.x) {
+        return vec2<f32>(value.x, 0.0);
+    }
+    // Newton iterations on sqrt(x) = x * inverseSqrt(x)
+    let half_inverse = 0.5 * inverseSqrt(value.x);
+    var result = vec2<f32>(value.x * 2.0 * half_inverse, 0.0);
+    for (var i = 0; i < 2; i++) {
+        let residual = ds_sub(value, ds_mul(result, result));
+        result = ds_add(result, vec2<f32>(residual.x * half_inverse, 0.0));
+    }
+    return result;
+}
+
+// Exact f64 bits of an f32
+fn f32_to_f64(value: f32) -> vec2<u32> {
+    let bits = bitcast<u32>(value);
+    let sign = bits & 0x80000000u;
+    let exponent = (bits >> 23u) & 0xffu;
+    var fraction = bits & 0x7fffffu;
+    if exponent == 0xffu {
+        return vec2<u32>(0u, sign | 0x7ff00000u | select(0u, 0x80000u, fraction != 0u));
+    }
+    var e = i32(exponent) - 127;
+    if exponent == 0u {
+        if fraction == 0u {
+            return vec2<u32>(0u, sign);
+        }
+        // subnormal, move the leading bit out of the fraction
+        let leading = firstLeadingBit(fraction);
+        e = i32(leading) - 149;
+        fraction = (fraction << (23u - leading)) & 0x7fffffu;
+    }
+    return vec2<u32>(fraction << 29u, sign | (u32(e + 1023) << 20u) | (fraction >> 3u));
+}
+
+// Values below the f32 normal range keep the precision the device has for subnormals
+fn tiny_f64_to_f32(bits: vec2<u32>, e: i32) -> f32 {
+    if e < -252 {
+        return bitcast<f32>(bits.y & 0x80000000u);
+    }
+    let significand = f32(0x800000u | ((bits.y & 0xfffffu) << 3u) | (bits.x >> 29u));
+    let value = significand * 0x1p-23f * pow2_f32(-126) * pow2_f32(e + 126);
+    return select(value, -value, (bits.y & 0x80000000u) != 0u);
+}
+
+// f64 bits to the nearest f32, ties to even
+fn f64_to_f32(bits: vec2<u32>) -> f32 {
+    let sign = bits.y & 0x80000000u;
+    let exponent = i32((bits.y >> 20u) & 0x7ffu);
+    if exponent == 0x7ff {
+        let is_nan = ((bits.y & 0xfffffu) | bits.x) != 0u;
+        return bitcast<f32>(sign | 0x7f800000u | select(0u, 0x400000u, is_nan));
+    }
+    let e = exponent - 1023;
+    if e > 127 {
+        return bitcast<f32>(sign | 0x7f800000u);
+    }
+    if e < -126 {
+        return tiny_f64_to_f32(bits, e);
+    }
+    var result = sign | (u32(e + 127) << 23u) | ((bits.y & 0xfffffu) << 3u) | (bits.x >> 29u);
+    let remainder = bits.x & 0x1fffffffu;
+    if remainder > 0x10000000u || (remainder == 0x10000000u && (result & 1u) == 1u) {
+        // a carry out of the fraction rounds up the exponent, possibly to infinity
+        result += 1u;
+    }
+    return bitcast<f32>(result);
+}
+
+fn f64_to_ds(bits: vec2<u32>) -> vec2<f32> {
+    let exponent = i32((bits.y >> 20u) & 0x7ffu);
+    let e = exponent - 1023;
+    if exponent == 0x7ff || e > 127 || e < -126 {
+        return vec2<f32>(f64_to_f32(bits), 0.0);
+    }
+    // the high part takes the leading 24 bits, the low part the remaining 29 bits
+    let sign = bits.y & 0x80000000u;
+    let high = bitcast<f32>(sign | (u32(e + 127) << 23u) | ((bits.y & 0xfffffu) << 3u) | (bits.x >> 29u));
+    let low = f32(bits.x & 0x1fffffffu) * 0x1p-52f * pow2_f32(e);
+    return quick_two_sum(high, select(low, -low, sign != 0u));
+}
+
+// f64 bits of hi + lo, within one ulp of the f64 nearest to the exact sum
+fn ds_to_f64(value: vec2<f32>) -> vec2<u32> {
+    let high_bits = bitcast<u32>(value.x);
+    let exponent = (high_bits >> 23u) & 0xffu;
+    if exponent == 0xffu || exponent == 0u || value.y == 0.0 {
+        return f32_to_f64(value.x);
+    }
+    let sign = high_bits & 0x80000000u;
+    var e = i32(exponent) - 127;
+
+    // low part in units of the last place of a f64 with the exponent of the high part,
+    // the scaling is split in two since ldexp only takes exponents up to 128
+    var scale = 52 - e;
+    var low = value.y;
+    if scale > 100 {
+        low = ldexp(low, 64);
+        scale -= 64;
+    }
+    low = round(ldexp(low, scale));
+    let delta = i32(select(low, -low, sign != 0u));
+
+    let high_significand = 0x800000u | (high_bits & 0x7fffffu);
+    var significand = vec2<u32>(high_significand << 29u, high_significand >> 3u);
+    if delta >= 0 {
+        significand = add_64(significand, vec2<u32>(u32(delta), 0u));
+    } else {
+        significand = sub_64(significand, vec2<u32>(u32(-delta), 0u));
+    }
+
+    // the sum can fall just under the leading bit, or reach the next power of two
+    if significand.y < 0x100000u {
+        significand = vec2<u32>(significand.x << 1u, (significand.y << 1u) | (significand.x >> 31u));
+        e -= 1;
+    } else if significand.y >= 0x200000u {
+        significand = vec2<u32>((significand.x >> 1u) | (significand.y << 31u), significand.y >> 1u);
+        e += 1;
+    }
+    return vec2<u32>(significand.x, sign | (u32(e + 1023) << 20u) | (significand.y & 0xfffffu));
+}
+
+// f64 bits of a u64, rounded to the nearest f64 with ties to even
+fn u64_to_f64(value: vec2<u32>) -> vec2<u32> {
+    if value.x == 0u && value.y == 0u {
+        return vec2<u32>(0u, 0u);
+    }
+    // shift the leading one to bit 63
+    let shift = select(countLeadingZeros(value.y), 32u + countLeadingZeros(value.x), value.y == 0u);
+    var normalized = value;
+    if shift >= 32u {
+        normalized = vec2<u32>(0u, value.x << (shift - 32u));
+    } else if shift != 0u {
+        normalized = vec2<u32>(value.x << shift, (value.y << shift) | (value.x >> (32u - shift)));
+    }
+    // the leading 53 bits form the significand, the 11 bits below them are rounded off
+    var significand = vec2<u32>((normalized.y << 21u) | (normalized.x >> 11u), normalized.y >> 11u);
+    var exponent = 1086u - shift;
+    let remainder = normalized.x & 0x7ffu;
+    if remainder > 0x400u || (remainder == 0x400u && (significand.x & 1u) == 1u) {
+        significand = add_64(significand, vec2<u32>(1u, 0u));
+        if significand.y == 0x200000u {
+            significand = vec2<u32>(0u, 0x100000u);
+            exponent += 1u;
+        }
+    }
+    return vec2<u32>(significand.x, (exponent << 20u) | (significand.y & 0xfffffu));
+}
+
+// f64 bits of an i64, rounded to the nearest f64 with ties to even
+fn i64_to_f64(value: vec2<u32>) -> vec2<u32> {
+    if (value.y & 0x80000000u) == 0u {
+        return u64_to_f64(value);
+    }
+    let magnitude = u64_to_f64(sub_64(vec2<u32>(0u, 0u), value));
+    return vec2<u32>(magnitude.x, magnitude.y | 0x80000000u);
+}
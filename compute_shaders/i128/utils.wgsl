@@ -0,0 +1,188 @@
+// 128 bit values are stored as vec4<u32> in little endian order.
+// x holds the lowest 32 bits and w holds the highest 32 bits along with the sign.
+// Needs mul_wide_32 from compute_shaders/u64/utils.wgsl.
+
+struct Add128 {
+    sum: vec4<u32>,
+    carry: u32,
+}
+
+fn add_128_carry(left: vec4<u32>, right: vec4<u32>) -> Add128 {
+    var result = vec4<u32>(0u);
+    var carry = 0u;
+    for (var i = 0u; i < 4u; i++) {
+        let partial = left[i] + right[i];
+        let total = partial + carry;
+        carry = select(0u, 1u, partial < left[i]) + select(0u, 1u, total < partial);
+        result[i] = total;
+    }
+    return Add128(result, carry);
+}
+
+fn add_128(left: vec4<u32>, right: vec4<u32>) -> vec4<u32> {
+    return add_128_carry(left, right).sum;
+}
+
+fn neg_128(value: vec4<u32>) -> vec4<u32> {
+    return add_128(~value, vec4<u32>(1u, 0u, 0u, 0u));
+}
+
+fn sub_128(left: vec4<u32>, right: vec4<u32>) -> vec4<u32> {
+    return add_128(left, neg_128(right));
+}
+
+fn is_negative_128(value: vec4<u32>) -> bool {
+    return (value.w & 0x80000000u) != 0u;
+}
+
+fn abs_128(value: vec4<u32>) -> vec4<u32> {
+    return select(value, neg_128(value), is_negative_128(value));
+}
+
+// returns the low 128 bits of the product, the same for signed and unsigned values
+fn mul_128(left: vec4<u32>, right: vec4<u32>) -> vec4<u32> {
+    var result = vec4<u32>(0u);
+    for (var i = 0u; i < 4u; i++) {
+        var carry = 0u;
+        for (var j = 0u; i + j < 4u; j++) {
+            let product = mul_wide_32(left[i], right[j]);
+            let partial = result[i + j] + product.x;
+            let total = partial + carry;
+            carry = product.y + select(0u, 1u, partial < product.x) + select(0u, 1u, total < partial);
+            result[i + j] = total;
+        }
+    }
+    return result;
+}
+
+struct Mul128 {
+    product: vec4<u32>,
+    overflow: bool,
+}
+
+// multiplies two i128, overflow is set when the product does not fit in an i128
+fn mul_i128_checked(left: vec4<u32>, right: vec4<u32>) -> Mul128 {
+    let negative = is_negative_128(left) != is_negative_128(right);
+    let left_magnitude = abs_128(left);
+    let right_magnitude = abs_128(right);
+    var magnitude = vec4<u32>(0u);
+    var overflow = false;
+    for (var i = 0u; i < 4u; i++) {
+        var carry = 0u;
+        for (var j = 0u; j < 4u; j++) {
+            let product = mul_wide_32(left_magnitude[i], right_magnitude[j]);
+            if i + j < 4u {
+                let partial = magnitude[i + j] + product.x;
+                let total = partial + carry;
+                carry = product.y + select(0u, 1u, partial < product.x) + select(0u, 1u, total < partial);
+                magnitude[i + j] = total;
+            } else if any(product != vec2<u32>(0u)) {
+                overflow = true;
+            }
+        }
+        overflow = overflow || carry != 0u;
+    }
+    // the magnitude of i128::MIN is the only one with the sign bit set that fits
+    let min_magnitude = vec4<u32>(0u, 0u, 0u, 0x80000000u);
+    overflow = overflow || (is_negative_128(magnitude) && !(negative && eq_128(magnitude, min_magnitude)));
+    return Mul128(select(magnitude, neg_128(magnitude), negative), overflow);
+}
+
+fn eq_128(left: vec4<u32>, right: vec4<u32>) -> bool {
+    return all(left == right);
+}
+
+fn gt_i128(left: vec4<u32>, right: vec4<u32>) -> bool {
+    let left_high = bitcast<i32>(left.w);
+    let right_high = bitcast<i32>(right.w);
+    if left_high != right_high {
+        return left_high > right_high;
+    }
+    if left.z != right.z {
+        return left.z > right.z;
+    }
+    if left.y != right.y {
+        return left.y > right.y;
+    }
+    return left.x > right.x;
+}
+
+fn lt_i128(left: vec4<u32>, right: vec4<u32>) -> bool {
+    return gt_i128(right, left);
+}
+
+struct DivRem128 {
+    quotient: vec4<u32>,
+    remainder: u32,
+}
+
+// divides an unsigned 128 bit value by a u32 smaller than 2^31
+fn div_rem_u128_u32(value: vec4<u32>, divisor: u32) -> DivRem128 {
+    var quotient = vec4<u32>(0u);
+    quotient.w = value.w / divisor;
+    var remainder = value.w - quotient.w * divisor;
+    for (var limb = 2i; limb >= 0i; limb--) {
+        var word = 0u;
+        for (var bit = 31i; bit >= 0i; bit--) {
+            remainder = (remainder << 1u) | ((value[limb] >> u32(bit)) & 1u);
+            if remainder >= divisor {
+                remainder -= divisor;
+                word |= 1u << u32(bit);
+            }
+        }
+        quotient[limb] = word;
+    }
+    return DivRem128(quotient, remainder);
+}
+
+// divides an i128 by 10^(9 * steps) * divisor rounding toward zero,
+// the divisor has to be smaller than 2^31
+fn trunc_div_pow10_i128(value: vec4<u32>, steps: u32, divisor: u32) -> vec4<u32> {
+    var magnitude = abs_128(value);
+    for (var i = 0u; i < steps; i++) {
+        magnitude = div_rem_u128_u32(magnitude, 1000000000u).quotient;
+    }
+    magnitude = div_rem_u128_u32(magnitude, divisor).quotient;
+    return select(magnitude, neg_128(magnitude), is_negative_128(value));
+}
+
+fn i32_to_i128(value: i32) -> vec4<u32> {
+    let sign = select(0u, 0xffffffffu, value < 0);
+    return vec4<u32>(bitcast<u32>(value), sign, sign, sign);
+}
+
+fn i64_to_i128(value: vec2<u32>) -> vec4<u32> {
+    let sign = select(0u, 0xffffffffu, (value.y & 0x80000000u) != 0u);
+    return vec4<u32>(value.x, value.y, sign, sign);
+}
+
+fn i128_to_f32(value: vec4<u32>) -> f32 {
+    let magnitude = abs_128(value);
+    let result = ((f32(magnitude.w) * 4294967296.0 + f32(magnitude.z)) * 4294967296.0
+        + f32(magnitude.y)) * 4294967296.0 + f32(magnitude.x);
+    return select(result, -result, is_negative_128(value));
+}
+
+// converts a finite f32 to i128 rounding toward zero, values out of range wrap
+fn f32_to_i128(value: f32) -> vec4<u32> {
+    let bits = bitcast<u32>(abs(value));
+    let exponent = i32(bits >> 23u) - 150;
+    var magnitude = vec4<u32>(0u);
+    if (bits >> 23u) != 0u {
+        let mantissa = (bits & 0x7fffffu) | 0x800000u;
+        if exponent < 0 {
+            magnitude.x = select(0u, mantissa >> u32(-exponent), exponent > -24);
+        } else {
+            // shift the 24 bit mantissa left by exponent bits
+            let limb = u32(exponent) / 32u;
+            let shift = u32(exponent) % 32u;
+            if limb < 4u {
+                magnitude[limb] = mantissa << shift;
+            }
+            if shift != 0u && limb + 1u < 4u {
+                magnitude[limb + 1u] = mantissa >> (32u - shift);
+            }
+        }
+    }
+    return select(magnitude, neg_128(magnitude), value < 0.0);
+}
@@ -0,0 +1,46 @@
+// 64 bit values are stored as vec2<u32> in little endian order.
+// x holds the low 32 bits and y holds the high 32 bits along with the sign.
+// Only the high word needs to be compared as signed, equality and the
+// arithmetic helpers from compute_shaders/u64/utils.wgsl are shared.
+
+fn gt_i64(left: vec2<u32>, right: vec2<u32>) -> bool {
+    let left_high = bitcast<i32>(left.y);
+    let right_high = bitcast<i32>(right.y);
+    return left_high > right_high || (left_high == right_high && left.x > right.x);
+}
+
+fn lt_i64(left: vec2<u32>, right: vec2<u32>) -> bool {
+    return gt_i64(right, left);
+}
+
+fn is_negative_i64(value: vec2<u32>) -> bool {
+    return (value.y & 0x80000000u) != 0u;
+}
+
+fn neg_i64(value: vec2<u32>) -> vec2<u32> {
+    return sub_64(vec2<u32>(0u, 0u), value);
+}
+
+// divides an i64 by a positive u32 smaller than 2^31, rounding toward zero
+fn trunc_div_i64_u32(value: vec2<u32>, divisor: u32) -> vec2<u32> {
+    if is_negative_i64(value) {
+        return neg_i64(div_rem_u64_u32(neg_i64(value), divisor).quotient);
+    }
+    return div_rem_u64_u32(value, divisor).quotient;
+}
+
+// divides an i64 by a positive u32 smaller than 2^31, rounding toward negative infinity.
+// The remainder is always positive.
+fn floor_div_rem_i64_u32(value: vec2<u32>, divisor: u32) -> DivRem64 {
+    if is_negative_i64(value) {
+        let result = div_rem_u64_u32(neg_i64(value), divisor);
+        if result.remainder == 0u {
+            return DivRem64(neg_i64(result.quotient), 0u);
+        }
+        return DivRem64(
+            sub_64(neg_i64(result.quotient), vec2<u32>(1u, 0u)),
+            divisor - result.remainder
+        );
+    }
+    return div_rem_u64_u32(value, divisor);
+}
@@ -0,0 +1,72 @@
+// 64 bit values are stored as vec2<u32> in little endian order.
+// x holds the low 32 bits and y holds the high 32 bits.
+// Addition, subtraction and multiplication are the same for
+// signed and unsigned values in two's complement.
+
+fn add_64(left: vec2<u32>, right: vec2<u32>) -> vec2<u32> {
+    let low = left.x + right.x;
+    let carry = select(0u, 1u, low < left.x);
+    return vec2<u32>(low, left.y + right.y + carry);
+}
+
+fn sub_64(left: vec2<u32>, right: vec2<u32>) -> vec2<u32> {
+    let low = left.x - right.x;
+    let borrow = select(0u, 1u, left.x < right.x);
+    return vec2<u32>(low, left.y - right.y - borrow);
+}
+
+// returns the full 64 bit product of two u32
+fn mul_wide_32(left: u32, right: u32) -> vec2<u32> {
+    let l_lo = left & 0xffffu;
+    let l_hi = left >> 16u;
+    let r_lo = right & 0xffffu;
+    let r_hi = right >> 16u;
+
+    let lo_lo = l_lo * r_lo;
+    let hi_lo = l_hi * r_lo;
+    let lo_hi = l_lo * r_hi;
+    let hi_hi = l_hi * r_hi;
+
+    let cross = (lo_lo >> 16u) + (hi_lo & 0xffffu) + (lo_hi & 0xffffu);
+    let low = (cross << 16u) | (lo_lo & 0xffffu);
+    let high = hi_hi + (hi_lo >> 16u) + (lo_hi >> 16u) + (cross >> 16u);
+    return vec2<u32>(low, high);
+}
+
+fn mul_64(left: vec2<u32>, right: vec2<u32>) -> vec2<u32> {
+    let product = mul_wide_32(left.x, right.x);
+    return vec2<u32>(product.x, product.y + left.x * right.y + left.y * right.x);
+}
+
+fn eq_64(left: vec2<u32>, right: vec2<u32>) -> bool {
+    return left.x == right.x && left.y == right.y;
+}
+
+fn gt_u64(left: vec2<u32>, right: vec2<u32>) -> bool {
+    return left.y > right.y || (left.y == right.y && left.x > right.x);
+}
+
+fn lt_u64(left: vec2<u32>, right: vec2<u32>) -> bool {
+    return gt_u64(right, left);
+}
+
+struct DivRem64 {
+    quotient: vec2<u32>,
+    remainder: u32,
+}
+
+// divides a u64 by a u32, the divisor has to be smaller than 2^31
+// so the running remainder can be shifted without overflowing
+fn div_rem_u64_u32(value: vec2<u32>, divisor: u32) -> DivRem64 {
+    let high = value.y / divisor;
+    var remainder = value.y - high * divisor;
+    var low = 0u;
+    for (var bit = 31i; bit >= 0i; bit--) {
+        remainder = (remainder << 1u) | ((value.x >> u32(bit)) & 1u);
+        if remainder >= divisor {
+            remainder -= divisor;
+            low |= 1u << u32(bit);
+        }
+    }
+    return DivRem64(vec2<u32>(low, high), remainder);
+}
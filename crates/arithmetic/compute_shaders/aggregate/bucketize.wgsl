@@ -0,0 +1,49 @@
+// Buckets of the values, bucketize finds the number of boundaries not above each value by
+// binary search. Expects the prelude of ds_values.wgsl.
+
+struct Params {
+    len: u32,
+    boundaries: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+// sorted f32 boundaries
+@group(0)
+@binding(3)
+var<storage, read> boundaries: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn bucketize(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len {
+        return;
+    }
+    let value = load_ds(row);
+    // NaN is above every boundary like in the sort
+    var low = params.boundaries;
+    if !is_nan_ds(value) {
+        low = 0u;
+        var high = params.boundaries;
+        while low < high {
+            let middle = (low + high) / 2u;
+            if ds_ge(value, vec2<f32>(boundaries[middle], 0.0)) {
+                low = middle + 1u;
+            } else {
+                high = middle;
+            }
+        }
+    }
+    output[row] = low;
+}
@@ -0,0 +1,40 @@
+struct Params {
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<atomic<u32>>;
+
+var<workgroup> block_count: atomic<u32>;
+
+// counts the set bits of one validity word per invocation
+@compute
+@workgroup_size(256)
+fn count_valid(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>
+) {
+    let word = global_id.x;
+    if word * 32u < params.len {
+        var bits = validity[word];
+        let rows = params.len - word * 32u;
+        if rows < 32u {
+            bits &= (1u << rows) - 1u;
+        }
+        atomicAdd(&block_count, countOneBits(bits));
+    }
+    workgroupBarrier();
+
+    if local_id.x == 0u {
+        atomicAdd(&output[0], atomicLoad(&block_count));
+    }
+}
@@ -0,0 +1,179 @@
+// Covariance and correlation of the rows where both arrays are valid. Like variance.wgsl the
+// blocks merge the count, the means, the sums of squared differences from the means and the
+// sum of the products of the differences (c) in double-single, and write them as 11 words.
+// The covariance is null unless there are more than ddof rows, the correlation unless there
+// are at least 2.
+// Expects the prelude of ds_values.wgsl.
+
+struct Params {
+    len: u32,
+    ddof: u32,
+    correlation: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> other: array<u32>;
+
+// rows where both arrays are valid
+@group(0)
+@binding(4)
+var<storage, read> validity: array<u32>;
+
+struct CoMoments {
+    count: u32,
+    mean_x: vec2<f32>,
+    mean_y: vec2<f32>,
+    m2_x: vec2<f32>,
+    m2_y: vec2<f32>,
+    c: vec2<f32>,
+}
+
+const wg_size = 256u;
+
+var<workgroup> shared_moments: array<CoMoments, wg_size>;
+
+fn load_other_ds(row: u32) -> vec2<f32> {
+    let word = value_word(row);
+    var words = vec2<u32>(other[word], 0u);
+    if VALUE_WORDS == 2u {
+        words.y = other[word + 1u];
+    }
+    return decode_ds(words, row);
+}
+
+fn merge(a: CoMoments, b: CoMoments) -> CoMoments {
+    if a.count == 0u {
+        return b;
+    }
+    if b.count == 0u {
+        return a;
+    }
+    let count = a.count + b.count;
+    // n_b / n and n_a * n_b / n
+    let weight = ds_div(u32_to_ds(b.count), u32_to_ds(count));
+    let factor = ds_mul(u32_to_ds(a.count), weight);
+    let delta_x = ds_sub(b.mean_x, a.mean_x);
+    let delta_y = ds_sub(b.mean_y, a.mean_y);
+    return CoMoments(
+        count,
+        ds_add(a.mean_x, ds_mul(delta_x, weight)),
+        ds_add(a.mean_y, ds_mul(delta_y, weight)),
+        ds_add(ds_add(a.m2_x, b.m2_x), ds_mul(ds_mul(delta_x, delta_x), factor)),
+        ds_add(ds_add(a.m2_y, b.m2_y), ds_mul(ds_mul(delta_y, delta_y), factor)),
+        ds_add(ds_add(a.c, b.c), ds_mul(ds_mul(delta_x, delta_y), factor))
+    );
+}
+
+fn reduce_block(local: u32, moments: CoMoments) {
+    shared_moments[local] = moments;
+    workgroupBarrier();
+
+    for (var s = wg_size / 2u; s > 0u; s /= 2u) {
+        if local < s {
+            shared_moments[local] = merge(shared_moments[local], shared_moments[local + s]);
+        }
+        workgroupBarrier();
+    }
+}
+
+fn write_ds(index: u32, value: vec2<f32>) {
+    let bits = ds_to_bits(value);
+    output[index] = bits.x;
+    output[index + 1u] = bits.y;
+}
+
+fn read_ds(index: u32) -> vec2<f32> {
+    return bits_to_ds(vec2<u32>(input[index], input[index + 1u]));
+}
+
+fn write_block(block: u32, blocks: u32) {
+    let moments = shared_moments[0];
+    if blocks == 1u {
+        var valid = moments.count > params.ddof;
+        if params.correlation == 1u {
+            valid = moments.count >= 2u;
+        }
+        var result = vec2<u32>(0u, 0x7ff80000u);
+        if valid && params.correlation == 1u {
+            let deviations = ds_mul(ds_sqrt(moments.m2_x), ds_sqrt(moments.m2_y));
+            result = ds_to_f64(ds_div(moments.c, deviations));
+        } else if valid {
+            result = ds_to_f64(ds_div(moments.c, u32_to_ds(moments.count - params.ddof)));
+        }
+        output[0] = result.x;
+        output[1] = result.y;
+        output[2] = u32(valid);
+        return;
+    }
+    let base = block * 11u;
+    output[base] = moments.count;
+    write_ds(base + 1u, moments.mean_x);
+    write_ds(base + 3u, moments.mean_y);
+    write_ds(base + 5u, moments.m2_x);
+    write_ds(base + 7u, moments.m2_y);
+    write_ds(base + 9u, moments.c);
+}
+
+@compute
+@workgroup_size(256)
+fn covariance_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    let zero = vec2<f32>(0.0, 0.0);
+    var moments = CoMoments(0u, zero, zero, zero, zero, zero);
+    if row < params.len && is_valid(row) {
+        moments = CoMoments(1u, load_ds(row), load_other_ds(row), zero, zero, zero);
+    }
+
+    reduce_block(local_id.x, moments);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
+
+// input holds the moments of the blocks of the previous level, other is not read
+@compute
+@workgroup_size(256)
+fn covariance_lanes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    let zero = vec2<f32>(0.0, 0.0);
+    var moments = CoMoments(0u, zero, zero, zero, zero, zero);
+    if row < params.len {
+        let base = row * 11u;
+        moments = CoMoments(
+            input[base],
+            read_ds(base + 1u),
+            read_ds(base + 3u),
+            read_ds(base + 5u),
+            read_ds(base + 7u),
+            read_ds(base + 9u)
+        );
+    }
+
+    reduce_block(local_id.x, moments);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
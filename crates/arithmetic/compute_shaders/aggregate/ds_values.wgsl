@@ -0,0 +1,43 @@
+// Helpers of the aggregates computed in double-single, see compute_shaders/f64/utils.wgsl.
+// Expects a prelude defining `value_word` and `decode_ds` for the type of `input`.
+
+// exact double-single of a u32, both halves of 16 bits convert exactly to f32
+fn u32_to_ds(value: u32) -> vec2<f32> {
+    return two_sum(f32(value & 0xffff0000u), f32(value & 0xffffu));
+}
+
+// value of row in double-single
+fn load_ds(row: u32) -> vec2<f32> {
+    let word = value_word(row);
+    var words = vec2<u32>(input[word], 0u);
+    if VALUE_WORDS == 2u {
+        words.y = input[word + 1u];
+    }
+    return decode_ds(words, row);
+}
+
+// largest integer not above value, exact while it fits in the double-single
+fn ds_floor(value: vec2<f32>) -> vec2<f32> {
+    let high = floor(value.x);
+    if high != value.x {
+        return vec2<f32>(high, 0.0);
+    }
+    return quick_two_sum(high, floor(value.y));
+}
+
+fn ds_to_bits(value: vec2<f32>) -> vec2<u32> {
+    return bitcast<vec2<u32>>(value);
+}
+
+fn bits_to_ds(bits: vec2<u32>) -> vec2<f32> {
+    return bitcast<vec2<f32>>(bits);
+}
+
+fn is_nan_ds(value: vec2<f32>) -> bool {
+    return (bitcast<u32>(value.x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+// value is not below other
+fn ds_ge(value: vec2<f32>, other: vec2<f32>) -> bool {
+    return value.x > other.x || (value.x == other.x && value.y >= other.y);
+}
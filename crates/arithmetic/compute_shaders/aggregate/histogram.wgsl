@@ -0,0 +1,95 @@
+// Bins of the valid values. histogram counts the values in `bins` bins of equal width over
+// [lower, upper], the last bin includes upper and values outside the range or NaN are not
+// counted. Up to LOCAL_BINS bins are counted in workgroup memory first, then added to the
+// counts. Expects the prelude of ds_values.wgsl and validity.wgsl.
+
+const LOCAL_BINS = 2048u;
+
+struct HistogramParams {
+    len: u32,
+    bins: u32,
+    _padding: vec2<u32>,
+    // f64 bits of the range
+    lower: vec2<u32>,
+    upper: vec2<u32>,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: HistogramParams;
+
+@group(0)
+@binding(2)
+var<storage, read_write> counts: array<atomic<u32>>;
+
+@group(0)
+@binding(3)
+var<storage, read> validity: array<u32>;
+
+const wg_size = 256u;
+
+var<workgroup> local_counts: array<atomic<u32>, LOCAL_BINS>;
+
+// lower edge of bin
+fn bin_edge(bin: u32, lower: vec2<f32>, width: vec2<f32>) -> vec2<f32> {
+    return ds_add(lower, ds_mul(width, u32_to_ds(bin)));
+}
+
+// bin of a value in the range, checked against the edges since the division may round
+// a value on an edge into the previous bin
+fn value_bin(value: vec2<f32>, lower: vec2<f32>, width: vec2<f32>) -> u32 {
+    let position = ds_floor(ds_div(ds_sub(value, lower), width));
+    var bin = min(u32(max(position.x + position.y, 0.0)), params.bins - 1u);
+    if bin > 0u && !ds_ge(value, bin_edge(bin, lower, width)) {
+        bin -= 1u;
+    } else if bin + 1u < params.bins && ds_ge(value, bin_edge(bin + 1u, lower, width)) {
+        bin += 1u;
+    }
+    return bin;
+}
+
+@compute
+@workgroup_size(256)
+fn histogram(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>
+) {
+    let row = global_id.x;
+    let local = local_id.x;
+    let use_local = params.bins <= LOCAL_BINS;
+    if use_local {
+        for (var bin = local; bin < params.bins; bin += wg_size) {
+            atomicStore(&local_counts[bin], 0u);
+        }
+    }
+    workgroupBarrier();
+
+    if row < params.len && is_valid(row) {
+        let value = load_ds(row);
+        let lower = f64_to_ds(params.lower);
+        let upper = f64_to_ds(params.upper);
+        if !is_nan_ds(value) && ds_ge(value, lower) && ds_ge(upper, value) {
+            let width = ds_div(ds_sub(upper, lower), u32_to_ds(params.bins));
+            let bin = value_bin(value, lower, width);
+            if use_local {
+                atomicAdd(&local_counts[bin], 1u);
+            } else {
+                atomicAdd(&counts[bin], 1u);
+            }
+        }
+    }
+    workgroupBarrier();
+
+    if use_local {
+        for (var bin = local; bin < params.bins; bin += wg_size) {
+            let count = atomicLoad(&local_counts[bin]);
+            if count != 0u {
+                atomicAdd(&counts[bin], count);
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// 16 bit values, unsigned when VALUE_KIND is 0, signed when 1 and f16 when 2.
+// decode_ds takes the words holding the value, see load_ds.
+
+const VALUE_WORDS = 1u;
+
+fn value_word(row: u32) -> u32 {
+    return row / 2u;
+}
+
+fn decode_ds(words: vec2<u32>, row: u32) -> vec2<f32> {
+    if VALUE_KIND == 2u {
+        return vec2<f32>(load_half2(words.x)[row % 2u], 0.0);
+    }
+    let value = (words.x >> ((row % 2u) * 16u)) & 0xffffu;
+    if VALUE_KIND == 1u {
+        return vec2<f32>(f32(bitcast<i32>(value << 16u) >> 16u), 0.0);
+    }
+    return vec2<f32>(f32(value), 0.0);
+}
@@ -0,0 +1,20 @@
+// 32 bit values, unsigned when VALUE_KIND is 0, signed when 1 and f32 when 2.
+// decode_ds takes the words holding the value, see load_ds.
+
+const VALUE_WORDS = 1u;
+
+fn value_word(row: u32) -> u32 {
+    return row;
+}
+
+fn decode_ds(words: vec2<u32>, row: u32) -> vec2<f32> {
+    let value = words.x;
+    if VALUE_KIND == 2u {
+        return vec2<f32>(bitcast<f32>(value), 0.0);
+    }
+    if VALUE_KIND == 1u {
+        // the high half keeps the sign and converts exactly
+        return two_sum(f32(bitcast<i32>(value & 0xffff0000u)), f32(value & 0xffffu));
+    }
+    return u32_to_ds(value);
+}
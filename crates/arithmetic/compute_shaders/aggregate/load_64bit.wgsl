@@ -0,0 +1,22 @@
+// 64 bit values, unsigned when VALUE_KIND is 0, signed when 1 and f64 when 2.
+// decode_ds takes the words holding the value, see load_ds.
+
+const VALUE_WORDS = 2u;
+
+fn value_word(row: u32) -> u32 {
+    return row * 2u;
+}
+
+fn u64_to_ds(value: vec2<u32>) -> vec2<f32> {
+    return ds_add(u32_to_ds(value.y) * 0x1p32f, u32_to_ds(value.x));
+}
+
+fn decode_ds(words: vec2<u32>, row: u32) -> vec2<f32> {
+    if VALUE_KIND == 2u {
+        return f64_to_ds(words);
+    }
+    if VALUE_KIND == 1u && (words.y & 0x80000000u) != 0u {
+        return -u64_to_ds(sub_64(vec2<u32>(0u, 0u), words));
+    }
+    return u64_to_ds(words);
+}
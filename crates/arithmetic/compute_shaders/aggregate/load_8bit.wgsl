@@ -0,0 +1,16 @@
+// 8 bit values, signed when VALUE_KIND is 1.
+// decode_ds takes the words holding the value, see load_ds.
+
+const VALUE_WORDS = 1u;
+
+fn value_word(row: u32) -> u32 {
+    return row / 4u;
+}
+
+fn decode_ds(words: vec2<u32>, row: u32) -> vec2<f32> {
+    let value = (words.x >> ((row % 4u) * 8u)) & 0xffu;
+    if VALUE_KIND == 1u {
+        return vec2<f32>(f32(bitcast<i32>(value << 24u) >> 24u), 0.0);
+    }
+    return vec2<f32>(f32(value), 0.0);
+}
@@ -0,0 +1,106 @@
+// Mean of the valid values. Values are summed in double-single, see compute_shaders/f64/utils.wgsl,
+// and each block writes its sum and count as 3 words. The blocks are reduced recursively by
+// mean_lanes, the last level runs a single block which writes the mean as f64 bits.
+// Expects the prelude of ds_values.wgsl.
+
+struct Params {
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> validity: array<u32>;
+
+const wg_size = 256u;
+
+var<workgroup> shared_sum: array<vec2<f32>, wg_size>;
+var<workgroup> shared_count: array<u32, wg_size>;
+
+fn reduce_block(local: u32, sum: vec2<f32>, count: u32) {
+    shared_sum[local] = sum;
+    shared_count[local] = count;
+    workgroupBarrier();
+
+    for (var s = wg_size / 2u; s > 0u; s /= 2u) {
+        if local < s {
+            shared_sum[local] = ds_add(shared_sum[local], shared_sum[local + s]);
+            shared_count[local] += shared_count[local + s];
+        }
+        workgroupBarrier();
+    }
+}
+
+fn write_block(block: u32, blocks: u32) {
+    let sum = shared_sum[0];
+    let count = shared_count[0];
+    if blocks == 1u {
+        var mean = vec2<u32>(0u, 0x7ff80000u);
+        if count != 0u {
+            mean = ds_to_f64(ds_div(sum, u32_to_ds(count)));
+        }
+        output[0] = mean.x;
+        output[1] = mean.y;
+        return;
+    }
+    let sum_bits = ds_to_bits(sum);
+    output[block * 3u] = sum_bits.x;
+    output[block * 3u + 1u] = sum_bits.y;
+    output[block * 3u + 2u] = count;
+}
+
+@compute
+@workgroup_size(256)
+fn mean_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    var sum = vec2<f32>(0.0, 0.0);
+    var count = 0u;
+    if row < params.len && is_valid(row) {
+        sum = load_ds(row);
+        count = 1u;
+    }
+
+    reduce_block(local_id.x, sum, count);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
+
+// input holds the sums and counts of the blocks of the previous level
+@compute
+@workgroup_size(256)
+fn mean_lanes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    var sum = vec2<f32>(0.0, 0.0);
+    var count = 0u;
+    if row < params.len {
+        sum = bits_to_ds(vec2<u32>(input[row * 3u], input[row * 3u + 1u]));
+        count = input[row * 3u + 2u];
+    }
+
+    reduce_block(local_id.x, sum, count);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
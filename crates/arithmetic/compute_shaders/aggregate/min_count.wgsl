@@ -0,0 +1,24 @@
+struct Params {
+    min_count: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> count: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> validity: array<u32>;
+
+// the aggregate is null when it was computed from fewer than min_count values
+@compute
+@workgroup_size(1)
+fn min_count_validity() {
+    if count[0] < params.min_count {
+        validity[0] &= ~1u;
+    }
+}
@@ -0,0 +1,74 @@
+// Quantiles of the valid values. quantile_sorted.wgsl reads the values at the ranks around
+// the quantile in the sorted values. The approximate path counts the values in APPROX_BINS
+// bins of equal width between the minimum and the maximum with quantile_histogram.wgsl,
+// then quantile_approx.wgsl interpolates the values at the ranks within their bins.
+// Both write the result as f64 bits followed by its validity.
+// Expects the prelude of ds_values.wgsl, the shaders declare `params`.
+
+const LINEAR = 0u;
+const LOWER = 1u;
+const HIGHER = 2u;
+const NEAREST = 3u;
+const MIDPOINT = 4u;
+
+const APPROX_BINS = 2048u;
+
+struct Params {
+    len: u32,
+    interpolation: u32,
+    // row of the maximum in bounds
+    max_row: u32,
+    _padding: u32,
+    // f64 bits of the quantile
+    q: vec2<u32>,
+}
+
+struct Rank {
+    low: u32,
+    high: u32,
+    fraction: vec2<f32>,
+}
+
+// ranks of the values around the quantile of `count` values, at q * (count - 1)
+fn quantile_rank(count: u32) -> Rank {
+    let position = ds_mul(f64_to_ds(params.q), u32_to_ds(count - 1u));
+    var floor_position = ds_floor(position);
+    var fraction = ds_sub(position, floor_position);
+    // positions that are integers in f64, like 0.1 * 10, may fall just below them in
+    // double-single, which carries fewer bits
+    let nearest = ds_floor(ds_add(position, vec2<f32>(0.5, 0.0)));
+    if abs(ds_sub(position, nearest).x) <= max(position.x, 1.0) * 0x1p-40f {
+        floor_position = nearest;
+        fraction = vec2<f32>(0.0, 0.0);
+    }
+    let low = min(u32(floor_position.x) + bitcast<u32>(i32(floor_position.y)), count - 1u);
+    var high = low;
+    if fraction.x > 0.0 {
+        high = min(low + 1u, count - 1u);
+    }
+    return Rank(low, high, fraction);
+}
+
+// f64 bits of the quantile from the f64 bits of the values at the ranks
+fn interpolate(rank: Rank, low: vec2<u32>, high: vec2<u32>) -> vec2<u32> {
+    if rank.low == rank.high || params.interpolation == LOWER {
+        return low;
+    }
+    if params.interpolation == HIGHER {
+        return high;
+    }
+    if params.interpolation == NEAREST {
+        let half = ds_sub(rank.fraction, vec2<f32>(0.5, 0.0));
+        // ties go to the even rank
+        if half.x < 0.0 || (half.x == 0.0 && (rank.low & 1u) == 0u) {
+            return low;
+        }
+        return high;
+    }
+    let low_ds = f64_to_ds(low);
+    let high_ds = f64_to_ds(high);
+    if params.interpolation == MIDPOINT {
+        return ds_to_f64(ds_mul(ds_add(low_ds, high_ds), vec2<f32>(0.5, 0.0)));
+    }
+    return ds_to_f64(ds_add(low_ds, ds_mul(ds_sub(high_ds, low_ds), rank.fraction)));
+}
@@ -0,0 +1,70 @@
+// Approximate quantile from the counts of quantile_histogram.wgsl.
+// Expects the prelude of quantile.wgsl.
+
+// the minimum followed by the maximum, in the type of the values
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> bin_counts: array<u32>;
+
+fn write_result(result: vec2<u32>, valid: bool) {
+    output[0] = result.x;
+    output[1] = result.y;
+    output[2] = u32(valid);
+}
+
+// value at rank, spreading the values of its bin evenly over the bin
+fn approx_value(rank: u32, count: u32, minimum: vec2<f32>, maximum: vec2<f32>) -> vec2<u32> {
+    if rank == 0u {
+        return ds_to_f64(minimum);
+    }
+    if rank == count - 1u {
+        return ds_to_f64(maximum);
+    }
+    var before = 0u;
+    var bin = 0u;
+    for (; bin < APPROX_BINS - 1u; bin++) {
+        let count = bin_counts[bin];
+        if before + count > rank {
+            break;
+        }
+        before += count;
+    }
+    let offset = (f32(rank - before) + 0.5) / f32(max(bin_counts[bin], 1u));
+    let width = ds_mul(ds_sub(maximum, minimum), vec2<f32>(1.0 / f32(APPROX_BINS), 0.0));
+    var value = ds_add(minimum, ds_mul(width, ds_add(u32_to_ds(bin), vec2<f32>(offset, 0.0))));
+    if ds_sub(value, maximum).x > 0.0 {
+        value = maximum;
+    }
+    return ds_to_f64(value);
+}
+
+@compute
+@workgroup_size(1)
+fn quantile_approx() {
+    var count = 0u;
+    for (var bin = 0u; bin < APPROX_BINS; bin++) {
+        count += bin_counts[bin];
+    }
+    if count == 0u {
+        write_result(vec2<u32>(0u, 0x7ff80000u), false);
+        return;
+    }
+    let minimum = load_ds(0u);
+    let maximum = load_ds(params.max_row);
+    let rank = quantile_rank(count);
+    let low = approx_value(rank.low, count, minimum, maximum);
+    let high = approx_value(rank.high, count, minimum, maximum);
+    write_result(interpolate(rank, low, high), true);
+}
@@ -0,0 +1,69 @@
+// Counts of the valid values in APPROX_BINS bins of equal width between the minimum and
+// the maximum. Expects the prelude of quantile.wgsl and validity.wgsl.
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> histogram: array<atomic<u32>>;
+
+@group(0)
+@binding(3)
+var<storage, read> validity: array<u32>;
+
+// the minimum followed by the maximum, in the type of the values
+@group(0)
+@binding(4)
+var<storage, read> bounds: array<u32>;
+
+const wg_size = 256u;
+
+var<workgroup> local_histogram: array<atomic<u32>, APPROX_BINS>;
+
+fn load_bound(row: u32) -> vec2<f32> {
+    let word = value_word(row);
+    var words = vec2<u32>(bounds[word], 0u);
+    if VALUE_WORDS == 2u {
+        words.y = bounds[word + 1u];
+    }
+    return decode_ds(words, row);
+}
+
+@compute
+@workgroup_size(256)
+fn quantile_histogram(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>
+) {
+    let row = global_id.x;
+    let local = local_id.x;
+    for (var bin = local; bin < APPROX_BINS; bin += wg_size) {
+        atomicStore(&local_histogram[bin], 0u);
+    }
+    workgroupBarrier();
+
+    if row < params.len && is_valid(row) {
+        let minimum = load_bound(0u);
+        let range = ds_sub(load_bound(params.max_row), minimum);
+        var bin = 0u;
+        if range.x > 0.0 {
+            let position = ds_div(ds_sub(load_ds(row), minimum), range).x * f32(APPROX_BINS);
+            bin = min(u32(max(position, 0.0)), APPROX_BINS - 1u);
+        }
+        atomicAdd(&local_histogram[bin], 1u);
+    }
+    workgroupBarrier();
+
+    for (var bin = local; bin < APPROX_BINS; bin += wg_size) {
+        let count = atomicLoad(&local_histogram[bin]);
+        if count != 0u {
+            atomicAdd(&histogram[bin], count);
+        }
+    }
+}
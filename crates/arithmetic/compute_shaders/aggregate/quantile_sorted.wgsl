@@ -0,0 +1,53 @@
+// Quantile read from the values sorted with the nulls last.
+// Expects the prelude of quantile.wgsl.
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+// the number of valid values
+@group(0)
+@binding(3)
+var<storage, read> valid_count: array<u32>;
+
+// f64 bits of the value of row, 64 bit values are converted without going through
+// double-single which only carries 48 bits
+fn load_f64(row: u32) -> vec2<u32> {
+    if VALUE_WORDS == 2u {
+        let words = vec2<u32>(input[row * 2u], input[row * 2u + 1u]);
+        if VALUE_KIND == 2u {
+            return words;
+        }
+        if VALUE_KIND == 1u {
+            return i64_to_f64(words);
+        }
+        return u64_to_f64(words);
+    }
+    return ds_to_f64(load_ds(row));
+}
+
+fn write_result(result: vec2<u32>, valid: bool) {
+    output[0] = result.x;
+    output[1] = result.y;
+    output[2] = u32(valid);
+}
+
+@compute
+@workgroup_size(1)
+fn quantile_sorted() {
+    let count = valid_count[0];
+    if count == 0u {
+        write_result(vec2<u32>(0u, 0x7ff80000u), false);
+        return;
+    }
+    let rank = quantile_rank(count);
+    write_result(interpolate(rank, load_f64(rank.low), load_f64(rank.high)), true);
+}
@@ -0,0 +1,5 @@
+// Validity of the rows of the aggregates, expects the shader to declare `validity`
+
+fn is_valid(row: u32) -> bool {
+    return ((validity[row / 32u] >> (row % 32u)) & 1u) != 0u;
+}
@@ -0,0 +1,141 @@
+// Variance and standard deviation of the valid values. Each block merges the count, mean and
+// sum of squared differences from the mean (m2) of its values with the parallel algorithm of
+// Chan et al. in double-single, and writes them as 5 words. The blocks are reduced recursively
+// by variance_lanes, the last level runs a single block which writes the result as f64 bits
+// followed by its validity, the result is null unless there are more than ddof values.
+// Expects the prelude of ds_values.wgsl.
+
+struct Params {
+    len: u32,
+    ddof: u32,
+    stddev: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> validity: array<u32>;
+
+struct Moments {
+    count: u32,
+    mean: vec2<f32>,
+    m2: vec2<f32>,
+}
+
+const wg_size = 256u;
+
+var<workgroup> shared_moments: array<Moments, wg_size>;
+
+fn merge(a: Moments, b: Moments) -> Moments {
+    if a.count == 0u {
+        return b;
+    }
+    if b.count == 0u {
+        return a;
+    }
+    let count = a.count + b.count;
+    // n_b / n and n_a * n_b / n
+    let weight = ds_div(u32_to_ds(b.count), u32_to_ds(count));
+    let factor = ds_mul(u32_to_ds(a.count), weight);
+    let delta = ds_sub(b.mean, a.mean);
+    let mean = ds_add(a.mean, ds_mul(delta, weight));
+    let m2 = ds_add(ds_add(a.m2, b.m2), ds_mul(ds_mul(delta, delta), factor));
+    return Moments(count, mean, m2);
+}
+
+fn reduce_block(local: u32, moments: Moments) {
+    shared_moments[local] = moments;
+    workgroupBarrier();
+
+    for (var s = wg_size / 2u; s > 0u; s /= 2u) {
+        if local < s {
+            shared_moments[local] = merge(shared_moments[local], shared_moments[local + s]);
+        }
+        workgroupBarrier();
+    }
+}
+
+fn write_block(block: u32, blocks: u32) {
+    let moments = shared_moments[0];
+    if blocks == 1u {
+        let valid = moments.count > params.ddof;
+        var result = vec2<u32>(0u, 0x7ff80000u);
+        if valid {
+            var variance = ds_div(moments.m2, u32_to_ds(moments.count - params.ddof));
+            if params.stddev == 1u {
+                variance = ds_sqrt(variance);
+            }
+            result = ds_to_f64(variance);
+        }
+        output[0] = result.x;
+        output[1] = result.y;
+        output[2] = u32(valid);
+        return;
+    }
+    let mean = ds_to_bits(moments.mean);
+    let m2 = ds_to_bits(moments.m2);
+    output[block * 5u] = moments.count;
+    output[block * 5u + 1u] = mean.x;
+    output[block * 5u + 2u] = mean.y;
+    output[block * 5u + 3u] = m2.x;
+    output[block * 5u + 4u] = m2.y;
+}
+
+@compute
+@workgroup_size(256)
+fn variance_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    let zero = vec2<f32>(0.0, 0.0);
+    var moments = Moments(0u, zero, zero);
+    if row < params.len && is_valid(row) {
+        moments = Moments(1u, load_ds(row), zero);
+    }
+
+    reduce_block(local_id.x, moments);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
+
+// input holds the moments of the blocks of the previous level
+@compute
+@workgroup_size(256)
+fn variance_lanes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let row = global_id.x;
+    let zero = vec2<f32>(0.0, 0.0);
+    var moments = Moments(0u, zero, zero);
+    if row < params.len {
+        let base = row * 5u;
+        moments = Moments(
+            input[base],
+            bits_to_ds(vec2<u32>(input[base + 1u], input[base + 2u])),
+            bits_to_ds(vec2<u32>(input[base + 3u], input[base + 4u]))
+        );
+    }
+
+    reduce_block(local_id.x, moments);
+    if local_id.x == 0u {
+        write_block(wg_id.x, num_workgroups.x);
+    }
+}
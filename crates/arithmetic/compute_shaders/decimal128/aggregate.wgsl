@@ -0,0 +1,101 @@
+// Sums are accumulated in 160 bits, a 128 bit value in `low` and the top 32 bits in `high`,
+// so the sum of up to 2^32 decimals can not overflow before the final check.
+// Partial sums are stored as two consecutive vec4, the second one only uses x.
+
+@group(0)
+@binding(0)
+var<storage, read> input_data: array<vec4<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output_data: array<vec4<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read> validity: array<u32>;
+
+const wg_size = 256u;
+
+var<workgroup> shared_low: array<vec4<u32>, wg_size>;
+var<workgroup> shared_high: array<u32, wg_size>;
+
+fn is_valid(index: u32) -> bool {
+    return (validity[index / 32u] & (1u << (index % 32u))) != 0u;
+}
+
+fn reduce_shared(local_id: u32) {
+    workgroupBarrier();
+
+    for (var s = 1u; s < wg_size; s *= 2u) {
+        let index = 2u * s * local_id;
+
+        if index < wg_size && (index + s) < wg_size {
+            let sum = add_128_carry(shared_low[index], shared_low[index + s]);
+            shared_low[index] = sum.sum;
+            shared_high[index] = shared_high[index] + shared_high[index + s] + sum.carry;
+        }
+
+        workgroupBarrier();
+    }
+}
+
+// first pass over the decimal values, null values are skipped
+@compute
+@workgroup_size(256)
+fn sum_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    if global_id.x < arrayLength(&input_data) && is_valid(global_id.x) {
+        let value = input_data[global_id.x];
+        shared_low[local_id.x] = value;
+        shared_high[local_id.x] = select(0u, 0xffffffffu, is_negative_128(value));
+    } else {
+        shared_low[local_id.x] = vec4<u32>(0u);
+        shared_high[local_id.x] = 0u;
+    }
+
+    reduce_shared(local_id.x);
+
+    if local_id.x == 0u {
+        output_data[2u * wg_id.x] = shared_low[0];
+        output_data[2u * wg_id.x + 1u] = vec4<u32>(shared_high[0], 0u, 0u, 0u);
+    }
+}
+
+// reduces the partial sums of the previous pass
+@compute
+@workgroup_size(256)
+fn sum_partials(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    if 2u * global_id.x < arrayLength(&input_data) {
+        shared_low[local_id.x] = input_data[2u * global_id.x];
+        shared_high[local_id.x] = input_data[2u * global_id.x + 1u].x;
+    } else {
+        shared_low[local_id.x] = vec4<u32>(0u);
+        shared_high[local_id.x] = 0u;
+    }
+
+    reduce_shared(local_id.x);
+
+    if local_id.x == 0u {
+        output_data[2u * wg_id.x] = shared_low[0];
+        output_data[2u * wg_id.x + 1u] = vec4<u32>(shared_high[0], 0u, 0u, 0u);
+    }
+}
+
+// writes the final sum followed by its validity bit,
+// the sum is null when it does not fit in an i128
+@compute
+@workgroup_size(1)
+fn sum_finish() {
+    let low = input_data[0];
+    let high = input_data[1].x;
+    let sign = select(0u, 0xffffffffu, is_negative_128(low));
+    output_data[0] = low;
+    output_data[1] = vec4<u32>(select(0u, 1u, high == sign), 0u, 0u, 0u);
+}
@@ -0,0 +1,64 @@
+// functions add_128, sub_128, is_negative_128 and mul_i128_checked are present in compute_shaders/i128/utils.wgsl
+// both operands are rescaled to a common scale before add and sub
+
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<vec4<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<vec4<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec4<u32>>;
+
+// the rows whose result overflows are cleared
+@group(0)
+@binding(3)
+var<storage, read_write> validity: array<atomic<u32>>;
+
+// overflowing rows are zeroed and null
+fn write_checked(index: u32, value: vec4<u32>, overflow: bool) {
+    new_values[index] = select(value, vec4<u32>(0u), overflow);
+    if overflow {
+        atomicAnd(&validity[index / 32u], ~(1u << (index % 32u)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn add_decimal128(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = left_values[global_id.x];
+        let right = right_values[global_id.x];
+        let sum = add_128(left, right);
+        // operands of the same sign overflow when the sign of the sum differs
+        let overflow = is_negative_128(left) == is_negative_128(right)
+            && is_negative_128(sum) != is_negative_128(left);
+        write_checked(global_id.x, sum, overflow);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sub_decimal128(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = left_values[global_id.x];
+        let right = right_values[global_id.x];
+        let difference = sub_128(left, right);
+        // operands of different signs overflow when the sign of the difference is not the left one
+        let overflow = is_negative_128(left) != is_negative_128(right)
+            && is_negative_128(difference) != is_negative_128(left);
+        write_checked(global_id.x, difference, overflow);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn mul_decimal128(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let result = mul_i128_checked(left_values[global_id.x], right_values[global_id.x]);
+        write_checked(global_id.x, result.product, result.overflow);
+    }
+}
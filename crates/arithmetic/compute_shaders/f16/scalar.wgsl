@@ -0,0 +1,55 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16,
+// the scalar is the first value of the operand word
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> operand: u32;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+fn scalar() -> half2 {
+    return half2(load_half2(operand).x);
+}
+
+@compute
+@workgroup_size(256)
+fn f16_add(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = load_half2(original_values[global_id.x]);
+        new_values[global_id.x] = store_half2(value + scalar());
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f16_sub(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = load_half2(original_values[global_id.x]);
+        new_values[global_id.x] = store_half2(value - scalar());
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f16_mul(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = load_half2(original_values[global_id.x]);
+        new_values[global_id.x] = store_half2(value * scalar());
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f16_div(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = load_half2(original_values[global_id.x]);
+        new_values[global_id.x] = store_half2(value / scalar());
+    }
+}
+
@@ -0,0 +1,53 @@
+// Needs compute_shaders/u64/utils.wgsl and compute_shaders/f64/utils.wgsl
+
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<vec2<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn add_f64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = f64_to_ds(left_values[global_id.x]);
+        let right = f64_to_ds(right_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_add(left, right));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sub_f64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = f64_to_ds(left_values[global_id.x]);
+        let right = f64_to_ds(right_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_sub(left, right));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn mul_f64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = f64_to_ds(left_values[global_id.x]);
+        let right = f64_to_ds(right_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_mul(left, right));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn div_f64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = f64_to_ds(left_values[global_id.x]);
+        let right = f64_to_ds(right_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_div(left, right));
+    }
+}
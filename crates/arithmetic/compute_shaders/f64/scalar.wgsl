@@ -0,0 +1,49 @@
+// Needs compute_shaders/u64/utils.wgsl and compute_shaders/f64/utils.wgsl
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read> operand: vec2<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn f64_add(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = f64_to_ds(original_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_add(value, f64_to_ds(operand)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f64_sub(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = f64_to_ds(original_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_sub(value, f64_to_ds(operand)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f64_mul(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = f64_to_ds(original_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_mul(value, f64_to_ds(operand)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn f64_div(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = f64_to_ds(original_values[global_id.x]);
+        new_values[global_id.x] = ds_to_f64(ds_div(value, f64_to_ds(operand)));
+    }
+}
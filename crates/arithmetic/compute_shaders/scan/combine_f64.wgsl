@@ -0,0 +1,31 @@
+// Combines the lanes of the f64 scans, they hold the f64 bits and sums and products
+// are computed in double-single
+
+fn lane_zero() -> Lane {
+    return vec2<u32>(0u, 0u);
+}
+
+fn lane_one() -> Lane {
+    return vec2<u32>(0u, 0x3ff00000u);
+}
+
+// orders the bits like the values, negative values have all their bits flipped
+fn total_order_key(value: Lane) -> Lane {
+    if (value.y & 0x80000000u) != 0u {
+        return ~value;
+    }
+    return vec2<u32>(value.x, value.y | 0x80000000u);
+}
+
+fn combine(op: u32, left: Lane, right: Lane) -> Lane {
+    if op == SCAN_PRODUCT {
+        return ds_to_f64(ds_mul(f64_to_ds(left), f64_to_ds(right)));
+    }
+    if op == SCAN_MIN {
+        return select(right, left, lt_u64(total_order_key(left), total_order_key(right)));
+    }
+    if op == SCAN_MAX {
+        return select(right, left, gt_u64(total_order_key(left), total_order_key(right)));
+    }
+    return ds_to_f64(ds_add(f64_to_ds(left), f64_to_ds(right)));
+}
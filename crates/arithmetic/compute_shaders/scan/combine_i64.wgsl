@@ -0,0 +1,22 @@
+// Combines the lanes of the i64 scans
+
+fn lane_zero() -> Lane {
+    return vec2<u32>(0u, 0u);
+}
+
+fn lane_one() -> Lane {
+    return vec2<u32>(1u, 0u);
+}
+
+fn combine(op: u32, left: Lane, right: Lane) -> Lane {
+    if op == SCAN_PRODUCT {
+        return mul_64(left, right);
+    }
+    if op == SCAN_MIN {
+        return select(right, left, lt_i64(left, right));
+    }
+    if op == SCAN_MAX {
+        return select(right, left, gt_i64(left, right));
+    }
+    return add_64(left, right);
+}
@@ -0,0 +1,47 @@
+// Helpers of the scans in scan.wgsl and the reductions in reduce.wgsl, which declare the
+// bindings. store_values writes the scanned or reduced lanes in the array type.
+// Expects a prelude defining `Lane`, `lane_zero`, `lane_one`, `combine`, the lane accessors,
+// `load_value`, `store_values` and the bits of the lowest and highest lane values.
+
+const SCAN_SUM = 0u;
+const SCAN_PRODUCT = 1u;
+const SCAN_MIN = 2u;
+const SCAN_MAX = 3u;
+
+struct Params {
+    op: u32,
+    len: u32,
+    exclusive: u32,
+}
+
+const wg_size = 256u;
+
+var<workgroup> shared_data: array<Lane, wg_size>;
+
+fn identity(op: u32) -> Lane {
+    if op == SCAN_PRODUCT {
+        return lane_one();
+    }
+    if op == SCAN_MIN {
+        return bitcast<Lane>(LANE_HIGHEST_BITS);
+    }
+    if op == SCAN_MAX {
+        return bitcast<Lane>(LANE_LOWEST_BITS);
+    }
+    return lane_zero();
+}
+
+fn is_valid(row: u32) -> bool {
+    return ((validity[row / 32u] >> (row % 32u)) & 1u) != 0u;
+}
+
+// value of the scan at row, shifted by one row for exclusive scans
+fn result_lane(row: u32) -> Lane {
+    if params.exclusive == 0u {
+        return read_input(row);
+    }
+    if row == 0u {
+        return identity(params.op);
+    }
+    return read_input(row - 1u);
+}
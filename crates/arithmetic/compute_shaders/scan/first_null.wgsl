@@ -0,0 +1,36 @@
+struct Params {
+    op: u32,
+    len: u32,
+    exclusive: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// starts as 0xffffffff
+@group(0)
+@binding(2)
+var<storage, read_write> first_null: array<atomic<u32>>;
+
+// each invocation looks for the first null row of one word
+@compute
+@workgroup_size(256)
+fn first_null_row(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    let start = word * 32u;
+    if start >= params.len {
+        return;
+    }
+    var nulls = ~validity[word];
+    if params.len - start < 32u {
+        nulls &= (1u << (params.len - start)) - 1u;
+    }
+    if nulls != 0u {
+        atomicMin(&first_null[0], start + firstTrailingBit(nulls));
+    }
+}
@@ -0,0 +1,27 @@
+// 16 bit values are widened to 32 bit lanes, sign extended when the lanes are signed,
+// and truncated back when stored. Each store invocation packs one word.
+
+fn load_value(row: u32) -> Lane {
+    var value = (input[row / 2u] >> ((row % 2u) * 16u)) & 0xffffu;
+    if LANE_LOWEST_BITS != 0u && (value & 0x8000u) != 0u {
+        value |= 0xffff0000u;
+    }
+    return bitcast<Lane>(value);
+}
+
+@compute
+@workgroup_size(256)
+fn store_values(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    if word * 2u >= params.len {
+        return;
+    }
+    var result = 0u;
+    for (var lane = 0u; lane < 2u; lane++) {
+        let row = word * 2u + lane;
+        if row < params.len {
+            result |= (bitcast<u32>(result_lane(row)) & 0xffffu) << (lane * 16u);
+        }
+    }
+    output[word] = result;
+}
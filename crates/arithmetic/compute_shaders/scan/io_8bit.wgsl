@@ -0,0 +1,27 @@
+// 8 bit values are widened to 32 bit lanes, sign extended when the lanes are signed,
+// and truncated back when stored. Each store invocation packs one word.
+
+fn load_value(row: u32) -> Lane {
+    var value = (input[row / 4u] >> ((row % 4u) * 8u)) & 0xffu;
+    if LANE_LOWEST_BITS != 0u && (value & 0x80u) != 0u {
+        value |= 0xffffff00u;
+    }
+    return bitcast<Lane>(value);
+}
+
+@compute
+@workgroup_size(256)
+fn store_values(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    if word * 4u >= params.len {
+        return;
+    }
+    var result = 0u;
+    for (var lane = 0u; lane < 4u; lane++) {
+        let row = word * 4u + lane;
+        if row < params.len {
+            result |= (bitcast<u32>(result_lane(row)) & 0xffu) << (lane * 8u);
+        }
+    }
+    output[word] = result;
+}
@@ -0,0 +1,21 @@
+// f16 values are scanned in f32 lanes and rounded back when stored.
+// Each store invocation packs one word.
+
+fn load_value(row: u32) -> Lane {
+    return load_half2(input[row / 2u])[row % 2u];
+}
+
+@compute
+@workgroup_size(256)
+fn store_values(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    if word * 2u >= params.len {
+        return;
+    }
+    var value = vec2<f32>(0.0, 0.0);
+    value.x = result_lane(word * 2u);
+    if word * 2u + 1u < params.len {
+        value.y = result_lane(word * 2u + 1u);
+    }
+    output[word] = store_half2(value);
+}
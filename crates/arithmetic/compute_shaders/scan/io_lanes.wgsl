@@ -0,0 +1,14 @@
+// Values of 32 and 64 bit arrays are scanned as they are stored
+
+fn load_value(row: u32) -> Lane {
+    return read_input(row);
+}
+
+@compute
+@workgroup_size(256)
+fn store_values(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < params.len {
+        write_output(row, result_lane(row));
+    }
+}
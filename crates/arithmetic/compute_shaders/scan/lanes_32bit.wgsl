@@ -0,0 +1,40 @@
+// Lanes of the u32, i32 and f32 scans, one word per lane
+
+const LANE_WORDS = 1u;
+
+fn lane_zero() -> Lane {
+    return Lane(0);
+}
+
+fn lane_one() -> Lane {
+    return Lane(1);
+}
+
+fn combine(op: u32, left: Lane, right: Lane) -> Lane {
+    if op == SCAN_PRODUCT {
+        return left * right;
+    }
+    if op == SCAN_MIN {
+        return min(left, right);
+    }
+    if op == SCAN_MAX {
+        return max(left, right);
+    }
+    return left + right;
+}
+
+fn read_input(index: u32) -> Lane {
+    return bitcast<Lane>(input[index]);
+}
+
+fn read_output(index: u32) -> Lane {
+    return bitcast<Lane>(output[index]);
+}
+
+fn write_output(index: u32, value: Lane) {
+    output[index] = bitcast<u32>(value);
+}
+
+fn lane_words(value: Lane) -> vec2<u32> {
+    return vec2<u32>(bitcast<u32>(value), 0u);
+}
@@ -0,0 +1,20 @@
+// Lanes of the 64 bit scans, two words per lane with the low word first
+
+const LANE_WORDS = 2u;
+
+fn read_input(index: u32) -> Lane {
+    return vec2<u32>(input[index * 2u], input[index * 2u + 1u]);
+}
+
+fn read_output(index: u32) -> Lane {
+    return vec2<u32>(output[index * 2u], output[index * 2u + 1u]);
+}
+
+fn write_output(index: u32, value: Lane) {
+    output[index * 2u] = value.x;
+    output[index * 2u + 1u] = value.y;
+}
+
+fn lane_words(value: Lane) -> vec2<u32> {
+    return value;
+}
@@ -0,0 +1,39 @@
+struct Params {
+    op: u32,
+    len: u32,
+    exclusive: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> first_null: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_validity: array<u32>;
+
+// rows before the first null are valid, the first null itself is valid in an exclusive scan
+@compute
+@workgroup_size(256)
+fn prefix_validity(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    let start = word * 32u;
+    if start >= params.len {
+        return;
+    }
+    var end = min(first_null[0], params.len);
+    if params.exclusive != 0u && end < params.len {
+        end += 1u;
+    }
+    var bits = 0u;
+    if end >= start + 32u {
+        bits = 0xffffffffu;
+    } else if end > start {
+        bits = (1u << (end - start)) - 1u;
+    }
+    new_validity[word] = bits;
+}
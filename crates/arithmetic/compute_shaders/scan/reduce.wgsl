@@ -0,0 +1,72 @@
+// Reduction of the array, reduce_values and reduce_lanes write the total of each block of
+// 256 rows, like the scan the totals are reduced recursively until one is left.
+// Expects the prelude of common.wgsl.
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// the total of each block
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> validity: array<u32>;
+
+fn reduce_block(local: u32, value: Lane) -> Lane {
+    shared_data[local] = value;
+    workgroupBarrier();
+
+    for (var s = wg_size / 2u; s > 0u; s /= 2u) {
+        if local < s {
+            shared_data[local] = combine(params.op, shared_data[local], shared_data[local + s]);
+        }
+        workgroupBarrier();
+    }
+
+    return shared_data[0];
+}
+
+@compute
+@workgroup_size(256)
+fn reduce_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    var value = identity(params.op);
+    if row < params.len && is_valid(row) {
+        value = load_value(row);
+    }
+
+    let total = reduce_block(local_id.x, value);
+    if local_id.x == 0u {
+        write_output(wg_id.x, total);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn reduce_lanes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    var value = identity(params.op);
+    if row < params.len {
+        value = read_input(row);
+    }
+
+    let total = reduce_block(local_id.x, value);
+    if local_id.x == 0u {
+        write_output(wg_id.x, total);
+    }
+}
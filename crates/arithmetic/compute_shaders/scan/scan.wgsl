@@ -0,0 +1,105 @@
+// Multi-level inclusive scan. scan_values scans blocks of 256 rows and writes the total of
+// each block, the totals are scanned recursively with scan_lanes and combined back into
+// the blocks with add_block_prefixes. Expects the prelude of common.wgsl.
+
+@group(0)
+@binding(0)
+var<storage, read> input: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> block_totals: array<u32>;
+
+@group(0)
+@binding(4)
+var<storage, read> validity: array<u32>;
+
+fn write_total(index: u32, value: Lane) {
+    let words = lane_words(value);
+    block_totals[index * LANE_WORDS] = words.x;
+    if LANE_WORDS == 2u {
+        block_totals[index * LANE_WORDS + 1u] = words.y;
+    }
+}
+
+fn scan_block(local: u32, value: Lane) -> Lane {
+    shared_data[local] = value;
+    workgroupBarrier();
+
+    for (var s = 1u; s < wg_size; s *= 2u) {
+        var current = shared_data[local];
+        if local >= s {
+            current = combine(params.op, shared_data[local - s], current);
+        }
+        workgroupBarrier();
+        shared_data[local] = current;
+        workgroupBarrier();
+    }
+
+    return shared_data[local];
+}
+
+@compute
+@workgroup_size(256)
+fn scan_values(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    var value = identity(params.op);
+    if row < params.len && is_valid(row) {
+        value = load_value(row);
+    }
+
+    let scanned = scan_block(local_id.x, value);
+    if row < params.len {
+        write_output(row, scanned);
+    }
+    if local_id.x == wg_size - 1u {
+        write_total(wg_id.x, scanned);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn scan_lanes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    var value = identity(params.op);
+    if row < params.len {
+        value = read_input(row);
+    }
+
+    let scanned = scan_block(local_id.x, value);
+    if row < params.len {
+        write_output(row, scanned);
+    }
+    if local_id.x == wg_size - 1u {
+        write_total(wg_id.x, scanned);
+    }
+}
+
+// input holds the scanned block totals
+@compute
+@workgroup_size(256)
+fn add_block_prefixes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    if wg_id.x > 0u && row < params.len {
+        write_output(row, combine(params.op, read_input(wg_id.x - 1u), read_output(row)));
+    }
+}
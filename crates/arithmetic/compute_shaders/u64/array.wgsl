@@ -0,0 +1,39 @@
+// functions add_64, sub_64 and mul_64 are present in compute_shaders/u64/utils.wgsl
+// the rust code concacts them at compile time. This workaround is needed due to lack of import
+// support
+
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<vec2<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn add_u64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = add_64(left_values[global_id.x], right_values[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sub_u64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = sub_64(left_values[global_id.x], right_values[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn mul_u64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = mul_64(left_values[global_id.x], right_values[global_id.x]);
+    }
+}
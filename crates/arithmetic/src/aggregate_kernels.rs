@@ -1,7 +1,42 @@
-use arrow_gpu_array::{
-    array::{ArrayUtils, ArrowPrimitiveType, PrimitiveArrayGpu},
-    gpu_utils::ArrowComputePipeline,
-};
+use arrow_gpu_array::{array::*, gpu_utils::ArrowComputePipeline};
+
+use crate::{ScanOp, ScanType};
+
+const COUNT_VALID_SHADER: &str = include_str!("../compute_shaders/aggregate/count_valid.wgsl");
+const MIN_COUNT_SHADER: &str = include_str!("../compute_shaders/aggregate/min_count.wgsl");
+
+/// Operation reducing the values of an aggregate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateOp {
+    Sum,
+    Product,
+    Min,
+    Max,
+}
+
+impl From<AggregateOp> for ScanOp {
+    fn from(op: AggregateOp) -> Self {
+        match op {
+            AggregateOp::Sum => ScanOp::Sum,
+            AggregateOp::Product => ScanOp::Product,
+            AggregateOp::Min => ScanOp::Min,
+            AggregateOp::Max => ScanOp::Max,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggregateOptions {
+    /// The result is null when fewer valid values are aggregated, like SQL the default
+    /// of 1 makes the aggregate of an empty or all null array null
+    pub min_count: usize,
+}
+
+impl Default for AggregateOptions {
+    fn default() -> Self {
+        Self { min_count: 1 }
+    }
+}
 
 /// Trait for sum of all elements in the array
 pub trait Sum: ArrayUtils + Sized {
@@ -12,41 +47,696 @@ pub trait Sum: ArrayUtils + Sized {
         result
     }
 
-    /// Computes sum of all elements in the array
+    /// Computes sum of all elements in the array, null values are skipped
+    /// and the sum of an empty or all null array is null
     fn sum_op(&self, pipeline: &mut ArrowComputePipeline) -> Self;
 }
 
 /// Helper trait for Arrow arrays backed by 32 bits that support sum
+#[deprecated(
+    note = "`Sum` is implemented for every `AggregateType`, the shader does not skip nulls"
+)]
 pub trait Sum32Bit: ArrowPrimitiveType {
     const SHADER: &'static str;
 }
 
-impl<T: Sum32Bit> Sum for PrimitiveArrayGpu<T> {
-    fn sum_op(&self, pipeline: &mut ArrowComputePipeline) -> Self {
-        let mut new_length = self.len.div_ceil(256);
-        let mut temp_buffer = pipeline.apply_unary_function(
-            &self.data,
-            (new_length * 4) as u64,
-            T::SHADER,
-            "sum",
-            new_length as u32,
-        );
-        while new_length != 1 {
-            new_length = new_length.div_ceil(256);
-            temp_buffer = pipeline.apply_unary_function(
-                &temp_buffer,
-                (new_length * 4) as u64,
-                T::SHADER,
-                "sum",
-                new_length as u32,
+/// Trait for reducing all elements in the array to a single value, null values are skipped
+pub trait Aggregate: ArrayUtils + Sized {
+    fn aggregate(&self, op: AggregateOp, options: AggregateOptions) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("aggregate"));
+        let result = self.aggregate_op(op, options, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn mean(&self, options: AggregateOptions) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("mean"));
+        let result = self.mean_op(options, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Reduces the elements in the array with `op`, integers wrap on overflow
+    fn aggregate_op(
+        &self,
+        op: AggregateOp,
+        options: AggregateOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+
+    /// Computes the mean of all elements in the array
+    fn mean_op(
+        &self,
+        options: AggregateOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU;
+
+    fn minimum(&self, options: AggregateOptions) -> Self {
+        self.aggregate(AggregateOp::Min, options)
+    }
+
+    fn minimum_op(&self, options: AggregateOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.aggregate_op(AggregateOp::Min, options, pipeline)
+    }
+
+    fn maximum(&self, options: AggregateOptions) -> Self {
+        self.aggregate(AggregateOp::Max, options)
+    }
+
+    fn maximum_op(&self, options: AggregateOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.aggregate_op(AggregateOp::Max, options, pipeline)
+    }
+
+    fn product(&self, options: AggregateOptions) -> Self {
+        self.aggregate(AggregateOp::Product, options)
+    }
+
+    fn product_op(&self, options: AggregateOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.aggregate_op(AggregateOp::Product, options, pipeline)
+    }
+}
+
+/// Trait for counting the elements in the array
+pub trait Count: ArrayUtils + Sized {
+    fn count(&self) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("count"));
+        let result = self.count_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn count_valid(&self) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("count_valid"));
+        let result = self.count_valid_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Counts all elements in the array, null or not
+    fn count_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU;
+    /// Counts the elements in the array that are not null
+    fn count_valid_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU;
+}
+
+/// Helper trait for Arrow arrays that support aggregates. Minimum, maximum and product
+/// reduce the lanes of the scan shader.
+pub trait AggregateType: ScanType {
+    /// Shader summing the values in double-single for the mean
+    const MEAN_SHADER: &'static str;
+    /// Shader merging the moments of the values for the variance
+    const VARIANCE_SHADER: &'static str;
+    /// Shader merging the co-moments of the values of two arrays
+    const COVARIANCE_SHADER: &'static str;
+    /// Shader reading the quantile from the sorted values
+    const QUANTILE_SORTED_SHADER: &'static str;
+    /// Shader counting the values in the bins of the approximate quantile
+    const QUANTILE_HISTOGRAM_SHADER: &'static str;
+    /// Shader reading the approximate quantile from the counts of the bins
+    const QUANTILE_APPROX_SHADER: &'static str;
+    /// Shader counting the values in bins
+    const HISTOGRAM_SHADER: &'static str;
+    /// Shader finding the buckets of the values
+    const BUCKETIZE_SHADER: &'static str;
+}
+
+// Prepends the kind of the values and the helpers loading them in double-single to shaders
+macro_rules! ds_shader {
+    ($kind: literal, $load_file: literal, $($shader_file: literal),*) => {
+        concat!(
+            "const VALUE_KIND = ",
+            $kind,
+            ";\n",
+            include_str!("../../../compute_shaders/u64/utils.wgsl"),
+            include_str!("../../../compute_shaders/f64/utils.wgsl"),
+            include_str!("../../../compute_shaders/f16/packed.wgsl"),
+            include_str!($load_file),
+            include_str!("../compute_shaders/aggregate/ds_values.wgsl"),
+            $(include_str!($shader_file)),*
+        )
+    };
+}
+
+macro_rules! impl_aggregate_type {
+    ($ty: ty, $kind: literal, $load_file: literal) => {
+        impl AggregateType for $ty {
+            const MEAN_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/validity.wgsl",
+                "../compute_shaders/aggregate/mean.wgsl"
+            );
+            const VARIANCE_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/validity.wgsl",
+                "../compute_shaders/aggregate/variance.wgsl"
+            );
+            const COVARIANCE_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/validity.wgsl",
+                "../compute_shaders/aggregate/covariance.wgsl"
+            );
+            const QUANTILE_SORTED_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/quantile.wgsl",
+                "../compute_shaders/aggregate/quantile_sorted.wgsl"
+            );
+            const QUANTILE_HISTOGRAM_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/validity.wgsl",
+                "../compute_shaders/aggregate/quantile.wgsl",
+                "../compute_shaders/aggregate/quantile_histogram.wgsl"
+            );
+            const QUANTILE_APPROX_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/quantile.wgsl",
+                "../compute_shaders/aggregate/quantile_approx.wgsl"
+            );
+            const HISTOGRAM_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/validity.wgsl",
+                "../compute_shaders/aggregate/histogram.wgsl"
+            );
+            const BUCKETIZE_SHADER: &'static str = ds_shader!(
+                $kind,
+                $load_file,
+                "../compute_shaders/aggregate/bucketize.wgsl"
+            );
+        }
+    };
+}
+
+impl_aggregate_type!(u8, "0u", "../compute_shaders/aggregate/load_8bit.wgsl");
+impl_aggregate_type!(i8, "1u", "../compute_shaders/aggregate/load_8bit.wgsl");
+impl_aggregate_type!(u16, "0u", "../compute_shaders/aggregate/load_16bit.wgsl");
+impl_aggregate_type!(i16, "1u", "../compute_shaders/aggregate/load_16bit.wgsl");
+impl_aggregate_type!(f16, "2u", "../compute_shaders/aggregate/load_16bit.wgsl");
+impl_aggregate_type!(u32, "0u", "../compute_shaders/aggregate/load_32bit.wgsl");
+impl_aggregate_type!(i32, "1u", "../compute_shaders/aggregate/load_32bit.wgsl");
+impl_aggregate_type!(f32, "2u", "../compute_shaders/aggregate/load_32bit.wgsl");
+impl_aggregate_type!(u64, "0u", "../compute_shaders/aggregate/load_64bit.wgsl");
+impl_aggregate_type!(i64, "1u", "../compute_shaders/aggregate/load_64bit.wgsl");
+impl_aggregate_type!(f64, "2u", "../compute_shaders/aggregate/load_64bit.wgsl");
+
+pub(crate) fn validity_buffer<T: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+) -> NullBitBufferGpu {
+    match &array.null_buffer {
+        Some(null_buffer) if array.len > 0 => null_buffer.clone(),
+        // at least one word is bound for empty arrays
+        _ => NullBitBufferGpu::new_set_with_capacity(array.gpu_device.clone(), array.len.max(1)),
+    }
+}
+
+/// Data of array to bind. Empty arrays may have no data to bind, a zeroed element
+/// stands in for it since no row is read.
+pub(crate) fn bound_data<T: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    pipeline: &ArrowComputePipeline,
+) -> buffer::ArrowGpuBuffer {
+    if array.len == 0 {
+        let size = T::ITEM_SIZE.div_ceil(4) * 4;
+        pipeline.device.create_empty_buffer(size).into()
+    } else {
+        array.data.clone()
+    }
+}
+
+/// Reduces the valid values of array with `op` to a single value, the blocks of 256 rows
+/// are reduced to one lane each, then the lanes recursively until one is left.
+/// The result is the identity of `op` when no value is valid.
+pub(crate) fn reduce_op<T: ScanType>(
+    array: &PrimitiveArrayGpu<T>,
+    op: ScanOp,
+    pipeline: &mut ArrowComputePipeline,
+) -> PrimitiveArrayGpu<T> {
+    let validity = validity_buffer(array);
+    let mut len = array.len;
+    let mut blocks = len.div_ceil(256).max(1);
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[op as u32, len as u32, 0, 0]);
+    let mut totals = pipeline
+        .device
+        .create_empty_buffer(blocks as u64 * T::LANE_SIZE);
+    pipeline.apply_function(
+        &[
+            &bound_data(array, pipeline),
+            &params,
+            &totals,
+            &validity.bit_buffer,
+        ],
+        T::REDUCE_SHADER,
+        "reduce_values",
+        blocks as u32,
+    );
+
+    while blocks > 1 {
+        len = blocks;
+        blocks = len.div_ceil(256);
+        let params = pipeline
+            .device
+            .create_uniform_buffer(&[op as u32, len as u32, 0, 0]);
+        let new_totals = pipeline
+            .device
+            .create_empty_buffer(blocks as u64 * T::LANE_SIZE);
+        pipeline.apply_function(
+            &[&totals, &params, &new_totals],
+            T::REDUCE_SHADER,
+            "reduce_lanes",
+            blocks as u32,
+        );
+        totals = new_totals;
+    }
+
+    let params = pipeline.device.create_uniform_buffer(&[op as u32, 1, 0, 0]);
+    let new_size = std::mem::size_of::<T::NativeType>().div_ceil(4) * 4;
+    let new_values = pipeline.device.create_empty_buffer(new_size as u64);
+    pipeline.apply_function(
+        &[&totals, &params, &new_values],
+        T::REDUCE_SHADER,
+        "store_values",
+        1,
+    );
+
+    PrimitiveArrayGpu {
+        data: new_values.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: 1,
+        null_buffer: None,
+    }
+}
+
+/// Submits commands to clear the validity bit of an aggregate computed from fewer than
+/// `min_count` valid values
+pub(crate) fn apply_min_count_op(
+    count: &UInt32ArrayGPU,
+    min_count: usize,
+    validity: &NullBitBufferGpu,
+    pipeline: &mut ArrowComputePipeline,
+) {
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[min_count as u32, 0, 0, 0]);
+    pipeline.apply_function(
+        &[&count.data, &params, &validity.bit_buffer],
+        MIN_COUNT_SHADER,
+        "min_count_validity",
+        1,
+    );
+}
+
+/// Null buffer of an aggregate of array, see `AggregateOptions::min_count`
+pub(crate) fn aggregate_null_buffer_op<T: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    options: AggregateOptions,
+    pipeline: &mut ArrowComputePipeline,
+) -> Option<NullBitBufferGpu> {
+    if array.null_buffer.is_none() && array.len >= options.min_count {
+        return None;
+    }
+    let count = array.count_valid_op(pipeline);
+    let validity = NullBitBufferGpu::new_set_with_capacity(pipeline.device.clone(), 1);
+    apply_min_count_op(&count, options.min_count, &validity, pipeline);
+    Some(validity)
+}
+
+impl<T: AggregateType> Aggregate for PrimitiveArrayGpu<T> {
+    fn aggregate_op(
+        &self,
+        op: AggregateOp,
+        options: AggregateOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let mut result = reduce_op(self, op.into(), pipeline);
+        result.null_buffer = aggregate_null_buffer_op(self, options, pipeline);
+        result
+    }
+
+    fn mean_op(
+        &self,
+        options: AggregateOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU {
+        let validity = validity_buffer(self);
+        let mut len = self.len;
+        let mut blocks = len.div_ceil(256).max(1);
+        let create_partials = |pipeline: &mut ArrowComputePipeline, blocks: usize| {
+            // the single block of the last level writes the mean
+            let size = if blocks == 1 { 8 } else { blocks as u64 * 12 };
+            pipeline.device.create_empty_buffer(size)
+        };
+
+        let params = pipeline
+            .device
+            .create_uniform_buffer(&[len as u32, 0, 0, 0]);
+        let mut partials = create_partials(pipeline, blocks);
+        pipeline.apply_function(
+            &[
+                &bound_data(self, pipeline),
+                &params,
+                &partials,
+                &validity.bit_buffer,
+            ],
+            T::MEAN_SHADER,
+            "mean_values",
+            blocks as u32,
+        );
+
+        while blocks > 1 {
+            len = blocks;
+            blocks = len.div_ceil(256);
+            let params = pipeline
+                .device
+                .create_uniform_buffer(&[len as u32, 0, 0, 0]);
+            let new_partials = create_partials(pipeline, blocks);
+            pipeline.apply_function(
+                &[&partials, &params, &new_partials],
+                T::MEAN_SHADER,
+                "mean_lanes",
+                blocks as u32,
             );
+            partials = new_partials;
         }
-        Self {
-            data: temp_buffer.into(),
-            gpu_device: self.get_gpu_device(),
-            phantom: std::marker::PhantomData,
+
+        Float64ArrayGPU {
+            data: partials.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
             len: 1,
-            null_buffer: None,
+            null_buffer: aggregate_null_buffer_op(self, options, pipeline),
+        }
+    }
+}
+
+impl<T: AggregateType> Sum for PrimitiveArrayGpu<T> {
+    fn sum_op(&self, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.aggregate_op(AggregateOp::Sum, Default::default(), pipeline)
+    }
+}
+
+fn count_valid_bits_op(
+    null_buffer: &NullBitBufferGpu,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[null_buffer.len as u32, 0, 0, 0]);
+    let count = pipeline.device.create_empty_buffer(4);
+    pipeline.apply_function(
+        &[&null_buffer.bit_buffer, &params, &count],
+        COUNT_VALID_SHADER,
+        "count_valid",
+        null_buffer.len.div_ceil(32).div_ceil(256).max(1) as u32,
+    );
+
+    UInt32ArrayGPU {
+        data: count.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len: 1,
+        null_buffer: None,
+    }
+}
+
+fn count_rows_op(len: usize, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+    let count = pipeline.device.create_gpu_buffer_with_data(&[len as u32]);
+    UInt32ArrayGPU {
+        data: count.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len: 1,
+        null_buffer: None,
+    }
+}
+
+impl<T: ArrowPrimitiveType> Count for PrimitiveArrayGpu<T> {
+    fn count_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        count_rows_op(self.len, pipeline)
+    }
+
+    fn count_valid_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        match &self.null_buffer {
+            Some(null_buffer) if self.len > 0 => count_valid_bits_op(null_buffer, pipeline),
+            _ => count_rows_op(self.len, pipeline),
         }
     }
 }
+
+impl Count for BooleanArrayGPU {
+    fn count_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        count_rows_op(self.len, pipeline)
+    }
+
+    fn count_valid_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        match &self.null_buffer {
+            Some(null_buffer) if self.len > 0 => count_valid_bits_op(null_buffer, pipeline),
+            _ => count_rows_op(self.len, pipeline),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    fn valid_values<T: Copy>(values: &[Option<T>]) -> Vec<T> {
+        values.iter().flatten().copied().collect()
+    }
+
+    // the mean is summed in double-single, which carries 48 bits of significand
+    fn assert_mean(mean: Float64ArrayGPU, expected: f64) {
+        let mean = mean.values()[0].unwrap();
+        assert!(
+            (mean - expected).abs() <= expected.abs() * 1e-12,
+            "{mean} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_min_max_i32_multi_level() {
+        let values = (0..70_000)
+            .map(|x: i32| (x % 13 != 5).then_some((x * 7919) % 100_003 - 50_000))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let valid = valid_values(&values);
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![valid.iter().min().copied()]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![valid.iter().max().copied()]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_8bit() {
+        let values = (0..1000)
+            .map(|x: u32| (!x.is_multiple_of(7)).then_some((x * 37 % 256) as u8))
+            .collect::<Vec<_>>();
+        let array = UInt8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let valid = valid_values(&values);
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![valid.iter().min().copied()]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![valid.iter().max().copied()]
+        );
+        assert_eq!(
+            array.product(Default::default()).values(),
+            vec![Some(valid.iter().fold(1u8, |a, b| a.wrapping_mul(*b)))]
+        );
+        let sum = valid.iter().map(|x| *x as f64).sum::<f64>();
+        assert_mean(array.mean(Default::default()), sum / valid.len() as f64);
+
+        let values = (0..300)
+            .map(|x| Some((x % 256) as u8 as i8))
+            .collect::<Vec<_>>();
+        let array = Int8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![Some(i8::MIN)]
+        );
+        let sum = values.iter().flatten().map(|x| *x as f64).sum::<f64>();
+        assert_mean(array.mean(Default::default()), sum / 300.0);
+    }
+
+    #[test]
+    fn test_aggregate_16bit() {
+        let values = (0..777)
+            .map(|x| (x % 5 != 1).then_some(1000 - x * 3))
+            .collect::<Vec<Option<i16>>>();
+        let array = Int16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let valid = valid_values(&values);
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![valid.iter().min().copied()]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![valid.iter().max().copied()]
+        );
+        let sum = valid.iter().map(|x| *x as f64).sum::<f64>();
+        assert_mean(array.mean(Default::default()), sum / valid.len() as f64);
+
+        let values = [0.5, -2.0, 8.25, 3.0].map(|x| Some(f16::from_f32(x)));
+        let array = Float16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![Some(f16::from_f32(-2.0))]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![Some(f16::from_f32(8.25))]
+        );
+        assert_eq!(
+            array.product(Default::default()).values(),
+            vec![Some(f16::from_f32(-24.75))]
+        );
+        assert_mean(array.mean(Default::default()), 2.4375);
+    }
+
+    #[test]
+    fn test_mean_i32_does_not_wrap() {
+        let values = vec![Some(i32::MAX); 1000];
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_mean(array.mean(Default::default()), i32::MAX as f64);
+
+        let values = (0..5000)
+            .map(|x: i32| Some(x.wrapping_mul(1_000_003)))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let sum = values.iter().flatten().map(|x| *x as f64).sum::<f64>();
+        assert_mean(array.mean(Default::default()), sum / 5000.0);
+    }
+
+    #[test]
+    fn test_aggregate_64bit() {
+        let values = (0..3000)
+            .map(|x: i64| (x % 3 != 0).then_some(x * 1_000_000_007 - 1_500_000_000_000))
+            .collect::<Vec<_>>();
+        let array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let valid = valid_values(&values);
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![valid.iter().min().copied()]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![valid.iter().max().copied()]
+        );
+        let sum = valid.iter().map(|x| *x as f64).sum::<f64>();
+        assert_mean(array.mean(Default::default()), sum / valid.len() as f64);
+
+        let values = (0..2000)
+            .map(|x| Some(x as f64 * 0.37 - 100.0))
+            .collect::<Vec<_>>();
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.minimum(Default::default()).values(),
+            vec![Some(-100.0)]
+        );
+        assert_eq!(
+            array.maximum(Default::default()).values(),
+            vec![Some(1999.0 * 0.37 - 100.0)]
+        );
+        assert_mean(
+            array.mean(Default::default()),
+            values.iter().flatten().sum::<f64>() / 2000.0,
+        );
+
+        let values = [Some(3u64), None, Some(u64::MAX / 2), Some(5)];
+        let array = UInt64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.product(Default::default()).values(),
+            vec![Some(15u64.wrapping_mul(u64::MAX / 2))]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_all_null() {
+        let array = Float32ArrayGPU::from_optional_slice(&[None, None], GPU_DEVICE.clone());
+        assert_eq!(array.mean(Default::default()).values(), vec![None]);
+        assert_eq!(array.minimum(Default::default()).values(), vec![None]);
+        assert_eq!(array.sum().values(), vec![None]);
+
+        let options = AggregateOptions { min_count: 0 };
+        assert!(array.mean(options).values()[0].unwrap().is_nan());
+        assert_eq!(array.minimum(options).values(), vec![Some(f32::INFINITY)]);
+        let sum = array.aggregate(AggregateOp::Sum, options);
+        assert_eq!(sum.values(), vec![Some(0.0)]);
+
+        let array = Int32ArrayGPU::from_slice(&[1], GPU_DEVICE.clone()).slice(0, 0);
+        assert_eq!(array.maximum(Default::default()).values(), vec![None]);
+        assert_eq!(
+            array.aggregate(AggregateOp::Sum, options).values(),
+            vec![Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_empty() {
+        use crate::{Covariance, Variance};
+
+        let array = Float32ArrayGPU::from_slice(&[], GPU_DEVICE.clone());
+        assert_eq!(array.sum().values(), vec![None]);
+        assert_eq!(array.minimum(Default::default()).values(), vec![None]);
+        assert_eq!(array.mean(Default::default()).values(), vec![None]);
+        assert_eq!(array.variance(0).values(), vec![None]);
+        assert_eq!(array.covariance(&array).values(), vec![None]);
+        assert_eq!(array.count_valid().values(), vec![Some(0)]);
+
+        let options = AggregateOptions { min_count: 0 };
+        let sum = array.aggregate(AggregateOp::Sum, options);
+        assert_eq!(sum.values(), vec![Some(0.0)]);
+
+        let array = Int32ArrayGPU::from_optional_slice(&[], GPU_DEVICE.clone());
+        assert_eq!(array.maximum(Default::default()).values(), vec![None]);
+    }
+
+    #[test]
+    fn test_sum_skips_nulls() {
+        let values = (0..70_000)
+            .map(|x: i64| (x % 3 != 0).then_some(x - 30_000))
+            .collect::<Vec<_>>();
+        let array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let sum = values.iter().flatten().sum::<i64>();
+        assert_eq!(array.sum().values(), vec![Some(sum)]);
+
+        let values = [Some(1u16), None, Some(2), None, Some(3)];
+        let array = UInt16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.sum().values(), vec![Some(6)]);
+        assert_eq!(array.product(Default::default()).values(), vec![Some(6)]);
+
+        let options = AggregateOptions { min_count: 3 };
+        assert_eq!(array.minimum(options).values(), vec![Some(1)]);
+        let options = AggregateOptions { min_count: 4 };
+        assert_eq!(array.minimum(options).values(), vec![None]);
+        assert_eq!(array.mean(options).values(), vec![None]);
+        assert_eq!(array.slice(0, 3).minimum(options).values(), vec![None]);
+    }
+
+    #[test]
+    fn test_count() {
+        let values = (0..10_000)
+            .map(|x| (x % 3 != 0).then_some(x as f32))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.count().values(), vec![Some(10_000)]);
+        assert_eq!(array.count_valid().values(), vec![Some(6666)]);
+        assert_eq!(array.slice(1, 100).count_valid().values(), vec![Some(67)]);
+
+        let array = BooleanArrayGPU::from_slice(&[true, false, true], GPU_DEVICE.clone());
+        assert_eq!(array.count_valid().values(), vec![Some(3)]);
+    }
+}
@@ -124,10 +124,14 @@ dyn_fn!(
     "Add a scalar to each element in the array",
     add_scalar_op_dyn,
     add_scalar_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
     Int32ArrayGPU,
     Date32ArrayGPU,
     UInt32ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
     UInt16ArrayGPU,
 );
 
@@ -136,9 +140,13 @@ dyn_fn!(
     "Subtract a scalar from each element in the array",
     sub_scalar_op_dyn,
     sub_scalar_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
     Int32ArrayGPU,
     UInt32ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
 );
 
 dyn_fn!(
@@ -146,9 +154,13 @@ dyn_fn!(
     "Multiply a scalar to each element in the array",
     mul_scalar_op_dyn,
     mul_scalar_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
     Int32ArrayGPU,
     UInt32ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
 );
 
 dyn_fn!(
@@ -156,7 +168,9 @@ dyn_fn!(
     "Divide each element in the array by scalar",
     div_scalar_op_dyn,
     div_scalar_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
     Int32ArrayGPU,
     UInt32ArrayGPU,
 );
@@ -227,10 +241,15 @@ dyn_fn!(
     "Compute x + y for each pair (x, y) in zip(lhs, rhs)",
     add_array_op_dyn,
     add_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
     UInt32ArrayGPU,
     Int32ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
     Date32ArrayGPU,
+    Decimal128ArrayGPU,
     [Int32ArrayGPU, Date32ArrayGPU],
     [Date32ArrayGPU, Int32ArrayGPU]
 );
@@ -240,7 +259,12 @@ dyn_fn!(
     "Compute x - y for each pair (x, y) in zip(lhs, rhs)",
     sub_array_op_dyn,
     sub_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Decimal128ArrayGPU,
 );
 
 dyn_fn!(
@@ -248,7 +272,12 @@ dyn_fn!(
     "Compute x * y for each pair (x, y) in zip(lhs, rhs)",
     mul_array_op_dyn,
     mul_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Decimal128ArrayGPU,
 );
 
 dyn_fn!(
@@ -256,7 +285,9 @@ dyn_fn!(
     "Compute x / y for each pair (x, y) in zip(lhs, rhs)",
     div_array_op_dyn,
     div_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
+    Float64ArrayGPU,
 );
 
 dyn_fn!(
@@ -0,0 +1,364 @@
+use crate::*;
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_cast::DecimalCast;
+
+const DECIMAL128_ARRAY_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i128/utils.wgsl"),
+    include_str!("../compute_shaders/decimal128/array.wgsl")
+);
+const DECIMAL128_AGGREGATE_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i128/utils.wgsl"),
+    include_str!("../compute_shaders/decimal128/aggregate.wgsl")
+);
+
+/// Applies `entry_point` to both operands, the rows that overflow an i128 are null
+fn apply_decimal_binary_op(
+    left: &PrimitiveArrayGpu<i128>,
+    right: &PrimitiveArrayGpu<i128>,
+    precision: u8,
+    scale: i8,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Decimal128ArrayGPU {
+    let null_buffer =
+        NullBitBufferGpu::merge_null_bit_buffer_op(&left.null_buffer, &right.null_buffer, pipeline)
+            .unwrap_or_else(|| {
+                NullBitBufferGpu::new_set_with_capacity(left.gpu_device.clone(), left.len)
+            });
+    let new_data_buffer = pipeline
+        .device
+        .create_empty_buffer(left.data.size().max(16));
+    if left.len > 0 {
+        pipeline.apply_function(
+            &[
+                &left.data,
+                &right.data,
+                &new_data_buffer,
+                &null_buffer.bit_buffer,
+            ],
+            DECIMAL128_ARRAY_SHADER,
+            entry_point,
+            left.len.div_ceil(256) as u32,
+        );
+    }
+
+    let values = PrimitiveArrayGpu {
+        data: new_data_buffer.into(),
+        gpu_device: left.gpu_device.clone(),
+        phantom: Default::default(),
+        len: left.len,
+        null_buffer: Some(null_buffer),
+    };
+    Decimal128ArrayGPU::new(values, precision, scale)
+}
+
+/// Rescales both operands to the larger scale and applies `entry_point`,
+/// the precision grows by one digit to hold the carry.
+/// Rows that overflow an i128, while rescaling or in `entry_point`, are null.
+fn rescaled_decimal_op(
+    left: &Decimal128ArrayGPU,
+    right: &Decimal128ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Decimal128ArrayGPU {
+    let scale = left.scale.max(right.scale);
+    let integer_digits =
+        (left.precision as i8 - left.scale).max(right.precision as i8 - right.scale);
+    let precision = (integer_digits + scale + 1).min(DECIMAL128_MAX_PRECISION as i8) as u8;
+
+    let rescale = |array: &Decimal128ArrayGPU, pipeline: &mut ArrowComputePipeline| {
+        (array.scale != scale).then(|| {
+            let precision = (array.precision as i8 + scale - array.scale)
+                .min(DECIMAL128_MAX_PRECISION as i8) as u8;
+            array.cast_decimal128_op(precision, scale, pipeline)
+        })
+    };
+    let new_left = rescale(left, pipeline);
+    let new_right = rescale(right, pipeline);
+    let left = new_left.as_ref().map_or(&left.values, |x| &x.values);
+    let right = new_right.as_ref().map_or(&right.values, |x| &x.values);
+
+    apply_decimal_binary_op(left, right, precision, scale, entry_point, pipeline)
+}
+
+impl ArrowAdd<Decimal128ArrayGPU> for Decimal128ArrayGPU {
+    type Output = Self;
+
+    fn add_op(&self, value: &Decimal128ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        rescaled_decimal_op(self, value, "add_decimal128", pipeline)
+    }
+}
+
+impl ArrowSub<Decimal128ArrayGPU> for Decimal128ArrayGPU {
+    type Output = Self;
+
+    fn sub_op(&self, value: &Decimal128ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        rescaled_decimal_op(self, value, "sub_decimal128", pipeline)
+    }
+}
+
+impl ArrowMul<Decimal128ArrayGPU> for Decimal128ArrayGPU {
+    type Output = Self;
+
+    /// The scale of the product is the sum of the scales, when it is larger than 38 the
+    /// operand with the larger scale is rounded first so the product has a scale of 38.
+    /// The product is null when it overflows an i128.
+    fn mul_op(&self, value: &Decimal128ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let (mut left_scale, mut right_scale) = (self.scale, value.scale);
+        while left_scale + right_scale > DECIMAL128_MAX_PRECISION as i8 {
+            if left_scale >= right_scale {
+                left_scale -= 1;
+            } else {
+                right_scale -= 1;
+            }
+        }
+        let rescale = |array: &Decimal128ArrayGPU,
+                       scale: i8,
+                       pipeline: &mut ArrowComputePipeline| {
+            (array.scale != scale).then(|| {
+                // one more digit holds the carry of rounding
+                let precision = (array.precision as i8 + scale - array.scale + 1)
+                    .clamp(1, DECIMAL128_MAX_PRECISION as i8) as u8;
+                array.cast_decimal128_op(precision, scale, pipeline)
+            })
+        };
+        let new_left = rescale(self, left_scale, pipeline);
+        let new_right = rescale(value, right_scale, pipeline);
+        let left = new_left.as_ref().unwrap_or(self);
+        let right = new_right.as_ref().unwrap_or(value);
+
+        let precision = (left.precision + right.precision + 1).min(DECIMAL128_MAX_PRECISION);
+        apply_decimal_binary_op(
+            &left.values,
+            &right.values,
+            precision,
+            left_scale + right_scale,
+            "mul_decimal128",
+            pipeline,
+        )
+    }
+}
+
+impl Sum for Decimal128ArrayGPU {
+    /// Null values are skipped, the sum is null when it overflows an i128
+    /// or when no value is valid
+    fn sum_op(&self, pipeline: &mut ArrowComputePipeline) -> Self {
+        let validity = validity_buffer(&self.values);
+
+        let mut new_length = self.len.div_ceil(256).max(1);
+        let mut temp_buffer = pipeline
+            .device
+            .create_empty_buffer((new_length * 32) as u64);
+        pipeline.apply_function(
+            &[
+                &bound_data(&self.values, pipeline),
+                &temp_buffer,
+                &validity.bit_buffer,
+            ],
+            DECIMAL128_AGGREGATE_SHADER,
+            "sum_values",
+            new_length as u32,
+        );
+        while new_length != 1 {
+            new_length = new_length.div_ceil(256);
+            temp_buffer = pipeline.apply_unary_function(
+                &temp_buffer,
+                (new_length * 32) as u64,
+                DECIMAL128_AGGREGATE_SHADER,
+                "sum_partials",
+                new_length as u32,
+            );
+        }
+        let result = pipeline.apply_unary_function(
+            &temp_buffer,
+            32,
+            DECIMAL128_AGGREGATE_SHADER,
+            "sum_finish",
+            1,
+        );
+
+        let data = pipeline.device.create_empty_buffer(16);
+        let bit_buffer = pipeline.device.create_empty_buffer(4);
+        pipeline.copy_buffer_to_buffer(&result, 0, &data, 0, 16);
+        pipeline.copy_buffer_to_buffer(&result, 16, &bit_buffer, 0, 4);
+
+        let null_buffer = NullBitBufferGpu {
+            bit_buffer: bit_buffer.into(),
+            len: 1,
+            gpu_device: self.gpu_device.clone(),
+        };
+        let min_count = AggregateOptions::default().min_count;
+        if self.values.null_buffer.is_some() || self.len < min_count {
+            let count = self.values.count_valid_op(pipeline);
+            apply_min_count_op(&count, min_count, &null_buffer, pipeline);
+        }
+
+        let values = PrimitiveArrayGpu {
+            data: data.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: 1,
+            null_buffer: Some(null_buffer),
+        };
+        let precision = (self.precision + 10).min(DECIMAL128_MAX_PRECISION);
+        Decimal128ArrayGPU::new(values, precision, self.scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_decimal128_add_sub() {
+        let device = GPU_DEVICE.clone();
+        let left = Decimal128ArrayGPU::from_optional_slice(
+            &[Some(1_25), Some(-3_50), None, Some(1 << 100)],
+            31,
+            2,
+            device.clone(),
+        );
+        let right = Decimal128ArrayGPU::from_optional_slice(
+            &[Some(1_005), Some(2_000), Some(7), Some(-1)],
+            20,
+            3,
+            device,
+        );
+
+        let sum = left.add(&right);
+        assert_eq!((sum.precision, sum.scale), (33, 3));
+        assert_eq!(
+            sum.values(),
+            vec![
+                Some(2_255),
+                Some(-1_500),
+                None,
+                Some((1i128 << 100) * 10 - 1)
+            ]
+        );
+
+        let difference: Decimal128ArrayGPU =
+            sub_dyn(&left.into(), &right.into()).try_into().unwrap();
+        assert_eq!(
+            difference.values(),
+            vec![Some(245), Some(-5_500), None, Some((1i128 << 100) * 10 + 1)]
+        );
+    }
+
+    #[test]
+    fn test_decimal128_mul() {
+        let device = GPU_DEVICE.clone();
+        let left = Decimal128ArrayGPU::from_slice(&[1_5, -2_5, 1 << 70], 5, 1, device.clone());
+        let right = Decimal128ArrayGPU::from_slice(&[2_25, 4_00, -(1 << 50)], 5, 2, device);
+        let product = left.mul(&right);
+        assert_eq!((product.precision, product.scale), (11, 3));
+        assert_eq!(
+            product.raw_values().unwrap(),
+            vec![3_375, -10_000, -(1i128 << 120)]
+        );
+    }
+
+    #[test]
+    fn test_decimal128_mul_overflow() {
+        let device = GPU_DEVICE.clone();
+        let left = Decimal128ArrayGPU::from_optional_slice(
+            &[
+                Some(1 << 100),
+                Some(i128::MIN),
+                Some(i128::MIN),
+                None,
+                Some(-(1 << 63)),
+            ],
+            38,
+            0,
+            device.clone(),
+        );
+        let right = Decimal128ArrayGPU::from_slice(&[1 << 30, 1, -1, 2, 1 << 64], 38, 0, device);
+        assert_eq!(
+            left.mul(&right).values(),
+            vec![None, Some(i128::MIN), None, None, Some(i128::MIN)]
+        );
+    }
+
+    #[test]
+    fn test_decimal128_add_sub_overflow() {
+        let device = GPU_DEVICE.clone();
+        let max = 10i128.pow(38) - 1;
+        // rescaling max from scale 0 to scale 2 overflows
+        let left = Decimal128ArrayGPU::from_slice(&[max], 38, 2, device.clone());
+        let right = Decimal128ArrayGPU::from_slice(&[max], 38, 0, device.clone());
+        assert_eq!(left.add(&right).values(), vec![None]);
+        assert_eq!(left.sub(&right).values(), vec![None]);
+
+        let left = Decimal128ArrayGPU::from_optional_slice(
+            &[
+                Some(i128::MAX),
+                Some(i128::MIN),
+                None,
+                Some(i128::MIN),
+                Some(-5),
+            ],
+            38,
+            0,
+            device.clone(),
+        );
+        let right = Decimal128ArrayGPU::from_slice(&[1, -1, 1, i128::MAX, 7], 38, 0, device);
+        assert_eq!(
+            left.add(&right).values(),
+            vec![None, None, None, Some(-1), Some(2)]
+        );
+        assert_eq!(
+            left.sub(&right).values(),
+            vec![
+                Some(i128::MAX - 1),
+                Some(i128::MIN + 1),
+                None,
+                None,
+                Some(-12)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decimal128_mul_large_scale() {
+        let device = GPU_DEVICE.clone();
+        // 1.25 and 0.5 with scales 30 and 20
+        let left = Decimal128ArrayGPU::from_slice(&[125 * 10i128.pow(28)], 31, 30, device.clone());
+        let right = Decimal128ArrayGPU::from_slice(&[5 * 10i128.pow(19)], 20, 20, device);
+        let product = left.mul(&right);
+        assert_eq!(product.scale, 38);
+        assert_eq!(product.values(), vec![Some(625 * 10i128.pow(35))]);
+    }
+
+    #[test]
+    fn test_decimal128_sum() {
+        let device = GPU_DEVICE.clone();
+        let values: Vec<Option<i128>> = (0..1000)
+            .map(|x| (x % 7 != 0).then_some((x as i128 - 500) << 90))
+            .collect();
+        let array = Decimal128ArrayGPU::from_optional_slice(&values, 38, 4, device.clone());
+        let sum = array.sum();
+        assert_eq!((sum.precision, sum.scale), (38, 4));
+        assert_eq!(sum.values(), vec![Some(values.iter().flatten().sum())]);
+
+        let array = Decimal128ArrayGPU::from_slice(&[i128::MAX, 1, 1], 38, 0, device.clone());
+        assert_eq!(array.sum().values(), vec![None]);
+
+        // intermediate sums may exceed i128 as long as the total fits
+        let array = Decimal128ArrayGPU::from_slice(&[i128::MAX, 1, -1], 38, 0, device.clone());
+        assert_eq!(array.sum().values(), vec![Some(i128::MAX)]);
+
+        let array = Decimal128ArrayGPU::from_slice(&[i128::MIN, -1, 2], 38, 0, device.clone());
+        assert_eq!(array.sum().values(), vec![Some(i128::MIN + 1)]);
+
+        let array = Decimal128ArrayGPU::from_optional_slice(&[None, None], 38, 0, device.clone());
+        assert_eq!(array.sum().values(), vec![None]);
+
+        let array = Decimal128ArrayGPU::from_slice(&[], 38, 0, device);
+        assert_eq!(array.sum().values(), vec![None]);
+    }
+}
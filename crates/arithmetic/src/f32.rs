@@ -11,6 +11,7 @@ const F32_SCALAR_SHADER: &str = include_str!("../compute_shaders/f32/scalar.wgsl
 const F32_ARRAY_SHADER: &str = include_str!("../compute_shaders/f32/array.wgsl");
 const F32_NEG_SHADER: &str = include_str!("../compute_shaders/f32/neg.wgsl");
 
+#[allow(deprecated)]
 impl Sum32Bit for f32 {
     const SHADER: &'static str = include_str!("../compute_shaders/f32/aggregate.wgsl");
 }
@@ -287,4 +288,21 @@ mod tests {
         4 * 1024 * 1024,
         4.0 * 1024.0 * 1024.0 * 5.0
     );
+
+    #[test]
+    fn test_add_sliced_f32_arrays() {
+        let device = arrow_gpu_array::GPU_DEVICE.clone();
+        let left_values = (0..200)
+            .map(|x| (x % 4 != 0).then_some(x as f32))
+            .collect::<Vec<_>>();
+        let right_values = (0..200).map(|x| Some(x as f32 * 0.5)).collect::<Vec<_>>();
+        let left = Float32ArrayGPU::from_optional_slice(&left_values, device.clone());
+        let right = Float32ArrayGPU::from_optional_slice(&right_values, device);
+
+        let result = left.slice(64, 100).add(&right.slice(3, 100));
+        let expected = (0..100)
+            .map(|i| left_values[64 + i].map(|x| x + right_values[3 + i].unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(result.values(), expected);
+    }
 }
@@ -0,0 +1,150 @@
+use crate::*;
+use arrow_gpu_array::array::{types::*, *};
+use arrow_gpu_array::gpu_utils::*;
+
+const F64_SCALAR_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/f64/utils.wgsl"),
+    include_str!("../compute_shaders/f64/scalar.wgsl")
+);
+const F64_ARRAY_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/f64/utils.wgsl"),
+    include_str!("../compute_shaders/f64/array.wgsl")
+);
+
+impl_arithmetic_op!(
+    ArrowScalarAdd,
+    Float64Type,
+    add_scalar_op,
+    Float64ArrayGPU,
+    F64_SCALAR_SHADER,
+    "f64_add"
+);
+impl_arithmetic_op!(
+    ArrowScalarSub,
+    Float64Type,
+    sub_scalar_op,
+    Float64ArrayGPU,
+    F64_SCALAR_SHADER,
+    "f64_sub"
+);
+impl_arithmetic_op!(
+    ArrowScalarMul,
+    Float64Type,
+    mul_scalar_op,
+    Float64ArrayGPU,
+    F64_SCALAR_SHADER,
+    "f64_mul"
+);
+impl_arithmetic_op!(
+    ArrowScalarDiv,
+    Float64Type,
+    div_scalar_op,
+    Float64ArrayGPU,
+    F64_SCALAR_SHADER,
+    "f64_div"
+);
+
+impl_arithmetic_array_op!(
+    ArrowAdd,
+    Float64Type,
+    add_op,
+    Float64ArrayGPU,
+    F64_ARRAY_SHADER,
+    "add_f64"
+);
+impl_arithmetic_array_op!(
+    ArrowSub,
+    Float64Type,
+    sub_op,
+    Float64ArrayGPU,
+    F64_ARRAY_SHADER,
+    "sub_f64"
+);
+impl_arithmetic_array_op!(
+    ArrowMul,
+    Float64Type,
+    mul_op,
+    Float64ArrayGPU,
+    F64_ARRAY_SHADER,
+    "mul_f64"
+);
+impl_arithmetic_array_op!(
+    ArrowDiv,
+    Float64Type,
+    div_op,
+    Float64ArrayGPU,
+    F64_ARRAY_SHADER,
+    "div_f64"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    fn assert_close(result: &[Option<f64>], expected: &[Option<f64>]) {
+        assert_eq!(result.len(), expected.len());
+        for (result, expected) in result.iter().zip(expected) {
+            match (result, expected) {
+                (Some(result), Some(expected)) if expected.is_finite() => assert!(
+                    (result - expected).abs() <= expected.abs() * 1e-13,
+                    "{result} != {expected}"
+                ),
+                (Some(result), Some(expected)) if expected.is_nan() => assert!(result.is_nan()),
+                _ => assert_eq!(result, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn test_f64_array_op() {
+        let device = GPU_DEVICE.clone();
+        let left_values = [
+            Some(0.1),
+            Some(1.5),
+            None,
+            Some(1e10 + 1e-4),
+            Some(-7.25e-20),
+            Some(f64::INFINITY),
+            Some(123456.789012345),
+        ];
+        let right_values = [0.2, 2.25, 3.0, -1e10, 3.0e5, 1.0, -0.000987654321];
+        let left = Float64ArrayGPU::from_optional_slice(&left_values, device.clone());
+        let right = Float64ArrayGPU::from_slice(&right_values, device);
+
+        let apply = |f: fn(f64, f64) -> f64| {
+            left_values
+                .iter()
+                .zip(right_values)
+                .map(|(l, r)| l.map(|l| f(l, r)))
+                .collect::<Vec<_>>()
+        };
+        assert_close(&left.add(&right).values(), &apply(|l, r| l + r));
+        assert_close(&left.sub(&right).values(), &apply(|l, r| l - r));
+        assert_close(&left.mul(&right).values(), &apply(|l, r| l * r));
+        let quotient: Float64ArrayGPU = div_dyn(&left.clone_array().into(), &right.into())
+            .try_into()
+            .unwrap();
+        assert_close(&quotient.values(), &apply(|l, r| l / r));
+
+        // values with short significands are exact
+        assert_eq!(left.add(&left).values()[1], Some(3.0));
+    }
+
+    #[test]
+    fn test_f64_scalar_op() {
+        let device = GPU_DEVICE.clone();
+        let values = [1.0, -2.0, 1e-3, 0.0];
+        let array = Float64ArrayGPU::from_slice(&values, device.clone());
+        let scalar = Float64ArrayGPU::from_slice(&[3.0], device);
+
+        let expected = |f: fn(f64) -> f64| values.map(|x| Some(f(x)));
+        assert_close(&array.add_scalar(&scalar).values(), &expected(|x| x + 3.0));
+        assert_close(&array.sub_scalar(&scalar).values(), &expected(|x| x - 3.0));
+        assert_close(&array.mul_scalar(&scalar).values(), &expected(|x| x * 3.0));
+        let quotient: Float64ArrayGPU = div_dyn(&array.into(), &scalar.into()).try_into().unwrap();
+        assert_close(&quotient.values(), &expected(|x| x / 3.0));
+    }
+}
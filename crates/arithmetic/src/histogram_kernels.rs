@@ -0,0 +1,205 @@
+use arrow_gpu_array::{array::*, gpu_utils::*};
+
+use crate::{AggregateType, validity_buffer};
+
+/// Trait for binning the elements in the array
+pub trait Histogram: ArrayUtils + Sized {
+    fn histogram(&self, bins: usize, range: (f64, f64)) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("histogram"));
+        let result = self.histogram_op(bins, range, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn bucketize(&self, boundaries: &Float32ArrayGPU) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("bucketize"));
+        let result = self.bucketize_op(boundaries, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Counts the elements in `bins` bins of equal width over `range`, the last bin
+    /// includes the end of the range. Null values, NaN and values outside of the range
+    /// are not counted.
+    fn histogram_op(
+        &self,
+        bins: usize,
+        range: (f64, f64),
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU;
+
+    /// Assigns each element the number of boundaries not above it, so the bin of a value
+    /// `v` is `i` when `boundaries[i - 1] <= v < boundaries[i]`. NaN is above every
+    /// boundary and null values stay null. The boundaries must be sorted and valid.
+    fn bucketize_op(
+        &self,
+        boundaries: &Float32ArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU;
+}
+
+impl<T: AggregateType> Histogram for PrimitiveArrayGpu<T> {
+    fn histogram_op(
+        &self,
+        bins: usize,
+        range: (f64, f64),
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU {
+        let (lower, upper) = range;
+        assert!(bins > 0, "Histogram needs at least one bin");
+        assert!(
+            lower < upper && lower.is_finite() && upper.is_finite(),
+            "Histogram range {range:?} is not a finite non empty range"
+        );
+
+        let (lower, upper) = (lower.to_bits(), upper.to_bits());
+        let params = pipeline.device.create_uniform_buffer(&[
+            self.len as u32,
+            bins as u32,
+            0,
+            0,
+            lower as u32,
+            (lower >> 32) as u32,
+            upper as u32,
+            (upper >> 32) as u32,
+        ]);
+        let validity = validity_buffer(self);
+        let counts = pipeline.device.create_empty_buffer(bins as u64 * 4);
+        pipeline.apply_function(
+            &[&self.data, &params, &counts, &validity.bit_buffer],
+            T::HISTOGRAM_SHADER,
+            "histogram",
+            self.len.div_ceil(256) as u32,
+        );
+
+        UInt32ArrayGPU {
+            data: counts.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: bins,
+            null_buffer: None,
+        }
+    }
+
+    fn bucketize_op(
+        &self,
+        boundaries: &Float32ArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU {
+        assert!(
+            boundaries.null_buffer.is_none(),
+            "Boundaries of bucketize can not be null"
+        );
+        let output = pipeline
+            .device
+            .create_empty_buffer(self.len.max(1) as u64 * 4);
+        // without boundaries every value is in the first bucket
+        if boundaries.len > 0 {
+            let params = pipeline.device.create_uniform_buffer(&[
+                self.len as u32,
+                boundaries.len as u32,
+                0,
+                0,
+            ]);
+            pipeline.apply_function(
+                &[&self.data, &params, &output, &boundaries.data],
+                T::BUCKETIZE_SHADER,
+                "bucketize",
+                self.len.div_ceil(256) as u32,
+            );
+        }
+
+        UInt32ArrayGPU {
+            data: output.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    fn cpu_histogram(values: &[Option<f64>], bins: usize, range: (f64, f64)) -> Vec<Option<u32>> {
+        let mut counts = vec![0; bins];
+        let width = (range.1 - range.0) / bins as f64;
+        for value in values.iter().flatten() {
+            if *value >= range.0 && *value <= range.1 {
+                let bin = (((value - range.0) / width) as usize).min(bins - 1);
+                counts[bin] += 1;
+            }
+        }
+        counts.into_iter().map(Some).collect()
+    }
+
+    #[test]
+    fn test_histogram_f32() {
+        let values = (0..70_000)
+            .map(|x: u32| (!x.is_multiple_of(11)).then_some(((x * 7919) % 10_007) as f32 / 4.0))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        for (bins, range) in [(10, (0.0, 2500.0)), (3000, (100.0, 1600.0))] {
+            assert_eq!(
+                array.histogram(bins, range).values(),
+                cpu_histogram(&as_f64, bins, range)
+            );
+        }
+    }
+
+    #[test]
+    fn test_histogram_edges_and_nan() {
+        let values = [0.0, 0.25, 0.5, 0.75, 1.0, -0.5, 1.5, f64::NAN];
+        let array = Float64ArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        let counts = array.histogram(4, (0.0, 1.0));
+        assert_eq!(counts.values(), vec![Some(1), Some(1), Some(1), Some(2)]);
+
+        let values = [Some(-3i8), None, Some(7), Some(0), Some(-3)];
+        let array = Int8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let counts = array.histogram(2, (-4.0, 8.0));
+        assert_eq!(counts.values(), vec![Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn test_bucketize() {
+        let boundaries = Float32ArrayGPU::from_slice(&[-1.0, 0.0, 2.5, 10.0], GPU_DEVICE.clone());
+        let values = [
+            Some(-5),
+            Some(-1),
+            None,
+            Some(0),
+            Some(2),
+            Some(3),
+            Some(10),
+            Some(100),
+        ];
+        let array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.bucketize(&boundaries).values(),
+            vec![
+                Some(0),
+                Some(1),
+                None,
+                Some(2),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(4)
+            ]
+        );
+
+        let values = [0.1f32, f32::NAN, -0.0];
+        let array = Float32ArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.bucketize(&boundaries).values(),
+            vec![Some(2), Some(4), Some(2)]
+        );
+
+        let empty = Float32ArrayGPU::from_slice(&[1.0], GPU_DEVICE.clone()).slice(0, 0);
+        assert_eq!(array.bucketize(&empty).values(), vec![Some(0); 3]);
+    }
+}
@@ -6,6 +6,7 @@ use arrow_gpu_array::gpu_utils::*;
 const I32_SCALAR_SHADER: &str = include_str!("../compute_shaders/i32/scalar.wgsl");
 const I32_ARRAY_SHADER: &str = include_str!("../compute_shaders/i32/array.wgsl");
 
+#[allow(deprecated)]
 impl Sum32Bit for i32 {
     const SHADER: &'static str = include_str!("../compute_shaders/i32/aggregate.wgsl");
 }
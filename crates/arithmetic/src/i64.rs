@@ -0,0 +1,150 @@
+use crate::u64::{U64_ARRAY_SHADER, U64_SCALAR_SHADER};
+use crate::*;
+use arrow_gpu_array::array::{types::Int64Type, *};
+use arrow_gpu_array::gpu_utils::*;
+
+// Two's complement add, sub and mul give the same bits for signed and unsigned values,
+// hence the u64 shaders are reused.
+
+impl_arithmetic_op!(
+    ArrowScalarAdd,
+    Int64Type,
+    add_scalar_op,
+    Int64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_add"
+);
+
+impl_arithmetic_op!(
+    ArrowScalarSub,
+    Int64Type,
+    sub_scalar_op,
+    Int64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_sub"
+);
+
+impl_arithmetic_op!(
+    ArrowScalarMul,
+    Int64Type,
+    mul_scalar_op,
+    Int64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_mul"
+);
+
+impl_arithmetic_array_op!(
+    ArrowAdd,
+    Int64Type,
+    add_op,
+    Int64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "add_u64"
+);
+
+impl_arithmetic_array_op!(
+    ArrowSub,
+    Int64Type,
+    sub_op,
+    Int64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "sub_u64"
+);
+
+impl_arithmetic_array_op!(
+    ArrowMul,
+    Int64Type,
+    mul_op,
+    Int64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "mul_u64"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_test_macros::{test_array_op, test_scalar_op};
+
+    test_scalar_op!(
+        test_add_i64_scalar_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        [0, -1, i32::MAX as i64, i64::MIN, -(1 << 40)],
+        add_scalar,
+        add_scalar_dyn,
+        -2i64,
+        [-2, -3, i32::MAX as i64 - 2, i64::MAX - 1, -(1 << 40) - 2]
+    );
+
+    test_scalar_op!(
+        test_sub_i64_scalar_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        [0, -1, 1 << 32, i64::MIN, -(1 << 40)],
+        sub_scalar,
+        sub_scalar_dyn,
+        5i64,
+        [-5, -6, (1 << 32) - 5, i64::MAX - 4, -(1 << 40) - 5]
+    );
+
+    test_scalar_op!(
+        test_mul_i64_scalar_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        [0, -1, 1 << 32, -123_456_789, 7],
+        mul_scalar,
+        mul_scalar_dyn,
+        -1_000_000i64,
+        [
+            0,
+            1_000_000,
+            -(1_000_000i64 << 32),
+            123_456_789_000_000,
+            -7_000_000
+        ]
+    );
+
+    test_array_op!(
+        test_add_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        add,
+        add_array_dyn,
+        [Some(-1i64), Some(i32::MIN as i64), None, Some(i64::MAX)],
+        [Some(1i64), Some(-1), Some(2), Some(1)],
+        [Some(0), Some(i32::MIN as i64 - 1), None, Some(i64::MIN)]
+    );
+
+    test_array_op!(
+        test_sub_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        sub,
+        sub_array_dyn,
+        [Some(-1i64), Some(0), None, Some(i64::MIN)],
+        [Some(1i64), Some(1 << 33), Some(2), Some(1)],
+        [Some(-2), Some(-(1 << 33)), None, Some(i64::MAX)]
+    );
+
+    test_array_op!(
+        test_mul_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        mul,
+        mul_array_dyn,
+        [Some(-3i64), Some(-(1 << 20)), None, Some(99_999_999)],
+        [Some(5i64), Some(-(1 << 30)), Some(2), Some(-99_999_999)],
+        [
+            Some(-15),
+            Some(1 << 50),
+            None,
+            Some(-99_999_999i64 * 99_999_999)
+        ]
+    );
+}
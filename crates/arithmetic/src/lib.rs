@@ -1,12 +1,25 @@
 pub(crate) mod aggregate_kernels;
 pub(crate) mod arithmetic_kernels;
+pub(crate) mod decimal;
+pub(crate) mod f16;
 pub(crate) mod f32;
+pub(crate) mod f64;
+pub(crate) mod histogram_kernels;
 pub(crate) mod i32;
+pub(crate) mod i64;
+pub(crate) mod quantile_kernels;
+pub(crate) mod scan_kernels;
+pub(crate) mod statistics_kernels;
 pub(crate) mod u16;
 pub(crate) mod u32;
+pub(crate) mod u64;
 
 pub use aggregate_kernels::*;
 pub use arithmetic_kernels::*;
+pub use histogram_kernels::*;
+pub use quantile_kernels::*;
+pub use scan_kernels::*;
+pub use statistics_kernels::*;
 
 macro_rules! impl_arithmetic_op {
     ($trait_name: ident, $array_type:ident, $trait_function: ident, $ty: ident, $shader: ident, $entry_point: expr) => {
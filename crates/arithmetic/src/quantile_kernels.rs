@@ -0,0 +1,317 @@
+use arrow_gpu_array::{array::*, gpu_utils::*};
+use arrow_gpu_routines::{FilterType, Sort, SortOptions, SortType};
+use wgpu::Buffer;
+
+use crate::{AggregateType, Count, ScanOp, f64_result_op, reduce_op, validity_buffer};
+
+/// Number of bins of the histogram of the approximate quantiles
+pub const APPROX_QUANTILE_BINS: usize = 2048;
+
+/// Value of a quantile falling between the values at ranks `i < j`, as in arrow compute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileInterpolation {
+    /// `value[i] + (value[j] - value[i]) * fraction`
+    #[default]
+    Linear,
+    /// `value[i]`
+    Lower,
+    /// `value[j]`
+    Higher,
+    /// The value at the nearest rank, the even one on ties
+    Nearest,
+    /// `(value[i] + value[j]) / 2`
+    Midpoint,
+}
+
+/// Trait for the quantiles of the elements in the array. Null values are skipped and
+/// the quantile is null when no value is valid or when `q` is not between 0 and 1.
+/// The quantile `q` lies at rank `q * (n - 1)` of the `n` valid values in sorted order.
+pub trait Quantile: ArrayUtils + Sized {
+    fn quantile(&self, q: f64, interpolation: QuantileInterpolation) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("quantile"));
+        let result = self.quantile_op(q, interpolation, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn approx_quantile(&self, q: f64, interpolation: QuantileInterpolation) -> Float64ArrayGPU {
+        let mut pipeline =
+            ArrowComputePipeline::new(self.get_gpu_device(), Some("approx_quantile"));
+        let result = self.approx_quantile_op(q, interpolation, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn median(&self) -> Float64ArrayGPU {
+        self.quantile(0.5, QuantileInterpolation::Linear)
+    }
+
+    /// Computes the quantile `q` of the elements in the array by sorting them
+    fn quantile_op(
+        &self,
+        q: f64,
+        interpolation: QuantileInterpolation,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU;
+
+    /// Approximates the quantile `q` from a histogram of the elements without sorting
+    /// them. The values of a rank are off by at most the width of a bin, the range of
+    /// the values divided by `APPROX_QUANTILE_BINS`.
+    fn approx_quantile_op(
+        &self,
+        q: f64,
+        interpolation: QuantileInterpolation,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU;
+
+    fn median_op(&self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        self.quantile_op(0.5, QuantileInterpolation::Linear, pipeline)
+    }
+}
+
+fn quantile_params(
+    q: f64,
+    interpolation: QuantileInterpolation,
+    len: usize,
+    max_row: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let q = q.to_bits();
+    pipeline.device.create_uniform_buffer(&[
+        len as u32,
+        interpolation as u32,
+        max_row as u32,
+        0,
+        q as u32,
+        (q >> 32) as u32,
+        0,
+        0,
+    ])
+}
+
+impl<T: AggregateType + SortType + FilterType> Quantile for PrimitiveArrayGpu<T> {
+    fn quantile_op(
+        &self,
+        q: f64,
+        interpolation: QuantileInterpolation,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU {
+        if self.len == 0 || !(0.0..=1.0).contains(&q) {
+            return Float64ArrayGPU::from_optional_slice(&[None], pipeline.device.clone());
+        }
+        let params = quantile_params(q, interpolation, self.len, 0, pipeline);
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let sorted = self.sort_op(options, pipeline);
+        let count = self.count_valid_op(pipeline);
+        let result = pipeline.device.create_empty_buffer(12);
+        pipeline.apply_function(
+            &[&sorted.data, &params, &result, &count.data],
+            T::QUANTILE_SORTED_SHADER,
+            "quantile_sorted",
+            1,
+        );
+        f64_result_op(&result, pipeline)
+    }
+
+    fn approx_quantile_op(
+        &self,
+        q: f64,
+        interpolation: QuantileInterpolation,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Float64ArrayGPU {
+        // the minimum and the maximum are both padded to a word
+        let bound_size = std::mem::size_of::<T::NativeType>().div_ceil(4) * 4;
+        let max_row = bound_size / std::mem::size_of::<T::NativeType>();
+        if !(0.0..=1.0).contains(&q) {
+            return Float64ArrayGPU::from_optional_slice(&[None], pipeline.device.clone());
+        }
+        let params = quantile_params(q, interpolation, self.len, max_row, pipeline);
+
+        let minimum = reduce_op(self, ScanOp::Min, pipeline);
+        let maximum = reduce_op(self, ScanOp::Max, pipeline);
+        let bounds = pipeline.device.create_empty_buffer(bound_size as u64 * 2);
+        pipeline.copy_buffer_to_buffer(&minimum.data, 0, &bounds, 0, bound_size as u64);
+        pipeline.copy_buffer_to_buffer(
+            &maximum.data,
+            0,
+            &bounds,
+            bound_size as u64,
+            bound_size as u64,
+        );
+
+        let validity = validity_buffer(self);
+        let histogram = pipeline
+            .device
+            .create_empty_buffer(APPROX_QUANTILE_BINS as u64 * 4);
+        pipeline.apply_function(
+            &[
+                &self.data,
+                &params,
+                &histogram,
+                &validity.bit_buffer,
+                &bounds,
+            ],
+            T::QUANTILE_HISTOGRAM_SHADER,
+            "quantile_histogram",
+            self.len.div_ceil(256) as u32,
+        );
+
+        let result = pipeline.device.create_empty_buffer(12);
+        pipeline.apply_function(
+            &[&bounds, &params, &result, &histogram],
+            T::QUANTILE_APPROX_SHADER,
+            "quantile_approx",
+            1,
+        );
+        f64_result_op(&result, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    const MODES: [QuantileInterpolation; 5] = [
+        QuantileInterpolation::Linear,
+        QuantileInterpolation::Lower,
+        QuantileInterpolation::Higher,
+        QuantileInterpolation::Nearest,
+        QuantileInterpolation::Midpoint,
+    ];
+
+    fn cpu_quantile(values: &[Option<f64>], q: f64, interpolation: QuantileInterpolation) -> f64 {
+        let mut sorted = values.iter().flatten().copied().collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+        let position = q * (sorted.len() - 1) as f64;
+        let (low, high) = (position.floor() as usize, position.ceil() as usize);
+        let fraction = position - low as f64;
+        let (a, b) = (sorted[low], sorted[high]);
+        match interpolation {
+            QuantileInterpolation::Linear => a + (b - a) * fraction,
+            QuantileInterpolation::Lower => a,
+            QuantileInterpolation::Higher => b,
+            QuantileInterpolation::Nearest if fraction < 0.5 => a,
+            QuantileInterpolation::Nearest if fraction > 0.5 => b,
+            QuantileInterpolation::Nearest if low % 2 == 0 => a,
+            QuantileInterpolation::Nearest => b,
+            QuantileInterpolation::Midpoint => (a + b) / 2.0,
+        }
+    }
+
+    // interpolated quantiles are computed in double-single
+    fn assert_close(result: Float64ArrayGPU, expected: f64, tolerance: f64) {
+        let result = result.values()[0].unwrap();
+        assert!(
+            (result - expected).abs() <= tolerance,
+            "{result} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_quantile_i32() {
+        let values = (0..3000)
+            .map(|x: i32| (x % 7 != 3).then_some((x * 7919) % 10_007 - 5000))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        for q in [0.0, 0.1, 0.25, 0.5, 0.9, 0.999, 1.0] {
+            for interpolation in MODES {
+                let expected = cpu_quantile(&as_f64, q, interpolation);
+                assert_close(array.quantile(q, interpolation), expected, 1e-8);
+            }
+        }
+        let expected = cpu_quantile(&as_f64, 0.5, QuantileInterpolation::Linear);
+        assert_close(array.median(), expected, 1e-8);
+    }
+
+    #[test]
+    fn test_quantile_modes_f64() {
+        let values = [Some(4.0), None, Some(1.0), Some(3.0), Some(2.0)];
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        // the quantile 0.5 lies halfway between the ranks 1 and 2, nearest takes the even one
+        let expected = [2.5, 2.0, 3.0, 3.0, 2.5];
+        for (interpolation, expected) in MODES.into_iter().zip(expected) {
+            assert_eq!(
+                array.quantile(0.5, interpolation).values(),
+                vec![Some(expected)]
+            );
+        }
+        // f64 values are taken exactly
+        let values = [Some(0.1f64), Some(1e300)];
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let lower = array.quantile(0.0, QuantileInterpolation::Lower);
+        assert_eq!(lower.values(), vec![Some(0.1)]);
+    }
+
+    #[test]
+    fn test_quantile_all_null() {
+        let array = Float32ArrayGPU::from_optional_slice(&[None, None], GPU_DEVICE.clone());
+        assert_eq!(array.median().values(), vec![None]);
+        let approx = array.approx_quantile(0.5, QuantileInterpolation::Linear);
+        assert_eq!(approx.values(), vec![None]);
+
+        let array = UInt8ArrayGPU::from_slice(&[1], GPU_DEVICE.clone()).slice(0, 0);
+        assert_eq!(array.median().values(), vec![None]);
+    }
+
+    #[test]
+    fn test_quantile_out_of_range() {
+        let array = Int32ArrayGPU::from_slice(&[1, 2, 3], GPU_DEVICE.clone());
+        for q in [-0.1, 1.5, f64::NAN] {
+            let quantile = array.quantile(q, QuantileInterpolation::Linear);
+            assert_eq!(quantile.values(), vec![None]);
+            let approx = array.approx_quantile(q, QuantileInterpolation::Linear);
+            assert_eq!(approx.values(), vec![None]);
+        }
+    }
+
+    #[test]
+    fn test_quantile_64bit() {
+        // the ranks are converted to f64 from all 64 bits, rounding to the nearest f64
+        let values = [i64::MAX, (1 << 60) + 1, -(1 << 62) - 3, (1 << 53) + 1];
+        let array = Int64ArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        let mut sorted = values;
+        sorted.sort();
+        for (rank, value) in sorted.iter().enumerate() {
+            let q = rank as f64 / 3.0;
+            let quantile = array.quantile(q, QuantileInterpolation::Nearest);
+            assert_eq!(quantile.values(), vec![Some(*value as f64)]);
+        }
+
+        let values = [u64::MAX, (1 << 63) + 1025, 3];
+        let array = UInt64ArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        let higher = array.quantile(0.6, QuantileInterpolation::Higher);
+        assert_eq!(higher.values(), vec![Some(u64::MAX as f64)]);
+        let lower = array.quantile(0.6, QuantileInterpolation::Lower);
+        assert_eq!(lower.values(), vec![Some(((1u64 << 63) + 1025) as f64)]);
+    }
+
+    #[test]
+    fn test_approx_quantile() {
+        let values = (0..70_000)
+            .map(|x: u32| (!x.is_multiple_of(9)).then_some(((x * 7919) % 100_003) as f32 / 8.0))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        let bin_width = 100_003.0 / 8.0 / APPROX_QUANTILE_BINS as f64;
+        for q in [0.0, 0.2, 0.5, 0.75, 1.0] {
+            for interpolation in MODES {
+                let expected = cpu_quantile(&as_f64, q, interpolation);
+                let approx = array.approx_quantile(q, interpolation);
+                assert_close(approx, expected, bin_width);
+            }
+        }
+
+        let values = (0..1000).map(|x| Some((x % 200) as u8)).collect::<Vec<_>>();
+        let array = UInt8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        let expected = cpu_quantile(&as_f64, 0.3, QuantileInterpolation::Nearest);
+        let approx = array.approx_quantile(0.3, QuantileInterpolation::Nearest);
+        assert_close(approx, expected, 1.0);
+    }
+}
@@ -0,0 +1,596 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+const FIRST_NULL_SHADER: &str = include_str!("../compute_shaders/scan/first_null.wgsl");
+const PREFIX_VALIDITY_SHADER: &str = include_str!("../compute_shaders/scan/prefix_validity.wgsl");
+
+/// Operation combining the values of a scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOp {
+    Sum = 0,
+    Product = 1,
+    Min = 2,
+    Max = 3,
+}
+
+/// How null values affect a scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanNulls {
+    /// Null values are skipped and stay null in the result
+    #[default]
+    Skip,
+    /// Every value from the first null value onwards is null
+    Propagate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScanOptions {
+    /// Each value of the result combines the values before it, without itself
+    pub exclusive: bool,
+    pub nulls: ScanNulls,
+}
+
+/// Trait for running scans over the elements of the array
+pub trait Scan: ArrayUtils + Sized {
+    fn scan(&self, op: ScanOp, options: ScanOptions) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("scan"));
+        let result = self.scan_op(op, options, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Computes the running `op` of the elements in the array
+    fn scan_op(
+        &self,
+        op: ScanOp,
+        options: ScanOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+
+    fn cumsum(&self, options: ScanOptions) -> Self {
+        self.scan(ScanOp::Sum, options)
+    }
+
+    fn cumsum_op(&self, options: ScanOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.scan_op(ScanOp::Sum, options, pipeline)
+    }
+
+    fn cumprod(&self, options: ScanOptions) -> Self {
+        self.scan(ScanOp::Product, options)
+    }
+
+    fn cumprod_op(&self, options: ScanOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.scan_op(ScanOp::Product, options, pipeline)
+    }
+
+    fn cummin(&self, options: ScanOptions) -> Self {
+        self.scan(ScanOp::Min, options)
+    }
+
+    fn cummin_op(&self, options: ScanOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.scan_op(ScanOp::Min, options, pipeline)
+    }
+
+    fn cummax(&self, options: ScanOptions) -> Self {
+        self.scan(ScanOp::Max, options)
+    }
+
+    fn cummax_op(&self, options: ScanOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        self.scan_op(ScanOp::Max, options, pipeline)
+    }
+}
+
+/// Helper trait for Arrow arrays that support scans
+pub trait ScanType: ArrowPrimitiveType {
+    const SHADER: &'static str;
+    /// Shader reducing the values to the total of each block
+    const REDUCE_SHADER: &'static str;
+    /// Size in bytes of the lane a value is scanned in
+    const LANE_SIZE: u64;
+    /// Number of values written by one invocation of `store_values`
+    const VALUES_PER_INVOCATION: usize;
+}
+
+// Prepends the lane type, the bits of its lowest and highest values and the helpers of
+// the scan to the scan or the reduction shader
+macro_rules! scan_shader {
+    ($lane: literal, $lowest: literal, $highest: literal, [$($file: literal),*], $shader_file: literal) => {
+        concat!(
+            "alias Lane = ", $lane, ";\n",
+            "const LANE_LOWEST_BITS = ", $lowest, ";\n",
+            "const LANE_HIGHEST_BITS = ", $highest, ";\n",
+            $(include_str!($file),)*
+            include_str!("../compute_shaders/scan/common.wgsl"),
+            include_str!($shader_file)
+        )
+    };
+}
+
+macro_rules! impl_scan_type {
+    (
+        $ty: ty,
+        $lane_size: expr,
+        $values_per_invocation: expr,
+        $lane: literal,
+        $lowest: literal,
+        $highest: literal,
+        $($file: literal),*
+    ) => {
+        impl ScanType for $ty {
+            const SHADER: &'static str = scan_shader!(
+                $lane,
+                $lowest,
+                $highest,
+                [$($file),*],
+                "../compute_shaders/scan/scan.wgsl"
+            );
+            const REDUCE_SHADER: &'static str = scan_shader!(
+                $lane,
+                $lowest,
+                $highest,
+                [$($file),*],
+                "../compute_shaders/scan/reduce.wgsl"
+            );
+            const LANE_SIZE: u64 = $lane_size;
+            const VALUES_PER_INVOCATION: usize = $values_per_invocation;
+        }
+    };
+}
+
+impl_scan_type!(
+    u8,
+    4,
+    4,
+    "u32",
+    "0u",
+    "0xffu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_8bit.wgsl"
+);
+impl_scan_type!(
+    i8,
+    4,
+    4,
+    "i32",
+    "0xffffff80u",
+    "0x7fu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_8bit.wgsl"
+);
+impl_scan_type!(
+    u16,
+    4,
+    2,
+    "u32",
+    "0u",
+    "0xffffu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_16bit.wgsl"
+);
+impl_scan_type!(
+    i16,
+    4,
+    2,
+    "i32",
+    "0xffff8000u",
+    "0x7fffu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_16bit.wgsl"
+);
+impl_scan_type!(
+    f16,
+    4,
+    2,
+    "f32",
+    "0xff800000u",
+    "0x7f800000u",
+    "../../../compute_shaders/f16/packed.wgsl",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_f16.wgsl"
+);
+impl_scan_type!(
+    u32,
+    4,
+    1,
+    "u32",
+    "0u",
+    "0xffffffffu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+impl_scan_type!(
+    i32,
+    4,
+    1,
+    "i32",
+    "0x80000000u",
+    "0x7fffffffu",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+impl_scan_type!(
+    f32,
+    4,
+    1,
+    "f32",
+    "0xff800000u",
+    "0x7f800000u",
+    "../compute_shaders/scan/lanes_32bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+impl_scan_type!(
+    u64,
+    8,
+    1,
+    "vec2<u32>",
+    "vec2<u32>(0u, 0u)",
+    "vec2<u32>(0xffffffffu, 0xffffffffu)",
+    "../../../compute_shaders/u64/utils.wgsl",
+    "../compute_shaders/scan/combine_u64.wgsl",
+    "../compute_shaders/scan/lanes_64bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+impl_scan_type!(
+    i64,
+    8,
+    1,
+    "vec2<u32>",
+    "vec2<u32>(0u, 0x80000000u)",
+    "vec2<u32>(0xffffffffu, 0x7fffffffu)",
+    "../../../compute_shaders/u64/utils.wgsl",
+    "../../../compute_shaders/i64/utils.wgsl",
+    "../compute_shaders/scan/combine_i64.wgsl",
+    "../compute_shaders/scan/lanes_64bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+impl_scan_type!(
+    f64,
+    8,
+    1,
+    "vec2<u32>",
+    "vec2<u32>(0u, 0xfff00000u)",
+    "vec2<u32>(0u, 0x7ff00000u)",
+    "../../../compute_shaders/u64/utils.wgsl",
+    "../../../compute_shaders/f64/utils.wgsl",
+    "../compute_shaders/scan/combine_f64.wgsl",
+    "../compute_shaders/scan/lanes_64bit.wgsl",
+    "../compute_shaders/scan/io_lanes.wgsl"
+);
+
+/// Scans `len` values in blocks of 256 rows, the totals of the blocks are scanned
+/// recursively and combined back into the blocks. Values are read from the array when
+/// `validity` is given, else `input` holds lanes of a previous level.
+fn scan_blocks_op<T: ScanType>(
+    input: &dyn GpuBufferView,
+    validity: Option<&dyn GpuBufferView>,
+    len: usize,
+    op: ScanOp,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let blocks = len.div_ceil(256);
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[op as u32, len as u32, 0, 0]);
+    let scanned = pipeline
+        .device
+        .create_empty_buffer(len.max(1) as u64 * T::LANE_SIZE);
+    let block_totals = pipeline
+        .device
+        .create_empty_buffer(blocks.max(1) as u64 * T::LANE_SIZE);
+
+    match validity {
+        Some(validity) => pipeline.apply_function(
+            &[input, &params, &scanned, &block_totals, validity],
+            T::SHADER,
+            "scan_values",
+            blocks as u32,
+        ),
+        None => pipeline.apply_function(
+            &[input, &params, &scanned, &block_totals],
+            T::SHADER,
+            "scan_lanes",
+            blocks as u32,
+        ),
+    }
+
+    if blocks > 1 {
+        let scanned_totals = scan_blocks_op::<T>(&block_totals, None, blocks, op, pipeline);
+        pipeline.apply_function(
+            &[&scanned_totals, &params, &scanned],
+            T::SHADER,
+            "add_block_prefixes",
+            blocks as u32,
+        );
+    }
+
+    scanned
+}
+
+/// Null buffer of a scan propagating nulls, rows from the first null one onwards are null
+fn prefix_null_buffer_op(
+    null_buffer: &NullBitBufferGpu,
+    params: &Buffer,
+    pipeline: &mut ArrowComputePipeline,
+) -> NullBitBufferGpu {
+    let words = null_buffer.len.div_ceil(32);
+    let first_null = pipeline.device.create_scalar_buffer(&u32::MAX);
+    pipeline.apply_function(
+        &[&null_buffer.bit_buffer, params, &first_null],
+        FIRST_NULL_SHADER,
+        "first_null_row",
+        words.div_ceil(256) as u32,
+    );
+
+    let new_validity = pipeline.device.create_empty_buffer(words.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&first_null, params, &new_validity],
+        PREFIX_VALIDITY_SHADER,
+        "prefix_validity",
+        words.div_ceil(256) as u32,
+    );
+
+    NullBitBufferGpu {
+        bit_buffer: new_validity.into(),
+        len: null_buffer.len,
+        gpu_device: pipeline.device.clone(),
+    }
+}
+
+impl<T: ScanType> Scan for PrimitiveArrayGpu<T> {
+    fn scan_op(
+        &self,
+        op: ScanOp,
+        options: ScanOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        if self.len == 0 {
+            return self.slice(0, 0);
+        }
+        let validity = match &self.null_buffer {
+            Some(null_buffer) => null_buffer.clone(),
+            None => NullBitBufferGpu::new_set_with_capacity(self.gpu_device.clone(), self.len),
+        };
+        let scanned = scan_blocks_op::<T>(
+            &self.data,
+            Some(&validity.bit_buffer),
+            self.len,
+            op,
+            pipeline,
+        );
+
+        let params = pipeline.device.create_uniform_buffer(&[
+            op as u32,
+            self.len as u32,
+            options.exclusive as u32,
+            0,
+        ]);
+        let new_size = (self.len * std::mem::size_of::<T::NativeType>()).div_ceil(4) * 4;
+        let new_values = pipeline.device.create_empty_buffer(new_size.max(4) as u64);
+        pipeline.apply_function(
+            &[&scanned, &params, &new_values],
+            T::SHADER,
+            "store_values",
+            self.len.div_ceil(T::VALUES_PER_INVOCATION).div_ceil(256) as u32,
+        );
+
+        let null_buffer = match (&self.null_buffer, options.nulls) {
+            (None, _) => None,
+            (Some(null_buffer), ScanNulls::Skip) => Some(null_buffer.clone()),
+            (Some(null_buffer), ScanNulls::Propagate) => {
+                Some(prefix_null_buffer_op(null_buffer, &params, pipeline))
+            }
+        };
+
+        Self {
+            data: new_values.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    const PROPAGATE: ScanOptions = ScanOptions {
+        exclusive: false,
+        nulls: ScanNulls::Propagate,
+    };
+    const EXCLUSIVE: ScanOptions = ScanOptions {
+        exclusive: true,
+        nulls: ScanNulls::Skip,
+    };
+
+    // scans the values on the cpu the way the gpu does with nulls skipped
+    fn expected_scan<T: Copy>(
+        values: &[Option<T>],
+        identity: T,
+        exclusive: bool,
+        combine: impl Fn(T, T) -> T,
+    ) -> Vec<Option<T>> {
+        let mut state = identity;
+        values
+            .iter()
+            .map(|value| {
+                let before = state;
+                if let Some(value) = value {
+                    state = combine(state, *value);
+                }
+                value.map(|_| if exclusive { before } else { state })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cumsum_u32_multi_level() {
+        let values = (0..70_000u32).map(Some).collect::<Vec<_>>();
+        let array = UInt32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let expected = expected_scan(&values, 0, false, u32::wrapping_add);
+        assert_eq!(array.cumsum(Default::default()).values(), expected);
+    }
+
+    #[test]
+    fn test_cumsum_i32_nulls() {
+        let values = (0..1000)
+            .map(|x| (x % 7 != 3).then_some(x - 500))
+            .collect::<Vec<Option<i32>>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, 0, false, i32::wrapping_add)
+        );
+        assert_eq!(
+            array.cumsum(EXCLUSIVE).values(),
+            expected_scan(&values, 0, true, i32::wrapping_add)
+        );
+    }
+
+    #[test]
+    fn test_scan_propagate_nulls() {
+        let values = [Some(3), Some(1), None, Some(2)];
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(PROPAGATE).values(),
+            vec![Some(3), Some(4), None, None]
+        );
+
+        let options = ScanOptions {
+            exclusive: true,
+            nulls: ScanNulls::Propagate,
+        };
+        assert_eq!(
+            array.cummax(options).values(),
+            vec![Some(i32::MIN), Some(3), Some(3), None]
+        );
+    }
+
+    #[test]
+    fn test_scan_empty() {
+        let array = Float32ArrayGPU::from_slice(&[], GPU_DEVICE.clone());
+        assert_eq!(array.cumsum(Default::default()).values(), vec![]);
+        assert_eq!(array.cumprod(PROPAGATE).values(), vec![]);
+        assert_eq!(array.cummin(EXCLUSIVE).values(), vec![]);
+    }
+
+    #[test]
+    fn test_scan_u8_packing() {
+        let values = (0..1030)
+            .map(|x| (x % 9 != 0).then_some((x * 37 % 256) as u8))
+            .collect::<Vec<_>>();
+        let array = UInt8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, 0, false, u8::wrapping_add)
+        );
+        assert_eq!(
+            array.cummin(EXCLUSIVE).values(),
+            expected_scan(&values, u8::MAX, true, u8::min)
+        );
+    }
+
+    #[test]
+    fn test_scan_i8() {
+        let values = (0..300)
+            .map(|x| Some((x % 256) as u8 as i8))
+            .collect::<Vec<_>>();
+        let array = Int8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cummax(EXCLUSIVE).values(),
+            expected_scan(&values, i8::MIN, true, i8::max)
+        );
+        assert_eq!(
+            array.cumprod(Default::default()).values(),
+            expected_scan(&values, 1, false, i8::wrapping_mul)
+        );
+    }
+
+    #[test]
+    fn test_scan_16bit() {
+        let values = (0..777)
+            .map(|x| (x % 5 != 1).then_some(1000 - x * 3))
+            .collect::<Vec<Option<i16>>>();
+        let array = Int16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cummin(Default::default()).values(),
+            expected_scan(&values, i16::MAX, false, i16::min)
+        );
+
+        let values = (0..777)
+            .map(|x| Some((x as u16).wrapping_mul(101)))
+            .collect::<Vec<_>>();
+        let array = UInt16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(EXCLUSIVE).values(),
+            expected_scan(&values, 0, true, u16::wrapping_add)
+        );
+    }
+
+    #[test]
+    fn test_scan_f32() {
+        let values = (0..600)
+            .map(|x| Some(((x % 17) as f32 - 8.0) * 0.5))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cummax(Default::default()).values(),
+            expected_scan(&values, f32::NEG_INFINITY, false, f32::max)
+        );
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, 0.0, false, |a, b| a + b)
+        );
+    }
+
+    #[test]
+    fn test_scan_f16() {
+        let values = (0..300)
+            .map(|x| (x % 4 != 0).then(|| f16::from_f32((x % 8) as f32 * 0.25)))
+            .collect::<Vec<_>>();
+        let array = Float16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, f16::ZERO, false, |a, b| a + b)
+        );
+    }
+
+    #[test]
+    fn test_scan_64bit() {
+        let values = (0..600)
+            .map(|x| (x % 11 != 0).then_some((x - 300) * 10_000_000_000))
+            .collect::<Vec<Option<i64>>>();
+        let array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, 0, false, i64::wrapping_add)
+        );
+        assert_eq!(
+            array.cummin(Default::default()).values(),
+            expected_scan(&values, i64::MAX, false, i64::min)
+        );
+
+        let values = (1..40).map(|x| Some(x as u64)).collect::<Vec<_>>();
+        let array = UInt64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumprod(Default::default()).values(),
+            expected_scan(&values, 1, false, u64::wrapping_mul)
+        );
+
+        let values = (0..300)
+            .map(|x| Some((x % 13) as f64 - 6.5))
+            .collect::<Vec<_>>();
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(
+            array.cumsum(Default::default()).values(),
+            expected_scan(&values, 0.0, false, |a, b| a + b)
+        );
+        assert_eq!(
+            array.cummin(EXCLUSIVE).values(),
+            expected_scan(&values, f64::INFINITY, true, f64::min)
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use arrow_gpu_array::{array::*, gpu_utils::*};
+use wgpu::Buffer;
+
+use crate::{AggregateType, bound_data, validity_buffer};
+
+/// Trait for the variance and standard deviation of the elements in the array.
+/// Null values are skipped and the result is null unless more than `ddof` values are valid.
+pub trait Variance: ArrayUtils + Sized {
+    fn variance(&self, ddof: u32) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("variance"));
+        let result = self.variance_op(ddof, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn stddev(&self, ddof: u32) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("stddev"));
+        let result = self.stddev_op(ddof, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Computes the variance with `n - ddof` as divisor, 0 for the population and
+    /// 1 for the sample variance
+    fn variance_op(&self, ddof: u32, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU;
+
+    /// Computes the square root of the variance
+    fn stddev_op(&self, ddof: u32, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU;
+}
+
+/// Trait for the covariance and correlation of the elements in two arrays of the same length.
+/// Rows where either array is null are skipped.
+pub trait Covariance: ArrayUtils + Sized {
+    fn covariance(&self, other: &Self) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("covariance"));
+        let result = self.covariance_op(other, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn correlation(&self, other: &Self) -> Float64ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("correlation"));
+        let result = self.correlation_op(other, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Computes the sample covariance, null unless at least 2 rows are valid
+    fn covariance_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU;
+
+    /// Computes the Pearson correlation coefficient, null unless at least 2 rows are valid
+    fn correlation_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU;
+}
+
+/// Merges the moments of blocks of 256 rows, then of the blocks recursively until one is
+/// left, which writes the result as f64 bits followed by its validity.
+/// The first level binds the first of `values` as input and the others after the params
+/// and the output.
+fn moments_op(
+    values: &[&dyn GpuBufferView],
+    len: usize,
+    params: [u32; 2],
+    state_words: u64,
+    shader: &str,
+    name: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float64ArrayGPU {
+    let mut len = len;
+    let mut blocks = len.div_ceil(256).max(1);
+    let create_params = |pipeline: &mut ArrowComputePipeline, len: usize| {
+        pipeline
+            .device
+            .create_uniform_buffer(&[len as u32, params[0], params[1], 0])
+    };
+    let create_partials = |pipeline: &mut ArrowComputePipeline, blocks: usize| {
+        // the single block of the last level writes the result and its validity
+        let size = if blocks == 1 {
+            12
+        } else {
+            blocks as u64 * state_words * 4
+        };
+        pipeline.device.create_empty_buffer(size)
+    };
+
+    let uniform = create_params(pipeline, len);
+    let mut partials = create_partials(pipeline, blocks);
+    let mut buffers: Vec<&dyn GpuBufferView> = vec![values[0], &uniform, &partials];
+    buffers.extend_from_slice(&values[1..]);
+    pipeline.apply_function(&buffers, shader, &format!("{name}_values"), blocks as u32);
+
+    while blocks > 1 {
+        len = blocks;
+        blocks = len.div_ceil(256);
+        let uniform = create_params(pipeline, len);
+        let new_partials = create_partials(pipeline, blocks);
+        pipeline.apply_function(
+            &[&partials, &uniform, &new_partials],
+            shader,
+            &format!("{name}_lanes"),
+            blocks as u32,
+        );
+        partials = new_partials;
+    }
+
+    f64_result_op(&partials, pipeline)
+}
+
+/// Creates an array of length 1 from a result written by a shader as f64 bits
+/// followed by its validity
+pub(crate) fn f64_result_op(
+    result: &Buffer,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float64ArrayGPU {
+    let data = pipeline.device.create_empty_buffer(8);
+    let bit_buffer = pipeline.device.create_empty_buffer(4);
+    pipeline.copy_buffer_to_buffer(result, 0, &data, 0, 8);
+    pipeline.copy_buffer_to_buffer(result, 8, &bit_buffer, 0, 4);
+
+    Float64ArrayGPU {
+        data: data.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len: 1,
+        null_buffer: Some(NullBitBufferGpu {
+            bit_buffer: bit_buffer.into(),
+            len: 1,
+            gpu_device: pipeline.device.clone(),
+        }),
+    }
+}
+
+fn variance_moments_op<T: AggregateType>(
+    array: &PrimitiveArrayGpu<T>,
+    ddof: u32,
+    stddev: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float64ArrayGPU {
+    let validity = validity_buffer(array);
+    moments_op(
+        &[&bound_data(array, pipeline), &validity.bit_buffer],
+        array.len,
+        [ddof, stddev as u32],
+        5,
+        T::VARIANCE_SHADER,
+        "variance",
+        pipeline,
+    )
+}
+
+fn co_moments_op<T: AggregateType>(
+    array: &PrimitiveArrayGpu<T>,
+    other: &PrimitiveArrayGpu<T>,
+    correlation: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float64ArrayGPU {
+    assert_eq!(
+        array.len, other.len,
+        "Arrays of the covariance must have the same length"
+    );
+    let validity = match array.len {
+        0 => validity_buffer(array),
+        _ => NullBitBufferGpu::merge_null_bit_buffer_op(
+            &array.null_buffer,
+            &other.null_buffer,
+            pipeline,
+        )
+        .unwrap_or_else(|| validity_buffer(array)),
+    };
+    moments_op(
+        &[
+            &bound_data(array, pipeline),
+            &bound_data(other, pipeline),
+            &validity.bit_buffer,
+        ],
+        array.len,
+        [1, correlation as u32],
+        11,
+        T::COVARIANCE_SHADER,
+        "covariance",
+        pipeline,
+    )
+}
+
+impl<T: AggregateType> Variance for PrimitiveArrayGpu<T> {
+    fn variance_op(&self, ddof: u32, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        variance_moments_op(self, ddof, false, pipeline)
+    }
+
+    fn stddev_op(&self, ddof: u32, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        variance_moments_op(self, ddof, true, pipeline)
+    }
+}
+
+impl<T: AggregateType> Covariance for PrimitiveArrayGpu<T> {
+    fn covariance_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        co_moments_op(self, other, false, pipeline)
+    }
+
+    fn correlation_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        co_moments_op(self, other, true, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    // two pass moments on the cpu: count, mean_x, mean_y, m2_x, m2_y, c
+    fn cpu_moments(x: &[Option<f64>], y: &[Option<f64>]) -> (f64, f64, f64, f64, f64, f64) {
+        let rows = x
+            .iter()
+            .zip(y)
+            .filter_map(|(x, y)| Some(((*x)?, (*y)?)))
+            .collect::<Vec<_>>();
+        let n = rows.len() as f64;
+        let mean_x = rows.iter().map(|r| r.0).sum::<f64>() / n;
+        let mean_y = rows.iter().map(|r| r.1).sum::<f64>() / n;
+        let m2_x = rows.iter().map(|r| (r.0 - mean_x).powi(2)).sum::<f64>();
+        let m2_y = rows.iter().map(|r| (r.1 - mean_y).powi(2)).sum::<f64>();
+        let c = rows
+            .iter()
+            .map(|r| (r.0 - mean_x) * (r.1 - mean_y))
+            .sum::<f64>();
+        (n, mean_x, mean_y, m2_x, m2_y, c)
+    }
+
+    // the moments are merged in double-single, which carries 48 bits of significand
+    fn assert_close(result: Float64ArrayGPU, expected: f64) {
+        let result = result.values()[0].unwrap();
+        assert!(
+            (result - expected).abs() <= expected.abs() * 1e-10,
+            "{result} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_variance_f32_multi_level() {
+        let values = (0..70_000)
+            .map(|x| (x % 13 != 5).then_some(((x * 7919) % 10_007) as f32 * 0.25 + 1000.0))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        let (n, _, _, m2, _, _) = cpu_moments(&as_f64, &as_f64);
+        assert_close(array.variance(0), m2 / n);
+        assert_close(array.variance(1), m2 / (n - 1.0));
+        assert_close(array.stddev(1), (m2 / (n - 1.0)).sqrt());
+    }
+
+    #[test]
+    fn test_variance_integers() {
+        let values = (0..3000)
+            .map(|x: i32| (x % 4 != 1).then_some(x.wrapping_mul(1_000_003)))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        let (n, _, _, m2, _, _) = cpu_moments(&as_f64, &as_f64);
+        assert_close(array.variance(1), m2 / (n - 1.0));
+
+        let values = (0..500)
+            .map(|x| Some((x * 37 % 256) as u8))
+            .collect::<Vec<_>>();
+        let array = UInt8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let as_f64 = values.iter().map(|x| x.map(f64::from)).collect::<Vec<_>>();
+        let (n, _, _, m2, _, _) = cpu_moments(&as_f64, &as_f64);
+        assert_close(array.stddev(0), (m2 / n).sqrt());
+    }
+
+    #[test]
+    fn test_variance_null_below_ddof() {
+        let array = Float32ArrayGPU::from_optional_slice(&[None, Some(2.0)], GPU_DEVICE.clone());
+        assert_eq!(array.variance(0).values(), vec![Some(0.0)]);
+        assert_eq!(array.variance(1).values(), vec![None]);
+
+        let array = Int32ArrayGPU::from_slice(&[1], GPU_DEVICE.clone()).slice(0, 0);
+        assert_eq!(array.variance(0).values(), vec![None]);
+    }
+
+    #[test]
+    fn test_covariance_correlation() {
+        let x = (0..5000)
+            .map(|i: i64| (i % 7 != 3).then_some((i * 7919) % 1009 - 500))
+            .collect::<Vec<_>>();
+        let y = (0..5000)
+            .map(|i: i64| (i % 11 != 2).then_some(i * 3 - (i * 31) % 97))
+            .collect::<Vec<_>>();
+        let x_array = Int64ArrayGPU::from_optional_slice(&x, GPU_DEVICE.clone());
+        let y_array = Int64ArrayGPU::from_optional_slice(&y, GPU_DEVICE.clone());
+        let x = x.iter().map(|v| v.map(|v| v as f64)).collect::<Vec<_>>();
+        let y = y.iter().map(|v| v.map(|v| v as f64)).collect::<Vec<_>>();
+        let (n, _, _, m2_x, m2_y, c) = cpu_moments(&x, &y);
+        assert_close(x_array.covariance(&y_array), c / (n - 1.0));
+        assert_close(x_array.correlation(&y_array), c / (m2_x * m2_y).sqrt());
+
+        let x = [1.0f32, 2.0, 3.0, 4.0];
+        let x_array = Float32ArrayGPU::from_slice(&x, GPU_DEVICE.clone());
+        let y_array = Float32ArrayGPU::from_slice(&x.map(|v| 1.0 - 2.0 * v), GPU_DEVICE.clone());
+        assert_close(x_array.correlation(&y_array), -1.0);
+        assert_close(x_array.covariance(&y_array), -10.0 / 3.0);
+
+        let y_array = Float32ArrayGPU::from_optional_slice(
+            &[None, Some(1.0), None, None],
+            GPU_DEVICE.clone(),
+        );
+        assert_eq!(x_array.covariance(&y_array).values(), vec![None]);
+        assert_eq!(x_array.correlation(&y_array).values(), vec![None]);
+    }
+}
@@ -60,6 +60,7 @@ impl_arithmetic_array_op!(
     "add_u32"
 );
 
+#[allow(deprecated)]
 impl Sum32Bit for u32 {
     const SHADER: &'static str = include_str!("../compute_shaders/u32/aggregate.wgsl");
 }
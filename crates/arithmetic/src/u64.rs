@@ -0,0 +1,172 @@
+use crate::*;
+use arrow_gpu_array::array::{types::UInt64Type, *};
+use arrow_gpu_array::gpu_utils::*;
+
+pub(crate) const U64_SCALAR_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../compute_shaders/u64/scalar.wgsl")
+);
+pub(crate) const U64_ARRAY_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../compute_shaders/u64/array.wgsl")
+);
+
+impl_arithmetic_op!(
+    ArrowScalarAdd,
+    UInt64Type,
+    add_scalar_op,
+    UInt64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_add"
+);
+
+impl_arithmetic_op!(
+    ArrowScalarSub,
+    UInt64Type,
+    sub_scalar_op,
+    UInt64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_sub"
+);
+
+impl_arithmetic_op!(
+    ArrowScalarMul,
+    UInt64Type,
+    mul_scalar_op,
+    UInt64ArrayGPU,
+    U64_SCALAR_SHADER,
+    "u64_mul"
+);
+
+impl_arithmetic_array_op!(
+    ArrowAdd,
+    UInt64Type,
+    add_op,
+    UInt64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "add_u64"
+);
+
+impl_arithmetic_array_op!(
+    ArrowSub,
+    UInt64Type,
+    sub_op,
+    UInt64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "sub_u64"
+);
+
+impl_arithmetic_array_op!(
+    ArrowMul,
+    UInt64Type,
+    mul_op,
+    UInt64ArrayGPU,
+    U64_ARRAY_SHADER,
+    "mul_u64"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_test_macros::{test_array_op, test_scalar_op};
+
+    test_scalar_op!(
+        test_add_u64_scalar_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, u32::MAX as u64, u64::MAX, 1 << 40],
+        add_scalar,
+        add_scalar_dyn,
+        1u64,
+        [1, 2, 1 << 32, 0, (1 << 40) + 1]
+    );
+
+    test_scalar_op!(
+        test_sub_u64_scalar_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, 1 << 32, u64::MAX, 1 << 40],
+        sub_scalar,
+        sub_scalar_dyn,
+        1u64,
+        [u64::MAX, 0, u32::MAX as u64, u64::MAX - 1, (1 << 40) - 1]
+    );
+
+    test_scalar_op!(
+        test_mul_u64_scalar_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, u32::MAX as u64, u64::MAX, 1 << 40],
+        mul_scalar,
+        mul_scalar_dyn,
+        (1u64 << 32) + 3,
+        [
+            0,
+            (1 << 32) + 3,
+            (u32::MAX as u64).wrapping_mul((1 << 32) + 3),
+            u64::MAX.wrapping_mul((1 << 32) + 3),
+            (1u64 << 40).wrapping_mul((1 << 32) + 3)
+        ]
+    );
+
+    test_array_op!(
+        test_add_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        add,
+        add_array_dyn,
+        [
+            Some(0u64),
+            Some(u32::MAX as u64),
+            None,
+            Some(u64::MAX),
+            Some(4)
+        ],
+        [Some(1u64), Some(1), Some(2), Some(2), None],
+        [Some(1), Some(1 << 32), None, Some(1), None]
+    );
+
+    test_array_op!(
+        test_sub_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        sub,
+        sub_array_dyn,
+        [Some(0u64), Some(1 << 32), None, Some(u64::MAX), Some(4)],
+        [Some(1u64), Some(1), Some(2), Some(2), None],
+        [
+            Some(u64::MAX),
+            Some(u32::MAX as u64),
+            None,
+            Some(u64::MAX - 2),
+            None
+        ]
+    );
+
+    test_array_op!(
+        test_mul_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        mul,
+        mul_array_dyn,
+        [
+            Some(3u64),
+            Some(u32::MAX as u64),
+            None,
+            Some(123_456_789_012)
+        ],
+        [Some(5u64), Some(u32::MAX as u64), Some(2), Some(1_000_003)],
+        [
+            Some(15),
+            Some((u32::MAX as u64) * (u32::MAX as u64)),
+            None,
+            Some(123_456_789_012u64.wrapping_mul(1_000_003))
+        ]
+    );
+}
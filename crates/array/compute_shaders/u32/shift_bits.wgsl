@@ -0,0 +1,40 @@
+// Copies `bit_len` bits starting at bit `shift` of word `word_offset` to the start of
+// new_values, the bits after `bit_len` in the last word are cleared.
+
+struct ShiftParams {
+    word_offset: u32,
+    shift: u32,
+    bit_len: u32,
+    padding: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> operand: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: ShiftParams;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn shift_bits(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index >= arrayLength(&new_values) {
+        return;
+    }
+    let source_index = params.word_offset + index;
+    var word = operand[source_index] >> params.shift;
+    if params.shift != 0u && source_index + 1u < arrayLength(&operand) {
+        word |= operand[source_index + 1u] << (32u - params.shift);
+    }
+    let remaining_bits = params.bit_len - index * 32u;
+    if remaining_bits < 32u {
+        word &= (1u << remaining_bits) - 1u;
+    }
+    new_values[index] = word;
+}
@@ -0,0 +1,18 @@
+// 64 bit values are stored as two u32 words in little endian order,
+// so the same shader is used for both u64 and i64.
+
+@group(0)
+@binding(0)
+var<storage, read> operand: vec2<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn broadcast(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = operand;
+    }
+}
@@ -0,0 +1,44 @@
+struct Params {
+    // number of strings
+    len: u32,
+    // number of bytes of the broadcast string
+    value_len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> value: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_offsets: array<i32>;
+
+// each invocation writes one offset and one word of the repeated bytes,
+// the bytes after the last string are zeroed
+@compute
+@workgroup_size(256)
+fn broadcast(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x <= params.len && global_id.x < arrayLength(&new_offsets) {
+        new_offsets[global_id.x] = i32(global_id.x * params.value_len);
+    }
+    if global_id.x < arrayLength(&new_values) {
+        var word = 0u;
+        for (var i = 0u; i < 4u; i++) {
+            let byte = global_id.x * 4u + i;
+            if byte < params.len * params.value_len {
+                let source = byte % params.value_len;
+                let source_byte = (value[source / 4u] >> (8u * (source % 4u))) & 0xffu;
+                word |= source_byte << (8u * i);
+            }
+        }
+        new_values[global_id.x] = word;
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
-use wgpu::Buffer;
+use super::buffer::ArrowGpuBuffer;
 
 use crate::ArrowErrorGPU;
 use crate::kernels::broadcast::Broadcast;
@@ -13,7 +13,7 @@ use super::{
 
 /// bool arrow array in gpu
 pub struct BooleanArrayGPU {
-    pub data: Arc<Buffer>,
+    pub data: ArrowGpuBuffer,
     pub gpu_device: Arc<GpuDevice>,
     /// Actual len of the array
     pub len: usize,
@@ -42,7 +42,7 @@ impl BooleanArrayGPU {
         let null_buffer = NullBitBufferGpu::new(gpu_device.clone(), &null_buffer_builder);
 
         Self {
-            data: Arc::new(data),
+            data: data.into(),
             gpu_device,
             len: value.len(),
             null_buffer,
@@ -62,7 +62,7 @@ impl BooleanArrayGPU {
         let data = gpu_device.create_gpu_buffer_with_data(&buffer.data);
 
         Self {
-            data: Arc::new(data),
+            data: data.into(),
             gpu_device,
             len: value.len(),
             null_buffer: None,
@@ -74,7 +74,7 @@ impl BooleanArrayGPU {
         let null_buffer = None;
 
         Self {
-            data: Arc::new(data),
+            data: data.into(),
             gpu_device,
             len: value.len(),
             null_buffer,
@@ -116,6 +116,17 @@ impl BooleanArrayGPU {
         result_vec
     }
 
+    /// Returns a zero-copy view of `len` values starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            data: self.data.slice_bits(offset as u64, len as u64),
+            gpu_device: self.gpu_device.clone(),
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+
     pub fn broadcast_op(value: bool, len: usize, pipeline: &mut ArrowComputePipeline) -> Self {
         let buffer = if value {
             BooleanBufferBuilder::new_set_with_capacity(len)
@@ -126,7 +137,7 @@ impl BooleanArrayGPU {
         let data = pipeline.device.create_gpu_buffer_with_data(&buffer.data);
 
         Self {
-            data: Arc::new(data),
+            data: data.into(),
             gpu_device: pipeline.device.clone(),
             len,
             null_buffer: None,
@@ -185,7 +196,7 @@ impl Broadcast<bool> for BooleanArrayGPU {
         let data = pipeline.device.create_gpu_buffer_with_data(&buffer.data);
 
         Self {
-            data: Arc::new(data),
+            data: data.into(),
             gpu_device: pipeline.device.clone(),
             len,
             null_buffer: None,
@@ -225,5 +236,20 @@ mod tests {
         assert_eq!(gpu_values, values);
     }
 
+    #[test]
+    fn test_boolean_slice() {
+        let values = (0..300)
+            .map(|x| (x % 5 != 0).then_some(x % 3 == 0))
+            .collect::<Vec<_>>();
+        let array = BooleanArrayGPU::from_optional_slice(&values, crate::GPU_DEVICE.clone());
+
+        for (offset, len) in [(0, 31), (7, 100), (32, 64), (2048 / 8, 44), (299, 1)] {
+            assert_eq!(
+                array.slice(offset, len).values(),
+                values[offset..offset + len]
+            );
+        }
+    }
+
     test_broadcast!(test_broadcast_bool, BooleanArrayGPU, true);
 }
@@ -1,9 +1,15 @@
-use std::{ops::Deref, sync::Arc};
+use std::sync::Arc;
 use wgpu::Buffer;
 
-#[derive(Debug)]
+use crate::gpu_utils::{ArrowComputePipeline, GpuBufferView};
+
+/// Gpu buffer of an arrow array, a window over the bits of a shared `wgpu::Buffer`.
+/// Slicing only changes the window, kernels realign it when binding if needed.
+#[derive(Debug, Clone)]
 pub struct ArrowGpuBuffer {
     buffer: Arc<Buffer>,
+    bit_offset: u64,
+    bit_len: u64,
 }
 
 impl ArrowGpuBuffer {
@@ -19,35 +25,71 @@ impl ArrowGpuBuffer {
         todo!()
     }
 
+    /// Size in bytes of the view, padded to 4 bytes
     pub fn size(&self) -> u64 {
-        self.buffer.size()
+        self.view_size()
+    }
+
+    /// Returns a view of `byte_len` bytes starting at `byte_offset` of this view
+    pub fn slice(&self, byte_offset: u64, byte_len: u64) -> Self {
+        self.slice_bits(byte_offset * 8, byte_len * 8)
+    }
+
+    /// Returns a view of `bit_len` bits starting at `bit_offset` of this view
+    pub fn slice_bits(&self, bit_offset: u64, bit_len: u64) -> Self {
+        assert!(
+            bit_offset + bit_len <= self.bit_len,
+            "slice of {bit_len} bits at {bit_offset} is out of bounds of a view of {} bits",
+            self.bit_len
+        );
+        Self {
+            buffer: self.buffer.clone(),
+            bit_offset: self.bit_offset + bit_offset,
+            bit_len,
+        }
+    }
+
+    /// Copies the view into a buffer of its own unless it already is the only view of its buffer,
+    /// needed before kernels write to it in place
+    pub fn make_unique(&mut self, pipeline: &mut ArrowComputePipeline) {
+        if !self.is_entire_buffer() || Arc::strong_count(&self.buffer) > 1 {
+            *self = pipeline.clone_buffer(self).into();
+        }
+    }
+
+    /// Returns true when both views share the same gpu buffer
+    pub fn shares_buffer(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.buffer, &other.buffer)
     }
 }
 
-impl AsRef<Buffer> for ArrowGpuBuffer {
-    fn as_ref(&self) -> &Buffer {
+impl GpuBufferView for ArrowGpuBuffer {
+    fn buffer(&self) -> &Buffer {
         &self.buffer
     }
-}
 
-impl Deref for ArrowGpuBuffer {
-    type Target = Buffer;
+    fn bit_offset(&self) -> u64 {
+        self.bit_offset
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.buffer
+    fn bit_len(&self) -> u64 {
+        self.bit_len
     }
 }
 
 impl From<Buffer> for ArrowGpuBuffer {
     fn from(value: Buffer) -> Self {
-        Self {
-            buffer: Arc::new(value),
-        }
+        Arc::new(value).into()
     }
 }
 
 impl From<Arc<Buffer>> for ArrowGpuBuffer {
     fn from(buffer: Arc<Buffer>) -> Self {
-        Self { buffer }
+        let bit_len = buffer.size() * 8;
+        Self {
+            buffer,
+            bit_offset: 0,
+            bit_len,
+        }
     }
 }
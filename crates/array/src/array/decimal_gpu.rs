@@ -0,0 +1,151 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+
+use super::{ArrayUtils, ArrowArrayGPU, GpuDevice, PrimitiveArrayGpu};
+
+/// Largest number of decimal digits a Decimal128 value can hold
+pub const DECIMAL128_MAX_PRECISION: u8 = 38;
+
+/// Decimal arrow array in gpu, each value is `values[i] / 10^scale`.
+/// Values are i128 stored as 4 little endian u32 limbs.
+pub struct Decimal128ArrayGPU {
+    pub values: PrimitiveArrayGpu<i128>,
+    /// Total number of decimal digits
+    pub precision: u8,
+    /// Number of decimal digits after the decimal point
+    pub scale: i8,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+}
+
+impl Decimal128ArrayGPU {
+    pub fn new(values: PrimitiveArrayGpu<i128>, precision: u8, scale: i8) -> Self {
+        assert!(
+            (1..=DECIMAL128_MAX_PRECISION).contains(&precision),
+            "Decimal128 precision {} is out of range 1..=38",
+            precision
+        );
+        assert!(
+            scale >= 0 && scale as u8 <= precision,
+            "Decimal128 scale {} must be between 0 and the precision {}",
+            scale,
+            precision
+        );
+        Self {
+            gpu_device: values.gpu_device.clone(),
+            len: values.len,
+            values,
+            precision,
+            scale,
+        }
+    }
+
+    pub fn from_slice(
+        value: &[i128],
+        precision: u8,
+        scale: i8,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(
+            PrimitiveArrayGpu::from_slice(value, gpu_device),
+            precision,
+            scale,
+        )
+    }
+
+    pub fn from_optional_slice(
+        value: &[Option<i128>],
+        precision: u8,
+        scale: i8,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(
+            PrimitiveArrayGpu::from_optional_slice(value, gpu_device),
+            precision,
+            scale,
+        )
+    }
+
+    pub fn raw_values(&self) -> Option<Vec<i128>> {
+        self.values.raw_values()
+    }
+
+    pub fn values(&self) -> Vec<Option<i128>> {
+        self.values.values()
+    }
+
+    /// Returns a zero-copy view of `len` values starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new(self.values.slice(offset, len), self.precision, self.scale)
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self::new(self.values.clone_array(), self.precision, self.scale)
+    }
+}
+
+impl Debug for Decimal128ArrayGPU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "precision: {}, scale: {}", self.precision, self.scale)?;
+        writeln!(f, "values: {:?}", self.values)?;
+        write!(f, "}}")
+    }
+}
+
+impl ArrayUtils for Decimal128ArrayGPU {
+    fn get_gpu_device(&self) -> Arc<GpuDevice> {
+        self.gpu_device.clone()
+    }
+}
+
+impl From<Decimal128ArrayGPU> for ArrowArrayGPU {
+    fn from(val: Decimal128ArrayGPU) -> Self {
+        ArrowArrayGPU::Decimal128ArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for Decimal128ArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into Decimal128ArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::ArrowType;
+    use crate::utils::ScalarArray;
+
+    #[test]
+    fn test_decimal128_array() {
+        let values = [Some(i128::MAX), None, Some(-12345), Some(i128::MIN)];
+        let array = Decimal128ArrayGPU::from_optional_slice(&values, 38, 2, GPU_DEVICE.clone());
+        assert_eq!(array.values(), values.to_vec());
+
+        let array: ArrowArrayGPU = array.into();
+        assert_eq!(array.get_dtype(), ArrowType::Decimal128Type(38, 2));
+        assert_eq!(
+            array.get_raw_values(),
+            ScalarArray::I128Vec(vec![i128::MAX, 0, -12345, i128::MIN])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decimal128_invalid_scale() {
+        Decimal128ArrayGPU::from_slice(&[1], 5, 6, GPU_DEVICE.clone());
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+use crate::utils::ScalarArray;
+
+use super::{ArrayUtils, ArrowArrayGPU, ArrowPrimitiveType, GpuDevice, PrimitiveArrayGpu};
+
+/// Helper trait for primitive types that can be used as dictionary keys
+pub trait ArrowDictionaryKeyType: ArrowPrimitiveType {
+    fn as_index(key: Self::NativeType) -> usize;
+}
+
+macro_rules! impl_dictionary_key_type {
+    ($ty: ident) => {
+        impl ArrowDictionaryKeyType for $ty {
+            fn as_index(key: $ty) -> usize {
+                key as usize
+            }
+        }
+    };
+}
+
+impl_dictionary_key_type!(u8);
+impl_dictionary_key_type!(u16);
+impl_dictionary_key_type!(u32);
+
+/// Dictionary encoded arrow array in gpu
+pub struct DictionaryArrayGPU<K: ArrowDictionaryKeyType> {
+    /// Indexes into `values`, a null key is a null element of the array
+    pub keys: PrimitiveArrayGpu<K>,
+    /// Values referenced by the keys, shared between arrays built from the same dictionary
+    pub values: Arc<ArrowArrayGPU>,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+}
+
+/// Dictionary arrow array with UInt8 keys in gpu
+pub type UInt8DictionaryArrayGPU = DictionaryArrayGPU<u8>;
+/// Dictionary arrow array with UInt16 keys in gpu
+pub type UInt16DictionaryArrayGPU = DictionaryArrayGPU<u16>;
+/// Dictionary arrow array with UInt32 keys in gpu
+pub type UInt32DictionaryArrayGPU = DictionaryArrayGPU<u32>;
+
+impl<K: ArrowDictionaryKeyType> DictionaryArrayGPU<K> {
+    pub fn new(keys: PrimitiveArrayGpu<K>, values: Arc<ArrowArrayGPU>) -> Self {
+        assert!(Arc::ptr_eq(&keys.gpu_device, &values.get_gpu_device()));
+        Self {
+            gpu_device: keys.gpu_device.clone(),
+            len: keys.len,
+            keys,
+            values,
+        }
+    }
+
+    /// Returns the decoded values, null keys are filled with the first value of the dictionary
+    pub fn raw_values(&self) -> Option<ScalarArray> {
+        let keys = self.keys.raw_values()?;
+        let keys = keys.into_iter().map(K::as_index).collect::<Vec<_>>();
+        Some(self.values.get_raw_values().take(&keys))
+    }
+
+    /// Returns a zero-copy view of `len` keys starting at `offset`, the values are shared
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self {
+            keys: self.keys.slice(offset, len),
+            values: self.values.clone(),
+            gpu_device: self.gpu_device.clone(),
+            len,
+        }
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self {
+            keys: self.keys.clone_array(),
+            values: self.values.clone(),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: ArrowDictionaryKeyType> Debug for DictionaryArrayGPU<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "keys: {:?}", self.keys)?;
+        writeln!(f, "values: {:?}", self.values)?;
+        write!(f, "}}")
+    }
+}
+
+impl<K: ArrowDictionaryKeyType> ArrayUtils for DictionaryArrayGPU<K> {
+    fn get_gpu_device(&self) -> Arc<GpuDevice> {
+        self.gpu_device.clone()
+    }
+}
+
+macro_rules! impl_dictionary_conversion {
+    ($ty: ident, $variant: ident) => {
+        impl From<$ty> for ArrowArrayGPU {
+            fn from(val: $ty) -> Self {
+                ArrowArrayGPU::$variant(val)
+            }
+        }
+
+        impl TryFrom<ArrowArrayGPU> for $ty {
+            type Error = ArrowErrorGPU;
+
+            fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+                match value {
+                    ArrowArrayGPU::$variant(x) => Ok(x),
+                    x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                        "could not cast {:?} into {}",
+                        x,
+                        stringify!($ty)
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_dictionary_conversion!(UInt8DictionaryArrayGPU, UInt8DictionaryArrayGPU);
+impl_dictionary_conversion!(UInt16DictionaryArrayGPU, UInt16DictionaryArrayGPU);
+impl_dictionary_conversion!(UInt32DictionaryArrayGPU, UInt32DictionaryArrayGPU);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::{StringArrayGPU, UInt16ArrayGPU};
+
+    #[test]
+    fn test_dictionary_raw_values() {
+        let device = GPU_DEVICE.clone();
+        let values = StringArrayGPU::from_slice(&["low", "high"], device.clone());
+        let keys = UInt16ArrayGPU::from_optional_slice(&[Some(1), Some(0), None, Some(1)], device);
+        let array = DictionaryArrayGPU::new(keys, Arc::new(values.into()));
+
+        assert_eq!(array.len, 4);
+        assert_eq!(
+            array.raw_values().unwrap(),
+            ScalarArray::StringVec(vec![
+                "high".into(),
+                "low".into(),
+                "low".into(),
+                "high".into()
+            ])
+        );
+        assert_eq!(
+            array.clone_array().keys.values(),
+            vec![Some(1), Some(0), None, Some(1)]
+        );
+    }
+}
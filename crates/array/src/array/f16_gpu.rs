@@ -0,0 +1,83 @@
+use half::f16;
+
+use super::{ArrowArrayGPU, primitive_array_gpu::*, u32_gpu::UInt32ArrayGPU};
+use crate::ArrowErrorGPU;
+use crate::gpu_utils::*;
+use crate::kernels::broadcast::Broadcast;
+
+/// Float16 arrow array in gpu, two values are packed in each u32 word
+pub type Float16ArrayGPU = PrimitiveArrayGpu<f16>;
+
+/// Source of an f16 kernel built with the native f16 prelude and with the packed f32 prelude
+/// from `compute_shaders/f16`
+pub struct Float16Shader {
+    pub native: &'static str,
+    pub packed: &'static str,
+}
+
+impl Float16Shader {
+    /// Returns the shader to use on `device`
+    pub fn select(&self, device: &GpuDevice) -> &'static str {
+        if device.supports_shader_f16() {
+            self.native
+        } else {
+            self.packed
+        }
+    }
+}
+
+impl Broadcast<f16> for Float16ArrayGPU {
+    fn broadcast_op(value: f16, len: usize, pipeline: &mut ArrowComputePipeline) -> Self {
+        let new_len = len.div_ceil(2);
+        let bits = value.to_bits() as u32;
+        let broadcast_value = bits | (bits << 16);
+        let gpu_buffer =
+            UInt32ArrayGPU::create_broadcast_buffer_op(broadcast_value, new_len as u64, pipeline);
+        let data = gpu_buffer.into();
+        let null_buffer = None;
+
+        Self {
+            data,
+            gpu_device: pipeline.device.clone(),
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer,
+        }
+    }
+}
+
+impl From<Float16ArrayGPU> for ArrowArrayGPU {
+    fn from(val: Float16ArrayGPU) -> Self {
+        ArrowArrayGPU::Float16ArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for Float16ArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::Float16ArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into Float16ArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::primitive_array_gpu::test::*;
+
+    test_broadcast!(test_broadcast_f16, Float16ArrayGPU, f16::from_f32(1.5));
+
+    #[test]
+    fn test_f16_array() {
+        let values = [Some(f16::from_f32(-2.5)), None, Some(f16::MAX)];
+        let array = Float16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.values(), values.to_vec());
+    }
+}
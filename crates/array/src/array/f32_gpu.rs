@@ -1,9 +1,7 @@
-use super::buffer::ArrowGpuBuffer;
-use super::{ArrowArray, ArrowArrayGPU, ArrowType, NullBitBufferGpu, primitive_array_gpu::*};
+use super::{ArrowArrayGPU, primitive_array_gpu::*};
 use crate::ArrowErrorGPU;
 use crate::gpu_utils::*;
 use crate::kernels::broadcast::Broadcast;
-use std::any::Any;
 
 const F32_BROADCAST_SHADER: &str = include_str!("../../compute_shaders/f32/broadcast.wgsl");
 
@@ -56,38 +54,28 @@ impl TryFrom<ArrowArrayGPU> for Float32ArrayGPU {
     }
 }
 
-impl ArrowArray for Float32ArrayGPU {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn get_data_type(&self) -> ArrowType {
-        ArrowType::Float32Type
-    }
-
-    fn get_memory_used(&self) -> u64 {
-        self.data.size()
-    }
-
-    fn get_gpu_device(&self) -> &GpuDevice {
-        &self.gpu_device
-    }
-
-    fn get_buffer(&self) -> &ArrowGpuBuffer {
-        &self.data
-    }
-
-    fn get_null_bit_buffer(&self) -> Option<&NullBitBufferGpu> {
-        self.null_buffer.as_ref()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::array::NullBitBufferGpu;
     use crate::array::primitive_array_gpu::test::*;
     use std::sync::Arc;
 
+    #[test]
+    fn test_f32_slice_with_nulls() {
+        let device = crate::GPU_DEVICE.clone();
+        let values = (0..100)
+            .map(|x| (x % 3 != 0).then_some(x as f32))
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_optional_slice(&values, device);
+
+        // 64 elements is the storage buffer offset alignment of 256 bytes
+        for (offset, len) in [(0, 10), (5, 37), (64, 30), (64, 36), (99, 1)] {
+            let slice = array.slice(offset, len);
+            assert_eq!(slice.values(), values[offset..offset + len]);
+        }
+    }
+
     #[test]
     fn test_f32_array_from_optinal_vec() {
         let device = Arc::new(GpuDevice::new());
@@ -1,11 +1,5 @@
-use super::buffer::ArrowGpuBuffer;
-use super::{
-    ArrowArray, ArrowArrayGPU, ArrowComputePipeline, ArrowPrimitiveType, ArrowType,
-    NullBitBufferGpu, primitive_array_gpu::*,
-};
-use crate::gpu_utils::*;
+use super::{ArrowArrayGPU, ArrowComputePipeline, ArrowPrimitiveType, primitive_array_gpu::*};
 use crate::{ArrowErrorGPU, kernels::broadcast::Broadcast};
-use std::any::Any;
 
 const I32_BROADCAST_SHADER: &str = include_str!("../../compute_shaders/i32/broadcast.wgsl");
 
@@ -59,32 +53,6 @@ impl<T: ArrowPrimitiveType<NativeType = i32>> Broadcast<i32> for PrimitiveArrayG
     }
 }
 
-impl ArrowArray for Int32ArrayGPU {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn get_data_type(&self) -> ArrowType {
-        ArrowType::UInt32Type
-    }
-
-    fn get_memory_used(&self) -> u64 {
-        self.data.size()
-    }
-
-    fn get_gpu_device(&self) -> &GpuDevice {
-        &self.gpu_device
-    }
-
-    fn get_buffer(&self) -> &ArrowGpuBuffer {
-        &self.data
-    }
-
-    fn get_null_bit_buffer(&self) -> Option<&NullBitBufferGpu> {
-        self.null_buffer.as_ref()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
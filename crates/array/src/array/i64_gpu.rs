@@ -0,0 +1,74 @@
+use super::u64_gpu::U64_BROADCAST_SHADER;
+use super::{ArrowArrayGPU, ArrowPrimitiveType, primitive_array_gpu::*};
+use crate::gpu_utils::*;
+use crate::{ArrowErrorGPU, kernels::broadcast::Broadcast};
+
+/// Int64 arrow array in gpu
+pub type Int64ArrayGPU = PrimitiveArrayGpu<i64>;
+
+impl From<Int64ArrayGPU> for ArrowArrayGPU {
+    fn from(val: Int64ArrayGPU) -> Self {
+        ArrowArrayGPU::Int64ArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for Int64ArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::Int64ArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into Int64ArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType<NativeType = i64>> Broadcast<i64> for PrimitiveArrayGpu<T> {
+    fn broadcast_op(
+        value: i64,
+        len: usize,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> PrimitiveArrayGpu<T> {
+        let scalar_buffer = pipeline.device.create_scalar_buffer(&value);
+        let gpu_buffer = pipeline.apply_broadcast_function(
+            &scalar_buffer,
+            8 * len as u64,
+            U64_BROADCAST_SHADER,
+            "broadcast",
+            len.div_ceil(256) as u32,
+        );
+        let data = gpu_buffer.into();
+        let null_buffer = None;
+
+        Self {
+            data,
+            gpu_device: pipeline.device.clone(),
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::primitive_array_gpu::test::*;
+
+    test_broadcast!(test_broadcast_i64, Int64ArrayGPU, -(1i64 << 40));
+
+    #[test]
+    fn test_i64_array_from_optional_slice() {
+        use crate::GPU_DEVICE;
+        let values = [Some(i64::MIN), None, Some(-1), Some(i64::MAX)];
+        let gpu_array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(gpu_array.values(), values);
+        assert_eq!(
+            gpu_array.raw_values().unwrap(),
+            vec![i64::MIN, 0, -1, i64::MAX]
+        );
+    }
+}
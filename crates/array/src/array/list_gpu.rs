@@ -0,0 +1,266 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+use crate::utils::ScalarArray;
+
+use super::buffer::ArrowGpuBuffer;
+use super::{ArrayUtils, ArrowArrayGPU, GpuDevice, NullBitBufferGpu};
+
+/// List arrow array in gpu
+pub struct ListArrayGPU {
+    /// i32 offsets into `values`, contains `len + 1` entries
+    pub offsets: ArrowGpuBuffer,
+    /// Child array holding the elements of all the lists
+    pub values: Box<ArrowArrayGPU>,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+    pub null_buffer: Option<NullBitBufferGpu>,
+}
+
+/// FixedSizeList arrow array in gpu
+pub struct FixedSizeListArrayGPU {
+    /// Child array holding the elements of all the lists
+    pub values: Box<ArrowArrayGPU>,
+    /// Number of elements in each list
+    pub value_length: usize,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+    pub null_buffer: Option<NullBitBufferGpu>,
+}
+
+impl ListArrayGPU {
+    pub fn new(
+        offsets: &[i32],
+        values: ArrowArrayGPU,
+        null_buffer: Option<NullBitBufferGpu>,
+    ) -> Self {
+        assert!(!offsets.is_empty());
+        assert!(offsets.windows(2).all(|x| x[0] <= x[1]));
+        assert!(*offsets.last().unwrap() as usize <= values.len());
+
+        let gpu_device = values.get_gpu_device();
+        let offsets_buffer = gpu_device.create_gpu_buffer_with_data(offsets);
+
+        Self {
+            offsets: offsets_buffer.into(),
+            values: Box::new(values),
+            gpu_device,
+            len: offsets.len() - 1,
+            null_buffer,
+        }
+    }
+
+    /// Returns the offsets of the lists in the child array
+    pub fn raw_offsets(&self) -> Vec<i32> {
+        let result = self.gpu_device.retrive_data(&self.offsets);
+        let result: Vec<i32> = bytemuck::cast_slice(&result).to_vec();
+        result[0..self.len + 1].to_vec()
+    }
+
+    pub fn raw_values(&self) -> Option<ScalarArray> {
+        let offsets = self.raw_offsets();
+        let values = self.values.get_raw_values();
+        let result = offsets
+            .windows(2)
+            .map(|x| values.slice(x[0] as usize, x[1] as usize))
+            .collect::<Vec<_>>();
+        Some(result.into())
+    }
+
+    /// Returns a zero-copy view of `len` lists starting at `offset`.
+    /// The child array is shared whole, only the offsets are sliced.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            offsets: self.offsets.slice(offset as u64 * 4, (len as u64 + 1) * 4),
+            values: Box::new(self.values.slice(0, self.values.len())),
+            gpu_device: self.gpu_device.clone(),
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self {
+            offsets: self.gpu_device.clone_buffer(&self.offsets).into(),
+            values: Box::new(self.values.clone_array()),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer(&self.null_buffer),
+        }
+    }
+}
+
+impl FixedSizeListArrayGPU {
+    pub fn new(
+        values: ArrowArrayGPU,
+        value_length: usize,
+        null_buffer: Option<NullBitBufferGpu>,
+    ) -> Self {
+        assert!(value_length > 0);
+        assert_eq!(values.len() % value_length, 0);
+
+        Self {
+            gpu_device: values.get_gpu_device(),
+            len: values.len() / value_length,
+            values: Box::new(values),
+            value_length,
+            null_buffer,
+        }
+    }
+
+    pub fn raw_values(&self) -> Option<ScalarArray> {
+        let values = self.values.get_raw_values();
+        let result = (0..self.len)
+            .map(|x| values.slice(x * self.value_length, (x + 1) * self.value_length))
+            .collect::<Vec<_>>();
+        Some(result.into())
+    }
+
+    /// Returns a zero-copy view of `len` lists starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            values: Box::new(
+                self.values
+                    .slice(offset * self.value_length, len * self.value_length),
+            ),
+            value_length: self.value_length,
+            gpu_device: self.gpu_device.clone(),
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self {
+            values: Box::new(self.values.clone_array()),
+            value_length: self.value_length,
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer(&self.null_buffer),
+        }
+    }
+}
+
+impl Debug for ListArrayGPU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "{:?}", self.offsets)?;
+        writeln!(f, "{:?}", self.values)?;
+        writeln!(f, "Array of length {}", self.len)?;
+        write!(f, "}}")
+    }
+}
+
+impl Debug for FixedSizeListArrayGPU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "{:?}", self.values)?;
+        writeln!(
+            f,
+            "Array of length {} with lists of length {}",
+            self.len, self.value_length
+        )?;
+        write!(f, "}}")
+    }
+}
+
+macro_rules! impl_list_array {
+    ($ty: ident) => {
+        impl From<$ty> for ArrowArrayGPU {
+            fn from(val: $ty) -> Self {
+                ArrowArrayGPU::$ty(val)
+            }
+        }
+
+        impl TryFrom<ArrowArrayGPU> for $ty {
+            type Error = ArrowErrorGPU;
+
+            fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+                match value {
+                    ArrowArrayGPU::$ty(x) => Ok(x),
+                    x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                        "could not cast {:?} into {}",
+                        x,
+                        stringify!($ty)
+                    ))),
+                }
+            }
+        }
+
+        impl ArrayUtils for $ty {
+            fn get_gpu_device(&self) -> Arc<GpuDevice> {
+                self.gpu_device.clone()
+            }
+        }
+    };
+}
+
+impl_list_array!(ListArrayGPU);
+impl_list_array!(FixedSizeListArrayGPU);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::{Float32ArrayGPU, Int32ArrayGPU};
+
+    #[test]
+    fn test_list_values() {
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5], GPU_DEVICE.clone());
+        let array = ListArrayGPU::new(&[0, 2, 2, 5], values.into(), None);
+
+        assert_eq!(array.len, 3);
+        assert_eq!(array.raw_offsets(), vec![0, 2, 2, 5]);
+        assert_eq!(
+            array.clone_array().raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![1, 2]),
+                ScalarArray::I32Vec(vec![]),
+                ScalarArray::I32Vec(vec![3, 4, 5]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_list_slice() {
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5, 6], GPU_DEVICE.clone());
+        let array = ListArrayGPU::new(&[0, 2, 2, 5, 6], values.into(), None);
+        assert_eq!(
+            array.slice(1, 2).raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![]),
+                ScalarArray::I32Vec(vec![3, 4, 5]),
+            ])
+        );
+
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5, 6], GPU_DEVICE.clone());
+        let array = FixedSizeListArrayGPU::new(values.into(), 2, None);
+        assert_eq!(
+            array.slice(1, 2).raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![3, 4]),
+                ScalarArray::I32Vec(vec![5, 6]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_list_values() {
+        let values = Float32ArrayGPU::from_slice(&[1.0, 2.0, 3.0, 4.0], GPU_DEVICE.clone());
+        let array = FixedSizeListArrayGPU::new(values.into(), 2, None);
+
+        assert_eq!(array.len, 2);
+        assert_eq!(
+            array.clone_array().raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::F32Vec(vec![1.0, 2.0]),
+                ScalarArray::F32Vec(vec![3.0, 4.0]),
+            ])
+        );
+    }
+}
@@ -1,61 +1,108 @@
 use crate::gpu_utils::*;
 use crate::kernels::ScalarValue;
 use crate::kernels::broadcast::Broadcast;
+use crate::record_batch::Field;
 use crate::utils::ScalarArray;
-use buffer::ArrowGpuBuffer;
 use bytemuck::Pod;
+use std::fmt::Debug;
 use std::sync::Arc;
-use std::{any::Any, fmt::Debug};
 
 pub(crate) mod boolean_gpu;
 pub mod buffer;
 pub(crate) mod date32_gpu;
+pub(crate) mod decimal_gpu;
+pub(crate) mod dictionary_gpu;
+pub(crate) mod f16_gpu;
 pub(crate) mod f32_gpu;
+pub(crate) mod f64_gpu;
 pub(crate) mod i16_gpu;
 pub(crate) mod i32_gpu;
+pub(crate) mod i64_gpu;
 pub(crate) mod i8_gpu;
+pub(crate) mod list_gpu;
 pub(crate) mod null_bit_buffer;
 pub(crate) mod primitive_array_gpu;
+pub(crate) mod string_gpu;
+pub(crate) mod struct_gpu;
+pub(crate) mod temporal_gpu;
 pub mod types;
 pub(crate) mod u16_gpu;
 pub(crate) mod u32_gpu;
+pub(crate) mod u64_gpu;
 pub(crate) mod u8_gpu;
 
 pub use boolean_gpu::BooleanArrayGPU;
 pub use date32_gpu::Date32ArrayGPU;
 pub use date32_gpu::Date32Type;
+pub use decimal_gpu::{DECIMAL128_MAX_PRECISION, Decimal128ArrayGPU};
+pub use dictionary_gpu::*;
+pub use f16_gpu::{Float16ArrayGPU, Float16Shader};
 pub use f32_gpu::Float32ArrayGPU;
+pub use f64_gpu::Float64ArrayGPU;
+pub use half::f16;
 pub use i8_gpu::Int8ArrayGPU;
 pub use i16_gpu::Int16ArrayGPU;
 pub use i32_gpu::Int32ArrayGPU;
+pub use i64_gpu::Int64ArrayGPU;
+pub use list_gpu::{FixedSizeListArrayGPU, ListArrayGPU};
 pub use null_bit_buffer::*;
 pub use primitive_array_gpu::PrimitiveArrayGpu;
+pub use string_gpu::StringArrayGPU;
+pub use struct_gpu::StructArrayGPU;
+pub use temporal_gpu::{DurationArrayGPU, Time32ArrayGPU, TimeUnit, TimestampArrayGPU};
 pub use u8_gpu::UInt8ArrayGPU;
 pub use u16_gpu::UInt16ArrayGPU;
 pub use u32_gpu::UInt32ArrayGPU;
+pub use u64_gpu::UInt64ArrayGPU;
 
 /// Enum of apache arrow datatypes
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum ArrowType {
     BooleanType,
+    Float16Type,
     Float32Type,
+    Float64Type,
+    UInt64Type,
     UInt32Type,
     UInt16Type,
     UInt8Type,
+    Int64Type,
     Int32Type,
     Int16Type,
     Int8Type,
     Date32Type,
+    /// Timestamp with the given unit and optional timezone
+    TimestampType(TimeUnit, Option<String>),
+    /// Time of day stored in 32 bits with the given unit
+    Time32Type(TimeUnit),
+    /// Elapsed time with the given unit
+    DurationType(TimeUnit),
+    /// Decimal with the given precision and scale
+    Decimal128Type(u8, i8),
+    Utf8Type,
+    /// Dictionary with the given key and value types
+    DictionaryType(Box<ArrowType>, Box<ArrowType>),
+    /// List with the given child type
+    ListType(Box<ArrowType>),
+    /// List of fixed length with the given child type
+    FixedSizeListType(Box<ArrowType>, usize),
+    /// Struct with the given fields
+    StructType(Vec<Field>),
 }
 
 /// Trait expressing a Rust type that has the same in-memory representation as Arrow.
 pub trait RustNativeType: Pod + Debug + Default {}
 
+impl RustNativeType for i128 {}
+impl RustNativeType for i64 {}
 impl RustNativeType for i32 {}
 impl RustNativeType for i16 {}
 impl RustNativeType for i8 {}
+impl RustNativeType for f16 {}
 impl RustNativeType for f32 {}
+impl RustNativeType for f64 {}
+impl RustNativeType for u64 {}
 impl RustNativeType for u32 {}
 impl RustNativeType for u16 {}
 impl RustNativeType for u8 {}
@@ -75,24 +122,20 @@ macro_rules! impl_primitive_type {
     };
 }
 
+impl_primitive_type!(f16, f16, 2);
 impl_primitive_type!(f32, f32, 4);
+impl_primitive_type!(f64, f64, 8);
+impl_primitive_type!(u64, u64, 8);
 impl_primitive_type!(u32, u32, 4);
 impl_primitive_type!(u16, u16, 2);
 impl_primitive_type!(u8, u8, 1);
+impl_primitive_type!(i128, i128, 16);
+impl_primitive_type!(i64, i64, 8);
 impl_primitive_type!(i32, i32, 4);
 impl_primitive_type!(i16, i16, 4);
 impl_primitive_type!(i8, i8, 1);
 impl_primitive_type!(Date32Type, i32, 4);
 
-pub(crate) trait ArrowArray: Any + Sync + Send + Debug {
-    fn as_any(&self) -> &dyn Any;
-    fn get_data_type(&self) -> ArrowType;
-    fn get_memory_used(&self) -> u64;
-    fn get_gpu_device(&self) -> &GpuDevice;
-    fn get_buffer(&self) -> &ArrowGpuBuffer;
-    fn get_null_bit_buffer(&self) -> Option<&NullBitBufferGpu>;
-}
-
 /// Trait for utility functions implemented by all gpu arrow array
 pub trait ArrayUtils {
     fn get_gpu_device(&self) -> Arc<GpuDevice>;
@@ -102,85 +145,280 @@ pub trait ArrayUtils {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ArrowArrayGPU {
+    Float16ArrayGPU(Float16ArrayGPU),
     Float32ArrayGPU(Float32ArrayGPU),
+    Float64ArrayGPU(Float64ArrayGPU),
+    UInt64ArrayGPU(UInt64ArrayGPU),
     UInt32ArrayGPU(UInt32ArrayGPU),
     UInt16ArrayGPU(UInt16ArrayGPU),
     UInt8ArrayGPU(UInt8ArrayGPU),
+    Int64ArrayGPU(Int64ArrayGPU),
     Int32ArrayGPU(Int32ArrayGPU),
     Int16ArrayGPU(Int16ArrayGPU),
     Int8ArrayGPU(Int8ArrayGPU),
     Date32ArrayGPU(Date32ArrayGPU),
+    TimestampArrayGPU(TimestampArrayGPU),
+    Time32ArrayGPU(Time32ArrayGPU),
+    DurationArrayGPU(DurationArrayGPU),
+    Decimal128ArrayGPU(Decimal128ArrayGPU),
     BooleanArrayGPU(BooleanArrayGPU),
+    StringArrayGPU(StringArrayGPU),
+    UInt8DictionaryArrayGPU(UInt8DictionaryArrayGPU),
+    UInt16DictionaryArrayGPU(UInt16DictionaryArrayGPU),
+    UInt32DictionaryArrayGPU(UInt32DictionaryArrayGPU),
+    ListArrayGPU(ListArrayGPU),
+    FixedSizeListArrayGPU(FixedSizeListArrayGPU),
+    StructArrayGPU(StructArrayGPU),
 }
 
 impl ArrowArrayGPU {
     pub fn get_gpu_device(&self) -> Arc<GpuDevice> {
         match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::Float32ArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::UInt32ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::UInt16ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::UInt8ArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::Int32ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::Int16ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::Int8ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::Date32ArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::DurationArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.gpu_device.clone(),
             ArrowArrayGPU::BooleanArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::StringArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::ListArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.gpu_device.clone(),
+            ArrowArrayGPU::StructArrayGPU(x) => x.gpu_device.clone(),
         }
     }
 
     pub fn get_dtype(&self) -> ArrowType {
         match self {
+            ArrowArrayGPU::Float16ArrayGPU(_) => ArrowType::Float16Type,
             ArrowArrayGPU::Float32ArrayGPU(_) => ArrowType::Float32Type,
+            ArrowArrayGPU::Float64ArrayGPU(_) => ArrowType::Float64Type,
+            ArrowArrayGPU::UInt64ArrayGPU(_) => ArrowType::UInt64Type,
             ArrowArrayGPU::UInt32ArrayGPU(_) => ArrowType::UInt32Type,
             ArrowArrayGPU::UInt16ArrayGPU(_) => ArrowType::UInt16Type,
             ArrowArrayGPU::UInt8ArrayGPU(_) => ArrowType::UInt8Type,
+            ArrowArrayGPU::Int64ArrayGPU(_) => ArrowType::Int64Type,
             ArrowArrayGPU::Int32ArrayGPU(_) => ArrowType::Int32Type,
             ArrowArrayGPU::Int16ArrayGPU(_) => ArrowType::Int16Type,
             ArrowArrayGPU::Int8ArrayGPU(_) => ArrowType::Int8Type,
             ArrowArrayGPU::Date32ArrayGPU(_) => ArrowType::Date32Type,
+            ArrowArrayGPU::TimestampArrayGPU(x) => {
+                ArrowType::TimestampType(x.unit, x.timezone.clone())
+            }
+            ArrowArrayGPU::Time32ArrayGPU(x) => ArrowType::Time32Type(x.unit),
+            ArrowArrayGPU::DurationArrayGPU(x) => ArrowType::DurationType(x.unit),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => ArrowType::Decimal128Type(x.precision, x.scale),
             ArrowArrayGPU::BooleanArrayGPU(_) => ArrowType::BooleanType,
+            ArrowArrayGPU::StringArrayGPU(_) => ArrowType::Utf8Type,
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => ArrowType::DictionaryType(
+                Box::new(ArrowType::UInt8Type),
+                Box::new(x.values.get_dtype()),
+            ),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => ArrowType::DictionaryType(
+                Box::new(ArrowType::UInt16Type),
+                Box::new(x.values.get_dtype()),
+            ),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => ArrowType::DictionaryType(
+                Box::new(ArrowType::UInt32Type),
+                Box::new(x.values.get_dtype()),
+            ),
+            ArrowArrayGPU::ListArrayGPU(x) => ArrowType::ListType(Box::new(x.values.get_dtype())),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => {
+                ArrowType::FixedSizeListType(Box::new(x.values.get_dtype()), x.value_length)
+            }
+            ArrowArrayGPU::StructArrayGPU(x) => ArrowType::StructType(x.fields.clone()),
         }
     }
 
     pub fn get_raw_values(&self) -> ScalarArray {
         match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::Float32ArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::UInt16ArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::UInt32ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::UInt8ArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::Int32ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::Int16ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::Int8ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::Date32ArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::DurationArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.raw_values().unwrap().into(),
             ArrowArrayGPU::BooleanArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::StringArrayGPU(x) => x.raw_values().unwrap().into(),
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.raw_values().unwrap(),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.raw_values().unwrap(),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.raw_values().unwrap(),
+            ArrowArrayGPU::ListArrayGPU(x) => x.raw_values().unwrap(),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.raw_values().unwrap(),
+            ArrowArrayGPU::StructArrayGPU(x) => x.raw_values().unwrap(),
         }
     }
 
     pub fn clone_array(&self) -> ArrowArrayGPU {
         match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::Float32ArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::UInt32ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::UInt16ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::UInt8ArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::Int32ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::Int16ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::Int8ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::Date32ArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::DurationArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.clone_array().into(),
             ArrowArrayGPU::BooleanArrayGPU(_) => todo!(),
+            ArrowArrayGPU::StringArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::ListArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.clone_array().into(),
+            ArrowArrayGPU::StructArrayGPU(x) => x.clone_array().into(),
+        }
+    }
+
+    /// Returns a zero-copy view of `len` elements starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> ArrowArrayGPU {
+        match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Float32ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt32ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt16ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt8ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Int32ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Int16ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Int8ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Date32ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::DurationArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::BooleanArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::StringArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::ListArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.slice(offset, len).into(),
+            ArrowArrayGPU::StructArrayGPU(x) => x.slice(offset, len).into(),
         }
     }
 
     pub fn len(&self) -> usize {
         match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.len,
             ArrowArrayGPU::Float32ArrayGPU(x) => x.len,
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.len,
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.len,
             ArrowArrayGPU::UInt32ArrayGPU(x) => x.len,
             ArrowArrayGPU::UInt16ArrayGPU(x) => x.len,
             ArrowArrayGPU::UInt8ArrayGPU(x) => x.len,
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.len,
             ArrowArrayGPU::Int32ArrayGPU(x) => x.len,
             ArrowArrayGPU::Int16ArrayGPU(x) => x.len,
             ArrowArrayGPU::Int8ArrayGPU(x) => x.len,
             ArrowArrayGPU::Date32ArrayGPU(x) => x.len,
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.len,
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.len,
+            ArrowArrayGPU::DurationArrayGPU(x) => x.len,
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.len,
             ArrowArrayGPU::BooleanArrayGPU(x) => x.len,
+            ArrowArrayGPU::StringArrayGPU(x) => x.len,
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.len,
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.len,
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.len,
+            ArrowArrayGPU::ListArrayGPU(x) => x.len,
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.len,
+            ArrowArrayGPU::StructArrayGPU(x) => x.len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_null_bit_buffer(&self) -> Option<&NullBitBufferGpu> {
+        match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Float32ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt32ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt16ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt8ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Int32ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Int16ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Int8ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::Date32ArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.values.null_buffer.as_ref(),
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.values.null_buffer.as_ref(),
+            ArrowArrayGPU::DurationArrayGPU(x) => x.values.null_buffer.as_ref(),
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.values.null_buffer.as_ref(),
+            ArrowArrayGPU::BooleanArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::StringArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.keys.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.keys.null_buffer.as_ref(),
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.keys.null_buffer.as_ref(),
+            ArrowArrayGPU::ListArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.null_buffer.as_ref(),
+            ArrowArrayGPU::StructArrayGPU(x) => x.null_buffer.as_ref(),
+        }
+    }
+
+    pub fn set_null_bit_buffer(&mut self, null_buffer: Option<NullBitBufferGpu>) {
+        match self {
+            ArrowArrayGPU::Float16ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Float32ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Float64ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt64ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt32ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt16ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt8ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Int64ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Int32ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Int16ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Int8ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::Date32ArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::TimestampArrayGPU(x) => x.values.null_buffer = null_buffer,
+            ArrowArrayGPU::Time32ArrayGPU(x) => x.values.null_buffer = null_buffer,
+            ArrowArrayGPU::DurationArrayGPU(x) => x.values.null_buffer = null_buffer,
+            ArrowArrayGPU::Decimal128ArrayGPU(x) => x.values.null_buffer = null_buffer,
+            ArrowArrayGPU::BooleanArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::StringArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.keys.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.keys.null_buffer = null_buffer,
+            ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.keys.null_buffer = null_buffer,
+            ArrowArrayGPU::ListArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::FixedSizeListArrayGPU(x) => x.null_buffer = null_buffer,
+            ArrowArrayGPU::StructArrayGPU(x) => x.null_buffer = null_buffer,
         }
     }
 }
@@ -188,14 +426,19 @@ impl ArrowArrayGPU {
 /// Broadcast a single scalar value across the entire array of length `len`
 pub fn broadcast_dyn(value: ScalarValue, len: usize, device: Arc<GpuDevice>) -> ArrowArrayGPU {
     match value {
+        ScalarValue::F16(x) => Float16ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::F32(x) => Float32ArrayGPU::broadcast(x, len, device).into(),
+        ScalarValue::F64(x) => Float64ArrayGPU::broadcast(x, len, device).into(),
+        ScalarValue::U64(x) => UInt64ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::U32(x) => UInt32ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::U16(x) => UInt16ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::U8(x) => UInt8ArrayGPU::broadcast(x, len, device).into(),
+        ScalarValue::I64(x) => Int64ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::I32(x) => Int32ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::I16(x) => Int16ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::I8(x) => Int8ArrayGPU::broadcast(x, len, device).into(),
         ScalarValue::BOOL(x) => BooleanArrayGPU::broadcast(x, len, device).into(),
+        ScalarValue::Utf8(x) => StringArrayGPU::from_slice(&vec![x.as_str(); len], device).into(),
     }
 }
 
@@ -207,13 +450,18 @@ pub fn broadcast_op_dyn(
     pipeline: &mut ArrowComputePipeline,
 ) -> ArrowArrayGPU {
     match value {
+        ScalarValue::F16(x) => Float16ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::F32(x) => Float32ArrayGPU::broadcast_op(x, len, pipeline).into(),
+        ScalarValue::F64(x) => Float64ArrayGPU::broadcast_op(x, len, pipeline).into(),
+        ScalarValue::U64(x) => UInt64ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::U32(x) => UInt32ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::U16(x) => UInt16ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::U8(x) => UInt8ArrayGPU::broadcast_op(x, len, pipeline).into(),
+        ScalarValue::I64(x) => Int64ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::I32(x) => Int32ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::I16(x) => Int16ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::I8(x) => Int8ArrayGPU::broadcast_op(x, len, pipeline).into(),
         ScalarValue::BOOL(x) => BooleanArrayGPU::broadcast_op(x, len, pipeline).into(),
+        ScalarValue::Utf8(x) => StringArrayGPU::broadcast_op(&x, len, pipeline).into(),
     }
 }
@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use wgpu::{Buffer, CommandEncoder, util::align_to};
 
+use super::buffer::ArrowGpuBuffer;
+
 const LOGICAL_AND_SHADER: &str = include_str!("../../../logical/compute_shaders/u32/logical.wgsl");
 
 use crate::gpu_utils::*;
@@ -78,6 +80,28 @@ mod tests {
         assert!(buffer.is_set(0));
     }
 
+    #[test]
+    fn test_null_bit_buffer_slice() {
+        let device = crate::GPU_DEVICE.clone();
+        let mut builder = BooleanBufferBuilder::new_with_capacity(70);
+        let set_bits = (0..70).filter(|x| x % 3 == 0 || x % 7 == 0);
+        set_bits.clone().for_each(|x| builder.set_bit(x));
+        let null_buffer = NullBitBufferGpu::new(device, &builder).unwrap();
+
+        let slice = null_buffer.slice(5, 60);
+        let raw_values = slice.raw_values();
+        assert_eq!(raw_values.len(), 8);
+        let expected = set_bits.filter(|x| (5..65).contains(x)).map(|x| x - 5);
+        for pos in 0..60 {
+            assert_eq!(
+                BooleanBufferBuilder::is_set_in_slice(&raw_values, pos),
+                expected.clone().any(|x| x == pos)
+            );
+        }
+        // bits after the slice are cleared when it is realigned
+        assert_eq!(raw_values[7] >> 4, 0);
+    }
+
     #[test]
     fn test_new_set_with_capacity() {
         let buffer = BooleanBufferBuilder::new_set_with_capacity(10);
@@ -90,7 +114,7 @@ mod tests {
 /// Null bit buffer of arrow array stored in GPU
 #[derive(Debug, Clone)]
 pub struct NullBitBufferGpu {
-    pub bit_buffer: Arc<Buffer>,
+    pub bit_buffer: ArrowGpuBuffer,
     pub len: usize,
     pub gpu_device: Arc<GpuDevice>,
 }
@@ -101,7 +125,7 @@ impl NullBitBufferGpu {
             let data = gpu_device.create_gpu_buffer_with_data(&buffer_builder.data);
 
             Some(Self {
-                bit_buffer: Arc::new(data),
+                bit_buffer: data.into(),
                 len: buffer_builder.len,
                 gpu_device,
             })
@@ -115,12 +139,22 @@ impl NullBitBufferGpu {
         let data = gpu_device.create_gpu_buffer_with_data(&buffer_builder.data);
 
         Self {
-            bit_buffer: Arc::new(data),
+            bit_buffer: data.into(),
             len: buffer_builder.len,
             gpu_device,
         }
     }
 
+    /// Returns a zero-copy view of the `len` bits starting at bit `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            bit_buffer: self.bit_buffer.slice_bits(offset as u64, len as u64),
+            len,
+            gpu_device: self.gpu_device.clone(),
+        }
+    }
+
     pub fn raw_values(&self) -> Vec<u8> {
         let result = &self.gpu_device.retrive_data(&self.bit_buffer);
         let buffer_size = align_to(self.len, 8) / 8;
@@ -129,7 +163,7 @@ impl NullBitBufferGpu {
 
     pub fn clone_null_bit_buffer(data: &Option<Self>) -> Option<Self> {
         data.as_ref().map(|null_bit_buffer| NullBitBufferGpu {
-            bit_buffer: Arc::new(null_bit_buffer.clone_buffer()),
+            bit_buffer: null_bit_buffer.clone_buffer().into(),
             len: null_bit_buffer.len,
             gpu_device: null_bit_buffer.gpu_device.clone(),
         })
@@ -140,7 +174,7 @@ impl NullBitBufferGpu {
         encoder: &mut CommandEncoder,
     ) -> Option<Self> {
         data.as_ref().map(|null_bit_buffer| NullBitBufferGpu {
-            bit_buffer: Arc::new(null_bit_buffer.clone_buffer_pass(encoder)),
+            bit_buffer: null_bit_buffer.clone_buffer_pass(encoder).into(),
             len: null_bit_buffer.len,
             gpu_device: null_bit_buffer.gpu_device.clone(),
         })
@@ -151,7 +185,9 @@ impl NullBitBufferGpu {
         pipeline: &mut ArrowComputePipeline,
     ) -> Option<Self> {
         data.as_ref().map(|null_bit_buffer| NullBitBufferGpu {
-            bit_buffer: Arc::new(null_bit_buffer.clone_buffer_pass(&mut pipeline.encoder)),
+            bit_buffer: null_bit_buffer
+                .clone_buffer_pass(&mut pipeline.encoder)
+                .into(),
             len: null_bit_buffer.len,
             gpu_device: null_bit_buffer.gpu_device.clone(),
         })
@@ -195,7 +231,7 @@ impl NullBitBufferGpu {
                 let gpu_device = left.gpu_device.clone();
 
                 Some(Self {
-                    bit_buffer: Arc::new(new_bit_buffer),
+                    bit_buffer: new_bit_buffer.into(),
                     len,
                     gpu_device,
                 })
@@ -222,19 +258,20 @@ impl NullBitBufferGpu {
                 assert_eq!(left.bit_buffer.size(), right.bit_buffer.size());
                 assert_eq!(left.len, right.len);
                 assert!(Arc::ptr_eq(&left.gpu_device, &right.gpu_device));
-                let new_bit_buffer = left.gpu_device.apply_scalar_function(
+                let dispatch_size = left.bit_buffer.size().div_ceil(4).div_ceil(256) as u32;
+                let new_bit_buffer = pipeline.apply_binary_function(
                     &left.bit_buffer,
                     &right.bit_buffer,
                     left.bit_buffer.size(),
-                    4,
                     LOGICAL_AND_SHADER,
                     "bitwise_and",
+                    dispatch_size,
                 );
                 let len = left.len;
                 let gpu_device = left.gpu_device.clone();
 
                 Some(Self {
-                    bit_buffer: Arc::new(new_bit_buffer),
+                    bit_buffer: new_bit_buffer.into(),
                     len,
                     gpu_device,
                 })
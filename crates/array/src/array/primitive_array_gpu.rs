@@ -68,6 +68,10 @@ impl<T: ArrowPrimitiveType> PrimitiveArrayGpu<T> {
     }
 
     pub fn raw_values(&self) -> Option<Vec<T::NativeType>> {
+        // zero sized buffers can not be read back
+        if self.len == 0 {
+            return Some(vec![]);
+        }
         let result = self.gpu_device.retrive_data(&self.data);
         let result: Vec<T::NativeType> = bytemuck::cast_slice(&result).to_vec();
         Some(result[0..self.len].to_vec())
@@ -116,6 +120,27 @@ impl<T: ArrowPrimitiveType> PrimitiveArrayGpu<T> {
     }
 }
 
+impl<T: ArrowPrimitiveType> PrimitiveArrayGpu<T> {
+    /// Returns a zero-copy view of `len` elements starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset + len <= self.len,
+            "slice of {len} elements at {offset} is out of bounds of an array of length {}",
+            self.len
+        );
+        let item_size = std::mem::size_of::<T::NativeType>() as u64;
+        Self {
+            data: self
+                .data
+                .slice(offset as u64 * item_size, len as u64 * item_size),
+            gpu_device: self.gpu_device.clone(),
+            phantom: PhantomData,
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+}
+
 impl<T: ArrowPrimitiveType> Debug for PrimitiveArrayGpu<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{{")?;
@@ -0,0 +1,274 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use wgpu::util::align_to;
+
+use crate::ArrowErrorGPU;
+use crate::gpu_utils::ArrowComputePipeline;
+use crate::kernels::broadcast::Broadcast;
+
+use super::buffer::ArrowGpuBuffer;
+use super::{ArrayUtils, ArrowArrayGPU, BooleanBufferBuilder, GpuDevice, NullBitBufferGpu};
+
+const UTF8_BROADCAST_SHADER: &str = include_str!("../../compute_shaders/utf8/broadcast.wgsl");
+
+/// Utf8 arrow array in gpu
+pub struct StringArrayGPU {
+    /// i32 offsets into `values`, contains `len + 1` entries
+    pub offsets: ArrowGpuBuffer,
+    /// utf8 bytes of all the strings, padded to a multiple of 4 bytes
+    pub values: ArrowGpuBuffer,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+    pub null_buffer: Option<NullBitBufferGpu>,
+}
+
+impl StringArrayGPU {
+    pub fn from_optional_slice(value: &[Option<&str>], gpu_device: Arc<GpuDevice>) -> Self {
+        let mut null_buffer_builder = BooleanBufferBuilder::new_with_capacity(value.len());
+
+        for (index, val) in value.iter().enumerate() {
+            if val.is_some() {
+                null_buffer_builder.set_bit(index);
+            }
+        }
+
+        let strings = value.iter().map(|x| x.unwrap_or_default());
+        let mut array = Self::from_iter(strings, value.len(), gpu_device.clone());
+        array.null_buffer = NullBitBufferGpu::new(gpu_device, &null_buffer_builder);
+        array
+    }
+
+    pub fn from_slice(value: &[&str], gpu_device: Arc<GpuDevice>) -> Self {
+        Self::from_iter(value.iter().copied(), value.len(), gpu_device)
+    }
+
+    fn from_iter<'a>(
+        value: impl Iterator<Item = &'a str>,
+        len: usize,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        let mut offsets = Vec::<i32>::with_capacity(len + 1);
+        let mut bytes = Vec::<u8>::new();
+        offsets.push(0);
+        for val in value {
+            bytes.extend_from_slice(val.as_bytes());
+            offsets.push(bytes.len() as i32);
+        }
+        // storage buffers are read as u32 words, so they cannot be empty or unaligned
+        bytes.resize(align_to(bytes.len(), 4).max(4), 0);
+
+        let offsets = gpu_device.create_gpu_buffer_with_data(&offsets);
+        let values = gpu_device.create_gpu_buffer_with_data(&bytes);
+
+        Self {
+            offsets: offsets.into(),
+            values: values.into(),
+            gpu_device,
+            len,
+            null_buffer: None,
+        }
+    }
+
+    /// Returns the offsets of the strings in the value buffer
+    pub fn raw_offsets(&self) -> Vec<i32> {
+        let result = self.gpu_device.retrive_data(&self.offsets);
+        let result: Vec<i32> = bytemuck::cast_slice(&result).to_vec();
+        result[0..self.len + 1].to_vec()
+    }
+
+    /// Returns the strings in the array, fails if a value is not valid utf8
+    pub fn raw_values(&self) -> Result<Vec<String>, ArrowErrorGPU> {
+        let offsets = self.raw_offsets();
+        let bytes = self.gpu_device.retrive_data(&self.values);
+        offsets
+            .windows(2)
+            .map(|x| {
+                String::from_utf8(bytes[x[0] as usize..x[1] as usize].to_vec()).map_err(|error| {
+                    ArrowErrorGPU::InvalidArgument(format!("invalid utf8 string value: {error}"))
+                })
+            })
+            .collect()
+    }
+
+    pub fn values(&self) -> Vec<Option<String>> {
+        let primitive_values = self.raw_values().unwrap();
+
+        match &self.null_buffer {
+            Some(null_bit_buffer) => {
+                let null_values = null_bit_buffer.raw_values();
+                primitive_values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(pos, val)| {
+                        BooleanBufferBuilder::is_set_in_slice(&null_values, pos).then_some(val)
+                    })
+                    .collect()
+            }
+            None => primitive_values.into_iter().map(Some).collect(),
+        }
+    }
+
+    /// Returns a zero-copy view of `len` strings starting at `offset`.
+    /// The value buffer is shared whole, only the offsets are sliced.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            offsets: self.offsets.slice(offset as u64 * 4, (len as u64 + 1) * 4),
+            values: self.values.clone(),
+            gpu_device: self.gpu_device.clone(),
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self {
+            offsets: self.gpu_device.clone_buffer(&self.offsets).into(),
+            values: self.gpu_device.clone_buffer(&self.values).into(),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer(&self.null_buffer),
+        }
+    }
+}
+
+impl Debug for StringArrayGPU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "{:?}", self.offsets)?;
+        writeln!(f, "{:?}", self.values)?;
+        writeln!(f, "{:?}", self.gpu_device.device)?;
+        writeln!(f, "{:?}", self.gpu_device.queue)?;
+        writeln!(
+            f,
+            "Array of length {} contains {:?}",
+            self.len,
+            self.values()
+        )?;
+        write!(f, "}}")
+    }
+}
+
+impl From<StringArrayGPU> for ArrowArrayGPU {
+    fn from(val: StringArrayGPU) -> Self {
+        ArrowArrayGPU::StringArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for StringArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::StringArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into StringArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+impl Broadcast<&str> for StringArrayGPU {
+    fn broadcast_op(value: &str, len: usize, pipeline: &mut ArrowComputePipeline) -> Self {
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.resize(align_to(bytes.len(), 4).max(4), 0);
+        let value_buffer = pipeline.device.create_gpu_buffer_with_data(&bytes);
+        let params = pipeline
+            .device
+            .create_uniform_buffer(&[len as u32, value.len() as u32, 0, 0]);
+
+        let values_size = align_to(len as u64 * value.len() as u64, 4).max(4);
+        let values = pipeline.device.create_empty_buffer(values_size);
+        let offsets = pipeline.device.create_empty_buffer((len as u64 + 1) * 4);
+        let dispatch_size = (values_size as usize / 4).max(len + 1).div_ceil(256);
+        pipeline.apply_function(
+            &[&params, &value_buffer, &values, &offsets],
+            UTF8_BROADCAST_SHADER,
+            "broadcast",
+            dispatch_size as u32,
+        );
+
+        Self {
+            offsets: offsets.into(),
+            values: values.into(),
+            gpu_device: pipeline.device.clone(),
+            len,
+            null_buffer: None,
+        }
+    }
+}
+
+impl ArrayUtils for StringArrayGPU {
+    fn get_gpu_device(&self) -> Arc<GpuDevice> {
+        self.gpu_device.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+
+    #[test]
+    fn test_string_values() {
+        let values = [Some("hello"), None, Some(""), Some("wörld"), Some("gpu")];
+        let array = StringArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+
+        assert_eq!(array.raw_offsets(), vec![0, 5, 5, 5, 11, 14]);
+        assert_eq!(
+            array.raw_values().unwrap(),
+            vec!["hello", "", "", "wörld", "gpu"]
+        );
+        assert_eq!(array.values(), values.map(|x| x.map(String::from)).to_vec());
+    }
+
+    #[test]
+    fn test_string_broadcast() {
+        let device = GPU_DEVICE.clone();
+        for value in ["", "a", "wörld", "abcdefgh"] {
+            let array = StringArrayGPU::broadcast(value, 300, device.clone());
+            assert_eq!(array.raw_values().unwrap(), vec![value; 300]);
+        }
+        let array = StringArrayGPU::broadcast("abc", 0, device);
+        assert_eq!(array.raw_values().unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_string_slice() {
+        let device = crate::GPU_DEVICE.clone();
+        let array = StringArrayGPU::from_optional_slice(
+            &[Some("a"), None, Some("ccc"), Some(""), Some("eeeee")],
+            device,
+        );
+        let slice = array.slice(1, 3);
+        assert_eq!(
+            slice.values(),
+            vec![None, Some("ccc".into()), Some("".into())]
+        );
+        assert_eq!(slice.clone_array().raw_offsets(), vec![1, 1, 4, 4]);
+    }
+
+    #[test]
+    fn test_string_from_slice() {
+        let array = StringArrayGPU::from_slice(&["", "", "abc"], GPU_DEVICE.clone());
+        assert_eq!(array.raw_offsets(), vec![0, 0, 0, 3]);
+        assert_eq!(
+            array.clone_array().values(),
+            vec![Some("".into()), Some("".into()), Some("abc".into())]
+        );
+    }
+
+    #[test]
+    fn test_string_invalid_utf8() {
+        let mut array = StringArrayGPU::from_slice(&["ö"], GPU_DEVICE.clone());
+        // ends the string in the middle of the two byte character
+        array.offsets = GPU_DEVICE.create_gpu_buffer_with_data(&[0i32, 1]).into();
+        assert!(matches!(
+            array.raw_values(),
+            Err(ArrowErrorGPU::InvalidArgument(_))
+        ));
+    }
+}
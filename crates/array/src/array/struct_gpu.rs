@@ -0,0 +1,167 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+use crate::record_batch::Field;
+use crate::utils::ScalarArray;
+
+use super::{ArrayUtils, ArrowArrayGPU, GpuDevice, NullBitBufferGpu};
+
+/// Struct arrow array in gpu
+pub struct StructArrayGPU {
+    /// Name and type of each child array
+    pub fields: Vec<Field>,
+    /// Child arrays, one per field, all of length `len`
+    pub columns: Vec<Arc<ArrowArrayGPU>>,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+    pub null_buffer: Option<NullBitBufferGpu>,
+}
+
+impl StructArrayGPU {
+    pub fn new(
+        fields: Vec<Field>,
+        columns: Vec<Arc<ArrowArrayGPU>>,
+        null_buffer: Option<NullBitBufferGpu>,
+    ) -> Self {
+        assert!(!columns.is_empty());
+        assert_eq!(fields.len(), columns.len());
+        let len = columns[0].len();
+        for (field, column) in fields.iter().zip(&columns) {
+            assert_eq!(column.len(), len);
+            assert_eq!(field.data_type, column.get_dtype());
+        }
+
+        Self {
+            gpu_device: columns[0].get_gpu_device(),
+            fields,
+            columns,
+            len,
+            null_buffer,
+        }
+    }
+
+    /// Returns the child array of the field called `name`
+    pub fn column_by_name(&self, name: &str) -> Option<&Arc<ArrowArrayGPU>> {
+        self.fields
+            .iter()
+            .position(|field| field.name == name)
+            .map(|index| &self.columns[index])
+    }
+
+    pub fn raw_values(&self) -> Option<ScalarArray> {
+        Some(ScalarArray::StructVec(
+            self.columns.iter().map(|x| x.get_raw_values()).collect(),
+        ))
+    }
+
+    /// Returns a zero-copy view of `len` rows starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len);
+        Self {
+            fields: self.fields.clone(),
+            columns: self
+                .columns
+                .iter()
+                .map(|x| Arc::new(x.slice(offset, len)))
+                .collect(),
+            gpu_device: self.gpu_device.clone(),
+            len,
+            null_buffer: self.null_buffer.as_ref().map(|x| x.slice(offset, len)),
+        }
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            columns: self
+                .columns
+                .iter()
+                .map(|x| Arc::new(x.clone_array()))
+                .collect(),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer(&self.null_buffer),
+        }
+    }
+}
+
+impl Debug for StructArrayGPU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        for (field, column) in self.fields.iter().zip(&self.columns) {
+            writeln!(f, "{}: {:?}", field.name, column)?;
+        }
+        writeln!(f, "Array of length {}", self.len)?;
+        write!(f, "}}")
+    }
+}
+
+impl From<StructArrayGPU> for ArrowArrayGPU {
+    fn from(val: StructArrayGPU) -> Self {
+        ArrowArrayGPU::StructArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for StructArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::StructArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into StructArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+impl ArrayUtils for StructArrayGPU {
+    fn get_gpu_device(&self) -> Arc<GpuDevice> {
+        self.gpu_device.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::{ArrowType, Float32ArrayGPU, StringArrayGPU};
+
+    #[test]
+    fn test_struct_values() {
+        let device = GPU_DEVICE.clone();
+        let names = StringArrayGPU::from_slice(&["a", "b"], device.clone());
+        let scores = Float32ArrayGPU::from_slice(&[1.5, 2.5], device);
+        let array = StructArrayGPU::new(
+            vec![
+                Field::new("name", ArrowType::Utf8Type, false),
+                Field::new("score", ArrowType::Float32Type, false),
+            ],
+            vec![Arc::new(names.into()), Arc::new(scores.into())],
+            None,
+        );
+
+        assert_eq!(array.len, 2);
+        assert_eq!(
+            array.column_by_name("score").unwrap().get_raw_values(),
+            ScalarArray::F32Vec(vec![1.5, 2.5])
+        );
+        assert_eq!(
+            array.clone_array().raw_values().unwrap(),
+            ScalarArray::StructVec(vec![
+                ScalarArray::StringVec(vec!["a".into(), "b".into()]),
+                ScalarArray::F32Vec(vec![1.5, 2.5]),
+            ])
+        );
+        assert_eq!(
+            array.slice(1, 1).raw_values().unwrap(),
+            ScalarArray::StructVec(vec![
+                ScalarArray::StringVec(vec!["b".into()]),
+                ScalarArray::F32Vec(vec![2.5]),
+            ])
+        );
+    }
+}
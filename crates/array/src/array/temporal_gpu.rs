@@ -0,0 +1,280 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+
+use super::{ArrayUtils, ArrowArrayGPU, GpuDevice, Int32ArrayGPU, Int64ArrayGPU};
+
+/// Resolution of timestamp, time and duration values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl TimeUnit {
+    /// Number of values of this unit in a second
+    pub fn per_second(&self) -> u32 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => 1_000,
+            TimeUnit::Microsecond => 1_000_000,
+            TimeUnit::Nanosecond => 1_000_000_000,
+        }
+    }
+
+    /// Returns the finer of the two units
+    pub fn finer(self, other: TimeUnit) -> TimeUnit {
+        self.max(other)
+    }
+}
+
+/// Timestamp arrow array in gpu, values are the time elapsed since the unix epoch in `unit`
+pub struct TimestampArrayGPU {
+    pub values: Int64ArrayGPU,
+    pub unit: TimeUnit,
+    /// Timezone the timestamps are displayed in, values are always relative to UTC
+    pub timezone: Option<String>,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+}
+
+impl TimestampArrayGPU {
+    pub fn new(values: Int64ArrayGPU, unit: TimeUnit, timezone: Option<String>) -> Self {
+        Self {
+            gpu_device: values.gpu_device.clone(),
+            len: values.len,
+            values,
+            unit,
+            timezone,
+        }
+    }
+
+    pub fn from_slice(
+        value: &[i64],
+        unit: TimeUnit,
+        timezone: Option<String>,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(Int64ArrayGPU::from_slice(value, gpu_device), unit, timezone)
+    }
+
+    pub fn from_optional_slice(
+        value: &[Option<i64>],
+        unit: TimeUnit,
+        timezone: Option<String>,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(
+            Int64ArrayGPU::from_optional_slice(value, gpu_device),
+            unit,
+            timezone,
+        )
+    }
+
+    pub fn raw_values(&self) -> Option<Vec<i64>> {
+        self.values.raw_values()
+    }
+
+    pub fn values(&self) -> Vec<Option<i64>> {
+        self.values.values()
+    }
+
+    /// Returns a zero-copy view of `len` values starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new(
+            self.values.slice(offset, len),
+            self.unit,
+            self.timezone.clone(),
+        )
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self::new(self.values.clone_array(), self.unit, self.timezone.clone())
+    }
+}
+
+/// Duration arrow array in gpu, values are an elapsed time in `unit`
+pub struct DurationArrayGPU {
+    pub values: Int64ArrayGPU,
+    pub unit: TimeUnit,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+}
+
+impl DurationArrayGPU {
+    pub fn new(values: Int64ArrayGPU, unit: TimeUnit) -> Self {
+        Self {
+            gpu_device: values.gpu_device.clone(),
+            len: values.len,
+            values,
+            unit,
+        }
+    }
+
+    pub fn from_slice(value: &[i64], unit: TimeUnit, gpu_device: Arc<GpuDevice>) -> Self {
+        Self::new(Int64ArrayGPU::from_slice(value, gpu_device), unit)
+    }
+
+    pub fn from_optional_slice(
+        value: &[Option<i64>],
+        unit: TimeUnit,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(Int64ArrayGPU::from_optional_slice(value, gpu_device), unit)
+    }
+
+    pub fn raw_values(&self) -> Option<Vec<i64>> {
+        self.values.raw_values()
+    }
+
+    pub fn values(&self) -> Vec<Option<i64>> {
+        self.values.values()
+    }
+
+    /// Returns a zero-copy view of `len` values starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new(self.values.slice(offset, len), self.unit)
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self::new(self.values.clone_array(), self.unit)
+    }
+}
+
+/// Time32 arrow array in gpu, values are the time elapsed since midnight in `unit`.
+/// Only seconds and milliseconds are valid units.
+pub struct Time32ArrayGPU {
+    pub values: Int32ArrayGPU,
+    pub unit: TimeUnit,
+    pub gpu_device: Arc<GpuDevice>,
+    /// Actual len of the array
+    pub len: usize,
+}
+
+impl Time32ArrayGPU {
+    pub fn new(values: Int32ArrayGPU, unit: TimeUnit) -> Self {
+        assert!(
+            matches!(unit, TimeUnit::Second | TimeUnit::Millisecond),
+            "Time32 only supports second and millisecond units, got {:?}",
+            unit
+        );
+        Self {
+            gpu_device: values.gpu_device.clone(),
+            len: values.len,
+            values,
+            unit,
+        }
+    }
+
+    pub fn from_slice(value: &[i32], unit: TimeUnit, gpu_device: Arc<GpuDevice>) -> Self {
+        Self::new(Int32ArrayGPU::from_slice(value, gpu_device), unit)
+    }
+
+    pub fn from_optional_slice(
+        value: &[Option<i32>],
+        unit: TimeUnit,
+        gpu_device: Arc<GpuDevice>,
+    ) -> Self {
+        Self::new(Int32ArrayGPU::from_optional_slice(value, gpu_device), unit)
+    }
+
+    pub fn raw_values(&self) -> Option<Vec<i32>> {
+        self.values.raw_values()
+    }
+
+    pub fn values(&self) -> Vec<Option<i32>> {
+        self.values.values()
+    }
+
+    /// Returns a zero-copy view of `len` values starting at `offset`
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new(self.values.slice(offset, len), self.unit)
+    }
+
+    pub fn clone_array(&self) -> Self {
+        Self::new(self.values.clone_array(), self.unit)
+    }
+}
+
+macro_rules! impl_temporal_array {
+    ($ty: ident) => {
+        impl Debug for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                writeln!(f, "{{")?;
+                writeln!(f, "unit: {:?}", self.unit)?;
+                writeln!(f, "values: {:?}", self.values)?;
+                write!(f, "}}")
+            }
+        }
+
+        impl ArrayUtils for $ty {
+            fn get_gpu_device(&self) -> Arc<GpuDevice> {
+                self.gpu_device.clone()
+            }
+        }
+
+        impl From<$ty> for ArrowArrayGPU {
+            fn from(val: $ty) -> Self {
+                ArrowArrayGPU::$ty(val)
+            }
+        }
+
+        impl TryFrom<ArrowArrayGPU> for $ty {
+            type Error = ArrowErrorGPU;
+
+            fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+                match value {
+                    ArrowArrayGPU::$ty(x) => Ok(x),
+                    x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                        "could not cast {:?} into {}",
+                        x,
+                        stringify!($ty)
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_temporal_array!(TimestampArrayGPU);
+impl_temporal_array!(DurationArrayGPU);
+impl_temporal_array!(Time32ArrayGPU);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::ArrowType;
+
+    #[test]
+    fn test_timestamp_array() {
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[Some(1), None, Some(-3)],
+            TimeUnit::Millisecond,
+            Some("UTC".into()),
+            GPU_DEVICE.clone(),
+        );
+        assert_eq!(array.len, 3);
+        assert_eq!(array.values(), vec![Some(1), None, Some(-3)]);
+
+        let array: ArrowArrayGPU = array.clone_array().into();
+        assert_eq!(
+            array.get_dtype(),
+            ArrowType::TimestampType(TimeUnit::Millisecond, Some("UTC".into()))
+        );
+        assert!(array.get_null_bit_buffer().is_some());
+        let array: TimestampArrayGPU = array.try_into().unwrap();
+        assert_eq!(array.timezone.as_deref(), Some("UTC"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time32_invalid_unit() {
+        Time32ArrayGPU::from_slice(&[1], TimeUnit::Microsecond, GPU_DEVICE.clone());
+    }
+}
@@ -6,11 +6,26 @@ pub trait Int32Type {}
 impl Int32Type for i32 {}
 impl Int32Type for Date32Type {}
 
+/// Arrow Array backed by i64
+pub trait Int64Type {}
+
+impl Int64Type for i64 {}
+
 /// Arrow Array backed by f32
 pub trait Float32Type {}
 
 impl Float32Type for f32 {}
 
+/// Arrow Array backed by f64
+pub trait Float64Type {}
+
+impl Float64Type for f64 {}
+
+/// Arrow Array backed by u64
+pub trait UInt64Type {}
+
+impl UInt64Type for u64 {}
+
 /// Arrow Array backed by u32
 pub trait UInt32Type {}
 
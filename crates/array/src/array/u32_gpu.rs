@@ -1,10 +1,8 @@
 use super::ArrowPrimitiveType;
-use super::buffer::ArrowGpuBuffer;
-use super::{ArrowArray, ArrowArrayGPU, ArrowType, NullBitBufferGpu, primitive_array_gpu::*};
+use super::{ArrowArrayGPU, primitive_array_gpu::*};
 use crate::ArrowErrorGPU;
 use crate::gpu_utils::*;
 use crate::kernels::broadcast::Broadcast;
-use std::any::Any;
 use wgpu::Buffer;
 
 /// UInt32 arrow array in gpu
@@ -90,32 +88,6 @@ impl<T: ArrowPrimitiveType<NativeType = u32>> Broadcast<u32> for PrimitiveArrayG
     }
 }
 
-impl ArrowArray for UInt32ArrayGPU {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn get_data_type(&self) -> ArrowType {
-        ArrowType::UInt32Type
-    }
-
-    fn get_memory_used(&self) -> u64 {
-        self.data.size()
-    }
-
-    fn get_gpu_device(&self) -> &GpuDevice {
-        &self.gpu_device
-    }
-
-    fn get_buffer(&self) -> &ArrowGpuBuffer {
-        &self.data
-    }
-
-    fn get_null_bit_buffer(&self) -> Option<&NullBitBufferGpu> {
-        self.null_buffer.as_ref()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,74 @@
+use super::ArrowPrimitiveType;
+use super::{ArrowArrayGPU, primitive_array_gpu::*};
+use crate::ArrowErrorGPU;
+use crate::gpu_utils::*;
+use crate::kernels::broadcast::Broadcast;
+
+/// UInt64 arrow array in gpu
+pub type UInt64ArrayGPU = PrimitiveArrayGpu<u64>;
+
+pub(crate) const U64_BROADCAST_SHADER: &str =
+    include_str!("../../compute_shaders/u64/broadcast.wgsl");
+
+impl From<UInt64ArrayGPU> for ArrowArrayGPU {
+    fn from(val: UInt64ArrayGPU) -> Self {
+        ArrowArrayGPU::UInt64ArrayGPU(val)
+    }
+}
+
+impl TryFrom<ArrowArrayGPU> for UInt64ArrayGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: ArrowArrayGPU) -> Result<Self, Self::Error> {
+        match value {
+            ArrowArrayGPU::UInt64ArrayGPU(x) => Ok(x),
+            x => Err(ArrowErrorGPU::CastingNotSupported(format!(
+                "could not cast {:?} into UInt64ArrayGPU",
+                x
+            ))),
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType<NativeType = u64>> Broadcast<u64> for PrimitiveArrayGpu<T> {
+    fn broadcast_op(
+        value: u64,
+        len: usize,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> PrimitiveArrayGpu<T> {
+        let scalar_buffer = pipeline.device.create_scalar_buffer(&value);
+        let gpu_buffer = pipeline.apply_broadcast_function(
+            &scalar_buffer,
+            8 * len as u64,
+            U64_BROADCAST_SHADER,
+            "broadcast",
+            len.div_ceil(256) as u32,
+        );
+        let data = gpu_buffer.into();
+        let null_buffer = None;
+
+        Self {
+            data,
+            gpu_device: pipeline.device.clone(),
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::primitive_array_gpu::test::*;
+
+    test_broadcast!(test_broadcast_u64, UInt64ArrayGPU, u64::MAX - 1);
+
+    #[test]
+    fn test_u64_array_from_optional_slice() {
+        use crate::GPU_DEVICE;
+        let values = [Some(0u64), Some(u64::MAX), None, Some(1 << 40)];
+        let gpu_array = UInt64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(gpu_array.values(), values);
+    }
+}
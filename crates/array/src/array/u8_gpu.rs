@@ -54,4 +54,18 @@ mod tests {
     use crate::array::primitive_array_gpu::test::*;
 
     test_broadcast!(test_broadcast_u8, UInt8ArrayGPU, 1);
+
+    #[test]
+    fn test_u8_slice() {
+        let device = crate::GPU_DEVICE.clone();
+        let values = (0..20).collect::<Vec<u8>>();
+        let array = UInt8ArrayGPU::from_slice(&values, device);
+
+        let slice = array.slice(3, 9);
+        assert!(slice.data.shares_buffer(&array.data));
+        assert_eq!(slice.raw_values().unwrap(), values[3..12]);
+        assert_eq!(slice.slice(5, 4).raw_values().unwrap(), values[8..12]);
+        assert_eq!(array.slice(4, 16).raw_values().unwrap(), values[4..20]);
+        assert_eq!(array.slice(20, 0).raw_values().unwrap(), vec![]);
+    }
 }
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use wgpu::{BindingResource, Buffer, BufferBinding};
+
+/// A gpu buffer, or a window over the bits of one, that can be bound to a kernel
+pub trait GpuBufferView {
+    fn buffer(&self) -> &Buffer;
+
+    /// Position of the first bit of the view in the buffer
+    fn bit_offset(&self) -> u64 {
+        0
+    }
+
+    /// Number of bits in the view
+    fn bit_len(&self) -> u64 {
+        self.buffer().size() * 8
+    }
+
+    /// Size in bytes of the view padded to 4 bytes, the size of the buffer when not sliced
+    fn view_size(&self) -> u64 {
+        if self.is_entire_buffer() {
+            self.buffer().size()
+        } else {
+            self.bit_len().div_ceil(32) * 4
+        }
+    }
+
+    fn is_entire_buffer(&self) -> bool {
+        self.bit_offset() == 0 && self.bit_len() == self.buffer().size() * 8
+    }
+
+    /// The last word of the view holds no bits from outside of it, or the view ends
+    /// with the buffer and the remaining bits are its padding
+    fn has_clean_tail(&self) -> bool {
+        self.bit_len().is_multiple_of(32)
+            || (self.bit_offset() + self.bit_len()).div_ceil(32) * 4 >= self.buffer().size()
+    }
+}
+
+impl GpuBufferView for Buffer {
+    fn buffer(&self) -> &Buffer {
+        self
+    }
+}
+
+impl GpuBufferView for Arc<Buffer> {
+    fn buffer(&self) -> &Buffer {
+        self
+    }
+}
+
+/// A view prepared for binding, either a range of the original buffer or a realigned copy
+pub enum BoundBuffer<'a> {
+    View {
+        buffer: &'a Buffer,
+        offset: u64,
+        size: u64,
+    },
+    Entire(&'a Buffer),
+    Owned(Buffer),
+}
+
+impl BoundBuffer<'_> {
+    pub fn binding(&self) -> BindingResource<'_> {
+        match self {
+            BoundBuffer::View {
+                buffer,
+                offset,
+                size,
+            } => BindingResource::Buffer(BufferBinding {
+                buffer,
+                offset: *offset,
+                size: std::num::NonZeroU64::new(*size),
+            }),
+            BoundBuffer::Entire(buffer) => buffer.as_entire_binding(),
+            BoundBuffer::Owned(buffer) => buffer.as_entire_binding(),
+        }
+    }
+
+    /// Buffer, byte offset and byte size of the bound range
+    pub fn range(&self) -> (&Buffer, u64, u64) {
+        match self {
+            BoundBuffer::View {
+                buffer,
+                offset,
+                size,
+            } => (buffer, *offset, *size),
+            BoundBuffer::Entire(buffer) => (buffer, 0, buffer.size()),
+            BoundBuffer::Owned(buffer) => (buffer, 0, buffer.size()),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use wgpu::{Buffer, CommandEncoder};
 
-use super::{CmpQuery, GpuDevice};
+use super::{CmpQuery, GpuBufferView, GpuDevice};
 
 /// Creates a compute pipeline for Arrow array buffers to be sent to the GPU.  
 pub struct ArrowComputePipeline {
@@ -23,7 +23,7 @@ impl ArrowComputePipeline {
 
     pub fn apply_unary_function(
         &mut self,
-        original_values: &Buffer,
+        original_values: &dyn GpuBufferView,
         new_buffer_size: u64,
         shader: &str,
         entry_point: &str,
@@ -33,21 +33,11 @@ impl ArrowComputePipeline {
 
         let new_values_buffer = self.device.create_empty_buffer(new_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: original_values.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let bind_group_array = self.device.create_view_bind_group(
+            &compute_pipeline,
+            &[original_values, &new_values_buffer],
+            &mut self.encoder,
+        );
 
         let query = self.device.compute_pass(
             &mut self.encoder,
@@ -67,8 +57,8 @@ impl ArrowComputePipeline {
 
     pub fn apply_binary_function(
         &mut self,
-        operand_1: &Buffer,
-        operand_2: &Buffer,
+        operand_1: &dyn GpuBufferView,
+        operand_2: &dyn GpuBufferView,
         new_buffer_size: u64,
         shader: &str,
         entry_point: &str,
@@ -78,25 +68,11 @@ impl ArrowComputePipeline {
 
         let new_values_buffer = self.device.create_empty_buffer(new_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: operand_1.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: operand_2.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let bind_group_array = self.device.create_view_bind_group(
+            &compute_pipeline,
+            &[operand_1, operand_2, &new_values_buffer],
+            &mut self.encoder,
+        );
 
         let query = self.device.compute_pass(
             &mut self.encoder,
@@ -112,11 +88,12 @@ impl ArrowComputePipeline {
         new_values_buffer
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_ternary_function(
         &mut self,
-        operand_1: &Buffer,
-        operand_2: &Buffer,
-        operand_3: &Buffer,
+        operand_1: &dyn GpuBufferView,
+        operand_2: &dyn GpuBufferView,
+        operand_3: &dyn GpuBufferView,
         new_buffer_size: u64,
         shader: &str,
         entry_point: &str,
@@ -126,29 +103,11 @@ impl ArrowComputePipeline {
 
         let new_values_buffer = self.device.create_empty_buffer(new_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: operand_1.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: operand_2.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: operand_3.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 3,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let bind_group_array = self.device.create_view_bind_group(
+            &compute_pipeline,
+            &[operand_1, operand_2, operand_3, &new_values_buffer],
+            &mut self.encoder,
+        );
 
         let query = self.device.compute_pass(
             &mut self.encoder,
@@ -166,8 +125,8 @@ impl ArrowComputePipeline {
 
     pub fn apply_scalar_function(
         &mut self,
-        original_values: &Buffer,
-        scalar_value: &Buffer,
+        original_values: &dyn GpuBufferView,
+        scalar_value: &dyn GpuBufferView,
         output_buffer_size: u64,
         shader: &str,
         entry_point: &str,
@@ -177,25 +136,11 @@ impl ArrowComputePipeline {
 
         let new_values_buffer = self.device.create_empty_buffer(output_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: original_values.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: scalar_value.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let bind_group_array = self.device.create_view_bind_group(
+            &compute_pipeline,
+            &[original_values, scalar_value, &new_values_buffer],
+            &mut self.encoder,
+        );
 
         let query = self.device.compute_pass(
             &mut self.encoder,
@@ -214,7 +159,7 @@ impl ArrowComputePipeline {
 
     pub fn apply_broadcast_function(
         &mut self,
-        scalar_value: &Buffer,
+        scalar_value: &dyn GpuBufferView,
         output_buffer_size: u64,
         shader: &str,
         entry_point: &str,
@@ -224,21 +169,11 @@ impl ArrowComputePipeline {
 
         let new_values_buffer = self.device.create_empty_buffer(output_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: scalar_value.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let bind_group_array = self.device.create_view_bind_group(
+            &compute_pipeline,
+            &[scalar_value, &new_values_buffer],
+            &mut self.encoder,
+        );
 
         let query = self.device.compute_pass(
             &mut self.encoder,
@@ -255,6 +190,46 @@ impl ArrowComputePipeline {
         new_values_buffer
     }
 
+    /// Dispatches `entry_point` with `buffers` bound in order starting from binding 0.
+    /// The shader is expected to use every buffer passed in.
+    pub fn apply_function(
+        &mut self,
+        buffers: &[&dyn GpuBufferView],
+        shader: &str,
+        entry_point: &str,
+        dispatch_size: u32,
+    ) {
+        let compute_pipeline = self.device.create_compute_pipeline(shader, entry_point);
+
+        let bind_group_array =
+            self.device
+                .create_view_bind_group(&compute_pipeline, buffers, &mut self.encoder);
+
+        let query = self.device.compute_pass(
+            &mut self.encoder,
+            Some(entry_point),
+            &compute_pipeline,
+            &bind_group_array,
+            entry_point,
+            dispatch_size,
+        );
+
+        self.queries.push(query);
+    }
+
+    /// Submits the commands recorded so far and reads back `size` bytes of `buffer` from `offset`.
+    /// Needed when the size of an output buffer depends on values computed on the gpu.
+    pub fn read_buffer(&mut self, buffer: &dyn GpuBufferView, offset: u64, size: u64) -> Vec<u8> {
+        let staging_buffer = self.device.create_empty_buffer(size);
+        self.copy_buffer_to_buffer(buffer, offset, &staging_buffer, 0, size);
+
+        let encoder =
+            std::mem::replace(&mut self.encoder, self.device.create_command_encoder(None));
+        self.device.queue.submit(Some(encoder.finish()));
+
+        self.device.retrive_data(&staging_buffer)
+    }
+
     /// Submit the pipeline to the GPU
     pub fn finish(self) {
         let _submision_index = self.device.queue.submit(Some(self.encoder.finish()));
@@ -272,26 +247,23 @@ impl ArrowComputePipeline {
         // }
     }
 
-    pub fn clone_buffer(&mut self, buffer: &Buffer) -> Buffer {
-        let staging_buffer = self.device.create_empty_buffer(buffer.size());
-
-        self.encoder
-            .copy_buffer_to_buffer(buffer, 0, &staging_buffer, 0, buffer.size());
-
-        staging_buffer
+    pub fn clone_buffer(&mut self, buffer: &dyn GpuBufferView) -> Buffer {
+        self.device.clone_buffer_pass(buffer, &mut self.encoder)
     }
 
     pub fn copy_buffer_to_buffer(
         &mut self,
-        source: &Buffer,
+        source: &dyn GpuBufferView,
         source_offset: u64,
         destination: &Buffer,
         destination_offset: u64,
         copy_size: u64,
     ) {
+        let bound_source = self.device.copyable_view(source, &mut self.encoder);
+        let (source, view_offset, _) = bound_source.range();
         self.encoder.copy_buffer_to_buffer(
             source,
-            source_offset,
+            view_offset + source_offset,
             destination,
             destination_offset,
             copy_size,
@@ -1,17 +1,23 @@
-use std::{borrow::Cow, fmt::Debug, sync::Arc};
+use std::{borrow::Cow, fmt::Debug, ops::Deref, sync::Arc};
 
 use bytemuck::Pod;
 use hashbrown::Equivalent;
 use log::info;
 use pollster::FutureExt;
 use wgpu::{
-    Adapter, BindGroup, BindGroupDescriptor, Buffer, ComputePipeline, Device, MemoryHints, Queue,
-    ShaderModule, util::DeviceExt,
+    Adapter, BindGroup, BindGroupDescriptor, Buffer, CommandEncoder, ComputePipeline, Device,
+    MemoryHints, Queue, ShaderModule, util::DeviceExt,
 };
 
 use crate::array::RustNativeType;
 
-use super::{CmpQuery, append_hashmap::AppendHashMap};
+use super::{
+    BoundBuffer, CmpQuery, GpuBufferView,
+    append_hashmap::AppendHashMap,
+    view_offsets::{KernelLayout, ViewBinding, rewrite_shader},
+};
+
+const SHIFT_BITS_SHADER: &str = include_str!("../../compute_shaders/u32/shift_bits.wgsl");
 
 #[derive(PartialEq, Eq, Hash)]
 struct PiepelineEntry {
@@ -25,11 +31,35 @@ impl Equivalent<PiepelineEntry> for (&str, &str) {
     }
 }
 
+/// A compute pipeline along with the way views are bound to its kernel
+pub struct KernelPipeline {
+    pipeline: ComputePipeline,
+    layout: KernelLayout,
+}
+
+impl Deref for KernelPipeline {
+    type Target = ComputePipeline;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pipeline
+    }
+}
+
+/// Returns `SHADER_F16` when the adapter has it and the WGSL frontend parses `enable f16`,
+/// otherwise the f16 kernels fall back to the packed shaders
+fn shader_f16_feature(adapter: &Adapter) -> wgpu::Features {
+    if wgpu::naga::front::wgsl::parse_str("enable f16;").is_ok() {
+        adapter.features() & wgpu::Features::SHADER_F16
+    } else {
+        wgpu::Features::empty()
+    }
+}
+
 /// Represents a GPU device
 pub struct GpuDevice {
     pub device: Device,
     pub queue: Queue,
-    pipeline_cache: AppendHashMap<PiepelineEntry, ComputePipeline>,
+    pipeline_cache: AppendHashMap<PiepelineEntry, KernelPipeline>,
 }
 
 impl Debug for GpuDevice {
@@ -55,11 +85,12 @@ impl GpuDevice {
             .block_on()
             .unwrap();
 
-        let required_features = if cfg!(feature = "profile") {
+        let mut required_features = if cfg!(feature = "profile") {
             wgpu::Features::TIMESTAMP_QUERY
         } else {
             wgpu::Features::default()
         };
+        required_features |= shader_f16_feature(&adapter);
 
         let (device, queue) = adapter
             .request_device(
@@ -89,7 +120,7 @@ impl GpuDevice {
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: None,
-                    required_features: wgpu::Features::empty(),
+                    required_features: shader_f16_feature(&adapter),
                     required_limits: wgpu::Limits::downlevel_defaults(),
                     memory_hints: MemoryHints::Performance,
                 },
@@ -105,6 +136,11 @@ impl GpuDevice {
         }
     }
 
+    /// Returns true when f16 kernels can run with native half precision arithmetic
+    pub fn supports_shader_f16(&self) -> bool {
+        self.device.features().contains(wgpu::Features::SHADER_F16)
+    }
+
     /// Creates a new CommandEncoder for the device
     pub fn create_command_encoder(&self, label: Option<&str>) -> wgpu::CommandEncoder {
         self.device
@@ -142,11 +178,34 @@ impl GpuDevice {
             })
     }
 
-    pub fn create_compute_pipeline(&self, shader: &str, entry_point: &str) -> Arc<ComputePipeline> {
+    /// Creates the pipeline of `entry_point`, its storage arrays are indexed from the
+    /// offsets of the bound views so sliced views are bound without being copied.
+    /// Panics when the kernel is invalid or can not be indexed from the offsets.
+    pub fn create_compute_pipeline(&self, shader: &str, entry_point: &str) -> Arc<KernelPipeline> {
+        self.create_kernel_pipeline(shader, entry_point, true)
+    }
+
+    fn create_kernel_pipeline(
+        &self,
+        shader: &str,
+        entry_point: &str,
+        offset_views: bool,
+    ) -> Arc<KernelPipeline> {
         if let Some(pipeline) = self.pipeline_cache.get(&(shader, entry_point)) {
             pipeline
         } else {
-            let cs_module = self.create_shader_module(shader);
+            let (kernel, layout) = if offset_views {
+                rewrite_shader(shader, entry_point)
+            } else {
+                (
+                    Cow::Borrowed(shader),
+                    KernelLayout {
+                        bindings: vec![],
+                        offsets_binding: None,
+                    },
+                )
+            };
+            let cs_module = self.create_shader_module(&kernel);
             let pipeline = self
                 .device
                 .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
@@ -162,7 +221,7 @@ impl GpuDevice {
                     shader: shader.into(),
                     entry_point: entry_point.into(),
                 },
-                pipeline,
+                KernelPipeline { pipeline, layout },
             )
         }
     }
@@ -209,33 +268,255 @@ impl GpuDevice {
             })
     }
 
-    pub fn clone_buffer(&self, buffer: &Buffer) -> Buffer {
-        let staging_buffer = self.create_empty_buffer(buffer.size());
+    /// Creates a uniform buffer holding `value`, used to pass parameters to shaders
+    pub fn create_uniform_buffer(&self, value: &impl Pod) -> Buffer {
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("uniform Buffer"),
+                contents: bytemuck::cast_slice(&[*value]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            })
+    }
+
+    /// Prepares `view` for binding to a kernel. Views at an offset the device can bind are
+    /// bound in place, other word aligned views are copied and the rest are realigned by a kernel.
+    pub fn bind_view<'a>(
+        &self,
+        view: &'a dyn GpuBufferView,
+        encoder: &mut CommandEncoder,
+    ) -> BoundBuffer<'a> {
+        let alignment = self.device.limits().min_storage_buffer_offset_alignment as u64;
+        self.realign_view(view, alignment, encoder)
+    }
+
+    /// Like `bind_view` but only requires the alignment of buffer copies
+    pub fn copyable_view<'a>(
+        &self,
+        view: &'a dyn GpuBufferView,
+        encoder: &mut CommandEncoder,
+    ) -> BoundBuffer<'a> {
+        self.realign_view(view, wgpu::COPY_BUFFER_ALIGNMENT, encoder)
+    }
+
+    fn realign_view<'a>(
+        &self,
+        view: &'a dyn GpuBufferView,
+        alignment: u64,
+        encoder: &mut CommandEncoder,
+    ) -> BoundBuffer<'a> {
+        if view.is_entire_buffer() {
+            return BoundBuffer::Entire(view.buffer());
+        }
+        let size = view.view_size();
+        if size == 0 {
+            return BoundBuffer::Owned(self.create_empty_buffer(4));
+        }
+        let bit_offset = view.bit_offset();
+        if view.has_clean_tail() && bit_offset.is_multiple_of(32) {
+            if bit_offset.is_multiple_of(alignment * 8) {
+                return BoundBuffer::View {
+                    buffer: view.buffer(),
+                    offset: bit_offset / 8,
+                    size,
+                };
+            }
+            let new_buffer = self.create_empty_buffer(size);
+            encoder.copy_buffer_to_buffer(view.buffer(), bit_offset / 8, &new_buffer, 0, size);
+            return BoundBuffer::Owned(new_buffer);
+        }
+        BoundBuffer::Owned(self.shift_bits_pass(view, encoder))
+    }
+
+    fn shift_bits_pass(&self, view: &dyn GpuBufferView, encoder: &mut CommandEncoder) -> Buffer {
+        let size = view.view_size();
+        let new_buffer = self.create_empty_buffer(size);
+        let params = self.create_uniform_buffer(&[
+            (view.bit_offset() / 32) as u32,
+            (view.bit_offset() % 32) as u32,
+            view.bit_len() as u32,
+            0u32,
+        ]);
+
+        let compute_pipeline = self.create_kernel_pipeline(SHIFT_BITS_SHADER, "shift_bits", false);
+        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
+        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: view.buffer().as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: new_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        self.compute_pass(
+            encoder,
+            Some("shift_bits"),
+            &compute_pipeline,
+            &bind_group_array,
+            "shift_bits",
+            (size / 4).div_ceil(256) as u32,
+        );
+
+        new_buffer
+    }
+
+    /// Binds `view` in place from an aligned offset when the kernel indexes the binding
+    /// from the offset of the view, else falls back to `bind_view`. Returns the bound
+    /// range along with the offsets of the view in it.
+    fn bind_kernel_view<'a>(
+        &self,
+        view: &'a dyn GpuBufferView,
+        binding: ViewBinding,
+        encoder: &mut CommandEncoder,
+    ) -> (BoundBuffer<'a>, [u32; 4]) {
+        let stride = match binding {
+            ViewBinding::Plain => return (self.bind_view(view, encoder), [0; 4]),
+            ViewBinding::Elements { stride } => stride,
+            ViewBinding::Words => 4,
+        };
+        let size = view.view_size();
+        if view.is_entire_buffer() {
+            let offsets = [0, 0, u32::MAX, (size / stride) as u32];
+            return (BoundBuffer::Entire(view.buffer()), offsets);
+        }
+
+        let alignment = self.device.limits().min_storage_buffer_offset_alignment as u64;
+        let bit_offset = view.bit_offset();
+        let word_start = bit_offset / 32 * 4;
+        let start = word_start / alignment * alignment;
+        let in_place = size > 0
+            && match binding {
+                ViewBinding::Words => true,
+                _ => {
+                    view.has_clean_tail()
+                        && bit_offset.is_multiple_of(32)
+                        && (word_start - start).is_multiple_of(stride)
+                }
+            };
+        if !in_place {
+            let bound_buffer = self.bind_view(view, encoder);
+            let (_, _, bound_size) = bound_buffer.range();
+            return (bound_buffer, [0, 0, u32::MAX, (bound_size / stride) as u32]);
+        }
+
+        let end = ((bit_offset + view.bit_len()).div_ceil(32) * 4).min(view.buffer().size());
+        let offsets = [
+            ((word_start - start) / stride) as u32,
+            (bit_offset % 32) as u32,
+            view.bit_len().min(u32::MAX as u64) as u32,
+            (size / stride) as u32,
+        ];
+        let bound_buffer = BoundBuffer::View {
+            buffer: view.buffer(),
+            offset: start,
+            size: end - start,
+        };
+        (bound_buffer, offsets)
+    }
+
+    /// Creates a bind group with `views` bound in order starting from binding 0.
+    /// Views are bound in place when the kernel indexes them from their offset, the
+    /// other sliced views are realigned with commands recorded in `encoder`.
+    pub fn create_view_bind_group(
+        &self,
+        compute_pipeline: &KernelPipeline,
+        views: &[&dyn GpuBufferView],
+        encoder: &mut CommandEncoder,
+    ) -> BindGroup {
+        let layout = &compute_pipeline.layout;
+        let mut view_offsets = vec![[0u32; 4]; layout.bindings.len().max(views.len())];
+        let bound_buffers = views
+            .iter()
+            .enumerate()
+            .map(|(index, view)| {
+                let (bound_buffer, offsets) =
+                    self.bind_kernel_view(*view, layout.binding(index), encoder);
+                view_offsets[index] = offsets;
+                bound_buffer
+            })
+            .collect::<Vec<_>>();
+        let mut entries = bound_buffers
+            .iter()
+            .enumerate()
+            .map(|(index, bound_buffer)| wgpu::BindGroupEntry {
+                binding: index as u32,
+                resource: bound_buffer.binding(),
+            })
+            .collect::<Vec<_>>();
+
+        let offsets_buffer = layout.offsets_binding.map(|binding| {
+            let offsets = &view_offsets[..binding as usize];
+            let buffer = self
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("view offsets Buffer"),
+                    contents: bytemuck::cast_slice(offsets),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+            (binding, buffer)
+        });
+        if let Some((binding, buffer)) = &offsets_buffer {
+            entries.push(wgpu::BindGroupEntry {
+                binding: *binding,
+                resource: buffer.as_entire_binding(),
+            });
+        }
+
+        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &entries,
+        })
+    }
 
+    pub fn clone_buffer(&self, buffer: &dyn GpuBufferView) -> Buffer {
         let mut encoder = self.create_command_encoder(None);
 
-        encoder.copy_buffer_to_buffer(buffer, 0, &staging_buffer, 0, buffer.size());
+        let staging_buffer = self.clone_buffer_pass(buffer, &mut encoder);
 
         self.queue.submit(Some(encoder.finish()));
 
         staging_buffer
     }
 
-    pub fn clone_buffer_pass(&self, buffer: &Buffer, encoder: &mut wgpu::CommandEncoder) -> Buffer {
-        let staging_buffer = self.create_empty_buffer(buffer.size());
+    pub fn clone_buffer_pass(
+        &self,
+        buffer: &dyn GpuBufferView,
+        encoder: &mut wgpu::CommandEncoder,
+    ) -> Buffer {
+        match self.copyable_view(buffer, encoder) {
+            BoundBuffer::Owned(buffer) => buffer,
+            bound_buffer => {
+                let (buffer, offset, size) = bound_buffer.range();
+                let staging_buffer = self.create_empty_buffer(size);
 
-        encoder.copy_buffer_to_buffer(buffer, 0, &staging_buffer, 0, buffer.size());
+                encoder.copy_buffer_to_buffer(buffer, offset, &staging_buffer, 0, size);
 
-        staging_buffer
+                staging_buffer
+            }
+        }
     }
 
-    pub fn retrive_data(&self, data: &Buffer) -> Vec<u8> {
-        let size = data.size() as wgpu::BufferAddress;
+    pub fn retrive_data(&self, data: &dyn GpuBufferView) -> Vec<u8> {
+        let mut encoder = self.create_command_encoder(None);
+
+        let bound_buffer = self.copyable_view(data, &mut encoder);
+        let (data, offset, size) = bound_buffer.range();
 
         let staging_buffer = self.create_retrive_buffer(size);
-        let mut encoder = self.create_command_encoder(None);
 
-        encoder.copy_buffer_to_buffer(data, 0, &staging_buffer, 0, size);
+        encoder.copy_buffer_to_buffer(data, offset, &staging_buffer, 0, size);
 
         let submission_index = self.queue.submit(Some(encoder.finish()));
 
@@ -266,7 +547,7 @@ impl GpuDevice {
 
     pub fn apply_unary_function(
         &self,
-        original_values: &Buffer,
+        original_values: &dyn GpuBufferView,
         new_buffer_size: u64,
         item_size: u64,
         shader: &str,
@@ -276,24 +557,14 @@ impl GpuDevice {
 
         let new_values_buffer = self.create_empty_buffer(new_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: original_values.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
-
         let mut encoder = self.create_command_encoder(None);
-        let dispatch_size = original_values.size().div_ceil(item_size);
+        let bind_group_array = self.create_view_bind_group(
+            &compute_pipeline,
+            &[original_values, &new_values_buffer],
+            &mut encoder,
+        );
+
+        let dispatch_size = original_values.view_size().div_ceil(item_size);
 
         let query = self.compute_pass(
             &mut encoder,
@@ -312,8 +583,8 @@ impl GpuDevice {
 
     pub fn apply_scalar_function(
         &self,
-        original_values: &Buffer,
-        scalar_value: &Buffer,
+        original_values: &dyn GpuBufferView,
+        scalar_value: &dyn GpuBufferView,
         output_buffer_size: u64,
         item_size: u64,
         shader: &str,
@@ -323,28 +594,14 @@ impl GpuDevice {
 
         let new_values_buffer = self.create_empty_buffer(output_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: original_values.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: scalar_value.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
-
         let mut encoder = self.create_command_encoder(Some(entry_point));
-        let dispatch_size = original_values.size() / item_size;
+        let bind_group_array = self.create_view_bind_group(
+            &compute_pipeline,
+            &[original_values, scalar_value, &new_values_buffer],
+            &mut encoder,
+        );
+
+        let dispatch_size = original_values.view_size() / item_size;
 
         self.compute_pass(
             &mut encoder,
@@ -362,38 +619,24 @@ impl GpuDevice {
 
     pub fn apply_binary_function(
         &self,
-        operand_1: &Buffer,
-        operand_2: &Buffer,
+        operand_1: &dyn GpuBufferView,
+        operand_2: &dyn GpuBufferView,
         item_size: u64,
         shader: &str,
         entry_point: &str,
     ) -> Buffer {
         let compute_pipeline = self.create_compute_pipeline(shader, entry_point);
 
-        let new_values_buffer = self.create_empty_buffer(operand_1.size());
-
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: operand_1.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: operand_2.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let new_values_buffer = self.create_empty_buffer(operand_1.view_size());
 
         let mut encoder = self.create_command_encoder(Some(entry_point));
-        let dispatch_size = operand_1.size() / item_size;
+        let bind_group_array = self.create_view_bind_group(
+            &compute_pipeline,
+            &[operand_1, operand_2, &new_values_buffer],
+            &mut encoder,
+        );
+
+        let dispatch_size = operand_1.view_size() / item_size;
 
         self.compute_pass(
             &mut encoder,
@@ -411,43 +654,25 @@ impl GpuDevice {
 
     pub fn apply_ternary_function(
         &self,
-        operand_1: &Buffer,
-        operand_2: &Buffer,
-        operand_3: &Buffer,
+        operand_1: &dyn GpuBufferView,
+        operand_2: &dyn GpuBufferView,
+        operand_3: &dyn GpuBufferView,
         item_size: u64,
         shader: &str,
         entry_point: &str,
     ) -> Buffer {
         let compute_pipeline = self.create_compute_pipeline(shader, entry_point);
 
-        let new_values_buffer = self.create_empty_buffer(operand_1.size());
-
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: operand_1.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: operand_2.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: operand_3.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 3,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+        let new_values_buffer = self.create_empty_buffer(operand_1.view_size());
 
         let mut encoder = self.create_command_encoder(Some(entry_point));
-        let dispatch_size = operand_1.size() / item_size;
+        let bind_group_array = self.create_view_bind_group(
+            &compute_pipeline,
+            &[operand_1, operand_2, operand_3, &new_values_buffer],
+            &mut encoder,
+        );
+
+        let dispatch_size = operand_1.view_size() / item_size;
 
         self.compute_pass(
             &mut encoder,
@@ -465,7 +690,7 @@ impl GpuDevice {
 
     pub fn apply_broadcast_function(
         &self,
-        scalar_value: &Buffer,
+        scalar_value: &dyn GpuBufferView,
         output_buffer_size: u64,
         item_size: u64,
         shader: &str,
@@ -475,23 +700,13 @@ impl GpuDevice {
 
         let new_values_buffer = self.create_empty_buffer(output_buffer_size);
 
-        let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-        let bind_group_array = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: scalar_value.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
-
         let mut encoder = self.create_command_encoder(Some(entry_point));
+        let bind_group_array = self.create_view_bind_group(
+            &compute_pipeline,
+            &[scalar_value, &new_values_buffer],
+            &mut encoder,
+        );
+
         let dispatch_size = output_buffer_size / item_size;
 
         self.compute_pass(
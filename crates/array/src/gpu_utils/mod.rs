@@ -1,8 +1,11 @@
 mod append_hashmap;
+mod buffer_view;
 mod compute_pipeline;
 mod compute_query;
 mod gpu_device;
+mod view_offsets;
 
+pub use buffer_view::{BoundBuffer, GpuBufferView};
 pub use compute_pipeline::ArrowComputePipeline;
 pub use compute_query::CmpQuery;
-pub use gpu_device::GpuDevice;
+pub use gpu_device::{GpuDevice, KernelPipeline};
@@ -0,0 +1,513 @@
+// Kernels index their storage arrays from the start of the bound range, while a sliced view
+// can start anywhere in its buffer. Instead of copying such views before each dispatch, the
+// kernels are rewritten when their pipeline is created: each storage array is indexed from
+// an offset read from a uniform, so views are bound in place from an aligned byte offset.
+// The rewritten kernel is parsed again and every use of a rewritten array is checked to go
+// through the offsets, a kernel that can not be rewritten is an error rather than a kernel
+// reading the wrong rows.
+
+use std::borrow::Cow;
+
+use wgpu::naga::{
+    AddressSpace, ArraySize, Expression, Function, Handle, Module, Scalar, ScalarKind,
+    StorageAccess, TypeInner,
+    front::wgsl,
+    valid::{Capabilities, ModuleInfo, ValidationFlags, Validator},
+};
+
+/// How a view is bound to a binding of a kernel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ViewBinding {
+    /// Bound as it is, sliced views are realigned before binding
+    Plain,
+    /// Storage array indexed from the element offset of the view
+    Elements { stride: u64 },
+    /// Read only array of u32 read from the bit offset of the view, a word is
+    /// made of the next 32 bits and the bits after the view are cleared.
+    /// Views of u32 elements start on a word with no shift, their words are the elements.
+    Words,
+}
+
+/// Bindings of a kernel and the binding of the uniform holding the offsets of its views.
+/// The uniform holds `(word or element offset, bit shift, bit length, length)` per binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KernelLayout {
+    pub bindings: Vec<ViewBinding>,
+    pub offsets_binding: Option<u32>,
+}
+
+impl KernelLayout {
+    pub fn binding(&self, index: usize) -> ViewBinding {
+        self.bindings
+            .get(index)
+            .copied()
+            .unwrap_or(ViewBinding::Plain)
+    }
+}
+
+const OFFSETS_NAME: &str = "view_offsets";
+
+fn view_binding(module: &Module, ty: wgpu::naga::Handle<wgpu::naga::Type>) -> ViewBinding {
+    match module.types[ty].inner {
+        TypeInner::Array {
+            size: ArraySize::Dynamic,
+            stride,
+            ..
+        } => ViewBinding::Elements {
+            stride: stride as u64,
+        },
+        _ => ViewBinding::Plain,
+    }
+}
+
+fn parse_and_validate(shader: &str) -> Result<(Module, ModuleInfo), String> {
+    let module = wgsl::parse_str(shader).map_err(|x| x.emit_to_string(shader))?;
+    let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+        .validate(&module)
+        .map_err(|x| x.emit_to_string(shader))?;
+    Ok((module, info))
+}
+
+/// Rewrites the storage arrays used by `entry_point` to be indexed from the offsets of
+/// the bound views.
+///
+/// # Panics
+/// When the kernel or its rewrite does not validate, or a rewritten array is used in a
+/// way that does not go through the offsets of its view.
+pub(crate) fn rewrite_shader<'a>(
+    shader: &'a str,
+    entry_point: &str,
+) -> (Cow<'a, str>, KernelLayout) {
+    let (module, info) = parse_and_validate(shader)
+        .unwrap_or_else(|error| panic!("invalid kernel {entry_point}:\n{error}"));
+    let entry_index = module
+        .entry_points
+        .iter()
+        .position(|x| x.name == entry_point)
+        .unwrap_or_else(|| panic!("kernel {entry_point} not found in its shader"));
+    let entry_info = info.get_entry_point(entry_index);
+
+    let mut bindings = vec![];
+    let mut arrays = vec![];
+    for (handle, global) in module.global_variables.iter() {
+        let Some(binding) = &global.binding else {
+            continue;
+        };
+        if binding.group != 0 {
+            continue;
+        }
+        let index = binding.binding as usize;
+        if bindings.len() <= index {
+            bindings.resize(index + 1, ViewBinding::Plain);
+        }
+        let AddressSpace::Storage { access } = global.space else {
+            continue;
+        };
+        let mut kind = view_binding(&module, global.ty);
+        if let TypeInner::Array { base, .. } = module.types[global.ty].inner {
+            let is_u32 = module.types[base].inner
+                == TypeInner::Scalar(Scalar {
+                    kind: ScalarKind::Uint,
+                    width: 4,
+                });
+            if is_u32 && !access.contains(StorageAccess::STORE) {
+                kind = ViewBinding::Words;
+            }
+        }
+        if kind != ViewBinding::Plain && !entry_info[handle].is_empty() {
+            bindings[index] = kind;
+            if let Some(name) = &global.name {
+                arrays.push((name.clone(), index, kind));
+            }
+        }
+    }
+    if arrays.is_empty() {
+        let layout = KernelLayout {
+            bindings: vec![],
+            offsets_binding: None,
+        };
+        return (Cow::Borrowed(shader), layout);
+    }
+
+    let offsets_binding = bindings.len() as u32;
+    let mut rewritten = rewrite_accesses(shader, &arrays);
+    rewritten.push_str(&format!(
+        "\n@group(0)\n@binding({offsets_binding})\nvar<uniform> {OFFSETS_NAME}: array<vec4<u32>, {offsets_binding}>;\n"
+    ));
+    for (name, index, kind) in &arrays {
+        if *kind == ViewBinding::Words {
+            rewritten.push_str(&words_function(name, *index));
+        }
+    }
+
+    let (module, _) = parse_and_validate(&rewritten).unwrap_or_else(|error| {
+        panic!("kernel {entry_point} does not validate once rewritten to offset views:\n{error}")
+    });
+    if let Err(error) = check_offset_accesses(&module, &arrays) {
+        panic!("kernel {entry_point} can not be rewritten to offset views: {error}");
+    }
+    let layout = KernelLayout {
+        bindings,
+        offsets_binding: Some(offsets_binding),
+    };
+    (Cow::Owned(rewritten), layout)
+}
+
+/// Whether `expression` is computed from the offsets uniform
+fn reads_offsets(
+    function: &Function,
+    expression: Handle<Expression>,
+    offsets: &Expression,
+) -> bool {
+    let expressions = &function.expressions;
+    let reads = |x| reads_offsets(function, x, offsets);
+    match &expressions[expression] {
+        x if x == offsets => true,
+        Expression::Access { base, index } => reads(*base) || reads(*index),
+        Expression::AccessIndex { base, .. } => reads(*base),
+        Expression::Load { pointer } => reads(*pointer),
+        Expression::Binary { left, right, .. } => reads(*left) || reads(*right),
+        Expression::As { expr, .. } => reads(*expr),
+        Expression::Swizzle { vector, .. } => reads(*vector),
+        _ => false,
+    }
+}
+
+/// Checks that the rewritten arrays are only indexed from the offsets of their views:
+/// element arrays by an index computed from the offsets, word arrays in their
+/// `view_word` function, and that their length is never read from the bound range.
+fn check_offset_accesses(
+    module: &Module,
+    arrays: &[(String, usize, ViewBinding)],
+) -> Result<(), String> {
+    let global = |name: &str| {
+        module
+            .global_variables
+            .iter()
+            .find(|(_, x)| x.name.as_deref() == Some(name))
+            .map(|(handle, _)| Expression::GlobalVariable(handle))
+    };
+    let offsets = global(OFFSETS_NAME).ok_or("the offsets uniform is missing")?;
+    let functions = module
+        .functions
+        .iter()
+        .map(|(_, x)| x)
+        .chain(module.entry_points.iter().map(|x| &x.function));
+    for function in functions {
+        let function_name = function.name.as_deref().unwrap_or_default();
+        for (name, index, kind) in arrays {
+            let array = global(name).ok_or_else(|| format!("{name} is missing"))?;
+            let is_array = |x: &Handle<Expression>| function.expressions[*x] == array;
+            for (_, expression) in function.expressions.iter() {
+                let is_valid = match expression {
+                    Expression::Access { base, index: i } if is_array(base) => match kind {
+                        ViewBinding::Words => function_name == format!("view_word_{index}"),
+                        _ => reads_offsets(function, *i, &offsets),
+                    },
+                    Expression::AccessIndex { base, .. } | Expression::ArrayLength(base) => {
+                        !is_array(base)
+                    }
+                    _ => true,
+                };
+                if !is_valid {
+                    return Err(format!(
+                        "{name} is used in {function_name} without the offset of its view"
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn words_function(name: &str, index: usize) -> String {
+    format!(
+        "
+fn view_word_{index}(view_index: u32) -> u32 {{
+    let view_offset = {OFFSETS_NAME}[{index}];
+    let view_source = view_offset.x + view_index;
+    var view_word = {name}[view_source] >> view_offset.y;
+    if view_offset.y != 0u {{
+        view_word |= {name}[view_source + 1u] << (32u - view_offset.y);
+    }}
+    let view_bits = view_offset.z - min(view_offset.z, view_index * 32u);
+    if view_bits < 32u {{
+        view_word &= (1u << view_bits) - 1u;
+    }}
+    return view_word;
+}}
+"
+    )
+}
+
+fn is_identifier_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn skip_whitespace(text: &[u8], position: usize) -> usize {
+    position
+        + text[position.min(text.len())..]
+            .iter()
+            .take_while(|x| x.is_ascii_whitespace())
+            .count()
+}
+
+/// Position of the `]` closing the `[` at `open`
+fn closing_bracket(text: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (position, c) in text.iter().enumerate().skip(open) {
+        match c {
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(position);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Rewrites `name[index]` and `arrayLength(&name)` of each array outside of its
+/// declaration, the indexes are rewritten too. Other uses of the arrays are left as
+/// they are and rejected once the rewritten kernel is checked.
+fn rewrite_accesses(text: &str, arrays: &[(String, usize, ViewBinding)]) -> String {
+    let bytes = text.as_bytes();
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut position = 0;
+    let mut copied = 0;
+    while position < bytes.len() {
+        if bytes[position] == b'/' && bytes.get(position + 1) == Some(&b'/') {
+            position = text[position..]
+                .find('\n')
+                .map_or(bytes.len(), |x| position + x);
+            continue;
+        }
+        if !is_identifier_char(bytes[position])
+            || (position > 0
+                && (is_identifier_char(bytes[position - 1]) || bytes[position - 1] == b'.'))
+        {
+            position += 1;
+            continue;
+        }
+        let end = position
+            + bytes[position..]
+                .iter()
+                .take_while(|x| is_identifier_char(**x))
+                .count();
+        let word = &text[position..end];
+
+        if word == "arrayLength" {
+            // arrayLength ( & name )
+            let call = [b'(', b'&'].iter().try_fold(end, |position, token| {
+                let position = skip_whitespace(bytes, position);
+                (bytes.get(position) == Some(token)).then_some(position + 1)
+            });
+            let found = call.and_then(|call| {
+                let name_start = skip_whitespace(bytes, call);
+                arrays.iter().find_map(|array| {
+                    let name_end = name_start + array.0.len();
+                    let close = skip_whitespace(bytes, name_end);
+                    (text[name_start..].starts_with(array.0.as_str())
+                        && bytes.get(close) == Some(&b')'))
+                    .then_some((array, close))
+                })
+            });
+            if let Some(((_, index, _), close)) = found {
+                result.push_str(&text[copied..position]);
+                result.push_str(&format!("{OFFSETS_NAME}[{index}].w"));
+                position = close + 1;
+                copied = position;
+                continue;
+            }
+        }
+
+        let found = arrays.iter().find(|(name, ..)| name == word);
+        let open = skip_whitespace(bytes, end);
+        if let (Some((_, index, kind)), Some(b'[')) = (found, bytes.get(open))
+            && let Some(close) = closing_bracket(bytes, open)
+        {
+            let inner = rewrite_accesses(&text[open + 1..close], arrays);
+            result.push_str(&text[copied..position]);
+            match kind {
+                ViewBinding::Words => result.push_str(&format!("view_word_{index}(u32({inner}))")),
+                _ => result.push_str(&format!("{word}[{OFFSETS_NAME}[{index}].x + u32({inner})]")),
+            }
+            position = close + 1;
+            copied = position;
+            continue;
+        }
+        position = end;
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::buffer::ArrowGpuBuffer;
+    use crate::gpu_utils::ArrowComputePipeline;
+
+    const SHADER: &str = "
+@group(0)
+@binding(0)
+var<storage, read> operand: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: vec4<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    // operand[0]
+    if global_id.x < arrayLength(&new_values) {
+        new_values[operand[global_id.x]] = f32(params.x);
+    }
+}
+";
+
+    #[test]
+    fn test_rewrite_shader() {
+        let (shader, layout) = rewrite_shader(SHADER, "main");
+        assert_eq!(
+            layout.bindings,
+            vec![
+                ViewBinding::Words,
+                ViewBinding::Plain,
+                ViewBinding::Elements { stride: 4 }
+            ]
+        );
+        assert_eq!(layout.offsets_binding, Some(3));
+        assert!(shader.contains("// operand[0]"));
+        assert!(shader.contains("if global_id.x < view_offsets[2].w {"));
+        assert!(shader.contains(
+            "new_values[view_offsets[2].x + u32(view_word_0(u32(global_id.x)))] = f32(params.x);"
+        ));
+        assert!(wgsl::parse_str(&shader).is_ok());
+    }
+
+    #[test]
+    fn test_rewrite_shader_spacing() {
+        let shader = SHADER
+            .replace("arrayLength(&new_values)", "arrayLength( & new_values )")
+            .replace("new_values[", "new_values [");
+        let (shader, _) = rewrite_shader(&shader, "main");
+        assert!(shader.contains("if global_id.x < view_offsets[2].w {"));
+    }
+
+    #[test]
+    #[should_panic(expected = "new_values is used in main without the offset of its view")]
+    fn test_rewrite_shader_pointer() {
+        let shader = SHADER.replace(
+            "new_values[operand[global_id.x]] = f32(params.x);",
+            "let values = &new_values;\n        (*values)[operand[global_id.x]] = f32(params.x);",
+        );
+        rewrite_shader(&shader, "main");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid kernel main")]
+    fn test_rewrite_invalid_shader() {
+        rewrite_shader(&SHADER.replace("f32(params.x)", "params"), "main");
+    }
+
+    const SLICED_SHADER: &str = "
+@group(0)
+@binding(0)
+var<storage, read> bits: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<f32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> words: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&values) {
+        values[row] = values[row] * 2.0;
+    }
+    if row < arrayLength(&words) {
+        words[row] = bits[row];
+    }
+}
+";
+
+    #[test]
+    fn test_sliced_views_in_place() {
+        let device = GPU_DEVICE.clone();
+        let raw_bits = (0..40u32)
+            .map(|x| x.wrapping_mul(0x9e3779b9))
+            .collect::<Vec<_>>();
+        let raw_values = (0..300).map(|x| x as f32).collect::<Vec<_>>();
+        let bits = ArrowGpuBuffer::from(device.create_gpu_buffer_with_data(&raw_bits));
+        let values = ArrowGpuBuffer::from(device.create_gpu_buffer_with_data(&raw_values));
+        let words = device.create_empty_buffer(7 * 4);
+
+        let mut pipeline = ArrowComputePipeline::new(device.clone(), None);
+        pipeline.apply_function(
+            &[
+                &bits.slice_bits(37, 200),
+                &values.slice(70 * 4, 100 * 4),
+                &words,
+            ],
+            SLICED_SHADER,
+            "main",
+            1,
+        );
+        pipeline.finish();
+
+        // the rows of the view are updated in the original buffer
+        let new_values = device.retrive_data(&values);
+        let new_values: &[f32] = bytemuck::cast_slice(&new_values);
+        let expected_values = (0..300)
+            .map(|x| {
+                if (70..170).contains(&x) {
+                    2.0 * x as f32
+                } else {
+                    x as f32
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(new_values, expected_values);
+
+        let new_words = device.retrive_data(&words);
+        let new_words: &[u32] = bytemuck::cast_slice(&new_words);
+        let bit = |x: usize| (raw_bits[x / 32] >> (x % 32)) & 1;
+        let expected_words = (0..7)
+            .map(|word| {
+                (0..32)
+                    .filter(|x| word * 32 + x < 200)
+                    .map(|x| bit(37 + word * 32 + x) << x)
+                    .sum::<u32>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(new_words, expected_words);
+
+        // a view of u32 elements is read as its elements
+        let mut pipeline = ArrowComputePipeline::new(device.clone(), None);
+        pipeline.apply_function(
+            &[&bits.slice(3 * 4, 7 * 4), &values.slice(0, 4), &words],
+            SLICED_SHADER,
+            "main",
+            1,
+        );
+        pipeline.finish();
+        let new_words = device.retrive_data(&words);
+        let new_words: &[u32] = bytemuck::cast_slice(&new_words);
+        assert_eq!(new_words, &raw_bits[3..10]);
+    }
+}
@@ -1,18 +1,23 @@
-use crate::array::ArrowArrayGPU;
+use crate::array::{ArrowArrayGPU, f16};
 
 pub mod broadcast;
 
 /// Enum of scalar values used in kernels
 #[derive(Debug)]
 pub enum ScalarValue {
+    F16(f16),
     F32(f32),
+    F64(f64),
+    U64(u64),
     U32(u32),
     U16(u16),
     U8(u8),
+    I64(i64),
     I32(i32),
     I16(i16),
     I8(i8),
     BOOL(bool),
+    Utf8(String),
 }
 
 /// Enum of operands
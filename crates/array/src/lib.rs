@@ -1,6 +1,7 @@
 pub mod array;
 pub mod gpu_utils;
 pub mod kernels;
+pub mod record_batch;
 pub mod utils;
 
 use gpu_utils::GpuDevice;
@@ -11,6 +12,7 @@ use std::sync::{Arc, LazyLock};
 pub enum ArrowErrorGPU {
     OperationNotSupported(String),
     CastingNotSupported(String),
+    InvalidArgument(String),
 }
 
 #[doc(hidden)]
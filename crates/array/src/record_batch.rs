@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use crate::ArrowErrorGPU;
+use crate::array::{ArrayUtils, ArrowArrayGPU, ArrowType, StructArrayGPU};
+use crate::gpu_utils::GpuDevice;
+
+/// Name and type of a column
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub data_type: ArrowType,
+    pub nullable: bool,
+}
+
+impl Field {
+    pub fn new(name: impl Into<String>, data_type: ArrowType, nullable: bool) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            nullable,
+        }
+    }
+}
+
+/// Ordered list of the fields of a record batch
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the position of the field called `name`
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+
+    /// Returns a new schema with the fields at `indices`
+    pub fn project(&self, indices: &[usize]) -> Result<Self, ArrowErrorGPU> {
+        let fields = indices
+            .iter()
+            .map(|index| {
+                self.fields.get(*index).cloned().ok_or_else(|| {
+                    ArrowErrorGPU::InvalidArgument(format!(
+                        "field index {} out of bounds for schema with {} fields",
+                        index,
+                        self.fields.len()
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(fields))
+    }
+}
+
+/// Table of equal length gpu arrays described by a schema.
+/// Columns are shared, so projecting a batch does not copy any gpu buffer.
+#[derive(Debug)]
+pub struct RecordBatchGPU {
+    schema: Arc<Schema>,
+    columns: Vec<Arc<ArrowArrayGPU>>,
+    num_rows: usize,
+    gpu_device: Arc<GpuDevice>,
+}
+
+impl RecordBatchGPU {
+    /// Creates a record batch, checking that the columns match the schema,
+    /// have the same length and live on the same device
+    pub fn try_new(
+        schema: Arc<Schema>,
+        columns: Vec<Arc<ArrowArrayGPU>>,
+    ) -> Result<Self, ArrowErrorGPU> {
+        if columns.is_empty() {
+            return Err(ArrowErrorGPU::InvalidArgument(
+                "record batch needs at least one column".into(),
+            ));
+        }
+        if schema.fields.len() != columns.len() {
+            return Err(ArrowErrorGPU::InvalidArgument(format!(
+                "schema has {} fields but {} columns were given",
+                schema.fields.len(),
+                columns.len()
+            )));
+        }
+
+        let num_rows = columns[0].len();
+        let gpu_device = columns[0].get_gpu_device();
+        for (field, column) in schema.fields.iter().zip(&columns) {
+            if column.len() != num_rows {
+                return Err(ArrowErrorGPU::InvalidArgument(format!(
+                    "column {} has {} rows, expected {}",
+                    field.name,
+                    column.len(),
+                    num_rows
+                )));
+            }
+            if column.get_dtype() != field.data_type {
+                return Err(ArrowErrorGPU::InvalidArgument(format!(
+                    "column {} is of type {:?}, expected {:?}",
+                    field.name,
+                    column.get_dtype(),
+                    field.data_type
+                )));
+            }
+            if !Arc::ptr_eq(&column.get_gpu_device(), &gpu_device) {
+                return Err(ArrowErrorGPU::InvalidArgument(format!(
+                    "column {} is on a different gpu device",
+                    field.name
+                )));
+            }
+        }
+
+        Ok(Self {
+            schema,
+            columns,
+            num_rows,
+            gpu_device,
+        })
+    }
+
+    /// Creates a record batch from named columns, a field is nullable when its column has a null buffer
+    pub fn try_from_iter(
+        columns: impl IntoIterator<Item = (impl Into<String>, ArrowArrayGPU)>,
+    ) -> Result<Self, ArrowErrorGPU> {
+        let (fields, columns): (Vec<_>, Vec<_>) = columns
+            .into_iter()
+            .map(|(name, column)| {
+                let field = Field::new(
+                    name,
+                    column.get_dtype(),
+                    column.get_null_bit_buffer().is_some(),
+                );
+                (field, Arc::new(column))
+            })
+            .unzip();
+        Self::try_new(Arc::new(Schema::new(fields)), columns)
+    }
+
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn columns(&self) -> &[Arc<ArrowArrayGPU>] {
+        &self.columns
+    }
+
+    pub fn column(&self, index: usize) -> &Arc<ArrowArrayGPU> {
+        &self.columns[index]
+    }
+
+    pub fn column_by_name(&self, name: &str) -> Option<&Arc<ArrowArrayGPU>> {
+        self.schema.index_of(name).map(|index| &self.columns[index])
+    }
+
+    /// Returns a new record batch with the columns at `indices`, without copying them
+    pub fn project(&self, indices: &[usize]) -> Result<Self, ArrowErrorGPU> {
+        let schema = self.schema.project(indices)?;
+        let columns = indices
+            .iter()
+            .map(|index| self.columns[*index].clone())
+            .collect();
+        Self::try_new(Arc::new(schema), columns)
+    }
+}
+
+impl ArrayUtils for RecordBatchGPU {
+    fn get_gpu_device(&self) -> Arc<GpuDevice> {
+        self.gpu_device.clone()
+    }
+}
+
+impl From<RecordBatchGPU> for StructArrayGPU {
+    fn from(value: RecordBatchGPU) -> Self {
+        Self {
+            fields: value.schema.fields.clone(),
+            columns: value.columns,
+            gpu_device: value.gpu_device,
+            len: value.num_rows,
+            null_buffer: None,
+        }
+    }
+}
+
+impl TryFrom<StructArrayGPU> for RecordBatchGPU {
+    type Error = ArrowErrorGPU;
+
+    fn try_from(value: StructArrayGPU) -> Result<Self, Self::Error> {
+        if value.null_buffer.is_some() {
+            return Err(ArrowErrorGPU::InvalidArgument(
+                "cannot convert a struct array with a null buffer into a record batch".into(),
+            ));
+        }
+        Self::try_new(Arc::new(Schema::new(value.fields)), value.columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GPU_DEVICE;
+    use crate::array::{Float32ArrayGPU, Int32ArrayGPU, StringArrayGPU};
+    use crate::utils::ScalarArray;
+
+    fn batch() -> RecordBatchGPU {
+        let device = GPU_DEVICE.clone();
+        RecordBatchGPU::try_from_iter([
+            (
+                "id",
+                Int32ArrayGPU::from_slice(&[1, 2, 3], device.clone()).into(),
+            ),
+            (
+                "name",
+                StringArrayGPU::from_slice(&["a", "b", "c"], device.clone()).into(),
+            ),
+            (
+                "score",
+                Float32ArrayGPU::from_optional_slice(&[Some(1.0), None, Some(3.0)], device).into(),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_record_batch() {
+        let batch = batch();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.num_columns(), 3);
+        assert_eq!(batch.schema().index_of("name"), Some(1));
+        assert!(!batch.schema().fields[0].nullable);
+        assert!(batch.schema().fields[2].nullable);
+        assert_eq!(
+            batch.column_by_name("id").unwrap().get_raw_values(),
+            ScalarArray::I32Vec(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_record_batch_project() {
+        let batch = batch();
+        let projected = batch.project(&[2, 0]).unwrap();
+        assert_eq!(projected.num_columns(), 2);
+        assert_eq!(projected.schema().fields[0].name, "score");
+        assert!(Arc::ptr_eq(projected.column(1), batch.column(0)));
+        assert!(batch.project(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_record_batch_invalid() {
+        let device = GPU_DEVICE.clone();
+        let result = RecordBatchGPU::try_from_iter([
+            (
+                "a",
+                Int32ArrayGPU::from_slice(&[1, 2], device.clone()).into(),
+            ),
+            ("b", Int32ArrayGPU::from_slice(&[1], device.clone()).into()),
+        ]);
+        assert!(result.is_err());
+
+        let schema = Schema::new(vec![Field::new("a", ArrowType::Float32Type, false)]);
+        let column = Int32ArrayGPU::from_slice(&[1, 2], device);
+        let result = RecordBatchGPU::try_new(Arc::new(schema), vec![Arc::new(column.into())]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_record_batch_struct_round_trip() {
+        let array: StructArrayGPU = batch().into();
+        assert_eq!(array.len, 3);
+        let batch = RecordBatchGPU::try_from(array).unwrap();
+        assert_eq!(batch.schema().fields[1].data_type, ArrowType::Utf8Type);
+    }
+}
@@ -1,13 +1,24 @@
-#[derive(Debug, PartialEq)]
+use crate::array::f16;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ScalarArray {
+    F16Vec(Vec<f16>),
     F32Vec(Vec<f32>),
+    F64Vec(Vec<f64>),
+    U64Vec(Vec<u64>),
     U32Vec(Vec<u32>),
     U16Vec(Vec<u16>),
     U8Vec(Vec<u8>),
+    I128Vec(Vec<i128>),
+    I64Vec(Vec<i64>),
     I32Vec(Vec<i32>),
     I16Vec(Vec<i16>),
     I8Vec(Vec<i8>),
     BOOLVec(Vec<bool>),
+    StringVec(Vec<String>),
+    ListVec(Vec<ScalarArray>),
+    /// Values of each field of a struct array
+    StructVec(Vec<ScalarArray>),
 }
 
 macro_rules! impl_into_scalararray {
@@ -20,11 +31,58 @@ macro_rules! impl_into_scalararray {
     };
 }
 
+impl_into_scalararray!(f16, F16Vec);
 impl_into_scalararray!(f32, F32Vec);
+impl_into_scalararray!(f64, F64Vec);
+impl_into_scalararray!(u64, U64Vec);
 impl_into_scalararray!(u32, U32Vec);
 impl_into_scalararray!(u16, U16Vec);
 impl_into_scalararray!(u8, U8Vec);
+impl_into_scalararray!(i128, I128Vec);
+impl_into_scalararray!(i64, I64Vec);
 impl_into_scalararray!(i32, I32Vec);
 impl_into_scalararray!(i16, I16Vec);
 impl_into_scalararray!(i8, I8Vec);
 impl_into_scalararray!(bool, BOOLVec);
+impl_into_scalararray!(String, StringVec);
+impl_into_scalararray!(ScalarArray, ListVec);
+
+impl ScalarArray {
+    /// Creates a new array by taking the elements at `indexes`
+    pub fn take(&self, indexes: &[usize]) -> ScalarArray {
+        macro_rules! take {
+            ($($variant: ident),*) => {
+                match self {
+                    $(ScalarArray::$variant(x) => {
+                        ScalarArray::$variant(indexes.iter().map(|i| x[*i].clone()).collect())
+                    })*
+                    ScalarArray::StructVec(x) => {
+                        ScalarArray::StructVec(x.iter().map(|field| field.take(indexes)).collect())
+                    }
+                }
+            };
+        }
+        take!(
+            F16Vec, F32Vec, F64Vec, U64Vec, U32Vec, U16Vec, U8Vec, I128Vec, I64Vec, I32Vec, I16Vec,
+            I8Vec, BOOLVec, StringVec, ListVec
+        )
+    }
+
+    /// Creates a new array from the elements in `start..end`
+    pub fn slice(&self, start: usize, end: usize) -> ScalarArray {
+        macro_rules! slice {
+            ($($variant: ident),*) => {
+                match self {
+                    $(ScalarArray::$variant(x) => ScalarArray::$variant(x[start..end].to_vec()),)*
+                    ScalarArray::StructVec(x) => {
+                        ScalarArray::StructVec(x.iter().map(|field| field.slice(start, end)).collect())
+                    }
+                }
+            };
+        }
+        slice!(
+            F16Vec, F32Vec, F64Vec, U64Vec, U32Vec, U16Vec, U8Vec, I128Vec, I64Vec, I32Vec, I16Vec,
+            I8Vec, BOOLVec, StringVec, ListVec
+        )
+    }
+}
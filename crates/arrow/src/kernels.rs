@@ -2,7 +2,10 @@ pub use arrow_gpu_arithmetic::*;
 pub use arrow_gpu_array::kernels::broadcast::*;
 pub use arrow_gpu_cast::*;
 pub use arrow_gpu_compare::*;
+pub use arrow_gpu_list::*;
 pub use arrow_gpu_logical::*;
 pub use arrow_gpu_math::*;
 pub use arrow_gpu_routines::*;
+pub use arrow_gpu_string::*;
+pub use arrow_gpu_temporal::*;
 pub use arrow_gpu_trigonometry::*;
@@ -0,0 +1,149 @@
+// Values of every type are accessed as u32 words,
+// a decimal uses 4 words, an i64 2 words and i32 or f32 one word.
+
+struct Params {
+    // multiplier applied when scaling up
+    factor: vec4<u32>,
+    // scaling down divides by 10^(9 * div_steps) * divisor
+    div_steps: u32,
+    divisor: u32,
+    len: u32,
+    // 10^scale for conversions to and from floats
+    float_factor: f32,
+    // 10^precision, the decimals produced must be smaller in magnitude
+    limit: vec4<u32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+// the rows that do not fit in the decimal precision are cleared, not bound by the casts from decimals
+@group(0)
+@binding(3)
+var<storage, read_write> validity: array<atomic<u32>>;
+
+fn read_decimal(index: u32) -> vec4<u32> {
+    let offset = index * 4u;
+    return vec4<u32>(
+        original_values[offset],
+        original_values[offset + 1u],
+        original_values[offset + 2u],
+        original_values[offset + 3u]
+    );
+}
+
+fn write_decimal(index: u32, value: vec4<u32>) {
+    let offset = index * 4u;
+    new_values[offset] = value.x;
+    new_values[offset + 1u] = value.y;
+    new_values[offset + 2u] = value.z;
+    new_values[offset + 3u] = value.w;
+}
+
+// rows that do not fit in an i128 or have more digits than the precision are zeroed and null,
+// the magnitude of i128::MIN keeps the sign bit and never fits
+fn write_checked_decimal(index: u32, value: vec4<u32>, fits_i128: bool) {
+    let magnitude = abs_128(value);
+    let fits = fits_i128 && !is_negative_128(magnitude) && lt_i128(magnitude, params.limit);
+    write_decimal(index, select(vec4<u32>(0u), value, fits));
+    if !fits {
+        atomicAnd(&validity[index / 32u], ~(1u << (index % 32u)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn rescale_up(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let result = mul_i128_checked(read_decimal(global_id.x), params.factor);
+        write_checked_decimal(global_id.x, result.product, !result.overflow);
+    }
+}
+
+// divides by one less power of ten than needed so the last digit rounds half away from zero
+@compute
+@workgroup_size(256)
+fn rescale_down(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = read_decimal(global_id.x);
+        let truncated = abs_128(trunc_div_pow10_i128(value, params.div_steps, params.divisor));
+        let last_digit = div_rem_u128_u32(truncated, 10u);
+        let rounded = add_128(
+            last_digit.quotient,
+            vec4<u32>(select(0u, 1u, last_digit.remainder >= 5u), 0u, 0u, 0u)
+        );
+        write_checked_decimal(global_id.x, select(rounded, neg_128(rounded), is_negative_128(value)), true);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn from_i32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = i32_to_i128(bitcast<i32>(original_values[global_id.x]));
+        let result = mul_i128_checked(value, params.factor);
+        write_checked_decimal(global_id.x, result.product, !result.overflow);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn from_i64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let offset = global_id.x * 2u;
+        let value = i64_to_i128(vec2<u32>(original_values[offset], original_values[offset + 1u]));
+        let result = mul_i128_checked(value, params.factor);
+        write_checked_decimal(global_id.x, result.product, !result.overflow);
+    }
+}
+
+// rounds half away from zero, values that are not finite are null
+@compute
+@workgroup_size(256)
+fn from_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = bitcast<f32>(original_values[global_id.x]) * params.float_factor;
+        let rounded = select(floor(value + 0.5), ceil(value - 0.5), value < 0.0);
+        // 2^127, only -2^127 fits, comparisons with NaN are false
+        let limit = bitcast<f32>(0x7f000000u);
+        let fits = abs(rounded) < limit || rounded == -limit;
+        write_checked_decimal(global_id.x, f32_to_i128(rounded), fits);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn to_i32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = trunc_div_pow10_i128(read_decimal(global_id.x), params.div_steps, params.divisor);
+        new_values[global_id.x] = value.x;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn to_i64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = trunc_div_pow10_i128(read_decimal(global_id.x), params.div_steps, params.divisor);
+        new_values[global_id.x * 2u] = value.x;
+        new_values[global_id.x * 2u + 1u] = value.y;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn to_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = i128_to_f32(read_decimal(global_id.x)) / params.float_factor;
+        new_values[global_id.x] = bitcast<u32>(value);
+    }
+}
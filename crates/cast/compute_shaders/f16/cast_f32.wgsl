@@ -0,0 +1,20 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn cast_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&original_values) {
+        let values = vec2<f32>(load_half2(original_values[global_id.x]));
+        let new_pos = global_id.x * 2u;
+        new_values[new_pos] = values.x;
+        new_values[new_pos + 1u] = values.y;
+    }
+}
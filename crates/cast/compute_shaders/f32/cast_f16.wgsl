@@ -0,0 +1,22 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<f32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn cast_f16(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let pos = global_id.x * 2u;
+        var values = vec2<f32>(original_values[pos], 0.0);
+        if pos + 1u < arrayLength(&original_values) {
+            values.y = original_values[pos + 1u];
+        }
+        new_values[global_id.x] = store_half2(half2(values));
+    }
+}
@@ -0,0 +1,38 @@
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<f32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+// splits a non negative integral value into its low and high words
+fn split_words(value: f32) -> vec2<u32> {
+    let high = floor(value / 4294967296.0);
+    let low = value - high * 4294967296.0;
+    return vec2<u32>(u32(low), u32(high));
+}
+
+@compute
+@workgroup_size(256)
+fn cast_u64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = split_words(max(trunc(original_values[global_id.x]), 0.0));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn cast_i64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = trunc(original_values[global_id.x]);
+        let words = split_words(abs(value));
+        if value < 0.0 {
+            let low = ~words.x + 1u;
+            let carry = select(0u, 1u, low == 0u);
+            new_values[global_id.x] = vec2<u32>(low, ~words.y + carry);
+        } else {
+            new_values[global_id.x] = words;
+        }
+    }
+}
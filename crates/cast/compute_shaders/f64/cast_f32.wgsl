@@ -0,0 +1,17 @@
+// Needs compute_shaders/u64/utils.wgsl and compute_shaders/f64/utils.wgsl
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn cast_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = f64_to_f32(original_values[global_id.x]);
+    }
+}
@@ -0,0 +1,44 @@
+struct Params {
+    factor: u32,
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> original_values: array<i32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<i32>;
+
+// the rows that overflow are cleared, only bound by scale_up
+@group(0)
+@binding(3)
+var<storage, read_write> validity: array<atomic<u32>>;
+
+@compute
+@workgroup_size(256)
+fn scale_up(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let value = original_values[global_id.x];
+        let product = value * i32(params.factor);
+        // the product wraps around on overflow
+        let overflow = product / i32(params.factor) != value;
+        new_values[global_id.x] = select(product, 0, overflow);
+        if overflow {
+            atomicAnd(&validity[global_id.x / 32u], ~(1u << (global_id.x % 32u)));
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn scale_down(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = original_values[global_id.x] / i32(params.factor);
+    }
+}
@@ -0,0 +1,23 @@
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn cast_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = original_values[global_id.x];
+        if (value.y & 0x80000000u) != 0u {
+            // convert the magnitude to avoid losing precision on the low word
+            let low = ~value.x + 1u;
+            let high = ~value.y + select(0u, 1u, low == 0u);
+            new_values[global_id.x] = -(f32(high) * 4294967296.0 + f32(low));
+        } else {
+            new_values[global_id.x] = f32(value.y) * 4294967296.0 + f32(value.x);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+struct Params {
+    factor: u32,
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+// the rows that overflow are cleared, only bound by scale_up
+@group(0)
+@binding(3)
+var<storage, read_write> validity: array<atomic<u32>>;
+
+struct Mul64 {
+    product: vec2<u32>,
+    overflow: bool,
+}
+
+// multiplies an i64 by a u32, overflow is set when the product does not fit in an i64
+fn mul_i64_u32_checked(value: vec2<u32>, factor: u32) -> Mul64 {
+    let negative = is_negative_i64(value);
+    // the magnitude of i64::MIN is 2^63 as an u64
+    let magnitude = select(value, neg_i64(value), negative);
+    let low = mul_wide_32(magnitude.x, factor);
+    let high = mul_wide_32(magnitude.y, factor);
+    let product = vec2<u32>(low.x, low.y + high.x);
+    let carry = product.y < low.y;
+    // only -2^63 has the sign bit set and fits
+    let sign_overflow = is_negative_i64(product) && !(negative && eq_64(product, vec2<u32>(0u, 0x80000000u)));
+    let overflow = high.y != 0u || carry || sign_overflow;
+    return Mul64(select(product, neg_i64(product), negative), overflow);
+}
+
+@compute
+@workgroup_size(256)
+fn scale_up(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let result = mul_i64_u32_checked(original_values[global_id.x], params.factor);
+        new_values[global_id.x] = select(result.product, vec2<u32>(0u), result.overflow);
+        if result.overflow {
+            atomicAnd(&validity[global_id.x / 32u], ~(1u << (global_id.x % 32u)));
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn scale_down(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = trunc_div_i64_u32(original_values[global_id.x], params.factor);
+    }
+}
@@ -0,0 +1,25 @@
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn cast_u64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = vec2<u32>(original_values[global_id.x], 0u);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn cast_i64(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = original_values[global_id.x];
+        let high = select(0u, 0xffffffffu, (value & 0x80000000u) != 0u);
+        new_values[global_id.x] = vec2<u32>(value, high);
+    }
+}
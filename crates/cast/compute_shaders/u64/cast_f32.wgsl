@@ -0,0 +1,16 @@
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<f32>;
+
+@compute
+@workgroup_size(256)
+fn cast_f32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = original_values[global_id.x];
+        new_values[global_id.x] = f32(value.y) * 4294967296.0 + f32(value.x);
+    }
+}
@@ -0,0 +1,15 @@
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn cast_u32(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = original_values[global_id.x].x;
+    }
+}
@@ -7,7 +7,7 @@ const BOOLEAN_CAST_F32_SHADER: &str = include_str!("../compute_shaders/boolean/c
 
 pub fn apply_boolean_unary_function(
     gpu_device: &GpuDevice,
-    original_values: &Buffer,
+    original_values: &dyn GpuBufferView,
     new_buffer_size: u64,
     output_item_size: u64,
     shader: &str,
@@ -18,23 +18,11 @@ pub fn apply_boolean_unary_function(
 
     let new_values_buffer = gpu_device.create_empty_buffer(new_buffer_size);
 
-    let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-    let bind_group_array = gpu_device
-        .device
-        .create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: original_values.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: new_values_buffer.as_entire_binding(),
-                },
-            ],
-        });
+    let bind_group_array = gpu_device.create_view_bind_group(
+        &compute_pipeline,
+        &[original_values, &new_values_buffer],
+        &mut pipeline.encoder,
+    );
 
     let dispatch_size = new_buffer_size.div_ceil(output_item_size);
 
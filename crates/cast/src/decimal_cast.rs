@@ -0,0 +1,400 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::Cast;
+
+const DECIMAL128_CAST_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i128/utils.wgsl"),
+    include_str!("../compute_shaders/decimal128/cast.wgsl")
+);
+
+/// Trait for casting arrays into decimals of the given precision and scale
+pub trait DecimalCast: ArrayUtils {
+    fn cast_decimal128(&self, precision: u8, scale: i8) -> Decimal128ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), None);
+        let output = self.cast_decimal128_op(precision, scale, &mut pipeline);
+        pipeline.finish();
+        output
+    }
+
+    /// Cast self as a decimal, reducing the scale rounds half away from zero.
+    /// Values with more digits than `precision` are null.
+    fn cast_decimal128_op(
+        &self,
+        precision: u8,
+        scale: i8,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Decimal128ArrayGPU;
+}
+
+/// Uniform parameters of the decimal shaders.
+/// `div_exponent` is split in divisions by 10^9 followed by a division smaller than 2^31.
+fn decimal_params(
+    multiply_exponent: u32,
+    div_exponent: u32,
+    scale: i8,
+    precision: u8,
+    len: usize,
+) -> [u32; 12] {
+    let factor = 10u128.pow(multiply_exponent);
+    let limit = 10u128.pow(precision as u32);
+    [
+        factor as u32,
+        (factor >> 32) as u32,
+        (factor >> 64) as u32,
+        (factor >> 96) as u32,
+        div_exponent / 9,
+        10u32.pow(div_exponent % 9),
+        len as u32,
+        10f32.powi(scale as i32).to_bits(),
+        limit as u32,
+        (limit >> 32) as u32,
+        (limit >> 64) as u32,
+        (limit >> 96) as u32,
+    ]
+}
+
+fn apply_decimal_op(
+    data: &dyn GpuBufferView,
+    params: [u32; 12],
+    len: usize,
+    output_item_size: u64,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let params = pipeline.device.create_uniform_buffer(&params);
+    let new_buffer = pipeline
+        .device
+        .create_empty_buffer((len as u64 * output_item_size).max(4));
+    pipeline.apply_function(
+        &[&params, data, &new_buffer],
+        DECIMAL128_CAST_SHADER,
+        entry_point,
+        len.div_ceil(256) as u32,
+    );
+    new_buffer
+}
+
+/// Applies `entry_point` binding a validity buffer, the rows that do not fit are null
+fn new_decimal_op<T: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    params: [u32; 12],
+    entry_point: &str,
+    precision: u8,
+    scale: i8,
+    pipeline: &mut ArrowComputePipeline,
+) -> Decimal128ArrayGPU {
+    let params = pipeline.device.create_uniform_buffer(&params);
+    let new_buffer = pipeline
+        .device
+        .create_empty_buffer((array.len as u64 * 16).max(16));
+    let null_buffer = (array.len > 0).then(|| {
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline)
+            .unwrap_or_else(|| {
+                NullBitBufferGpu::new_set_with_capacity(array.gpu_device.clone(), array.len)
+            });
+        pipeline.apply_function(
+            &[&params, &array.data, &new_buffer, &null_buffer.bit_buffer],
+            DECIMAL128_CAST_SHADER,
+            entry_point,
+            array.len.div_ceil(256) as u32,
+        );
+        null_buffer
+    });
+
+    let values = PrimitiveArrayGpu {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer,
+    };
+    Decimal128ArrayGPU::new(values, precision, scale)
+}
+
+impl DecimalCast for Decimal128ArrayGPU {
+    fn cast_decimal128_op(
+        &self,
+        precision: u8,
+        scale: i8,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Decimal128ArrayGPU {
+        let (params, entry_point) = match scale.cmp(&self.scale) {
+            std::cmp::Ordering::Equal if precision >= self.precision => {
+                let values = PrimitiveArrayGpu {
+                    data: pipeline.clone_buffer(&self.values.data).into(),
+                    gpu_device: self.gpu_device.clone(),
+                    phantom: Default::default(),
+                    len: self.len,
+                    null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(
+                        &self.values.null_buffer,
+                        pipeline,
+                    ),
+                };
+                return Decimal128ArrayGPU::new(values, precision, scale);
+            }
+            std::cmp::Ordering::Less => (
+                decimal_params(
+                    0,
+                    (self.scale - scale) as u32 - 1,
+                    scale,
+                    precision,
+                    self.len,
+                ),
+                "rescale_down",
+            ),
+            // keeping the scale with a smaller precision multiplies by 10^0 to check the digits
+            _ => (
+                decimal_params((scale - self.scale) as u32, 0, scale, precision, self.len),
+                "rescale_up",
+            ),
+        };
+        new_decimal_op(
+            &self.values,
+            params,
+            entry_point,
+            precision,
+            scale,
+            pipeline,
+        )
+    }
+}
+
+macro_rules! impl_decimal_cast_from {
+    ($ty: ident, $entry_point: literal) => {
+        impl DecimalCast for $ty {
+            fn cast_decimal128_op(
+                &self,
+                precision: u8,
+                scale: i8,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> Decimal128ArrayGPU {
+                let params = decimal_params(scale as u32, 0, scale, precision, self.len);
+                new_decimal_op(self, params, $entry_point, precision, scale, pipeline)
+            }
+        }
+    };
+}
+
+impl_decimal_cast_from!(Int32ArrayGPU, "from_i32");
+impl_decimal_cast_from!(Int64ArrayGPU, "from_i64");
+impl_decimal_cast_from!(Float32ArrayGPU, "from_f32");
+
+macro_rules! impl_decimal_cast_into {
+    ($ty: ident, $entry_point: literal, $item_size: literal) => {
+        impl Cast<$ty> for Decimal128ArrayGPU {
+            fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> $ty {
+                let params =
+                    decimal_params(0, self.scale as u32, self.scale, self.precision, self.len);
+                let new_buffer = apply_decimal_op(
+                    &self.values.data,
+                    params,
+                    self.len,
+                    $item_size,
+                    $entry_point,
+                    pipeline,
+                );
+                $ty {
+                    data: new_buffer.into(),
+                    gpu_device: self.gpu_device.clone(),
+                    phantom: Default::default(),
+                    len: self.len,
+                    null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(
+                        &self.values.null_buffer,
+                        pipeline,
+                    ),
+                }
+            }
+        }
+    };
+}
+
+// Casting to integers truncates the fractional digits toward zero
+impl_decimal_cast_into!(Int32ArrayGPU, "to_i32", 4);
+impl_decimal_cast_into!(Int64ArrayGPU, "to_i64", 8);
+impl_decimal_cast_into!(Float32ArrayGPU, "to_f32", 4);
+
+/// Submits a command to the pipeline to cast `from` into a decimal type
+pub(crate) fn cast_decimal_op_dyn(
+    from: &ArrowArrayGPU,
+    into: &ArrowType,
+    pipeline: &mut ArrowComputePipeline,
+) -> Option<ArrowArrayGPU> {
+    let ArrowType::Decimal128Type(precision, scale) = into else {
+        return None;
+    };
+    let result = match from {
+        ArrowArrayGPU::Decimal128ArrayGPU(x) => x.cast_decimal128_op(*precision, *scale, pipeline),
+        ArrowArrayGPU::Int32ArrayGPU(x) => x.cast_decimal128_op(*precision, *scale, pipeline),
+        ArrowArrayGPU::Int64ArrayGPU(x) => x.cast_decimal128_op(*precision, *scale, pipeline),
+        ArrowArrayGPU::Float32ArrayGPU(x) => x.cast_decimal128_op(*precision, *scale, pipeline),
+        _ => return None,
+    };
+    Some(result.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast_dyn;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::utils::ScalarArray;
+
+    #[test]
+    fn test_decimal128_rescale() {
+        let device = GPU_DEVICE.clone();
+        let values = [
+            Some(12_345),
+            Some(-12_345),
+            None,
+            Some(12_350),
+            Some(-12_349),
+            Some(1 << 100),
+        ];
+        let array = Decimal128ArrayGPU::from_optional_slice(&values, 38, 3, device);
+
+        let down = array.cast_decimal128(38, 1);
+        assert_eq!((down.precision, down.scale), (38, 1));
+        assert_eq!(
+            down.values(),
+            vec![
+                Some(123),
+                Some(-123),
+                None,
+                Some(124),
+                Some(-123),
+                Some(((1i128 << 100) + 50) / 100)
+            ]
+        );
+
+        let up = array.cast_decimal128(38, 15);
+        assert_eq!(
+            up.raw_values().unwrap(),
+            vec![
+                12_345 * 10i128.pow(12),
+                -12_345 * 10i128.pow(12),
+                0,
+                12_350 * 10i128.pow(12),
+                -12_349 * 10i128.pow(12),
+                0
+            ]
+        );
+        assert_eq!(up.values()[5], None);
+
+        let down = array.cast_decimal128(38, 0);
+        assert_eq!(
+            down.raw_values().unwrap(),
+            vec![12, -12, 0, 12, -12, ((1i128 << 100) + 500) / 1000]
+        );
+    }
+
+    #[test]
+    fn test_decimal128_to_primitive() {
+        let device = GPU_DEVICE.clone();
+        let values = [1_999, -1_999, 0, 10i128.pow(21) + 7];
+        let array = Decimal128ArrayGPU::from_slice(&values, 30, 3, device);
+
+        let int32: Int32ArrayGPU = array.cast();
+        assert_eq!(int32.raw_values().unwrap()[..3], [1, -1, 0]);
+
+        let int64 = cast_dyn(&array.clone_array().into(), &ArrowType::Int64Type);
+        assert_eq!(
+            int64.get_raw_values(),
+            ScalarArray::I64Vec(vec![1, -1, 0, 10i64.pow(18)])
+        );
+
+        let float32: Float32ArrayGPU = array.cast();
+        let float32 = float32.raw_values().unwrap();
+        assert_eq!(float32[..3], [1.999, -1.999, 0.0]);
+        assert!((float32[3] / 1e18 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_primitive_to_decimal128() {
+        let device = GPU_DEVICE.clone();
+        let array =
+            Int32ArrayGPU::from_optional_slice(&[Some(-7), None, Some(i32::MAX)], device.clone());
+        let decimal = array.cast_decimal128(20, 4);
+        assert_eq!(
+            decimal.values(),
+            vec![Some(-70_000), None, Some(i32::MAX as i128 * 10_000)]
+        );
+
+        let array = Int64ArrayGPU::from_slice(&[i64::MIN, 42], device.clone());
+        let decimal = cast_dyn(&array.into(), &ArrowType::Decimal128Type(38, 10));
+        assert_eq!(decimal.get_dtype(), ArrowType::Decimal128Type(38, 10));
+        assert_eq!(
+            decimal.get_raw_values(),
+            ScalarArray::I128Vec(vec![i64::MIN as i128 * 10i128.pow(10), 42 * 10i128.pow(10)])
+        );
+
+        let array = Float32ArrayGPU::from_slice(&[1.256, -2.5, 0.004, 1e20], device);
+        let decimal = array.cast_decimal128(38, 2);
+        assert_eq!(
+            decimal.raw_values().unwrap(),
+            vec![126, -250, 0, (1e20f32 * 100.0) as i128]
+        );
+    }
+
+    #[test]
+    fn test_cast_f32_to_decimal_out_of_range() {
+        let device = GPU_DEVICE.clone();
+        let array = Float32ArrayGPU::from_optional_slice(
+            &[
+                Some(1e31),
+                Some(-1.5),
+                None,
+                Some(f32::NAN),
+                Some(f32::NEG_INFINITY),
+                Some(-1.7014118e30),
+            ],
+            device,
+        );
+        // -1.7014118e30 * 10^8 rounds to -2^127 in f32, it fits in an i128 but has 39 digits
+        let decimal = array.cast_decimal128(38, 8);
+        assert_eq!(
+            decimal.values(),
+            vec![None, Some(-150_000_000), None, None, None, None]
+        );
+    }
+
+    #[test]
+    fn test_cast_to_decimal_precision() {
+        let device = GPU_DEVICE.clone();
+        let array = Float32ArrayGPU::from_slice(&[1e30, 1.5], device.clone());
+        assert_eq!(array.cast_decimal128(10, 2).values(), vec![None, Some(150)]);
+
+        let array = Float32ArrayGPU::from_slice(&[99_999.0, -1e5], device.clone());
+        assert_eq!(
+            array.cast_decimal128(5, 0).values(),
+            vec![Some(99_999), None]
+        );
+        assert_eq!(
+            array.cast_decimal128(6, 0).values(),
+            vec![Some(99_999), Some(-100_000)]
+        );
+
+        let array = Int64ArrayGPU::from_slice(&[9_999, -10_000], device.clone());
+        assert_eq!(
+            array.cast_decimal128(4, 0).values(),
+            vec![Some(9_999), None]
+        );
+
+        let array = Decimal128ArrayGPU::from_slice(&[99_995, -9_999, 123_456], 6, 2, device);
+        // rounding 999.95 to 1000.0 carries into a fifth digit
+        assert_eq!(
+            array.cast_decimal128(4, 1).values(),
+            vec![None, Some(-1_000), None]
+        );
+        assert_eq!(
+            array.cast_decimal128(5, 2).values(),
+            vec![Some(99_995), Some(-9_999), None]
+        );
+        assert_eq!(
+            array.cast_decimal128(5, 3).values(),
+            vec![None, Some(-99_990), None]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Cast;
+
+const F16_CAST_F32_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f16/cast_f32.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f16/cast_f32.wgsl")
+    ),
+};
+const F32_CAST_F16_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f32/cast_f16.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f32/cast_f16.wgsl")
+    ),
+};
+
+impl Cast<Float32ArrayGPU> for Float16ArrayGPU {
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Float32ArrayGPU {
+        let new_buffer = pipeline.apply_unary_function(
+            &self.data,
+            self.data.size() * 2,
+            F16_CAST_F32_SHADER.select(&pipeline.device),
+            "cast_f32",
+            self.data.size().div_ceil(4).div_ceil(256) as u32,
+        );
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Float32ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+impl Cast<Float16ArrayGPU> for Float32ArrayGPU {
+    /// Values outside the range of f16 become infinity
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Float16ArrayGPU {
+        let new_length = self.len.div_ceil(2);
+        let new_buffer = pipeline.apply_unary_function(
+            &self.data,
+            (new_length * 4) as u64,
+            F32_CAST_F16_SHADER.select(&pipeline.device),
+            "cast_f16",
+            new_length.div_ceil(256) as u32,
+        );
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Float16ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Cast;
+    use crate::cast_dyn;
+    use crate::tests::test_cast_op;
+    use arrow_gpu_array::array::f16;
+    use arrow_gpu_array::array::*;
+
+    test_cast_op!(
+        test_cast_f16_to_f32,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        [0.0, 1.5, -2.25, 65504.0, f32::INFINITY, 0.1].map(f16::from_f32),
+        Float32Type,
+        [
+            0.0,
+            1.5,
+            -2.25,
+            65504.0,
+            f32::INFINITY,
+            f16::from_f32(0.1).to_f32()
+        ]
+    );
+
+    test_cast_op!(
+        test_cast_f32_to_f16,
+        Float32ArrayGPU,
+        Float16ArrayGPU,
+        [0.0, 1.5, -2.25, 1e6, -1e6, 0.1, 3.0],
+        Float16Type,
+        [0.0, 1.5, -2.25, f32::INFINITY, f32::NEG_INFINITY, 0.1, 3.0].map(f16::from_f32)
+    );
+}
@@ -1,9 +1,13 @@
-use arrow_gpu_array::array::{Float32ArrayGPU, NullBitBufferGpu, UInt8ArrayGPU};
+use arrow_gpu_array::array::{
+    Float32ArrayGPU, Int64ArrayGPU, NullBitBufferGpu, UInt8ArrayGPU, UInt64ArrayGPU,
+};
 use arrow_gpu_array::gpu_utils::*;
 
 use crate::Cast;
+use crate::impl_cast;
 
 const F32_CAST_U8_SHADER: &str = include_str!("../compute_shaders/f32/cast_u8.wgsl");
+const F32_CAST_U64_SHADER: &str = include_str!("../compute_shaders/f32/cast_u64.wgsl");
 
 impl Cast<UInt8ArrayGPU> for Float32ArrayGPU {
     fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt8ArrayGPU {
@@ -30,6 +34,24 @@ impl Cast<UInt8ArrayGPU> for Float32ArrayGPU {
     }
 }
 
+impl_cast!(
+    UInt64ArrayGPU,
+    Float32ArrayGPU,
+    F32_CAST_U64_SHADER,
+    "cast_u64",
+    4,
+    2
+);
+
+impl_cast!(
+    Int64ArrayGPU,
+    Float32ArrayGPU,
+    F32_CAST_U64_SHADER,
+    "cast_i64",
+    4,
+    2
+);
+
 #[cfg(test)]
 mod test {
     use crate::Cast;
@@ -46,4 +68,38 @@ mod test {
         UInt8Type,
         [0u8, 1, 0, (5713u16 % 256) as u8, 0, 255, 0]
     );
+
+    test_cast_op!(
+        test_cast_f32_to_u64,
+        Float32ArrayGPU,
+        UInt64ArrayGPU,
+        [
+            0.0,
+            1.9,
+            -1.0,
+            5713.0,
+            4294967296.0,
+            1099511627776.0,
+            16777216.0 * 4294967296.0
+        ],
+        UInt64Type,
+        [0u64, 1, 0, 5713, 1 << 32, 1 << 40, 1 << 56]
+    );
+
+    test_cast_op!(
+        test_cast_f32_to_i64,
+        Float32ArrayGPU,
+        Int64ArrayGPU,
+        [
+            0.0,
+            1.9,
+            -1.9,
+            -5713.0,
+            4294967296.0,
+            -4294967296.0,
+            -1099511627776.0
+        ],
+        Int64Type,
+        [0i64, 1, -1, -5713, 1 << 32, -(1 << 32), -(1 << 40)]
+    );
 }
@@ -0,0 +1,107 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Cast;
+
+const F64_CAST_F32_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/f64/utils.wgsl"),
+    include_str!("../compute_shaders/f64/cast_f32.wgsl")
+);
+const F32_CAST_F64_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/f64/utils.wgsl"),
+    include_str!("../compute_shaders/f32/cast_f64.wgsl")
+);
+
+impl Cast<Float32ArrayGPU> for Float64ArrayGPU {
+    /// Rounds to the nearest f32, values outside its range become infinity or zero
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Float32ArrayGPU {
+        let new_buffer = pipeline.apply_unary_function(
+            &self.data,
+            self.data.size() / 2,
+            F64_CAST_F32_SHADER,
+            "cast_f32",
+            self.len.div_ceil(256) as u32,
+        );
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Float32ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+impl Cast<Float64ArrayGPU> for Float32ArrayGPU {
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Float64ArrayGPU {
+        let new_buffer = pipeline.apply_unary_function(
+            &self.data,
+            self.len as u64 * 8,
+            F32_CAST_F64_SHADER,
+            "cast_f64",
+            self.len.div_ceil(256) as u32,
+        );
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Float64ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Cast;
+    use crate::cast_dyn;
+    use crate::tests::test_cast_op;
+    use arrow_gpu_array::array::*;
+
+    test_cast_op!(
+        test_cast_f64_to_f32,
+        Float64ArrayGPU,
+        Float32ArrayGPU,
+        [
+            0.1,
+            -2.5,
+            1e300,
+            -1e-300,
+            f64::NEG_INFINITY,
+            16777217.0,
+            3.4028235e38
+        ],
+        Float32Type,
+        [
+            0.1f32,
+            -2.5,
+            f32::INFINITY,
+            -0.0,
+            f32::NEG_INFINITY,
+            16777216.0,
+            f32::MAX
+        ]
+    );
+
+    test_cast_op!(
+        test_cast_f32_to_f64,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        [0.1f32, -2.5, f32::MAX, 1e-40, f32::INFINITY, -0.0],
+        Float64Type,
+        [
+            0.1f32 as f64,
+            -2.5,
+            f32::MAX as f64,
+            1e-40f32 as f64,
+            f64::INFINITY,
+            -0.0
+        ]
+    );
+}
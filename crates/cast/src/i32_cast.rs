@@ -0,0 +1,50 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Cast;
+use crate::impl_cast;
+
+pub(crate) const U32_CAST_U64_SHADER: &str = include_str!("../compute_shaders/u32/cast_u64.wgsl");
+
+impl_cast!(
+    Int64ArrayGPU,
+    Int32ArrayGPU,
+    U32_CAST_U64_SHADER,
+    "cast_i64",
+    4,
+    2
+);
+
+impl_cast!(
+    UInt64ArrayGPU,
+    Int32ArrayGPU,
+    U32_CAST_U64_SHADER,
+    "cast_i64",
+    4,
+    2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast_dyn;
+    use crate::tests::test_cast_op;
+
+    test_cast_op!(
+        test_cast_i32_to_i64,
+        Int32ArrayGPU,
+        Int64ArrayGPU,
+        [0, 1, -1, i32::MAX, i32::MIN, -5713],
+        Int64Type,
+        [0, 1, -1, i32::MAX as i64, i32::MIN as i64, -5713]
+    );
+
+    test_cast_op!(
+        test_cast_i32_to_u64,
+        Int32ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, -1, i32::MAX, i32::MIN],
+        UInt64Type,
+        [0, 1, u64::MAX, i32::MAX as u64, i32::MIN as u64]
+    );
+}
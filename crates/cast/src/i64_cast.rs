@@ -0,0 +1,91 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Cast;
+use crate::impl_cast;
+
+use crate::u64_cast::U64_CAST_U32_SHADER;
+
+const I64_CAST_F32_SHADER: &str = include_str!("../compute_shaders/i64/cast_f32.wgsl");
+
+impl_cast!(UInt64ArrayGPU, Int64ArrayGPU);
+
+impl_cast!(
+    UInt32ArrayGPU,
+    Int64ArrayGPU,
+    U64_CAST_U32_SHADER,
+    "cast_u32",
+    8,
+    1,
+    2
+);
+
+impl_cast!(
+    Int32ArrayGPU,
+    Int64ArrayGPU,
+    U64_CAST_U32_SHADER,
+    "cast_u32",
+    8,
+    1,
+    2
+);
+
+impl_cast!(
+    Float32ArrayGPU,
+    Int64ArrayGPU,
+    I64_CAST_F32_SHADER,
+    "cast_f32",
+    8,
+    1,
+    2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast_dyn;
+    use crate::tests::test_cast_op;
+
+    test_cast_op!(
+        test_cast_i64_to_u32,
+        Int64ArrayGPU,
+        UInt32ArrayGPU,
+        [0, 1, -1, 1 << 32, -(1 << 32) - 5, i64::MIN],
+        UInt32Type,
+        [0u32, 1, u32::MAX, 0, (-5i32) as u32, 0]
+    );
+
+    test_cast_op!(
+        test_cast_i64_to_i32,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        [0, 1, -1, 1 << 32, -(1 << 32) - 5, i64::MIN],
+        Int32Type,
+        [0i32, 1, -1, 0, -5, 0]
+    );
+
+    test_cast_op!(
+        test_cast_i64_to_f32,
+        Int64ArrayGPU,
+        Float32ArrayGPU,
+        [0, 1, -5713, 1 << 32, -(1 << 40), -(1 << 40) - (1 << 20)],
+        Float32Type,
+        [
+            0.0,
+            1.0,
+            -5713.0,
+            4294967296.0,
+            -1099511627776.0,
+            -1099512676352.0
+        ]
+    );
+
+    test_cast_op!(
+        test_cast_i64_to_u64,
+        Int64ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, -1, i64::MIN],
+        UInt64Type,
+        [0u64, 1, u64::MAX, 1 << 63]
+    );
+}
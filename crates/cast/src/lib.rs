@@ -2,14 +2,26 @@ use arrow_gpu_array::array::*;
 use arrow_gpu_array::gpu_utils::*;
 
 pub(crate) mod boolean_cast;
+pub(crate) mod decimal_cast;
+pub(crate) mod f16_cast;
 pub(crate) mod f32_cast;
+pub(crate) mod f64_cast;
 pub(crate) mod i16_cast;
+pub(crate) mod i32_cast;
+pub(crate) mod i64_cast;
 pub(crate) mod i8_cast;
+pub(crate) mod temporal_cast;
 pub(crate) mod u16_cast;
 pub(crate) mod u32_cast;
+pub(crate) mod u64_cast;
 pub(crate) mod u8_cast;
 
 pub use boolean_cast::*;
+pub use decimal_cast::DecimalCast;
+pub use temporal_cast::{TimeUnitCast, with_finer_unit_op};
+
+use decimal_cast::cast_decimal_op_dyn;
+use temporal_cast::cast_temporal_op_dyn;
 
 /// The cast ArrowArray into another ArrowArray
 pub trait Cast<T>: ArrayUtils {
@@ -39,13 +51,24 @@ pub trait BitCast<T>: ArrayUtils {
 
 macro_rules! impl_cast {
     ($into_ty: ident, $from_ty: ident, $shader: ident, $entry_point: literal, $item_size: literal, $buffer_size_mul: literal) => {
+        impl_cast!(
+            $into_ty,
+            $from_ty,
+            $shader,
+            $entry_point,
+            $item_size,
+            $buffer_size_mul,
+            1
+        );
+    };
+    ($into_ty: ident, $from_ty: ident, $shader: ident, $entry_point: literal, $item_size: literal, $buffer_size_mul: literal, $buffer_size_div: literal) => {
         impl Cast<$into_ty> for $from_ty {
             fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> $into_ty {
                 let dispatch_size = self.data.size().div_ceil($item_size).div_ceil(256) as u32;
 
                 let new_buffer = pipeline.apply_unary_function(
                     &self.data,
-                    self.data.size() * $buffer_size_mul,
+                    self.data.size() * $buffer_size_mul / $buffer_size_div,
                     $shader,
                     $entry_point,
                     dispatch_size,
@@ -122,11 +145,13 @@ macro_rules! dyn_cast {
         pub fn $function_op(from: &ArrowArrayGPU, into: &ArrowType, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
             match (from, into) {
                 $((ArrowArrayGPU::$from(x), ArrowType::$into_ty) => Cast::<$into>::cast_op(x, pipeline).into(),)+
-                _ => panic!(
+                _ => cast_temporal_op_dyn(from, into, pipeline)
+                    .or_else(|| cast_decimal_op_dyn(from, into, pipeline))
+                    .unwrap_or_else(|| panic!(
                     "Casting not supported for type {:?} {:?}",
                     from.get_dtype(),
                     into,
-                ),
+                )),
             }
         }
     )
@@ -156,8 +181,32 @@ dyn_cast!(
     [UInt16ArrayGPU, Int16Type, Int16ArrayGPU],
     [UInt16ArrayGPU, Int32Type, Int32ArrayGPU],
     [UInt16ArrayGPU, Float32Type, Float32ArrayGPU],
+    [Int32ArrayGPU, Int64Type, Int64ArrayGPU],
+    [Int32ArrayGPU, UInt64Type, UInt64ArrayGPU],
+    [UInt32ArrayGPU, Int64Type, Int64ArrayGPU],
+    [UInt32ArrayGPU, UInt64Type, UInt64ArrayGPU],
+    [Int64ArrayGPU, Int32Type, Int32ArrayGPU],
+    [Int64ArrayGPU, UInt32Type, UInt32ArrayGPU],
+    [Int64ArrayGPU, UInt64Type, UInt64ArrayGPU],
+    [Int64ArrayGPU, Float32Type, Float32ArrayGPU],
+    [UInt64ArrayGPU, Int32Type, Int32ArrayGPU],
+    [UInt64ArrayGPU, UInt32Type, UInt32ArrayGPU],
+    [UInt64ArrayGPU, Int64Type, Int64ArrayGPU],
+    [UInt64ArrayGPU, Float32Type, Float32ArrayGPU],
+    [Float16ArrayGPU, Float32Type, Float32ArrayGPU],
+    [Float32ArrayGPU, Float16Type, Float16ArrayGPU],
+    [Float64ArrayGPU, Float32Type, Float32ArrayGPU],
+    [Float32ArrayGPU, Float64Type, Float64ArrayGPU],
     [Float32ArrayGPU, UInt8Type, UInt8ArrayGPU],
-    [BooleanArrayGPU, Float32Type, Float32ArrayGPU]
+    [Float32ArrayGPU, Int64Type, Int64ArrayGPU],
+    [Float32ArrayGPU, UInt64Type, UInt64ArrayGPU],
+    [BooleanArrayGPU, Float32Type, Float32ArrayGPU],
+    [TimestampArrayGPU, Int64Type, Int64ArrayGPU],
+    [DurationArrayGPU, Int64Type, Int64ArrayGPU],
+    [Time32ArrayGPU, Int32Type, Int32ArrayGPU],
+    [Decimal128ArrayGPU, Int32Type, Int32ArrayGPU],
+    [Decimal128ArrayGPU, Int64Type, Int64ArrayGPU],
+    [Decimal128ArrayGPU, Float32Type, Float32ArrayGPU]
 );
 
 macro_rules! dyn_bitcast {
@@ -0,0 +1,350 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::Cast;
+
+const I64_CAST_UNIT_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i64/utils.wgsl"),
+    include_str!("../compute_shaders/i64/cast_unit.wgsl")
+);
+const I32_CAST_UNIT_SHADER: &str = include_str!("../compute_shaders/i32/cast_unit.wgsl");
+
+/// Trait for converting temporal arrays between time units
+pub trait TimeUnitCast: ArrayUtils + Sized {
+    fn cast_unit(&self, unit: TimeUnit) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), None);
+        let output = self.cast_unit_op(unit, &mut pipeline);
+        pipeline.finish();
+        output
+    }
+
+    /// Unit of the values
+    fn unit(&self) -> TimeUnit;
+
+    /// Converts the values to `unit`, converting to a coarser unit truncates toward zero.
+    /// Values that overflow when converted to a finer unit are null.
+    fn cast_unit_op(&self, unit: TimeUnit, pipeline: &mut ArrowComputePipeline) -> Self;
+}
+
+/// Calls `op` with `left` and `right` converted to the finer of their units along with
+/// that unit, the arrays already in that unit are passed as they are
+pub fn with_finer_unit_op<L: TimeUnitCast, R: TimeUnitCast, O>(
+    left: &L,
+    right: &R,
+    pipeline: &mut ArrowComputePipeline,
+    op: impl FnOnce(&L, &R, TimeUnit, &mut ArrowComputePipeline) -> O,
+) -> O {
+    let unit = left.unit().finer(right.unit());
+    let new_left = (left.unit() != unit).then(|| left.cast_unit_op(unit, pipeline));
+    let new_right = (right.unit() != unit).then(|| right.cast_unit_op(unit, pipeline));
+    op(
+        new_left.as_ref().unwrap_or(left),
+        new_right.as_ref().unwrap_or(right),
+        unit,
+        pipeline,
+    )
+}
+
+fn scale_values_op<T: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    from: TimeUnit,
+    into: TimeUnit,
+    shader: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> PrimitiveArrayGpu<T> {
+    if from == into {
+        return clone_primitive_op(array, pipeline);
+    }
+
+    let scale_up = into > from;
+    let (factor, entry_point) = if scale_up {
+        (into.per_second() / from.per_second(), "scale_up")
+    } else {
+        (from.per_second() / into.per_second(), "scale_down")
+    };
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[factor, array.len as u32]);
+    let new_buffer = pipeline.device.create_empty_buffer(array.data.size());
+    // only a finer unit can overflow, its rows that do are cleared in a new null buffer
+    let null_buffer = if scale_up && array.len > 0 {
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline)
+            .unwrap_or_else(|| {
+                NullBitBufferGpu::new_set_with_capacity(array.gpu_device.clone(), array.len)
+            });
+        pipeline.apply_function(
+            &[&params, &array.data, &new_buffer, &null_buffer.bit_buffer],
+            shader,
+            entry_point,
+            array.len.div_ceil(256) as u32,
+        );
+        Some(null_buffer)
+    } else {
+        pipeline.apply_function(
+            &[&params, &array.data, &new_buffer],
+            shader,
+            entry_point,
+            array.len.div_ceil(256) as u32,
+        );
+        NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline)
+    };
+
+    PrimitiveArrayGpu {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer,
+    }
+}
+
+fn clone_primitive_op<T: ArrowPrimitiveType, U: ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    pipeline: &mut ArrowComputePipeline,
+) -> PrimitiveArrayGpu<U> {
+    let new_buffer: Buffer = pipeline.clone_buffer(&array.data);
+    PrimitiveArrayGpu {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline),
+    }
+}
+
+impl TimeUnitCast for TimestampArrayGPU {
+    fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    fn cast_unit_op(&self, unit: TimeUnit, pipeline: &mut ArrowComputePipeline) -> Self {
+        let values = scale_values_op(
+            &self.values,
+            self.unit,
+            unit,
+            I64_CAST_UNIT_SHADER,
+            pipeline,
+        );
+        TimestampArrayGPU::new(values, unit, self.timezone.clone())
+    }
+}
+
+impl TimeUnitCast for DurationArrayGPU {
+    fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    fn cast_unit_op(&self, unit: TimeUnit, pipeline: &mut ArrowComputePipeline) -> Self {
+        let values = scale_values_op(
+            &self.values,
+            self.unit,
+            unit,
+            I64_CAST_UNIT_SHADER,
+            pipeline,
+        );
+        DurationArrayGPU::new(values, unit)
+    }
+}
+
+impl TimeUnitCast for Time32ArrayGPU {
+    fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    fn cast_unit_op(&self, unit: TimeUnit, pipeline: &mut ArrowComputePipeline) -> Self {
+        let values = scale_values_op(
+            &self.values,
+            self.unit,
+            unit,
+            I32_CAST_UNIT_SHADER,
+            pipeline,
+        );
+        Time32ArrayGPU::new(values, unit)
+    }
+}
+
+impl Cast<Int64ArrayGPU> for TimestampArrayGPU {
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Int64ArrayGPU {
+        clone_primitive_op(&self.values, pipeline)
+    }
+}
+
+impl Cast<Int64ArrayGPU> for DurationArrayGPU {
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Int64ArrayGPU {
+        clone_primitive_op(&self.values, pipeline)
+    }
+}
+
+impl Cast<Int32ArrayGPU> for Time32ArrayGPU {
+    fn cast_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        clone_primitive_op(&self.values, pipeline)
+    }
+}
+
+/// Submits a command to the pipeline to cast a temporal array or its integer
+/// representation into a temporal type with the given unit
+pub(crate) fn cast_temporal_op_dyn(
+    from: &ArrowArrayGPU,
+    into: &ArrowType,
+    pipeline: &mut ArrowComputePipeline,
+) -> Option<ArrowArrayGPU> {
+    let result = match (from, into) {
+        (ArrowArrayGPU::TimestampArrayGPU(x), ArrowType::TimestampType(unit, timezone)) => {
+            let mut result = x.cast_unit_op(*unit, pipeline);
+            result.timezone = timezone.clone();
+            result.into()
+        }
+        (ArrowArrayGPU::DurationArrayGPU(x), ArrowType::DurationType(unit)) => {
+            x.cast_unit_op(*unit, pipeline).into()
+        }
+        (ArrowArrayGPU::Time32ArrayGPU(x), ArrowType::Time32Type(unit)) => {
+            x.cast_unit_op(*unit, pipeline).into()
+        }
+        (ArrowArrayGPU::Int64ArrayGPU(x), ArrowType::TimestampType(unit, timezone)) => {
+            TimestampArrayGPU::new(clone_primitive_op(x, pipeline), *unit, timezone.clone()).into()
+        }
+        (ArrowArrayGPU::Int64ArrayGPU(x), ArrowType::DurationType(unit)) => {
+            DurationArrayGPU::new(clone_primitive_op(x, pipeline), *unit).into()
+        }
+        (ArrowArrayGPU::Int32ArrayGPU(x), ArrowType::Time32Type(unit)) => {
+            Time32ArrayGPU::new(clone_primitive_op(x, pipeline), *unit).into()
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast_dyn;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_timestamp_cast_unit() {
+        let device = GPU_DEVICE.clone();
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[Some(1_500), Some(-1_500), None, Some(1 << 40), Some(-999)],
+            TimeUnit::Millisecond,
+            None,
+            device,
+        );
+        let seconds = array.cast_unit(TimeUnit::Second);
+        assert_eq!(seconds.unit, TimeUnit::Second);
+        assert_eq!(
+            seconds.values(),
+            vec![Some(1), Some(-1), None, Some((1 << 40) / 1000), Some(0)]
+        );
+
+        let nanos = array.cast_unit(TimeUnit::Nanosecond);
+        assert_eq!(
+            nanos.values(),
+            vec![
+                Some(1_500_000_000),
+                Some(-1_500_000_000),
+                None,
+                Some((1 << 40) * 1_000_000),
+                Some(-999_000_000)
+            ]
+        );
+
+        let micros = cast_dyn(
+            &nanos.into(),
+            &ArrowType::TimestampType(TimeUnit::Microsecond, Some("UTC".into())),
+        );
+        assert_eq!(
+            micros.get_dtype(),
+            ArrowType::TimestampType(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        let micros: TimestampArrayGPU = micros.try_into().unwrap();
+        assert_eq!(
+            micros.values(),
+            vec![
+                Some(1_500_000),
+                Some(-1_500_000),
+                None,
+                Some((1 << 40) * 1_000),
+                Some(-999_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duration_cast_large_values() {
+        let device = GPU_DEVICE.clone();
+        let values = [i64::MAX, i64::MIN, 86_400_000_000_123, -86_400_000_000_123];
+        let array = DurationArrayGPU::from_slice(&values, TimeUnit::Nanosecond, device);
+        let seconds = array.cast_unit(TimeUnit::Second);
+        assert_eq!(
+            seconds.raw_values().unwrap(),
+            values.map(|x| x / 1_000_000_000).to_vec()
+        );
+        let int64 = cast_dyn(&array.into(), &ArrowType::Int64Type);
+        assert_eq!(
+            int64.get_raw_values(),
+            arrow_gpu_array::utils::ScalarArray::I64Vec(values.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_cast_unit_overflow() {
+        let device = GPU_DEVICE.clone();
+        let limit = i64::MAX / 1_000_000_000;
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[
+                Some(limit),
+                Some(limit + 1),
+                Some(-limit),
+                Some(-limit - 1),
+                None,
+            ],
+            TimeUnit::Second,
+            None,
+            device.clone(),
+        );
+        assert_eq!(
+            array.cast_unit(TimeUnit::Nanosecond).values(),
+            vec![
+                Some(limit * 1_000_000_000),
+                None,
+                Some(-limit * 1_000_000_000),
+                None,
+                None
+            ]
+        );
+
+        let array = DurationArrayGPU::from_slice(
+            &[i64::MIN / 1_000, i64::MIN / 1_000 - 1, i64::MAX],
+            TimeUnit::Millisecond,
+            device.clone(),
+        );
+        assert_eq!(
+            array.cast_unit(TimeUnit::Microsecond).values(),
+            vec![Some(i64::MIN / 1_000 * 1_000), None, None]
+        );
+
+        let array = Time32ArrayGPU::from_slice(
+            &[i32::MAX / 1_000, i32::MAX / 1_000 + 1, i32::MIN / 1_000 - 1],
+            TimeUnit::Second,
+            device,
+        );
+        assert_eq!(
+            array.cast_unit(TimeUnit::Millisecond).values(),
+            vec![Some(i32::MAX / 1_000 * 1_000), None, None]
+        );
+    }
+
+    #[test]
+    fn test_time32_cast() {
+        let device = GPU_DEVICE.clone();
+        let array = Int32ArrayGPU::from_slice(&[0, 3_599_999, 86_399_001], device);
+        let millis = cast_dyn(&array.into(), &ArrowType::Time32Type(TimeUnit::Millisecond));
+        let seconds = cast_dyn(&millis, &ArrowType::Time32Type(TimeUnit::Second));
+        let seconds: Time32ArrayGPU = seconds.try_into().unwrap();
+        assert_eq!(seconds.raw_values().unwrap(), vec![0, 3_599, 86_399]);
+        let millis = seconds.cast_unit(TimeUnit::Millisecond);
+        assert_eq!(millis.raw_values().unwrap(), vec![0, 3_599_000, 86_399_000]);
+    }
+}
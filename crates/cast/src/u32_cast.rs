@@ -1,16 +1,36 @@
-use crate::{BitCast, impl_bitcast};
-use arrow_gpu_array::array::{ArrayUtils, Float32ArrayGPU, NullBitBufferGpu, UInt32ArrayGPU};
+use crate::i32_cast::U32_CAST_U64_SHADER;
+use crate::{BitCast, Cast, impl_bitcast, impl_cast};
+use arrow_gpu_array::array::{
+    ArrayUtils, Float32ArrayGPU, Int64ArrayGPU, NullBitBufferGpu, UInt32ArrayGPU, UInt64ArrayGPU,
+};
 use arrow_gpu_array::gpu_utils::*;
 
 impl_bitcast!(Float32ArrayGPU, UInt32ArrayGPU);
 
+impl_cast!(
+    UInt64ArrayGPU,
+    UInt32ArrayGPU,
+    U32_CAST_U64_SHADER,
+    "cast_u64",
+    4,
+    2
+);
+
+impl_cast!(
+    Int64ArrayGPU,
+    UInt32ArrayGPU,
+    U32_CAST_U64_SHADER,
+    "cast_u64",
+    4,
+    2
+);
+
 #[cfg(test)]
 mod test {
     use crate::ArrowType;
-    use crate::BitCast;
-    use crate::bitcast_dyn;
-    use crate::tests::test_bitcast_op;
-    use arrow_gpu_array::array::{Float32ArrayGPU, UInt32ArrayGPU};
+    use crate::tests::{test_bitcast_op, test_cast_op};
+    use crate::{BitCast, Cast, bitcast_dyn, cast_dyn};
+    use arrow_gpu_array::array::{Float32ArrayGPU, Int64ArrayGPU, UInt32ArrayGPU, UInt64ArrayGPU};
 
     test_bitcast_op!(
         test_bitcast_u32_to_f32,
@@ -28,4 +48,22 @@ mod test {
             f32::from_bits(u32::MAX)
         ]
     );
+
+    test_cast_op!(
+        test_cast_u32_to_u64,
+        UInt32ArrayGPU,
+        UInt64ArrayGPU,
+        [0, 1, 5713, u32::MAX, 1 << 31],
+        UInt64Type,
+        [0, 1, 5713, u32::MAX as u64, 1 << 31]
+    );
+
+    test_cast_op!(
+        test_cast_u32_to_i64,
+        UInt32ArrayGPU,
+        Int64ArrayGPU,
+        [0, 1, 5713, u32::MAX, 1 << 31],
+        Int64Type,
+        [0, 1, 5713, u32::MAX as i64, 1 << 31]
+    );
 }
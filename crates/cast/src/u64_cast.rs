@@ -0,0 +1,90 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Cast;
+use crate::impl_cast;
+
+pub(crate) const U64_CAST_U32_SHADER: &str = include_str!("../compute_shaders/u64/cast_u32.wgsl");
+const U64_CAST_F32_SHADER: &str = include_str!("../compute_shaders/u64/cast_f32.wgsl");
+
+impl_cast!(Int64ArrayGPU, UInt64ArrayGPU);
+
+impl_cast!(
+    UInt32ArrayGPU,
+    UInt64ArrayGPU,
+    U64_CAST_U32_SHADER,
+    "cast_u32",
+    8,
+    1,
+    2
+);
+
+impl_cast!(
+    Int32ArrayGPU,
+    UInt64ArrayGPU,
+    U64_CAST_U32_SHADER,
+    "cast_u32",
+    8,
+    1,
+    2
+);
+
+impl_cast!(
+    Float32ArrayGPU,
+    UInt64ArrayGPU,
+    U64_CAST_F32_SHADER,
+    "cast_f32",
+    8,
+    1,
+    2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast_dyn;
+    use crate::tests::test_cast_op;
+
+    test_cast_op!(
+        test_cast_u64_to_u32,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        [0, 1, u32::MAX as u64, 1 << 32, (1 << 32) + 5, u64::MAX],
+        UInt32Type,
+        [0u32, 1, u32::MAX, 0, 5, u32::MAX]
+    );
+
+    test_cast_op!(
+        test_cast_u64_to_i32,
+        UInt64ArrayGPU,
+        Int32ArrayGPU,
+        [0, 1, u32::MAX as u64, 1 << 32, (1 << 32) + 5, u64::MAX],
+        Int32Type,
+        [0i32, 1, -1, 0, 5, -1]
+    );
+
+    test_cast_op!(
+        test_cast_u64_to_f32,
+        UInt64ArrayGPU,
+        Float32ArrayGPU,
+        [0, 1, 5713, 1 << 32, 1 << 40, (1 << 40) + (1 << 20)],
+        Float32Type,
+        [
+            0.0,
+            1.0,
+            5713.0,
+            4294967296.0,
+            1099511627776.0,
+            1099512676352.0
+        ]
+    );
+
+    test_cast_op!(
+        test_cast_u64_to_i64,
+        UInt64ArrayGPU,
+        Int64ArrayGPU,
+        [0, 1, u64::MAX, 1 << 63],
+        Int64Type,
+        [0i64, 1, -1, i64::MIN]
+    );
+}
@@ -124,4 +124,15 @@ mod tests {
         Float32Type,
         [0.0, 1.0, 2.0, 3.0, 255.0, 250.0, 7.0]
     );
+
+    #[test]
+    fn test_cast_sliced_u8_to_i16() {
+        let device = arrow_gpu_array::GPU_DEVICE.clone();
+        let values = (0..40).map(|x| x * 5).collect::<Vec<u8>>();
+        let array = UInt8ArrayGPU::from_slice(&values, device).slice(7, 21);
+
+        let new_array: Int16ArrayGPU = array.cast();
+        let expected = values[7..28].iter().map(|x| *x as i16).collect::<Vec<_>>();
+        assert_eq!(new_array.raw_values().unwrap(), expected);
+    }
 }
@@ -0,0 +1,73 @@
+// half and load_half2 are defined by a prelude in compute_shaders/f16,
+// each invocation compares one value
+
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+var<workgroup> local_set_bits: array<atomic<u32>, 8>;
+
+fn set_bit(index: u32, value: bool) {
+    let index_by_32 = index / 32u;
+    if value {
+        atomicOr(&local_set_bits[index_by_32], (1u << (index % 32u)));
+    }
+}
+
+fn load_left(index: u32) -> half {
+    return load_half2(left_values[index / 2u])[index % 2u];
+}
+
+fn load_right(index: u32) -> half {
+    return load_half2(right_values[index / 2u])[index % 2u];
+}
+
+fn write_bits(global_id: u32, local_id: u32) {
+    workgroupBarrier();
+    if global_id % 32u == 0u {
+        new_values[global_id / 32u] = atomicLoad(&local_set_bits[local_id / 32u]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn gt(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    set_bit(local_id.x, load_left(global_id.x) > load_right(global_id.x));
+    write_bits(global_id.x, local_id.x);
+}
+
+@compute
+@workgroup_size(256)
+fn gteq(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    set_bit(local_id.x, load_left(global_id.x) >= load_right(global_id.x));
+    write_bits(global_id.x, local_id.x);
+}
+
+@compute
+@workgroup_size(256)
+fn lt(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    set_bit(local_id.x, load_left(global_id.x) < load_right(global_id.x));
+    write_bits(global_id.x, local_id.x);
+}
+
+@compute
+@workgroup_size(256)
+fn lteq(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    set_bit(local_id.x, load_left(global_id.x) <= load_right(global_id.x));
+    write_bits(global_id.x, local_id.x);
+}
+
+@compute
+@workgroup_size(256)
+fn eq(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    set_bit(local_id.x, load_left(global_id.x) == load_right(global_id.x));
+    write_bits(global_id.x, local_id.x);
+}
@@ -0,0 +1,34 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16,
+// each invocation computes the two values packed in a word
+
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn max_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = load_half2(left_values[global_id.x]);
+        let right = load_half2(right_values[global_id.x]);
+        new_values[global_id.x] = store_half2(max(left, right));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn min_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let left = load_half2(left_values[global_id.x]);
+        let right = load_half2(right_values[global_id.x]);
+        new_values[global_id.x] = store_half2(min(left, right));
+    }
+}
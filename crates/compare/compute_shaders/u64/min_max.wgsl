@@ -0,0 +1,27 @@
+@group(0)
+@binding(0)
+var<storage, read> left_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_values: array<vec2<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+@compute
+@workgroup_size(256)
+fn max_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let left = left_values[global_id.x];
+    let right = right_values[global_id.x];
+    new_values[global_id.x] = select(right, left, gt_u64(left, right));
+}
+
+@compute
+@workgroup_size(256)
+fn min_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let left = left_values[global_id.x];
+    let right = right_values[global_id.x];
+    new_values[global_id.x] = select(right, left, lt_u64(left, right));
+}
@@ -0,0 +1,184 @@
+use arrow_gpu_array::array::{ArrayUtils, ArrowArrayGPU, PrimitiveArrayGpu, UInt32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_routines::{SortType, first_index_op};
+
+/// Trait for the position of the smallest or largest element of ArrowArrays
+pub trait ArgMinMax: ArrayUtils + Sized {
+    fn argmin(&self) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("argmin"));
+        let result = self.argmin_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn argmax(&self) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("argmax"));
+        let result = self.argmax_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Returns the index of the first smallest element as an array of length 1.
+    /// Null values are skipped and the index is null when no element is valid.
+    /// Floats follow the IEEE 754 total order like the sort, so NaN is larger than
+    /// every other value unless its sign bit is set.
+    fn argmin_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU;
+
+    /// Returns the index of the first largest element, see `argmin_op`
+    fn argmax_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU;
+}
+
+impl<T: SortType> ArgMinMax for PrimitiveArrayGpu<T> {
+    fn argmin_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        first_index_op(self, false, pipeline)
+    }
+
+    fn argmax_op(&self, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+        first_index_op(self, true, pipeline)
+    }
+}
+
+/// Computes the index of the first smallest element of data
+pub fn argmin_dyn(data: &ArrowArrayGPU) -> UInt32ArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("argmin"));
+    let result = argmin_op_dyn(data, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Computes the index of the first largest element of data
+pub fn argmax_dyn(data: &ArrowArrayGPU) -> UInt32ArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("argmax"));
+    let result = argmax_op_dyn(data, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! arg_min_max_op_dyn_arms {
+    ($data: ident, $pipeline: ident, $fn: ident, $($arr: ident),*) => {
+        match $data {
+            $(ArrowArrayGPU::$arr(array) => array.$fn($pipeline),)*
+            _ => panic!(
+                "Operation {} not supported for type {:?}",
+                stringify!($fn),
+                $data.get_dtype()
+            ),
+        }
+    };
+}
+
+/// Submits commands to compute the index of the first smallest element of data
+pub fn argmin_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+    arg_min_max_op_dyn_arms!(
+        data,
+        pipeline,
+        argmin_op,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU
+    )
+}
+
+/// Submits commands to compute the index of the first largest element of data
+pub fn argmax_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> UInt32ArrayGPU {
+    arg_min_max_op_dyn_arms!(
+        data,
+        pipeline,
+        argmax_op,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::*;
+
+    use super::*;
+
+    // first index of the smallest or largest valid value on the cpu
+    fn cpu_arg<V: Copy + PartialOrd>(values: &[Option<V>], max: bool) -> Option<u32> {
+        let mut best: Option<(usize, V)> = None;
+        for (row, value) in values.iter().enumerate() {
+            let Some(value) = *value else { continue };
+            let better = match best {
+                None => true,
+                Some((_, b)) if max => value > b,
+                Some((_, b)) => value < b,
+            };
+            if better {
+                best = Some((row, value));
+            }
+        }
+        best.map(|(row, _)| row as u32)
+    }
+
+    #[test]
+    fn test_arg_min_max_i32_multi_level() {
+        let values = (0..70_000)
+            .map(|x: i32| (x % 13 != 5).then_some((x * 7919) % 1009 - 500))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.argmin().values(), vec![cpu_arg(&values, false)]);
+        assert_eq!(array.argmax().values(), vec![cpu_arg(&values, true)]);
+    }
+
+    #[test]
+    fn test_arg_min_max_64bit() {
+        let values = (0..3000)
+            .map(|x: i64| (x % 3 != 0).then_some(((x * 37) % 101 - 50) << 33))
+            .collect::<Vec<_>>();
+        let array = Int64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.argmin().values(), vec![cpu_arg(&values, false)]);
+        assert_eq!(array.argmax().values(), vec![cpu_arg(&values, true)]);
+
+        let values = [Some(2.5), None, Some(-1.0), Some(2.5), Some(-1.0)];
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.argmin().values(), vec![Some(2)]);
+        assert_eq!(array.argmax().values(), vec![Some(0)]);
+    }
+
+    #[test]
+    fn test_arg_min_max_nan_and_nulls() {
+        let values = [Some(1.0f32), Some(f32::NAN), Some(-3.0), Some(f32::NAN)];
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        assert_eq!(array.argmin().values(), vec![Some(2)]);
+        assert_eq!(array.argmax().values(), vec![Some(1)]);
+
+        let array = UInt8ArrayGPU::from_optional_slice(&[None, None], GPU_DEVICE.clone());
+        assert_eq!(array.argmin().values(), vec![None]);
+        assert_eq!(array.argmax().values(), vec![None]);
+
+        let array = UInt16ArrayGPU::from_slice(&[1], GPU_DEVICE.clone()).slice(0, 0);
+        assert_eq!(array.argmax().values(), vec![None]);
+    }
+
+    #[test]
+    fn test_arg_min_max_dyn() {
+        let values = (0..500)
+            .map(|x: u32| (!x.is_multiple_of(5)).then_some(f16::from_f32((x % 37) as f32)))
+            .collect::<Vec<_>>();
+        let array: ArrowArrayGPU =
+            Float16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone()).into();
+        assert_eq!(argmin_dyn(&array).values(), vec![cpu_arg(&values, false)]);
+        assert_eq!(argmax_dyn(&array).values(), vec![cpu_arg(&values, true)]);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::*;
+use arrow_gpu_array::array::{DECIMAL128_MAX_PRECISION, Decimal128ArrayGPU};
+use arrow_gpu_cast::DecimalCast;
+
+/// Rescales `array` to `scale` unless it already uses it
+fn rescale_op(
+    array: &Decimal128ArrayGPU,
+    scale: i8,
+    pipeline: &mut ArrowComputePipeline,
+) -> Option<Decimal128ArrayGPU> {
+    (array.scale != scale).then(|| {
+        let precision =
+            (array.precision as i8 + scale - array.scale).min(DECIMAL128_MAX_PRECISION as i8);
+        array.cast_decimal128_op(precision as u8, scale, pipeline)
+    })
+}
+
+type I128ArrayGPU = PrimitiveArrayGpu<i128>;
+
+// Decimals are compared on their i128 values after rescaling both sides to the larger scale.
+fn rescaled_op<T>(
+    left: &Decimal128ArrayGPU,
+    right: &Decimal128ArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+    op: impl FnOnce(&Decimal128ArrayGPU, &Decimal128ArrayGPU, &mut ArrowComputePipeline) -> T,
+) -> T {
+    let scale = left.scale.max(right.scale);
+    let new_left = rescale_op(left, scale, pipeline);
+    let new_right = rescale_op(right, scale, pipeline);
+    op(
+        new_left.as_ref().unwrap_or(left),
+        new_right.as_ref().unwrap_or(right),
+        pipeline,
+    )
+}
+
+macro_rules! impl_decimal_compare {
+    ($($fn: ident),*) => {
+        impl Compare for Decimal128ArrayGPU {
+            $(
+                fn $fn(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+                    rescaled_op(self, operand, pipeline, |left, right, pipeline| {
+                        <I128ArrayGPU as Compare>::$fn(&left.values, &right.values, pipeline)
+                    })
+                }
+            )*
+        }
+    };
+}
+
+impl_decimal_compare!(gt_op, gteq_op, lt_op, lteq_op, eq_op);
+
+macro_rules! impl_decimal_min_max {
+    ($($fn: ident),*) => {
+        impl MinMax for Decimal128ArrayGPU {
+            $(
+                fn $fn(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> Self {
+                    rescaled_op(self, operand, pipeline, |left, right, pipeline| {
+                        let values = <I128ArrayGPU as MinMax>::$fn(&left.values, &right.values, pipeline);
+                        let precision = left.precision.max(right.precision);
+                        Decimal128ArrayGPU::new(values, precision, left.scale)
+                    })
+                }
+            )*
+        }
+    };
+}
+
+impl_decimal_min_max!(max_op, min_op);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_decimal128_compare() {
+        let device = GPU_DEVICE.clone();
+        let left = Decimal128ArrayGPU::from_optional_slice(
+            &[
+                Some(1_50),
+                Some(-1 << 100),
+                None,
+                Some(2_00),
+                Some(i128::MAX),
+            ],
+            38,
+            2,
+            device.clone(),
+        );
+        let right = Decimal128ArrayGPU::from_slice(&[1_5, 1 << 100, 0, 2_0, -1], 32, 1, device);
+
+        assert_eq!(
+            left.eq(&right).values(),
+            vec![Some(true), Some(false), None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            left.lt(&right).values(),
+            vec![Some(false), Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            gteq_dyn(&left.clone_array().into(), &right.clone_array().into()).values(),
+            vec![Some(true), Some(false), None, Some(true), Some(true)]
+        );
+
+        let min: Decimal128ArrayGPU = min_dyn(&left.into(), &right.into()).try_into().unwrap();
+        assert_eq!((min.precision, min.scale), (38, 2));
+        assert_eq!(
+            min.values(),
+            vec![Some(1_50), Some(-1 << 100), None, Some(2_00), Some(-10)]
+        );
+    }
+}
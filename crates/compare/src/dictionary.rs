@@ -0,0 +1,348 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_array::kernels::ScalarValue;
+use arrow_gpu_routines::{Swizzle, dictionary_indexes_op};
+use arrow_gpu_string::StringCompare;
+
+use crate::*;
+
+type CompareOpDyn =
+    fn(&ArrowArrayGPU, &ArrowArrayGPU, &mut ArrowComputePipeline) -> BooleanArrayGPU;
+type StringCompareOp = fn(&StringArrayGPU, &str, &mut ArrowComputePipeline) -> BooleanArrayGPU;
+
+macro_rules! default_scalar_impl {
+    ($self: ident, $value: ident, $fn: ident) => {
+        let mut pipeline = ArrowComputePipeline::new($self.get_gpu_device(), None);
+        let output = Self::$fn(&$self, $value, &mut pipeline);
+        pipeline.finish();
+        return output;
+    };
+}
+
+/// Trait for comparing dictionary encoded arrays against a scalar.
+/// Only the dictionary is compared, the result is then gathered using the keys.
+pub trait DictionaryCompare: ArrayUtils {
+    fn gt_scalar(&self, value: ScalarValue) -> BooleanArrayGPU {
+        default_scalar_impl!(self, value, gt_scalar_op);
+    }
+    fn gteq_scalar(&self, value: ScalarValue) -> BooleanArrayGPU {
+        default_scalar_impl!(self, value, gteq_scalar_op);
+    }
+    fn lt_scalar(&self, value: ScalarValue) -> BooleanArrayGPU {
+        default_scalar_impl!(self, value, lt_scalar_op);
+    }
+    fn lteq_scalar(&self, value: ScalarValue) -> BooleanArrayGPU {
+        default_scalar_impl!(self, value, lteq_scalar_op);
+    }
+    fn eq_scalar(&self, value: ScalarValue) -> BooleanArrayGPU {
+        default_scalar_impl!(self, value, eq_scalar_op);
+    }
+
+    /// Returns a boolean array indicating where `self` > `value`.
+    fn gt_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` >= `value`.
+    fn gteq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` < `value`.
+    fn lt_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` <= `value`.
+    fn lteq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` == `value`.
+    fn eq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+}
+
+fn compare_dictionary_op<K: ArrowDictionaryKeyType>(
+    array: &DictionaryArrayGPU<K>,
+    value: ScalarValue,
+    compare_op_dyn: CompareOpDyn,
+    string_compare_op: StringCompareOp,
+    pipeline: &mut ArrowComputePipeline,
+) -> BooleanArrayGPU {
+    let dictionary_result = match (array.values.as_ref(), value) {
+        (ArrowArrayGPU::StringArrayGPU(values), ScalarValue::Utf8(value)) => {
+            string_compare_op(values, &value, pipeline)
+        }
+        (values, value) => {
+            let scalar = broadcast_op_dyn(value, values.len(), pipeline);
+            compare_op_dyn(values, &scalar, pipeline)
+        }
+    };
+
+    let indexes = dictionary_indexes_op(&array.keys, pipeline);
+    let mut result = dictionary_result.take_op(&indexes, pipeline);
+    result.null_buffer = NullBitBufferGpu::merge_null_bit_buffer_op(
+        &result.null_buffer,
+        &indexes.null_buffer,
+        pipeline,
+    );
+    result
+}
+
+impl<K: ArrowDictionaryKeyType> DictionaryCompare for DictionaryArrayGPU<K> {
+    fn gt_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        compare_dictionary_op(
+            self,
+            value,
+            gt_op_dyn,
+            StringCompare::gt_scalar_op,
+            pipeline,
+        )
+    }
+
+    fn gteq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        compare_dictionary_op(
+            self,
+            value,
+            gteq_op_dyn,
+            StringCompare::gteq_scalar_op,
+            pipeline,
+        )
+    }
+
+    fn lt_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        compare_dictionary_op(
+            self,
+            value,
+            lt_op_dyn,
+            StringCompare::lt_scalar_op,
+            pipeline,
+        )
+    }
+
+    fn lteq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        compare_dictionary_op(
+            self,
+            value,
+            lteq_op_dyn,
+            StringCompare::lteq_scalar_op,
+            pipeline,
+        )
+    }
+
+    fn eq_scalar_op(
+        &self,
+        value: ScalarValue,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        compare_dictionary_op(
+            self,
+            value,
+            eq_op_dyn,
+            StringCompare::eq_scalar_op,
+            pipeline,
+        )
+    }
+}
+
+macro_rules! dyn_scalar_fn {
+    ($function:ident, $doc: expr, $function_op:ident, $op:ident, $( $y:ident ),*) => (
+        #[doc=$doc]
+        pub fn $function(data: &ArrowArrayGPU, value: ScalarValue) -> BooleanArrayGPU {
+            let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+            let result = $function_op(data, value, &mut pipeline);
+            pipeline.finish();
+            result
+        }
+
+        #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+        pub fn $function_op(data: &ArrowArrayGPU, value: ScalarValue, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+            match data {
+                $(ArrowArrayGPU::$y(arr) => arr.$op(value, pipeline),)+
+                _ => panic!(
+                    "Operation {} not supported for type {:?} {:?}",
+                    stringify!($function),
+                    data.get_dtype(),
+                    value,
+                ),
+            }
+        }
+    )
+}
+
+dyn_scalar_fn!(
+    gt_scalar_dyn,
+    "Construct bool array from computing x > value for each x in data",
+    gt_scalar_op_dyn,
+    gt_scalar_op,
+    UInt8DictionaryArrayGPU,
+    UInt16DictionaryArrayGPU,
+    UInt32DictionaryArrayGPU
+);
+
+dyn_scalar_fn!(
+    gteq_scalar_dyn,
+    "Construct bool array from computing x >= value for each x in data",
+    gteq_scalar_op_dyn,
+    gteq_scalar_op,
+    UInt8DictionaryArrayGPU,
+    UInt16DictionaryArrayGPU,
+    UInt32DictionaryArrayGPU
+);
+
+dyn_scalar_fn!(
+    lt_scalar_dyn,
+    "Construct bool array from computing x < value for each x in data",
+    lt_scalar_op_dyn,
+    lt_scalar_op,
+    UInt8DictionaryArrayGPU,
+    UInt16DictionaryArrayGPU,
+    UInt32DictionaryArrayGPU
+);
+
+dyn_scalar_fn!(
+    lteq_scalar_dyn,
+    "Construct bool array from computing x <= value for each x in data",
+    lteq_scalar_op_dyn,
+    lteq_scalar_op,
+    UInt8DictionaryArrayGPU,
+    UInt16DictionaryArrayGPU,
+    UInt32DictionaryArrayGPU
+);
+
+dyn_scalar_fn!(
+    eq_scalar_dyn,
+    "Construct bool array from computing x == value for each x in data",
+    eq_scalar_op_dyn,
+    eq_scalar_op,
+    UInt8DictionaryArrayGPU,
+    UInt16DictionaryArrayGPU,
+    UInt32DictionaryArrayGPU
+);
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::*;
+    use arrow_gpu_array::kernels::ScalarValue;
+
+    use crate::*;
+
+    #[test]
+    fn test_dictionary_compare_scalar() {
+        let device = GPU_DEVICE.clone();
+        let values =
+            Int32ArrayGPU::from_optional_slice(&[Some(10), Some(-5), None], device.clone());
+        let keys = UInt8ArrayGPU::from_optional_slice(
+            &[Some(0), Some(1), None, Some(2), Some(0), Some(1)],
+            device,
+        );
+        let array = DictionaryArrayGPU::new(keys, Arc::new(values.into()));
+
+        assert_eq!(
+            array.gt_scalar(ScalarValue::I32(0)).values(),
+            vec![Some(true), Some(false), None, None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            array.lteq_scalar(ScalarValue::I32(-5)).values(),
+            vec![Some(false), Some(true), None, None, Some(false), Some(true)]
+        );
+
+        let array = array.into();
+        assert_eq!(
+            eq_scalar_dyn(&array, ScalarValue::I32(10)).values(),
+            vec![Some(true), Some(false), None, None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            lt_scalar_dyn(&array, ScalarValue::I32(10)).values(),
+            vec![Some(false), Some(true), None, None, Some(false), Some(true)]
+        );
+        assert_eq!(
+            gteq_scalar_dyn(&array, ScalarValue::I32(10)).values(),
+            vec![Some(true), Some(false), None, None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_large_dictionary_compare_scalar() {
+        let device = GPU_DEVICE.clone();
+        let values = Float32ArrayGPU::from_slice(&[0.5, 1.5, 2.5, 3.5], device.clone());
+        let keys = (0..1000).map(|x| (x % 4) as u16).collect::<Vec<_>>();
+        let array = DictionaryArrayGPU::new(
+            UInt16ArrayGPU::from_slice(&keys, device),
+            Arc::new(values.into()),
+        );
+        let expected = keys.iter().map(|x| *x >= 2).collect::<Vec<_>>();
+        assert_eq!(
+            array.gt_scalar(ScalarValue::F32(2.0)).raw_values().unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_string_dictionary_compare_scalar() {
+        let device = GPU_DEVICE.clone();
+        let values = StringArrayGPU::from_optional_slice(
+            &[Some("pear"), None, Some("apple"), Some("peach")],
+            device.clone(),
+        );
+        let keys = UInt16ArrayGPU::from_optional_slice(
+            &[Some(0), Some(2), Some(1), None, Some(3), Some(0)],
+            device,
+        );
+        let array = DictionaryArrayGPU::new(keys, Arc::new(values.into()));
+        let value = || ScalarValue::Utf8("peach".into());
+
+        assert_eq!(
+            array.gt_scalar(value()).values(),
+            vec![Some(true), Some(false), None, None, Some(false), Some(true)]
+        );
+        assert_eq!(
+            array.lteq_scalar(value()).values(),
+            vec![Some(false), Some(true), None, None, Some(true), Some(false)]
+        );
+
+        let array = array.into();
+        assert_eq!(
+            eq_scalar_dyn(&array, value()).values(),
+            vec![
+                Some(false),
+                Some(false),
+                None,
+                None,
+                Some(true),
+                Some(false)
+            ]
+        );
+        assert_eq!(
+            lt_scalar_dyn(&array, ScalarValue::Utf8("pear".into())).values(),
+            vec![Some(false), Some(true), None, None, Some(true), Some(false)]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::*;
+use arrow_gpu_array::array::{Float16ArrayGPU, Float16Shader};
+
+const F16_COMPARE_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f16/cmp.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f16/cmp.wgsl")
+    ),
+};
+const F16_MIN_MAX_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f16/min_max.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f16/min_max.wgsl")
+    ),
+};
+
+fn compare_op(
+    left: &Float16ArrayGPU,
+    right: &Float16ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> BooleanArrayGPU {
+    let new_buffer = pipeline.apply_binary_function(
+        &left.data,
+        &right.data,
+        (left.len.div_ceil(32) * 4) as u64,
+        F16_COMPARE_SHADER.select(&pipeline.device),
+        entry_point,
+        left.len.div_ceil(256) as u32,
+    );
+    let null_buffer =
+        NullBitBufferGpu::merge_null_bit_buffer_op(&left.null_buffer, &right.null_buffer, pipeline);
+
+    BooleanArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: left.gpu_device.clone(),
+        len: left.len,
+        null_buffer,
+    }
+}
+
+fn min_max_op(
+    left: &Float16ArrayGPU,
+    right: &Float16ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float16ArrayGPU {
+    let new_buffer = pipeline.apply_binary_function(
+        &left.data,
+        &right.data,
+        left.data.size(),
+        F16_MIN_MAX_SHADER.select(&pipeline.device),
+        entry_point,
+        left.len.div_ceil(2).div_ceil(256) as u32,
+    );
+    let null_buffer =
+        NullBitBufferGpu::merge_null_bit_buffer_op(&left.null_buffer, &right.null_buffer, pipeline);
+
+    Float16ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: left.gpu_device.clone(),
+        len: left.len,
+        phantom: std::marker::PhantomData,
+        null_buffer,
+    }
+}
+
+impl Compare for Float16ArrayGPU {
+    fn gt_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        compare_op(self, operand, GT_ENTRY_POINT, pipeline)
+    }
+
+    fn gteq_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        compare_op(self, operand, GTEQ_ENTRY_POINT, pipeline)
+    }
+
+    fn lt_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        compare_op(self, operand, LT_ENTRY_POINT, pipeline)
+    }
+
+    fn lteq_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        compare_op(self, operand, LTEQ_ENTRY_POINT, pipeline)
+    }
+
+    fn eq_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        compare_op(self, operand, EQ_ENTRY_POINT, pipeline)
+    }
+}
+
+impl MinMax for Float16ArrayGPU {
+    fn max_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> Self {
+        min_max_op(self, operand, MAX_ENTRY_POINT, pipeline)
+    }
+
+    fn min_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> Self {
+        min_max_op(self, operand, MIN_ENTRY_POINT, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::f16;
+
+    fn f16_vec(values: &[f32]) -> Vec<f16> {
+        values.iter().map(|x| f16::from_f32(*x)).collect()
+    }
+
+    #[test]
+    fn test_f16_compare() {
+        let device = GPU_DEVICE.clone();
+        let values: Vec<f32> = (0..300).map(|x| x as f32 * 0.5 - 50.0).collect();
+        let left = Float16ArrayGPU::from_slice(&f16_vec(&values), device.clone());
+        let right = Float16ArrayGPU::from_slice(&f16_vec(&vec![10.0; 300]), device.clone());
+
+        let expected: Vec<Option<bool>> = values.iter().map(|x| Some(*x > 10.0)).collect();
+        assert_eq!(left.gt(&right).values(), expected);
+        let expected: Vec<Option<bool>> = values.iter().map(|x| Some(*x == 10.0)).collect();
+        assert_eq!(eq_dyn(&left.into(), &right.into()).values(), expected);
+
+        let left = Float16ArrayGPU::from_optional_slice(
+            &[Some(f16::from_f32(1.0)), None, Some(f16::NEG_INFINITY)],
+            device.clone(),
+        );
+        let right = Float16ArrayGPU::from_slice(&f16_vec(&[1.0, 2.0, -1.0]), device);
+        assert_eq!(
+            left.lteq(&right).values(),
+            vec![Some(true), None, Some(true)]
+        );
+        assert_eq!(
+            left.max(&right).values(),
+            vec![Some(f16::from_f32(1.0)), None, Some(f16::from_f32(-1.0))]
+        );
+        let min: Float16ArrayGPU = min_dyn(&left.into(), &right.into()).try_into().unwrap();
+        assert_eq!(min.raw_values().unwrap()[2], f16::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::*;
+
+const I128_COMPARE_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i128/utils.wgsl"),
+    include_str!("../compute_shaders/i128/cmp.wgsl")
+);
+const I128_MIN_MAX_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i128/utils.wgsl"),
+    include_str!("../compute_shaders/i128/min_max.wgsl")
+);
+
+impl CompareType for i128 {
+    const COMPARE_SHADER: &'static str = I128_COMPARE_SHADER;
+    const MIN_MAX_SHADER: &'static str = I128_MIN_MAX_SHADER;
+}
@@ -0,0 +1,199 @@
+use crate::*;
+
+const I64_COMPARE_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i64/utils.wgsl"),
+    include_str!("../compute_shaders/i64/cmp.wgsl")
+);
+const I64_MIN_MAX_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i64/utils.wgsl"),
+    include_str!("../compute_shaders/i64/min_max.wgsl")
+);
+
+impl CompareType for i64 {
+    const COMPARE_SHADER: &'static str = I64_COMPARE_SHADER;
+    const MIN_MAX_SHADER: &'static str = I64_MIN_MAX_SHADER;
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_gpu_array::array::*;
+    use arrow_gpu_test_macros::test_array_op;
+
+    use crate::*;
+
+    test_array_op!(
+        test_gt_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        BooleanArrayGPU,
+        gt,
+        gt_dyn,
+        [
+            Some(-1i64),
+            Some(1 << 32),
+            Some(i64::MIN),
+            Some(-5),
+            None,
+            Some(i64::MAX)
+        ],
+        [
+            Some(0i64),
+            Some(-(1 << 32)),
+            Some(i64::MIN),
+            Some(-(1 << 33)),
+            Some(4),
+            Some(-1)
+        ],
+        [
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true)
+        ]
+    );
+
+    test_array_op!(
+        test_gteq_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        BooleanArrayGPU,
+        gteq,
+        gteq_dyn,
+        [
+            Some(-1i64),
+            Some(1 << 32),
+            Some(i64::MIN),
+            Some(-5),
+            None,
+            Some(i64::MAX)
+        ],
+        [
+            Some(0i64),
+            Some(-(1 << 32)),
+            Some(i64::MIN),
+            Some(-(1 << 33)),
+            Some(4),
+            Some(-1)
+        ],
+        [
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(true)
+        ]
+    );
+
+    test_array_op!(
+        test_lt_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        BooleanArrayGPU,
+        lt,
+        lt_dyn,
+        [
+            Some(-1i64),
+            Some(1 << 32),
+            Some(i64::MIN),
+            Some(-5),
+            None,
+            Some(i64::MAX)
+        ],
+        [
+            Some(0i64),
+            Some(-(1 << 32)),
+            Some(i64::MIN),
+            Some(-(1 << 33)),
+            Some(4),
+            Some(-1)
+        ],
+        [
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            Some(false)
+        ]
+    );
+
+    test_array_op!(
+        test_lteq_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        BooleanArrayGPU,
+        lteq,
+        lteq_dyn,
+        [
+            Some(-1i64),
+            Some(1 << 32),
+            Some(i64::MIN),
+            Some(-5),
+            None,
+            Some(i64::MAX)
+        ],
+        [
+            Some(0i64),
+            Some(-(1 << 32)),
+            Some(i64::MIN),
+            Some(-(1 << 33)),
+            Some(4),
+            Some(-1)
+        ],
+        [
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(false)
+        ]
+    );
+
+    test_array_op!(
+        test_eq_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        BooleanArrayGPU,
+        eq,
+        eq_dyn,
+        [Some(-1i64), Some(1 << 32), Some(i64::MIN), Some(-5), None],
+        [
+            Some(-1i64),
+            Some(-(1 << 32)),
+            Some(i64::MIN),
+            Some(5),
+            Some(4)
+        ],
+        [Some(true), Some(false), Some(true), Some(false), None]
+    );
+
+    test_array_op!(
+        test_min_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        min,
+        min_dyn,
+        [Some(-1i64), Some(1 << 32), Some(3), Some(0), None, None],
+        [Some(1i64), Some(-(1 << 32)), Some(-3), None, Some(4), None],
+        [Some(-1i64), Some(-(1 << 32)), Some(-3), None, None, None]
+    );
+
+    test_array_op!(
+        test_max_i64_array_i64,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        Int64ArrayGPU,
+        max,
+        max_dyn,
+        [Some(-1i64), Some(1 << 32), Some(3), Some(0), None, None],
+        [Some(1i64), Some(-(1 << 32)), Some(-3), None, Some(4), None],
+        [Some(1i64), Some(1 << 32), Some(3), None, None, None]
+    );
+}
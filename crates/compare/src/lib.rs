@@ -1,19 +1,30 @@
-use std::sync::Arc;
-
 use arrow_gpu_array::array::{
     ArrayUtils, ArrowArrayGPU, ArrowPrimitiveType, BooleanArrayGPU, NullBitBufferGpu,
     PrimitiveArrayGpu,
 };
 use arrow_gpu_array::gpu_utils::*;
 
+pub(crate) mod arg_min_max;
+pub(crate) mod decimal;
+pub(crate) mod dictionary;
+pub(crate) mod f16;
 pub(crate) mod f32;
+pub(crate) mod i128;
 pub(crate) mod i16;
 pub(crate) mod i32;
+pub(crate) mod i64;
 pub(crate) mod i8;
+pub(crate) mod temporal;
+pub(crate) mod top_k;
 pub(crate) mod u16;
 pub(crate) mod u32;
+pub(crate) mod u64;
 pub(crate) mod u8;
 
+pub use arg_min_max::*;
+pub use dictionary::*;
+pub use top_k::*;
+
 const GT_ENTRY_POINT: &str = "gt";
 const GTEQ_ENTRY_POINT: &str = "gteq";
 const LT_ENTRY_POINT: &str = "lt";
@@ -102,7 +113,7 @@ macro_rules! apply_function {
         );
 
         return BooleanArrayGPU {
-            data: Arc::new(new_buffer),
+            data: new_buffer.into(),
             gpu_device: $self.gpu_device.clone(),
             len: $self.len,
             null_buffer,
@@ -202,6 +213,7 @@ dyn_fn!(
     gt,
     gt_op_dyn,
     gt_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -209,7 +221,13 @@ dyn_fn!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 dyn_fn!(
@@ -218,6 +236,7 @@ dyn_fn!(
     gteq,
     gteq_op_dyn,
     gteq_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -225,7 +244,13 @@ dyn_fn!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 dyn_fn!(
@@ -234,6 +259,7 @@ dyn_fn!(
     lt,
     lt_op_dyn,
     lt_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -241,7 +267,13 @@ dyn_fn!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 dyn_fn!(
@@ -250,6 +282,7 @@ dyn_fn!(
     lteq,
     lteq_op_dyn,
     lteq_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -257,7 +290,13 @@ dyn_fn!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 dyn_fn!(
@@ -266,6 +305,7 @@ dyn_fn!(
     eq,
     eq_op_dyn,
     eq_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -273,7 +313,13 @@ dyn_fn!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 macro_rules! dyn_minmax {
@@ -307,6 +353,7 @@ dyn_minmax!(
     max,
     max_op_dyn,
     max_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -314,7 +361,13 @@ dyn_minmax!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
 
 dyn_minmax!(
@@ -323,6 +376,7 @@ dyn_minmax!(
     min,
     min_op_dyn,
     min_op,
+    Float16ArrayGPU,
     Float32ArrayGPU,
     UInt32ArrayGPU,
     UInt16ArrayGPU,
@@ -330,5 +384,11 @@ dyn_minmax!(
     Int32ArrayGPU,
     Int16ArrayGPU,
     Int8ArrayGPU,
-    Date32ArrayGPU
+    UInt64ArrayGPU,
+    Int64ArrayGPU,
+    Date32ArrayGPU,
+    TimestampArrayGPU,
+    DurationArrayGPU,
+    Time32ArrayGPU,
+    Decimal128ArrayGPU
 );
@@ -0,0 +1,153 @@
+use crate::*;
+use arrow_gpu_array::array::{DurationArrayGPU, Time32ArrayGPU, TimestampArrayGPU};
+use arrow_gpu_cast::with_finer_unit_op;
+
+// Temporal arrays are compared on their underlying integer values,
+// after converting the side with the coarser unit to the finer one.
+macro_rules! impl_temporal_compare {
+    ($ty: ident) => {
+        impl Compare for $ty {
+            fn gt_op(
+                &self,
+                operand: &Self,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    left.values.gt_op(&right.values, pipeline)
+                })
+            }
+
+            fn gteq_op(
+                &self,
+                operand: &Self,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    left.values.gteq_op(&right.values, pipeline)
+                })
+            }
+
+            fn lt_op(
+                &self,
+                operand: &Self,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    left.values.lt_op(&right.values, pipeline)
+                })
+            }
+
+            fn lteq_op(
+                &self,
+                operand: &Self,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    left.values.lteq_op(&right.values, pipeline)
+                })
+            }
+
+            fn eq_op(
+                &self,
+                operand: &Self,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    left.values.eq_op(&right.values, pipeline)
+                })
+            }
+        }
+
+        impl MinMax for $ty {
+            fn max_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> Self {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    let mut result = left.clone_array();
+                    result.values = left.values.max_op(&right.values, pipeline);
+                    result
+                })
+            }
+
+            fn min_op(&self, operand: &Self, pipeline: &mut ArrowComputePipeline) -> Self {
+                with_finer_unit_op(self, operand, pipeline, |left, right, _, pipeline| {
+                    let mut result = left.clone_array();
+                    result.values = left.values.min_op(&right.values, pipeline);
+                    result
+                })
+            }
+        }
+    };
+}
+
+impl_temporal_compare!(TimestampArrayGPU);
+impl_temporal_compare!(DurationArrayGPU);
+impl_temporal_compare!(Time32ArrayGPU);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::TimeUnit;
+
+    #[test]
+    fn test_timestamp_compare() {
+        let device = GPU_DEVICE.clone();
+        let left = TimestampArrayGPU::from_optional_slice(
+            &[Some(-5), Some(1 << 40), None, Some(7)],
+            TimeUnit::Microsecond,
+            None,
+            device.clone(),
+        );
+        let right = TimestampArrayGPU::from_slice(
+            &[-6, 1 << 41, 0, 7],
+            TimeUnit::Microsecond,
+            None,
+            device,
+        );
+        assert_eq!(
+            left.gt(&right).values(),
+            vec![Some(true), Some(false), None, Some(false)]
+        );
+        assert_eq!(
+            eq_dyn(&left.clone_array().into(), &right.clone_array().into()).values(),
+            vec![Some(false), Some(false), None, Some(true)]
+        );
+
+        let max = max_dyn(&left.into(), &right.into());
+        assert_eq!(
+            max.get_dtype(),
+            arrow_gpu_array::array::ArrowType::TimestampType(TimeUnit::Microsecond, None)
+        );
+        let max: TimestampArrayGPU = max.try_into().unwrap();
+        assert_eq!(max.values(), vec![Some(-5), Some(1 << 41), None, Some(7)]);
+    }
+
+    #[test]
+    fn test_time32_min() {
+        let device = GPU_DEVICE.clone();
+        let left = Time32ArrayGPU::from_slice(&[10, 3600], TimeUnit::Second, device.clone());
+        let right = Time32ArrayGPU::from_slice(&[20, 60], TimeUnit::Second, device);
+        let min = left.min(&right);
+        assert_eq!(min.unit, TimeUnit::Second);
+        assert_eq!(min.raw_values().unwrap(), vec![10, 60]);
+    }
+
+    #[test]
+    fn test_duration_compare_units() {
+        let device = GPU_DEVICE.clone();
+        let left = DurationArrayGPU::from_slice(&[1, 2, -3], TimeUnit::Second, device.clone());
+        let right =
+            DurationArrayGPU::from_slice(&[1_500, 2_000, -2_999], TimeUnit::Millisecond, device);
+        assert_eq!(
+            left.lt(&right).values(),
+            vec![Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(
+            left.eq(&right).values(),
+            vec![Some(false), Some(true), Some(false)]
+        );
+
+        let max = left.max(&right);
+        assert_eq!(max.unit, TimeUnit::Millisecond);
+        assert_eq!(max.values(), vec![Some(1_500), Some(2_000), Some(-2_999)]);
+    }
+}
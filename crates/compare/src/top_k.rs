@@ -0,0 +1,179 @@
+use arrow_gpu_array::array::{ArrayUtils, ArrowArrayGPU, PrimitiveArrayGpu, UInt32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_routines::{FilterType, SortType, take_packed_op, top_k_indices_op};
+
+/// Trait for selecting the largest or smallest elements of ArrowArrays
+pub trait TopK: ArrayUtils + Sized {
+    fn top_k(&self, k: usize, descending: bool) -> (Self, UInt32ArrayGPU) {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("top_k"));
+        let result = self.top_k_op(k, descending, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Returns the `k` largest values when descending, else the `k` smallest ones,
+    /// in sorted order along with their indexes in self.
+    /// Null values are skipped, so fewer than `k` values are returned when self
+    /// holds fewer valid values.
+    fn top_k_op(
+        &self,
+        k: usize,
+        descending: bool,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> (Self, UInt32ArrayGPU);
+}
+
+impl<T: SortType + FilterType> TopK for PrimitiveArrayGpu<T> {
+    fn top_k_op(
+        &self,
+        k: usize,
+        descending: bool,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> (Self, UInt32ArrayGPU) {
+        let indexes = top_k_indices_op(self, k, descending, pipeline);
+        let mut values = take_packed_op(self, &indexes, pipeline);
+        values.null_buffer = None;
+        (values, indexes)
+    }
+}
+
+/// Selects the `k` largest values of data when descending, else the `k` smallest ones
+pub fn top_k_dyn(
+    data: &ArrowArrayGPU,
+    k: usize,
+    descending: bool,
+) -> (ArrowArrayGPU, UInt32ArrayGPU) {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("top_k"));
+    let result = top_k_op_dyn(data, k, descending, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! top_k_op_dyn_arms {
+    ($data: ident, $k: ident, $descending: ident, $pipeline: ident, $($arr: ident),*) => {
+        match $data {
+            $(ArrowArrayGPU::$arr(array) => {
+                let (values, indexes) = array.top_k_op($k, $descending, $pipeline);
+                (values.into(), indexes)
+            })*
+            _ => panic!("Operation top_k not supported for type {:?}", $data.get_dtype()),
+        }
+    };
+}
+
+/// Submits commands to select the `k` largest values of data when descending,
+/// else the `k` smallest ones
+pub fn top_k_op_dyn(
+    data: &ArrowArrayGPU,
+    k: usize,
+    descending: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> (ArrowArrayGPU, UInt32ArrayGPU) {
+    top_k_op_dyn_arms!(
+        data,
+        k,
+        descending,
+        pipeline,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::*;
+
+    use super::*;
+
+    // Expected values and indexes of the top k, by a stable sort on the cpu
+    fn cpu_top_k<V: Copy + PartialOrd>(
+        values: &[Option<V>],
+        k: usize,
+        descending: bool,
+    ) -> (Vec<Option<V>>, Vec<Option<u32>>) {
+        let mut rows = values
+            .iter()
+            .enumerate()
+            .filter_map(|(row, value)| value.map(|value| (row, value)))
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| {
+            let ordering = a.1.partial_cmp(&b.1).unwrap();
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        rows.truncate(k);
+        (
+            rows.iter().map(|(_, value)| Some(*value)).collect(),
+            rows.iter().map(|(row, _)| Some(*row as u32)).collect(),
+        )
+    }
+
+    #[test]
+    fn test_top_k_i32() {
+        let values = (0..3000)
+            .map(|x: i32| (x % 7 != 0).then_some((x * 7919) % 1000 - 500))
+            .collect::<Vec<_>>();
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        for k in [0, 1, 5, 100, 2000, 5000] {
+            for descending in [true, false] {
+                let (top_values, indexes) = array.top_k(k, descending);
+                let (expected_values, expected_indexes) = cpu_top_k(&values, k, descending);
+                assert_eq!(top_values.values(), expected_values);
+                assert_eq!(indexes.values(), expected_indexes);
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_k_ties() {
+        // the rows equal to the k-th value are taken in row order
+        let raw_values = (0..70_000).map(|x: u32| x % 100).collect::<Vec<_>>();
+        let values = raw_values.iter().copied().map(Some).collect::<Vec<_>>();
+        let array = UInt32ArrayGPU::from_slice(&raw_values, GPU_DEVICE.clone());
+        for (k, descending) in [(1000, false), (1234, true)] {
+            let (top_values, indexes) = array.top_k(k, descending);
+            let (expected_values, expected_indexes) = cpu_top_k(&values, k, descending);
+            assert_eq!(top_values.values(), expected_values);
+            assert_eq!(indexes.values(), expected_indexes);
+        }
+    }
+
+    #[test]
+    fn test_top_k_f64() {
+        let values = (0..1000)
+            .map(|x| (x % 11 != 3).then_some(((x * 37) % 101) as f64 / 4.0 - 10.0))
+            .collect::<Vec<_>>();
+        let array = Float64ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let (top_values, indexes) = array.top_k(10, true);
+        let (expected_values, expected_indexes) = cpu_top_k(&values, 10, true);
+        assert_eq!(top_values.values(), expected_values);
+        assert_eq!(indexes.values(), expected_indexes);
+    }
+
+    #[test]
+    fn test_top_k_dyn_u8() {
+        let values = (0..500)
+            .map(|x: u32| (!x.is_multiple_of(5)).then_some(((x * 13) % 256) as u8))
+            .collect::<Vec<_>>();
+        let array: ArrowArrayGPU =
+            UInt8ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone()).into();
+        let (top_values, indexes) = top_k_dyn(&array, 20, false);
+        let top_values: UInt8ArrayGPU = top_values.try_into().unwrap();
+        let (expected_values, expected_indexes) = cpu_top_k(&values, 20, false);
+        assert_eq!(top_values.values(), expected_values);
+        assert_eq!(indexes.values(), expected_indexes);
+    }
+}
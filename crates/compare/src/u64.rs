@@ -0,0 +1,237 @@
+use crate::*;
+
+const U64_COMPARE_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../compute_shaders/u64/cmp.wgsl")
+);
+const U64_MIN_MAX_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../compute_shaders/u64/min_max.wgsl")
+);
+
+impl CompareType for u64 {
+    const COMPARE_SHADER: &'static str = U64_COMPARE_SHADER;
+    const MIN_MAX_SHADER: &'static str = U64_MIN_MAX_SHADER;
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_gpu_array::array::*;
+    use arrow_gpu_test_macros::test_array_op;
+
+    use crate::*;
+
+    test_array_op!(
+        test_gt_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        gt,
+        gt_dyn,
+        [
+            Some(0u64),
+            Some(1 << 32),
+            Some(u64::MAX),
+            Some(5),
+            None,
+            Some(u32::MAX as u64)
+        ],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(u64::MAX),
+            Some(1 << 33),
+            Some(4),
+            Some(1)
+        ],
+        [
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            None,
+            Some(true)
+        ]
+    );
+
+    test_array_op!(
+        test_gteq_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        gteq,
+        gteq_dyn,
+        [
+            Some(0u64),
+            Some(1 << 32),
+            Some(u64::MAX),
+            Some(5),
+            None,
+            Some(u32::MAX as u64)
+        ],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(u64::MAX),
+            Some(1 << 33),
+            Some(4),
+            Some(1)
+        ],
+        [
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true)
+        ]
+    );
+
+    test_array_op!(
+        test_lt_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        lt,
+        lt_dyn,
+        [
+            Some(0u64),
+            Some(1 << 32),
+            Some(u64::MAX),
+            Some(5),
+            None,
+            Some(u32::MAX as u64)
+        ],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(u64::MAX),
+            Some(1 << 33),
+            Some(4),
+            Some(1)
+        ],
+        [
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true),
+            None,
+            Some(false)
+        ]
+    );
+
+    test_array_op!(
+        test_lteq_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        lteq,
+        lteq_dyn,
+        [
+            Some(0u64),
+            Some(1 << 32),
+            Some(u64::MAX),
+            Some(5),
+            None,
+            Some(u32::MAX as u64)
+        ],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(u64::MAX),
+            Some(1 << 33),
+            Some(4),
+            Some(1)
+        ],
+        [
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(false)
+        ]
+    );
+
+    test_array_op!(
+        test_eq_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        eq,
+        eq_dyn,
+        [
+            Some(0u64),
+            Some(1 << 32),
+            Some(u64::MAX),
+            Some(5),
+            None,
+            Some(1)
+        ],
+        [
+            Some(1u64),
+            Some(1),
+            Some(u64::MAX),
+            Some(5 + (1 << 32)),
+            Some(4),
+            Some(1)
+        ],
+        [
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(true)
+        ]
+    );
+
+    test_array_op!(
+        test_all_gt_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        gt,
+        [Some(1u64 << 40); 100],
+        [Some(1u64); 100],
+        [Some(true); 100]
+    );
+
+    test_array_op!(
+        test_min_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        min,
+        min_dyn,
+        [Some(0u64), Some(1 << 32), Some(3), Some(0), None, None],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(3),
+            None,
+            Some(4),
+            None
+        ],
+        [Some(0u64), Some(u32::MAX as u64), Some(3), None, None, None]
+    );
+
+    test_array_op!(
+        test_max_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        max,
+        max_dyn,
+        [Some(0u64), Some(1 << 32), Some(3), Some(0), None, None],
+        [
+            Some(1u64),
+            Some(u32::MAX as u64),
+            Some(3),
+            None,
+            Some(4),
+            None
+        ],
+        [Some(1u64), Some(1 << 32), Some(3), None, None, None]
+    );
+}
@@ -271,4 +271,18 @@ mod test {
             Some(true)
         ]
     );
+
+    #[test]
+    fn test_gt_sliced_u8_arrays() {
+        let device = arrow_gpu_array::GPU_DEVICE.clone();
+        let left_values = (0..50).map(|x| x % 17).collect::<Vec<u8>>();
+        let right_values = (0..50).map(|x| x % 13).collect::<Vec<u8>>();
+        let left = UInt8ArrayGPU::from_slice(&left_values, device.clone()).slice(5, 33);
+        let right = UInt8ArrayGPU::from_slice(&right_values, device).slice(10, 33);
+
+        let expected = (0..33)
+            .map(|i| left_values[5 + i] > right_values[10 + i])
+            .collect::<Vec<_>>();
+        assert_eq!(left.gt(&right).raw_values().unwrap(), expected);
+    }
 }
@@ -0,0 +1,70 @@
+// `T` is declared by the caller with an alias, e.g. `alias T = f32;`
+
+@group(0)
+@binding(0)
+var<storage, read> offsets: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> values: array<T>;
+
+@group(0)
+@binding(2)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_values: array<T>;
+
+fn is_valid(index: u32) -> bool {
+    return ((validity[index / 32u] >> (index % 32u)) & 1u) == 1u;
+}
+
+@compute
+@workgroup_size(256)
+fn list_sum(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&new_values) {
+        var acc = T(0);
+        for (var i = offsets[row]; i < offsets[row + 1u]; i++) {
+            if is_valid(i) {
+                acc += values[i];
+            }
+        }
+        new_values[row] = acc;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn list_min(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&new_values) {
+        var acc = T(0);
+        var found = false;
+        for (var i = offsets[row]; i < offsets[row + 1u]; i++) {
+            if is_valid(i) && (!found || values[i] < acc) {
+                acc = values[i];
+                found = true;
+            }
+        }
+        new_values[row] = acc;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn list_max(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&new_values) {
+        var acc = T(0);
+        var found = false;
+        for (var i = offsets[row]; i < offsets[row + 1u]; i++) {
+            if is_valid(i) && (!found || values[i] > acc) {
+                acc = values[i];
+                found = true;
+            }
+        }
+        new_values[row] = acc;
+    }
+}
@@ -0,0 +1,44 @@
+@group(0)
+@binding(0)
+var<storage, read> offsets: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read> list_validity: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_validity: array<u32>;
+
+var<workgroup> local_set_bits: array<atomic<u32>, 8>;
+
+fn is_set(bits: u32, index: u32) -> bool {
+    return ((bits >> (index % 32u)) & 1u) == 1u;
+}
+
+// A reduced value is valid when the list is valid and holds at least one valid element
+@compute
+@workgroup_size(256)
+fn reduce_validity(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    let row = global_id.x;
+    var value = false;
+    if row + 1u < arrayLength(&offsets) && is_set(list_validity[row / 32u], row) {
+        for (var i = offsets[row]; i < offsets[row + 1u]; i++) {
+            if is_set(validity[i / 32u], i) {
+                value = true;
+                break;
+            }
+        }
+    }
+    if value {
+        atomicOr(&local_set_bits[local_id.x / 32u], 1u << (local_id.x % 32u));
+    }
+    workgroupBarrier();
+    if row % 32u == 0u && row / 32u < arrayLength(&new_validity) {
+        new_validity[row / 32u] = atomicLoad(&local_set_bits[local_id.x / 32u]);
+    }
+}
@@ -0,0 +1,120 @@
+use arrow_gpu_array::array::{ArrayUtils, ArrowArrayGPU, Int32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+
+pub(crate) mod list;
+
+const VALUE_LENGTH_ENTRY_POINT: &str = "value_length";
+const SUM_ENTRY_POINT: &str = "list_sum";
+const MIN_ENTRY_POINT: &str = "list_min";
+const MAX_ENTRY_POINT: &str = "list_max";
+const REDUCE_VALIDITY_ENTRY_POINT: &str = "reduce_validity";
+
+macro_rules! default_impl {
+    ($self: ident, $fn: ident) => {
+        let mut pipeline = ArrowComputePipeline::new($self.get_gpu_device(), None);
+        let output = Self::$fn(&$self, &mut pipeline);
+        pipeline.finish();
+        return output;
+    };
+}
+
+/// Trait for functions on each list of the array
+pub trait ListUnary: ArrayUtils {
+    fn list_value_length(&self) -> Int32ArrayGPU {
+        default_impl!(self, list_value_length_op);
+    }
+    fn flatten(&self) -> ArrowArrayGPU {
+        default_impl!(self, flatten_op);
+    }
+
+    /// Compute the number of elements in each list, null lists stay null
+    fn list_value_length_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Returns the elements of all the lists as a single array
+    fn flatten_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU;
+}
+
+/// Trait for reducing the elements of each list of the array to a single value.
+/// Null elements are skipped, null lists and lists without valid elements reduce to null.
+pub trait ListReduce: ArrayUtils {
+    fn list_sum(&self) -> ArrowArrayGPU {
+        default_impl!(self, list_sum_op);
+    }
+    fn list_min(&self) -> ArrowArrayGPU {
+        default_impl!(self, list_min_op);
+    }
+    fn list_max(&self) -> ArrowArrayGPU {
+        default_impl!(self, list_max_op);
+    }
+
+    /// Compute the sum of the elements of each list
+    fn list_sum_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU;
+    /// Compute the minimum of the elements of each list
+    fn list_min_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU;
+    /// Compute the maximum of the elements of each list
+    fn list_max_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU;
+}
+
+macro_rules! dyn_list_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident, $($arr:ident),* ]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU) -> ArrowArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+                match data {
+                    $(ArrowArrayGPU::$arr(arr_1) => arr_1.$array_op(pipeline).into(),)*
+                    _ => panic!("Operation {} not supported for type {:?}", stringify!($dyn_op), data.get_dtype())
+                }
+            }
+        )+
+    }
+}
+
+dyn_list_fn!(
+    [
+        list_value_length_dyn,
+        "Compute the number of elements in each list",
+        list_value_length_op_dyn,
+        list_value_length_op,
+        ListArrayGPU,
+        FixedSizeListArrayGPU
+    ],
+    [
+        flatten_dyn,
+        "Return the elements of all the lists as a single array",
+        flatten_op_dyn,
+        flatten_op,
+        ListArrayGPU,
+        FixedSizeListArrayGPU
+    ],
+    [
+        list_sum_dyn,
+        "Compute the sum of the elements of each list",
+        list_sum_op_dyn,
+        list_sum_op,
+        ListArrayGPU,
+        FixedSizeListArrayGPU
+    ],
+    [
+        list_min_dyn,
+        "Compute the minimum of the elements of each list",
+        list_min_op_dyn,
+        list_min_op,
+        ListArrayGPU,
+        FixedSizeListArrayGPU
+    ],
+    [
+        list_max_dyn,
+        "Compute the maximum of the elements of each list",
+        list_max_op_dyn,
+        list_max_op,
+        ListArrayGPU,
+        FixedSizeListArrayGPU
+    ]
+);
@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_routines::fixed_size_list_offsets_op;
+
+use crate::*;
+
+const VALUE_LENGTH_SHADER: &str = include_str!("../compute_shaders/list/value_length.wgsl");
+const F32_REDUCE_SHADER: &str = concat!(
+    "alias T = f32;\n",
+    include_str!("../compute_shaders/list/reduce.wgsl")
+);
+const I32_REDUCE_SHADER: &str = concat!(
+    "alias T = i32;\n",
+    include_str!("../compute_shaders/list/reduce.wgsl")
+);
+const U32_REDUCE_SHADER: &str = concat!(
+    "alias T = u32;\n",
+    include_str!("../compute_shaders/list/reduce.wgsl")
+);
+const REDUCE_VALIDITY_SHADER: &str = include_str!("../compute_shaders/list/reduce_validity.wgsl");
+
+fn value_length_op(
+    offsets: &dyn GpuBufferView,
+    len: usize,
+    null_buffer: &Option<NullBitBufferGpu>,
+    gpu_device: Arc<GpuDevice>,
+    pipeline: &mut ArrowComputePipeline,
+) -> Int32ArrayGPU {
+    let new_buffer = pipeline.apply_unary_function(
+        offsets,
+        len as u64 * 4,
+        VALUE_LENGTH_SHADER,
+        VALUE_LENGTH_ENTRY_POINT,
+        len.div_ceil(256) as u32,
+    );
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(null_buffer, pipeline);
+
+    Int32ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device,
+        phantom: Default::default(),
+        len,
+        null_buffer,
+    }
+}
+
+/// Computes which reduced values are valid, i.e. belong to a valid list holding a valid element
+fn reduce_validity_op(
+    offsets: &dyn GpuBufferView,
+    len: usize,
+    validity: &dyn GpuBufferView,
+    list_null_buffer: &Option<NullBitBufferGpu>,
+    gpu_device: &Arc<GpuDevice>,
+    pipeline: &mut ArrowComputePipeline,
+) -> NullBitBufferGpu {
+    let list_validity = match list_null_buffer {
+        Some(null_buffer) => null_buffer.clone(),
+        None => NullBitBufferGpu::new_set_with_capacity(gpu_device.clone(), len),
+    };
+    let new_validity = pipeline
+        .device
+        .create_empty_buffer(len.div_ceil(32) as u64 * 4);
+    pipeline.apply_function(
+        &[offsets, validity, &list_validity.bit_buffer, &new_validity],
+        REDUCE_VALIDITY_SHADER,
+        REDUCE_VALIDITY_ENTRY_POINT,
+        len.div_ceil(256) as u32,
+    );
+
+    NullBitBufferGpu {
+        bit_buffer: new_validity.into(),
+        len,
+        gpu_device: gpu_device.clone(),
+    }
+}
+
+macro_rules! reduce_op_arms {
+    ($offsets: ident, $len: ident, $values: ident, $list_null_buffer: ident, $entry_point: ident, $pipeline: ident, $([$arr: ident, $shader: ident]),*) => {
+        match $values {
+            $(ArrowArrayGPU::$arr(values) => {
+                let validity = match &values.null_buffer {
+                    Some(null_buffer) => null_buffer.clone(),
+                    None => NullBitBufferGpu::new_set_with_capacity(values.gpu_device.clone(), values.len),
+                };
+                let new_values = $pipeline.device.create_empty_buffer($len as u64 * 4);
+                $pipeline.apply_function(
+                    &[$offsets, &values.data, &validity.bit_buffer, &new_values],
+                    $shader,
+                    $entry_point,
+                    $len.div_ceil(256) as u32,
+                );
+                let null_buffer = reduce_validity_op(
+                    $offsets,
+                    $len,
+                    &validity.bit_buffer,
+                    $list_null_buffer,
+                    &values.gpu_device,
+                    $pipeline,
+                );
+
+                $arr {
+                    data: new_values.into(),
+                    gpu_device: values.gpu_device.clone(),
+                    phantom: Default::default(),
+                    len: $len,
+                    null_buffer: Some(null_buffer),
+                }
+                .into()
+            })*
+            _ => panic!(
+                "Operation {} not supported for lists of {:?}",
+                $entry_point,
+                $values.get_dtype()
+            ),
+        }
+    };
+}
+
+/// Reduces each of the `len` lists described by `offsets` into a single value
+fn reduce_op(
+    offsets: &dyn GpuBufferView,
+    len: usize,
+    values: &ArrowArrayGPU,
+    list_null_buffer: &Option<NullBitBufferGpu>,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    reduce_op_arms!(
+        offsets,
+        len,
+        values,
+        list_null_buffer,
+        entry_point,
+        pipeline,
+        [Float32ArrayGPU, F32_REDUCE_SHADER],
+        [Int32ArrayGPU, I32_REDUCE_SHADER],
+        [UInt32ArrayGPU, U32_REDUCE_SHADER]
+    )
+}
+
+impl ListUnary for ListArrayGPU {
+    fn list_value_length_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        value_length_op(
+            &self.offsets,
+            self.len,
+            &self.null_buffer,
+            self.gpu_device.clone(),
+            pipeline,
+        )
+    }
+
+    // the elements of the lists are a zero-copy slice of the child, only its
+    // first and last offsets are read back
+    fn flatten_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        let bounds = pipeline.device.create_empty_buffer(8);
+        pipeline.copy_buffer_to_buffer(&self.offsets, 0, &bounds, 0, 4);
+        pipeline.copy_buffer_to_buffer(&self.offsets, self.len as u64 * 4, &bounds, 4, 4);
+        let bounds = pipeline.read_buffer(&bounds, 0, 8);
+        let start = u32::from_le_bytes(bounds[0..4].try_into().unwrap()) as usize;
+        let end = u32::from_le_bytes(bounds[4..8].try_into().unwrap()) as usize;
+        self.values.slice(start, end - start)
+    }
+}
+
+impl ListUnary for FixedSizeListArrayGPU {
+    fn list_value_length_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        value_length_op(
+            &offsets,
+            self.len,
+            &self.null_buffer,
+            self.gpu_device.clone(),
+            pipeline,
+        )
+    }
+
+    fn flatten_op(&self, _pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        self.values.clone_array()
+    }
+}
+
+impl ListReduce for ListArrayGPU {
+    fn list_sum_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        reduce_op(
+            &self.offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            SUM_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn list_min_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        reduce_op(
+            &self.offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            MIN_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn list_max_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        reduce_op(
+            &self.offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            MAX_ENTRY_POINT,
+            pipeline,
+        )
+    }
+}
+
+impl ListReduce for FixedSizeListArrayGPU {
+    fn list_sum_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        reduce_op(
+            &offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            SUM_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn list_min_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        reduce_op(
+            &offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            MIN_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn list_max_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        reduce_op(
+            &offsets,
+            self.len,
+            &self.values,
+            &self.null_buffer,
+            MAX_ENTRY_POINT,
+            pipeline,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::utils::ScalarArray;
+
+    fn int_list() -> ListArrayGPU {
+        let device = GPU_DEVICE.clone();
+        let values = Int32ArrayGPU::from_optional_slice(
+            &[Some(4), Some(-2), Some(7), None, Some(1), Some(3), Some(5)],
+            device.clone(),
+        );
+        let mut null_buffer = BooleanBufferBuilder::new_with_capacity(5);
+        (0..4).for_each(|x| null_buffer.set_bit(x));
+        let null_buffer = NullBitBufferGpu::new(device, &null_buffer);
+        ListArrayGPU::new(&[0, 3, 3, 4, 5, 7], values.into(), null_buffer)
+    }
+
+    #[test]
+    fn test_list_value_length() {
+        let array = int_list();
+        assert_eq!(
+            array.list_value_length().values(),
+            vec![Some(3), Some(0), Some(1), Some(1), None]
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_list_value_length() {
+        let values = Float32ArrayGPU::from_slice(&[1.0; 6], GPU_DEVICE.clone());
+        let array = FixedSizeListArrayGPU::new(values.into(), 3, None);
+        assert_eq!(
+            list_value_length_dyn(&array.into()).get_raw_values(),
+            ScalarArray::I32Vec(vec![3, 3])
+        );
+    }
+
+    #[test]
+    fn test_flatten() {
+        let array = int_list();
+        assert_eq!(
+            array.flatten().get_raw_values(),
+            ScalarArray::I32Vec(vec![4, -2, 7, 0, 1, 3, 5])
+        );
+
+        let values = UInt32ArrayGPU::from_slice(&[1, 2, 3, 4, 5, 6, 7], GPU_DEVICE.clone());
+        let array = ListArrayGPU::new(&[1, 3, 6, 7], values.into(), None);
+        assert_eq!(
+            flatten_dyn(&array.slice(0, 2).into()).get_raw_values(),
+            ScalarArray::U32Vec(vec![2, 3, 4, 5, 6])
+        );
+        assert_eq!(
+            array.slice(1, 2).flatten().get_raw_values(),
+            ScalarArray::U32Vec(vec![4, 5, 6, 7])
+        );
+    }
+
+    #[test]
+    fn test_list_reductions() {
+        let array = int_list();
+        let sum: Int32ArrayGPU = array.list_sum().try_into().unwrap();
+        let min: Int32ArrayGPU = array.list_min().try_into().unwrap();
+        let max: Int32ArrayGPU = list_max_dyn(&array.into()).try_into().unwrap();
+
+        assert_eq!(sum.values(), vec![Some(9), None, None, Some(1), None]);
+        assert_eq!(min.values(), vec![Some(-2), None, None, Some(1), None]);
+        assert_eq!(max.values(), vec![Some(7), None, None, Some(1), None]);
+    }
+
+    #[test]
+    fn test_fixed_size_list_reductions() {
+        let values = Float32ArrayGPU::from_slice(&[1.5, -2.0, 0.5, 8.0], GPU_DEVICE.clone());
+        let array: ArrowArrayGPU = FixedSizeListArrayGPU::new(values.into(), 2, None).into();
+
+        assert_eq!(
+            list_sum_dyn(&array).get_raw_values(),
+            ScalarArray::F32Vec(vec![-0.5, 8.5])
+        );
+        assert_eq!(
+            list_min_dyn(&array).get_raw_values(),
+            ScalarArray::F32Vec(vec![-2.0, 0.5])
+        );
+        assert_eq!(
+            list_max_dyn(&array).get_raw_values(),
+            ScalarArray::F32Vec(vec![1.5, 8.0])
+        );
+    }
+}
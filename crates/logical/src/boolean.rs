@@ -34,7 +34,7 @@ macro_rules! apply_binary_function_op {
         );
 
         return Self {
-            data: Arc::new(new_buffer),
+            data: new_buffer.into(),
             gpu_device: $self.gpu_device.clone(),
             len: $self.len,
             null_buffer,
@@ -67,7 +67,7 @@ impl Logical for BooleanArrayGPU {
         );
 
         Self {
-            data: Arc::new(new_buffer),
+            data: new_buffer.into(),
             gpu_device: self.gpu_device.clone(),
             len: self.len,
             null_buffer: NullBitBufferGpu::clone_null_bit_buffer(&self.null_buffer),
@@ -150,6 +150,7 @@ impl LogicalContains for BooleanArrayGPU {
 mod test {
     use super::*;
     use arrow_gpu_test_macros::{test_array_op, test_unary_op};
+    use std::sync::Arc;
 
     test_array_op!(
         test_bitwise_and_bool_array_bool,
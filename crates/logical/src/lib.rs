@@ -6,8 +6,6 @@ pub(crate) mod u16;
 pub(crate) mod u32;
 pub(crate) mod u8;
 
-use std::sync::Arc;
-
 use arrow_gpu_array::array::*;
 use arrow_gpu_array::gpu_utils::*;
 
@@ -0,0 +1,75 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16,
+// each invocation computes the two values packed in a word
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+fn apply(index: u32, value: half2) {
+    new_values[index] = store_half2(value);
+}
+
+fn load(index: u32) -> half2 {
+    return load_half2(original_values[index]);
+}
+
+@compute
+@workgroup_size(256)
+fn abs_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, abs(load(global_id.x)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sqrt_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, sqrt(load(global_id.x)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn cbrt_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = load(global_id.x);
+        apply(global_id.x, sign(value) * pow(abs(value), half2(1.0 / 3.0)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn exp_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, exp(load(global_id.x)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn exp2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, exp2(load(global_id.x)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, log(load(global_id.x)));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        apply(global_id.x, log2(load(global_id.x)));
+    }
+}
@@ -0,0 +1,214 @@
+// Needs compute_shaders/u64/utils.wgsl and compute_shaders/f64/utils.wgsl
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<vec2<u32>>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<vec2<u32>>;
+
+fn ln2_ds() -> vec2<f32> {
+    return vec2<f32>(bitcast<f32>(0x3f317218u), bitcast<f32>(0xb102e308u));
+}
+
+fn log2e_ds() -> vec2<f32> {
+    return vec2<f32>(bitcast<f32>(0x3fb8aa3bu), bitcast<f32>(0x32a57060u));
+}
+
+fn infinity_f32() -> f32 {
+    return bitcast<f32>(0x7f800000u);
+}
+
+fn ds_ldexp(value: vec2<f32>, exponent: i32) -> vec2<f32> {
+    return vec2<f32>(ldexp(value.x, exponent), ldexp(value.y, exponent));
+}
+
+// exp(r) for |r| <= ln(2) / 2, a Taylor series on r / 16 is squared back four times
+fn ds_exp_reduced(r: vec2<f32>) -> vec2<f32> {
+    let s = r * 0.0625;
+    var p = vec2<f32>(1.0, 0.0);
+    for (var i = 9; i > 1; i--) {
+        p = ds_add(vec2<f32>(1.0, 0.0), ds_div(ds_mul(p, s), vec2<f32>(f32(i), 0.0)));
+    }
+    // expm1 keeps the precision of the small terms while squaring
+    var m = ds_mul(s, p);
+    for (var i = 0; i < 4; i++) {
+        m = ds_add(m * 2.0, ds_mul(m, m));
+    }
+    return ds_add(vec2<f32>(1.0, 0.0), m);
+}
+
+// Handles the arguments where exp is infinity, zero or NaN, returns false otherwise
+fn exp_special(value: f32, max: f32, min: f32, result: ptr<function, vec2<f32>>) -> bool {
+    if value > max {
+        *result = vec2<f32>(infinity_f32(), 0.0);
+        return true;
+    }
+    if value < min {
+        *result = vec2<f32>(0.0);
+        return true;
+    }
+    if !is_finite_f32(value) {
+        *result = vec2<f32>(value, 0.0);
+        return true;
+    }
+    return false;
+}
+
+fn ds_exp(value: vec2<f32>) -> vec2<f32> {
+    var result: vec2<f32>;
+    if exp_special(value.x, 88.8, -104.0, &result) {
+        return result;
+    }
+    let k = round(value.x / ln2_ds().x);
+    let r = ds_sub(value, ds_mul(ln2_ds(), vec2<f32>(k, 0.0)));
+    return ds_ldexp(ds_exp_reduced(r), i32(k));
+}
+
+fn ds_exp2(value: vec2<f32>) -> vec2<f32> {
+    var result: vec2<f32>;
+    if exp_special(value.x, 128.0, -150.0, &result) {
+        return result;
+    }
+    let k = round(value.x);
+    let r = ds_mul(ds_sub(value, vec2<f32>(k, 0.0)), ln2_ds());
+    return ds_ldexp(ds_exp_reduced(r), i32(k));
+}
+
+// Handles the arguments where log is not finite, returns false otherwise
+fn log_special(value: f32, result: ptr<function, vec2<f32>>) -> bool {
+    if value < 0.0 {
+        *result = vec2<f32>(nan_f32(), 0.0);
+        return true;
+    }
+    if value == 0.0 {
+        *result = vec2<f32>(-infinity_f32(), 0.0);
+        return true;
+    }
+    if !is_finite_f32(value) {
+        *result = vec2<f32>(value, 0.0);
+        return true;
+    }
+    return false;
+}
+
+// log(m) for m in [0.75, 1.5) with two Newton iterations on exp
+fn ds_log_reduced(m: vec2<f32>) -> vec2<f32> {
+    var y = vec2<f32>(log(m.x), 0.0);
+    for (var i = 0; i < 2; i++) {
+        y = ds_add(y, ds_sub(ds_mul(m, ds_exp(-y)), vec2<f32>(1.0, 0.0)));
+    }
+    return y;
+}
+
+// Splits value into m * 2^e with m in [0.75, 1.5)
+fn ds_split_exponent(value: vec2<f32>, exponent: ptr<function, i32>) -> vec2<f32> {
+    var e = i32((bitcast<u32>(value.x) >> 23u) & 0xffu) - 127;
+    var m = ds_ldexp(value, -e);
+    if m.x < 0.75 {
+        m *= 2.0;
+        e -= 1;
+    } else if m.x >= 1.5 {
+        m *= 0.5;
+        e += 1;
+    }
+    *exponent = e;
+    return m;
+}
+
+fn ds_log(value: vec2<f32>) -> vec2<f32> {
+    var result: vec2<f32>;
+    if log_special(value.x, &result) {
+        return result;
+    }
+    if (bitcast<u32>(value.x) & 0x7f800000u) == 0u {
+        return vec2<f32>(log(value.x), 0.0);
+    }
+    var e: i32;
+    let m = ds_split_exponent(value, &e);
+    return ds_add(ds_mul(ln2_ds(), vec2<f32>(f32(e), 0.0)), ds_log_reduced(m));
+}
+
+fn ds_log2(value: vec2<f32>) -> vec2<f32> {
+    var result: vec2<f32>;
+    if log_special(value.x, &result) {
+        return result;
+    }
+    if (bitcast<u32>(value.x) & 0x7f800000u) == 0u {
+        return vec2<f32>(log2(value.x), 0.0);
+    }
+    var e: i32;
+    let m = ds_split_exponent(value, &e);
+    return ds_add(vec2<f32>(f32(e), 0.0), ds_mul(ds_log_reduced(m), log2e_ds()));
+}
+
+fn ds_cbrt(value: vec2<f32>) -> vec2<f32> {
+    if value.x == 0.0 || !is_finite_f32(value.x) {
+        return vec2<f32>(value.x, 0.0);
+    }
+    var y = vec2<f32>(sign(value.x) * pow(abs(value.x), 1.0 / 3.0), 0.0);
+    for (var i = 0; i < 2; i++) {
+        let square = ds_mul(y, y);
+        let residual = ds_sub(ds_mul(square, y), value);
+        y = ds_sub(y, ds_div(residual, square * 3.0));
+    }
+    return y;
+}
+
+@compute
+@workgroup_size(256)
+fn abs_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let value = original_values[global_id.x];
+        new_values[global_id.x] = vec2<u32>(value.x, value.y & 0x7fffffffu);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sqrt_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_sqrt(f64_to_ds(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn cbrt_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_cbrt(f64_to_ds(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn exp_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_exp(f64_to_ds(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn exp2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_exp2(f64_to_ds(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_log(f64_to_ds(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = ds_to_f64(ds_log2(f64_to_ds(original_values[global_id.x])));
+    }
+}
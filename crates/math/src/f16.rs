@@ -0,0 +1,118 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::*;
+
+const F16_UNARY_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f16/unary.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f16/unary.wgsl")
+    ),
+};
+
+fn apply_f16_unary_op(
+    array: &Float16ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float16ArrayGPU {
+    let new_buffer = pipeline.apply_unary_function(
+        &array.data,
+        array.data.size(),
+        F16_UNARY_SHADER.select(&pipeline.device),
+        entry_point,
+        array.len.div_ceil(2).div_ceil(256) as u32,
+    );
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+
+    Float16ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: std::marker::PhantomData,
+        len: array.len,
+        null_buffer,
+    }
+}
+
+impl MathUnary for Float16ArrayGPU {
+    type OutputType = Float16ArrayGPU;
+
+    fn abs_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, ABS_ENTRY_POINT, pipeline)
+    }
+}
+
+impl FloatMathUnary for Float16ArrayGPU {
+    type OutputType = Float16ArrayGPU;
+
+    fn sqrt_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, SQRT_ENTRY_POINT, pipeline)
+    }
+
+    fn cbrt_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, CBRT_ENTRY_POINT, pipeline)
+    }
+
+    fn exp_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, EXP_ENTRY_POINT, pipeline)
+    }
+
+    fn exp2_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, EXP2_ENTRY_POINT, pipeline)
+    }
+
+    fn log_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, LOG_ENTRY_POINT, pipeline)
+    }
+
+    fn log2_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f16_unary_op(self, LOG2_ENTRY_POINT, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::f16;
+
+    fn f16_vec(values: &[f32]) -> Vec<f16> {
+        values.iter().map(|x| f16::from_f32(*x)).collect()
+    }
+
+    #[test]
+    fn test_f16_math_unary() {
+        let device = GPU_DEVICE.clone();
+        let array = Float16ArrayGPU::from_optional_slice(
+            &[Some(f16::from_f32(-4.0)), None, Some(f16::from_f32(2.5))],
+            device.clone(),
+        );
+        assert_eq!(
+            array.abs().values(),
+            vec![Some(f16::from_f32(4.0)), None, Some(f16::from_f32(2.5))]
+        );
+
+        let array = Float16ArrayGPU::from_slice(&f16_vec(&[4.0, 0.25, 8.0, -27.0, 1.0]), device);
+        let sqrt = sqrt_dyn(&array.clone_array().into());
+        let sqrt: Float16ArrayGPU = sqrt.try_into().unwrap();
+        assert_eq!(
+            sqrt.raw_values().unwrap()[..3],
+            f16_vec(&[2.0, 0.5, 8f32.sqrt()])
+        );
+        assert!(sqrt.raw_values().unwrap()[3].is_nan());
+
+        let cbrt = array.cbrt().raw_values().unwrap();
+        assert!((cbrt[3].to_f32() + 3.0).abs() < 1e-2);
+        assert_eq!(
+            array.log2().raw_values().unwrap()[..3],
+            f16_vec(&[2.0, -2.0, 3.0])
+        );
+        assert_eq!(
+            array.exp().raw_values().unwrap()[4],
+            f16::from_f32(1f32.exp())
+        );
+    }
+}
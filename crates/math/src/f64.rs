@@ -0,0 +1,114 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::*;
+
+const F64_UNARY_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/f64/utils.wgsl"),
+    include_str!("../compute_shaders/f64/unary.wgsl")
+);
+
+fn apply_f64_unary_op(
+    array: &Float64ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float64ArrayGPU {
+    let new_buffer = pipeline.apply_unary_function(
+        &array.data,
+        array.data.size(),
+        F64_UNARY_SHADER,
+        entry_point,
+        array.len.div_ceil(256) as u32,
+    );
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+
+    Float64ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: std::marker::PhantomData,
+        len: array.len,
+        null_buffer,
+    }
+}
+
+impl MathUnary for Float64ArrayGPU {
+    type OutputType = Float64ArrayGPU;
+
+    fn abs_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, ABS_ENTRY_POINT, pipeline)
+    }
+}
+
+/// Computed in double-single precision, exp and log are within about 1e-13 relative error
+impl FloatMathUnary for Float64ArrayGPU {
+    type OutputType = Float64ArrayGPU;
+
+    fn sqrt_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, SQRT_ENTRY_POINT, pipeline)
+    }
+
+    fn cbrt_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, CBRT_ENTRY_POINT, pipeline)
+    }
+
+    fn exp_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, EXP_ENTRY_POINT, pipeline)
+    }
+
+    fn exp2_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, EXP2_ENTRY_POINT, pipeline)
+    }
+
+    fn log_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, LOG_ENTRY_POINT, pipeline)
+    }
+
+    fn log2_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::OutputType {
+        apply_f64_unary_op(self, LOG2_ENTRY_POINT, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    fn assert_close(result: Float64ArrayGPU, values: &[f64], function: fn(f64) -> f64) {
+        for (result, value) in result.raw_values().unwrap().iter().zip(values) {
+            let expected = function(*value);
+            if expected.is_nan() {
+                assert!(result.is_nan());
+            } else if expected.is_finite() {
+                assert!(
+                    (result - expected).abs() <= expected.abs() * 1e-13,
+                    "{value}: {result} != {expected}"
+                );
+            } else {
+                assert_eq!(*result, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f64_math_unary() {
+        let device = GPU_DEVICE.clone();
+        let array =
+            Float64ArrayGPU::from_optional_slice(&[Some(-0.1), None, Some(2.5)], device.clone());
+        assert_eq!(array.abs().values(), vec![Some(0.1), None, Some(2.5)]);
+
+        let values = [2.0, 0.1, 12345.6789, 1e-20, 7.0, 0.0, -3.0, f64::INFINITY];
+        let array = Float64ArrayGPU::from_slice(&values, device);
+        let sqrt: Float64ArrayGPU = sqrt_dyn(&array.clone_array().into()).try_into().unwrap();
+        assert_close(sqrt, &values, f64::sqrt);
+        assert_close(array.cbrt(), &values, f64::cbrt);
+        assert_close(array.log(), &values, f64::ln);
+        assert_close(array.log2(), &values, f64::log2);
+
+        let values = [0.5, -3.25, 10.0, 80.0, -60.0, 3.0, 85.0, -0.001];
+        let array = Float64ArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        assert_close(array.exp(), &values, f64::exp);
+        assert_close(array.exp2(), &values, f64::exp2);
+        assert_eq!(array.exp2().raw_values().unwrap()[5], 8.0);
+    }
+}
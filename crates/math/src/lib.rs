@@ -6,7 +6,9 @@ use arrow_gpu_array::array::{
 };
 use arrow_gpu_array::gpu_utils::*;
 
+pub(crate) mod f16;
 pub(crate) mod f32;
+pub(crate) mod f64;
 pub(crate) mod i32;
 
 const ABS_ENTRY_POINT: &str = "abs_";
@@ -264,7 +266,9 @@ dyn_unary_fn!(
         "Compute abs(x) for each x in array",
         abs_op_dyn,
         abs_op,
+        Float16ArrayGPU,
         Float32ArrayGPU,
+        Float64ArrayGPU,
         Int32ArrayGPU
     ],
     [
@@ -272,42 +276,54 @@ dyn_unary_fn!(
         "Compute square_root(x) for each x in array",
         sqrt_op_dyn,
         sqrt_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ],
     [
         cbrt_dyn,
         "Compute cube_root(x) for each x in array",
         cbrt_op_dyn,
         cbrt_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ],
     [
         exp_dyn,
         "Compute e^x for each x in array",
         exp_op_dyn,
         exp_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ],
     [
         exp2_dyn,
         "Compute 2^x for each x in array",
         exp2_op_dyn,
         exp2_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ],
     [
         log_dyn,
         "Compute log(x) for each x in array",
         log_op_dyn,
         log_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ],
     [
         log2_dyn,
         "Compute log_to_base_2(x) for each x in array",
         log2_op_dyn,
         log2_op,
-        Float32ArrayGPU
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU
     ]
 );
 
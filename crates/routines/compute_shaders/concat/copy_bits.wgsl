@@ -0,0 +1,52 @@
+// ORs the first `bit_len` bits of operand into new_values starting at bit `dst_bit_offset`.
+// Each invocation owns one word of new_values, words shared with the previous array are
+// merged with the bits it already wrote.
+
+struct Params {
+    dst_bit_offset: u32,
+    bit_len: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> operand: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn copy_bits(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    let first_word = params.dst_bit_offset / 32u;
+    let end_bit = params.dst_bit_offset + params.bit_len;
+    if params.bit_len == 0u || first_word + index > (end_bit - 1u) / 32u {
+        return;
+    }
+    let shift = params.dst_bit_offset % 32u;
+    let source_words = arrayLength(&operand);
+
+    var word = 0u;
+    if index < source_words {
+        word = operand[index] << shift;
+    }
+    if shift != 0u && index > 0u && index - 1u < source_words {
+        word |= operand[index - 1u] >> (32u - shift);
+    }
+
+    // keep the bits of the word that fall inside [dst_bit_offset, end_bit)
+    let word_start = (first_word + index) * 32u;
+    var mask = 0xffffffffu;
+    if word_start < params.dst_bit_offset {
+        mask &= 0xffffffffu << shift;
+    }
+    if end_bit - word_start < 32u {
+        mask &= (1u << (end_bit - word_start)) - 1u;
+    }
+    new_values[first_word + index] |= word & mask;
+}
@@ -0,0 +1,29 @@
+// Writes the `count` offsets of one array at `dst_start` of new_offsets,
+// moved so that the first one becomes `base`.
+
+struct Params {
+    dst_start: u32,
+    count: u32,
+    base: i32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> offsets: array<i32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_offsets: array<i32>;
+
+@compute
+@workgroup_size(256)
+fn rebase_offsets(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index < params.count {
+        new_offsets[params.dst_start + index] = offsets[index] - offsets[0] + params.base;
+    }
+}
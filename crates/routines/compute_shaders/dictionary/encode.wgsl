@@ -0,0 +1,93 @@
+struct EncodeParams {
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: EncodeParams;
+
+@group(0)
+@binding(2)
+var<storage, read> validity: array<u32>;
+
+// open addressing hash table holding `row + 1` of rows of distinct values, 0 if empty
+@group(0)
+@binding(3)
+var<storage, read_write> table: array<atomic<u32>>;
+
+// slot of the table holding the value of each row
+@group(0)
+@binding(4)
+var<storage, read_write> slots: array<u32>;
+
+const NONE: u32 = 0xffffffffu;
+
+fn is_valid(row: u32) -> bool {
+    return (validity[row / 32u] & (1u << (row % 32u))) != 0u;
+}
+
+fn hash_word(word: u32, seed: u32) -> u32 {
+    var h = word ^ seed;
+    h ^= h >> 16u;
+    h *= 0x85ebca6bu;
+    h ^= h >> 13u;
+    h *= 0xc2b2ae35u;
+    h ^= h >> 16u;
+    return h;
+}
+
+fn first_slot(value: vec2<u32>) -> u32 {
+    return hash_word(value.x, hash_word(value.y, 0x9e3779b9u)) & (arrayLength(&table) - 1u);
+}
+
+// Linear probing using only exchanges: the entry evicted from a slot is carried
+// on to the next slots, so a slot never becomes empty again and every value stays
+// reachable from its first slot. A value can end up in several slots,
+// `find_slot` settles on the first one of the probe sequence.
+@compute
+@workgroup_size(256)
+fn insert(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len || !is_valid(row) {
+        return;
+    }
+
+    var entry = row + 1u;
+    var slot = first_slot(get_value(row));
+    loop {
+        let evicted = atomicExchange(&table[slot], entry);
+        if evicted == 0u || all(get_value(evicted - 1u) == get_value(entry - 1u)) {
+            return;
+        }
+        entry = evicted;
+        slot = (slot + 1u) & (arrayLength(&table) - 1u);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn find_slot(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len {
+        return;
+    }
+    if !is_valid(row) {
+        slots[row] = NONE;
+        return;
+    }
+
+    let value = get_value(row);
+    var slot = first_slot(value);
+    loop {
+        let entry = atomicLoad(&table[slot]);
+        if entry == 0u {
+            slots[row] = NONE;
+            return;
+        }
+        if all(get_value(entry - 1u) == value) {
+            slots[row] = slot;
+            return;
+        }
+        slot = (slot + 1u) & (arrayLength(&table) - 1u);
+    }
+}
@@ -0,0 +1,45 @@
+@group(0)
+@binding(0)
+var<storage, read> first_rows: array<u32>;
+
+// flags marking the first occurrence of each value for `first_flags`,
+// their exclusive prefix sum, i.e. the position of each value in the dictionary, for `keys_and_rows`
+@group(0)
+@binding(1)
+var<storage, read_write> positions: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> keys: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> dictionary_rows: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn first_flags(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&first_rows) {
+        positions[row] = select(0u, 1u, first_rows[row] == row);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn keys_and_rows(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= arrayLength(&first_rows) {
+        return;
+    }
+
+    let first_row = first_rows[row];
+    if first_row == row {
+        dictionary_rows[positions[row]] = row;
+    }
+    if first_row == 0xffffffffu {
+        keys[row] = 0u;
+    } else {
+        keys[row] = positions[first_row];
+    }
+}
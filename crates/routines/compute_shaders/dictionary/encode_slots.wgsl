@@ -0,0 +1,37 @@
+@group(0)
+@binding(0)
+var<storage, read> slots: array<u32>;
+
+// bitwise not of the first row of each slot, 0 if no row maps to the slot
+@group(0)
+@binding(1)
+var<storage, read_write> slot_first_rows: array<atomic<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> first_rows: array<u32>;
+
+const NONE: u32 = 0xffffffffu;
+
+@compute
+@workgroup_size(256)
+fn slot_first_row(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&slots) && slots[row] != NONE {
+        atomicMax(&slot_first_rows[slots[row]], ~row);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn first_row(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= arrayLength(&slots) {
+        return;
+    }
+    if slots[row] == NONE {
+        first_rows[row] = NONE;
+    } else {
+        first_rows[row] = ~atomicLoad(&slot_first_rows[slots[row]]);
+    }
+}
@@ -0,0 +1,23 @@
+@group(0)
+@binding(0)
+var<storage, read> keys: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> indexes: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn u8_keys(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&indexes) {
+        indexes[global_id.x] = (keys[global_id.x / 4u] >> ((global_id.x % 4u) * 8u)) & 0xffu;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn u16_keys(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&indexes) {
+        indexes[global_id.x] = (keys[global_id.x / 2u] >> ((global_id.x % 2u) * 16u)) & 0xffffu;
+    }
+}
@@ -0,0 +1,7 @@
+@group(0)
+@binding(1)
+var<storage, read> values: array<u32>;
+
+fn get_value(row: u32) -> vec2<u32> {
+    return vec2<u32>(values[row], 0u);
+}
@@ -0,0 +1,7 @@
+@group(0)
+@binding(1)
+var<storage, read> values: array<vec2<u32>>;
+
+fn get_value(row: u32) -> vec2<u32> {
+    return values[row];
+}
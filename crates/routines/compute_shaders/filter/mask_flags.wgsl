@@ -0,0 +1,22 @@
+@group(0)
+@binding(0)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> flags: array<u32>;
+
+// flags a row with 1 when its mask bit is set and valid
+@compute
+@workgroup_size(256)
+fn mask_flags(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&flags) {
+        let bits = mask[row / 32u] & validity[row / 32u];
+        flags[row] = (bits >> (row % 32u)) & 1u;
+    }
+}
@@ -0,0 +1,26 @@
+// exclusive prefix sum of the row flags, holds one more entry than the number of rows
+@group(0)
+@binding(0)
+var<storage, read> prefix_sum: array<u32>;
+
+// holds one entry per row
+@group(0)
+@binding(1)
+var<storage, read_write> indexes: array<u32>;
+
+// a flagged row is the one whose prefix sum increases, it is written at its prefix sum.
+// The entries past the number of flagged rows are set to 0xffffffff.
+@compute
+@workgroup_size(256)
+fn scatter_indexes(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    let len = arrayLength(&prefix_sum) - 1u;
+    if row < len {
+        if prefix_sum[row + 1u] > prefix_sum[row] {
+            indexes[prefix_sum[row]] = row;
+        }
+        if row >= prefix_sum[len] {
+            indexes[row] = 0xffffffffu;
+        }
+    }
+}
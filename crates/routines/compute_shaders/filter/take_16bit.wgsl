@@ -0,0 +1,31 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> indexes: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<u32>;
+
+// each invocation packs the 2 halves of one word of new_values
+@compute
+@workgroup_size(256)
+fn take(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word = global_id.x;
+    if word >= arrayLength(&new_values) {
+        return;
+    }
+    var result = 0u;
+    for (var lane = 0u; lane < 2u; lane++) {
+        let row = word * 2u + lane;
+        if row < arrayLength(&indexes) {
+            let index = indexes[row];
+            let value = (values[index / 2u] >> ((index % 2u) * 16u)) & 0xffffu;
+            result |= value << (lane * 16u);
+        }
+    }
+    new_values[word] = result;
+}
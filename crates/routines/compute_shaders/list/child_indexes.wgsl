@@ -0,0 +1,42 @@
+// offset in the child array of the first element of each new list
+@group(0)
+@binding(0)
+var<storage, read> starts: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> new_offsets: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> child_indexes: array<u32>;
+
+// returns the last row whose new offset is <= index, i.e. the list containing element `index`
+fn find_row(rows: u32, index: u32) -> u32 {
+    var low = 0u;
+    var high = rows;
+    while low < high {
+        let mid = (low + high) / 2u;
+        if new_offsets[mid] <= index {
+            low = mid + 1u;
+        } else {
+            high = mid;
+        }
+    }
+    return low - 1u;
+}
+
+// the entries past the total number of elements are set to 0xffffffff
+@compute
+@workgroup_size(256)
+fn child_indexes_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let rows = arrayLength(&new_offsets) - 1u;
+    if global_id.x < arrayLength(&child_indexes) {
+        if global_id.x < new_offsets[rows] {
+            let row = find_row(rows, global_id.x);
+            child_indexes[global_id.x] = starts[row] + global_id.x - new_offsets[row];
+        } else {
+            child_indexes[global_id.x] = 0xffffffffu;
+        }
+    }
+}
@@ -0,0 +1,20 @@
+struct Params {
+    value_length: u32,
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read_write> offsets: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn fixed_size_offsets(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x <= params.len {
+        offsets[global_id.x] = global_id.x * params.value_length;
+    }
+}
@@ -0,0 +1,29 @@
+struct Params {
+    len: u32,
+    blocks: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> items: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> indices: array<u32>;
+
+// the row is the last word of each item
+@compute
+@workgroup_size(256)
+fn item_indices(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < params.len {
+        indices[row] = items[row * params.stride + params.stride - 1u];
+    }
+}
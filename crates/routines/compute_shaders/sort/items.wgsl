@@ -0,0 +1,70 @@
+// Builds the items sorted by the radix passes: the order preserving key of the value,
+// the null flag and the row. Expects a prelude defining KEY_KIND, KEY_WORDS, KEY_MASK,
+// SIGN_BIT and `load_raw`.
+
+const KEY_UNSIGNED = 0u;
+const KEY_SIGNED = 1u;
+const KEY_FLOAT = 2u;
+
+struct Params {
+    len: u32,
+    descending: u32,
+    nulls_first: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> validity: array<u32>;
+
+@group(0)
+@binding(2)
+var<uniform> params: Params;
+
+@group(0)
+@binding(3)
+var<storage, read_write> items: array<u32>;
+
+// maps the bits of a value to bits whose unsigned order is the order of the values,
+// floats follow the IEEE 754 total order with negative NaN first and positive NaN last
+fn order_key(raw: vec2<u32>) -> vec2<u32> {
+    if KEY_KIND == KEY_SIGNED {
+        return raw ^ SIGN_BIT;
+    }
+    if KEY_KIND == KEY_FLOAT {
+        if any((raw & SIGN_BIT) != vec2<u32>(0u, 0u)) {
+            return ~raw & KEY_MASK;
+        }
+        return raw | SIGN_BIT;
+    }
+    return raw;
+}
+
+@compute
+@workgroup_size(256)
+fn make_items(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len {
+        return;
+    }
+    let stride = KEY_WORDS + 2u;
+    let valid = ((validity[row / 32u] >> (row % 32u)) & 1u) != 0u;
+
+    var key = vec2<u32>(0u, 0u);
+    if valid {
+        key = order_key(load_raw(row));
+        if params.descending != 0u {
+            key ^= KEY_MASK;
+        }
+    }
+    items[row * stride] = key.x;
+    if KEY_WORDS == 2u {
+        items[row * stride + 1u] = key.y;
+    }
+    // the flag is the most significant digit, it puts the nulls before or after the values
+    items[row * stride + KEY_WORDS] = u32(valid == (params.nulls_first != 0u));
+    items[row * stride + KEY_WORDS + 1u] = row;
+}
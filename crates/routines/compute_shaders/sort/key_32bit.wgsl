@@ -0,0 +1,7 @@
+const KEY_WORDS = 1u;
+const KEY_MASK = vec2<u32>(0xffffffffu, 0u);
+const SIGN_BIT = vec2<u32>(0x80000000u, 0u);
+
+fn load_raw(row: u32) -> vec2<u32> {
+    return vec2<u32>(values[row], 0u);
+}
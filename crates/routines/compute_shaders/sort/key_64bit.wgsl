@@ -0,0 +1,7 @@
+const KEY_WORDS = 2u;
+const KEY_MASK = vec2<u32>(0xffffffffu, 0xffffffffu);
+const SIGN_BIT = vec2<u32>(0u, 0x80000000u);
+
+fn load_raw(row: u32) -> vec2<u32> {
+    return vec2<u32>(values[row * 2u], values[row * 2u + 1u]);
+}
@@ -0,0 +1,131 @@
+// One pass of a stable LSD radix sort over items of `stride` words, sorting by the byte
+// at `shift` of word `word`. histogram counts the digits of each block of 256 items,
+// scatter moves the items to the exclusive prefix sum of the counts plus their rank
+// among the items of the block with the same digit.
+
+struct Params {
+    len: u32,
+    blocks: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> items: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// counts of each digit, digit major, then their exclusive prefix sum when scattering
+@group(0)
+@binding(2)
+var<storage, read_write> counts: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_items: array<u32>;
+
+var<workgroup> block_histogram: array<atomic<u32>, 256>;
+
+// digits and local rows of the block, sorted by digit while scattering
+var<workgroup> block_digits: array<u32, 256>;
+
+var<workgroup> block_rows: array<u32, 256>;
+
+var<workgroup> scan_sums: array<u32, 256>;
+
+// position of the first row of each digit in the sorted block
+var<workgroup> digit_starts: array<u32, 256>;
+
+fn digit(row: u32) -> u32 {
+    return (items[row * params.stride + params.word] >> params.shift) & 0xffu;
+}
+
+@compute
+@workgroup_size(256)
+fn histogram(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    atomicStore(&block_histogram[local_id.x], 0u);
+    workgroupBarrier();
+
+    if global_id.x < params.len {
+        atomicAdd(&block_histogram[digit(global_id.x)], 1u);
+    }
+    workgroupBarrier();
+
+    counts[local_id.x * params.blocks + wg_id.x] = atomicLoad(&block_histogram[local_id.x]);
+}
+
+// exclusive prefix sum of one flag per invocation, scan_sums[255] holds the total after it
+fn block_exclusive_sum(local: u32, flag: u32) -> u32 {
+    scan_sums[local] = flag;
+    workgroupBarrier();
+    for (var stride = 1u; stride < 256u; stride <<= 1u) {
+        var sum = scan_sums[local];
+        if local >= stride {
+            sum += scan_sums[local - stride];
+        }
+        workgroupBarrier();
+        scan_sums[local] = sum;
+        workgroupBarrier();
+    }
+    return scan_sums[local] - flag;
+}
+
+// The block is sorted by digit with one stable split per bit, each a workgroup scan of the
+// bit flags. The rank of a row among the rows with its digit is then its distance
+// to the first of them.
+@compute
+@workgroup_size(256)
+fn scatter(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let local = local_id.x;
+    // rows past the end get the digit 256, sorted after all the others
+    var row_digit = 256u;
+    if global_id.x < params.len {
+        row_digit = digit(global_id.x);
+    }
+    block_digits[local] = row_digit;
+    block_rows[local] = local;
+    workgroupBarrier();
+
+    for (var bit = 0u; bit < 9u; bit++) {
+        let key = block_digits[local];
+        let block_row = block_rows[local];
+        let flag = (key >> bit) & 1u;
+        let ones_before = block_exclusive_sum(local, flag);
+        let zeros = 256u - scan_sums[255];
+        var position = local - ones_before;
+        if flag == 1u {
+            position = zeros + ones_before;
+        }
+        workgroupBarrier();
+        block_digits[position] = key;
+        block_rows[position] = block_row;
+        workgroupBarrier();
+    }
+
+    let key = block_digits[local];
+    if key < 256u && (local == 0u || block_digits[local - 1u] != key) {
+        digit_starts[key] = local;
+    }
+    workgroupBarrier();
+
+    if key < 256u {
+        let row = wg_id.x * 256u + block_rows[local];
+        let rank = local - digit_starts[key];
+        let destination = counts[key * params.blocks + wg_id.x] + rank;
+        for (var w = 0u; w < params.stride; w++) {
+            new_items[destination * params.stride + w] = items[row * params.stride + w];
+        }
+    }
+}
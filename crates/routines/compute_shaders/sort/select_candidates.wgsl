@@ -0,0 +1,53 @@
+struct Params {
+    len: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> items: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// words of the selected key
+@group(0)
+@binding(2)
+var<storage, read> state: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> flags: array<u32>;
+
+@group(0)
+@binding(4)
+var<storage, read_write> ties: array<u32>;
+
+// flags the valid rows whose key is below the selected key, the valid rows
+// whose key equals it are flagged in ties
+@compute
+@workgroup_size(256)
+fn select_candidates(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len {
+        return;
+    }
+    let key_words = params.stride - 2u;
+    let valid = items[row * params.stride + key_words] == 0u;
+    var below = false;
+    var equal = true;
+    for (var w = key_words; w > 0u; w--) {
+        let key = items[row * params.stride + w - 1u];
+        let threshold = state[w - 1u];
+        if key != threshold {
+            below = key < threshold;
+            equal = false;
+            break;
+        }
+    }
+    flags[row] = u32(valid && below);
+    ties[row] = u32(valid && equal);
+}
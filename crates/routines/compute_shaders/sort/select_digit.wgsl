@@ -0,0 +1,39 @@
+struct Params {
+    len: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> histogram: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// words of the prefix of the selected key followed by the rank left to find in it
+@group(0)
+@binding(2)
+var<storage, read_write> state: array<u32>;
+
+// Appends the digit holding the remaining rank to the prefix. When fewer values than
+// the rank are left the highest digit is taken, so that every value ends up selected.
+@compute
+@workgroup_size(1)
+fn select_digit() {
+    let rank_index = params.stride - 2u;
+    var remaining = state[rank_index];
+    var digit = 255u;
+    for (var d = 0u; d < 256u; d++) {
+        let count = histogram[d];
+        if count >= remaining {
+            digit = d;
+            break;
+        }
+        remaining -= count;
+    }
+    state[params.word] |= digit << params.shift;
+    state[rank_index] = remaining;
+}
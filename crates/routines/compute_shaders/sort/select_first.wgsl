@@ -0,0 +1,92 @@
+// Reduces sort items to the first one: valid before null, then by key, then by row, so that
+// the first row of the smallest key wins. Each block writes its first item, the last level
+// runs a single block which writes the row followed by whether it is valid.
+
+struct Params {
+    len: u32,
+    stride: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> items: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output: array<u32>;
+
+const wg_size = 256u;
+
+// key low word, key high word, null flag and row
+var<workgroup> shared_items: array<vec4<u32>, wg_size>;
+
+fn load_item(index: u32) -> vec4<u32> {
+    let base = index * params.stride;
+    let key_words = params.stride - 2u;
+    var item = vec4<u32>(items[base], 0u, items[base + key_words], items[base + key_words + 1u]);
+    if key_words == 2u {
+        item.y = items[base + 1u];
+    }
+    return item;
+}
+
+fn precedes(a: vec4<u32>, b: vec4<u32>) -> bool {
+    if a.z != b.z {
+        return a.z < b.z;
+    }
+    if a.y != b.y {
+        return a.y < b.y;
+    }
+    if a.x != b.x {
+        return a.x < b.x;
+    }
+    return a.w < b.w;
+}
+
+@compute
+@workgroup_size(256)
+fn select_first(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    let index = global_id.x;
+    let local = local_id.x;
+    // rows past the end behave as nulls
+    var item = vec4<u32>(0xffffffffu, 0xffffffffu, 1u, 0xffffffffu);
+    if index < params.len {
+        item = load_item(index);
+    }
+    shared_items[local] = item;
+    workgroupBarrier();
+
+    for (var s = wg_size / 2u; s > 0u; s /= 2u) {
+        if local < s && precedes(shared_items[local + s], shared_items[local]) {
+            shared_items[local] = shared_items[local + s];
+        }
+        workgroupBarrier();
+    }
+
+    if local != 0u {
+        return;
+    }
+    let first = shared_items[0];
+    if num_workgroups.x == 1u {
+        output[0] = first.w;
+        output[1] = u32(first.z == 0u);
+        return;
+    }
+    let base = wg_id.x * params.stride;
+    let key_words = params.stride - 2u;
+    output[base] = first.x;
+    if key_words == 2u {
+        output[base + 1u] = first.y;
+    }
+    output[base + key_words] = first.z;
+    output[base + key_words + 1u] = first.w;
+}
@@ -0,0 +1,75 @@
+// One pass of a radix select over the items of the radix sort. Counts the digits at `shift`
+// of word `word` of the valid items whose more significant bits match the prefix found so far.
+
+struct Params {
+    len: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> items: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// words of the prefix of the selected key followed by the rank left to find in it
+@group(0)
+@binding(2)
+var<storage, read> state: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> histogram: array<atomic<u32>>;
+
+var<workgroup> block_histogram: array<atomic<u32>, 256>;
+
+// bits of key word w that are more significant than the digit
+fn higher_mask(w: u32) -> u32 {
+    if w > params.word {
+        return 0xffffffffu;
+    }
+    if w < params.word || params.shift == 24u {
+        return 0u;
+    }
+    return ~((1u << (params.shift + 8u)) - 1u);
+}
+
+fn is_candidate(row: u32) -> bool {
+    let key_words = params.stride - 2u;
+    if items[row * params.stride + key_words] != 0u {
+        return false;
+    }
+    for (var w = 0u; w < key_words; w++) {
+        let mask = higher_mask(w);
+        if (items[row * params.stride + w] & mask) != (state[w] & mask) {
+            return false;
+        }
+    }
+    return true;
+}
+
+@compute
+@workgroup_size(256)
+fn select_histogram(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>
+) {
+    atomicStore(&block_histogram[local_id.x], 0u);
+    workgroupBarrier();
+
+    let row = global_id.x;
+    if row < params.len && is_candidate(row) {
+        let digit = (items[row * params.stride + params.word] >> params.shift) & 0xffu;
+        atomicAdd(&block_histogram[digit], 1u);
+    }
+    workgroupBarrier();
+
+    let count = atomicLoad(&block_histogram[local_id.x]);
+    if count != 0u {
+        atomicAdd(&histogram[local_id.x], count);
+    }
+}
@@ -0,0 +1,38 @@
+struct Params {
+    len: u32,
+    stride: u32,
+    word: u32,
+    shift: u32,
+}
+
+// exclusive prefix sum of the ties, holds one more entry than the number of rows
+@group(0)
+@binding(0)
+var<storage, read> tie_prefix: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// words of the selected key followed by the number of ties to select
+@group(0)
+@binding(2)
+var<storage, read> state: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> flags: array<u32>;
+
+// flags the first ties in row order, as many as are left to select
+@compute
+@workgroup_size(256)
+fn select_ties(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row >= params.len {
+        return;
+    }
+    let remaining = state[params.stride - 2u];
+    if tie_prefix[row + 1u] > tie_prefix[row] && tie_prefix[row] < remaining {
+        flags[row] = 1u;
+    }
+}
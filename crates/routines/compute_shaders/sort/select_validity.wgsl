@@ -0,0 +1,34 @@
+struct Params {
+    len: u32,
+}
+
+// selected indexes followed by 0xffffffff entries
+@group(0)
+@binding(0)
+var<storage, read> indexes: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+@group(0)
+@binding(2)
+var<storage, read_write> validity: array<u32>;
+
+// sets the bit of the entries holding an index, each invocation writes a word
+@compute
+@workgroup_size(256)
+fn select_validity(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let word_index = global_id.x;
+    if word_index >= arrayLength(&validity) {
+        return;
+    }
+    var word = 0u;
+    for (var bit = 0u; bit < 32u; bit++) {
+        let row = word_index * 32u + bit;
+        if row < params.len && indexes[row] != 0xffffffffu {
+            word |= 1u << bit;
+        }
+    }
+    validity[word_index] = word;
+}
@@ -0,0 +1,59 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+// byte offset in values of the string copied into each new row,
+// rows marked with 0xffffffff are skipped
+@group(0)
+@binding(1)
+var<storage, read> starts: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read> new_offsets: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_values: array<u32>;
+
+fn get_byte(index: u32) -> u32 {
+    return (values[index / 4u] >> ((index % 4u) * 8u)) & 0xffu;
+}
+
+// returns the last row whose new offset is <= index, i.e. the row containing byte `index`
+fn find_row(rows: u32, index: u32) -> u32 {
+    var low = 0u;
+    var high = rows;
+    while low < high {
+        let mid = (low + high) / 2u;
+        if new_offsets[mid] <= index {
+            low = mid + 1u;
+        } else {
+            high = mid;
+        }
+    }
+    return low - 1u;
+}
+
+// Each invocation fills one word of new_values. Bytes are or-ed into the word
+// so that multiple arrays can be gathered into the same buffer.
+@compute
+@workgroup_size(256)
+fn gather(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let rows = arrayLength(&new_offsets) - 1u;
+    let total = new_offsets[rows];
+    if global_id.x < arrayLength(&new_values) {
+        var word = 0u;
+        for (var i = 0u; i < 4u; i++) {
+            let index = global_id.x * 4u + i;
+            if index < total {
+                let row = find_row(rows, index);
+                let start = starts[row];
+                if start != 0xffffffffu {
+                    word |= get_byte(start + index - new_offsets[row]) << (i * 8u);
+                }
+            }
+        }
+        new_values[global_id.x] |= word;
+    }
+}
@@ -0,0 +1,33 @@
+@group(0)
+@binding(0)
+var<storage, read> left_offsets: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> right_offsets: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_lengths: array<u32>;
+
+fn is_set(index: u32) -> bool {
+    let index_by_32 = index / 32u;
+    return (mask[index_by_32] & (1u << (index % 32u))) != 0u;
+}
+
+@compute
+@workgroup_size(256)
+fn merge_lengths(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index < arrayLength(&new_lengths) {
+        if is_set(index) {
+            new_lengths[index] = left_offsets[index + 1u] - left_offsets[index];
+        } else {
+            new_lengths[index] = right_offsets[index + 1u] - right_offsets[index];
+        }
+    }
+}
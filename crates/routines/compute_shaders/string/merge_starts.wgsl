@@ -0,0 +1,35 @@
+@group(0)
+@binding(0)
+var<storage, read> offsets: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> mask: array<u32>;
+
+// rows which are not copied from this array are marked with 0xffffffff
+@group(0)
+@binding(2)
+var<storage, read_write> starts: array<u32>;
+
+fn is_set(index: u32) -> bool {
+    let index_by_32 = index / 32u;
+    return (mask[index_by_32] & (1u << (index % 32u))) != 0u;
+}
+
+@compute
+@workgroup_size(256)
+fn selected_starts(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index < arrayLength(&starts) {
+        starts[index] = select(0xffffffffu, offsets[index], is_set(index));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn not_selected_starts(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index < arrayLength(&starts) {
+        starts[index] = select(offsets[index], 0xffffffffu, is_set(index));
+    }
+}
@@ -0,0 +1,31 @@
+@group(0)
+@binding(0)
+var<storage, read> offsets: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> indexes: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_lengths: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> starts: array<u32>;
+
+// rows taking the index 0xffffffff are empty and skipped by the gather
+@compute
+@workgroup_size(256)
+fn take_lengths(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_lengths) {
+        let index = indexes[global_id.x];
+        if index == 0xffffffffu {
+            new_lengths[global_id.x] = 0u;
+            starts[global_id.x] = 0xffffffffu;
+        } else {
+            new_lengths[global_id.x] = offsets[index + 1u] - offsets[index];
+            starts[global_id.x] = offsets[index];
+        }
+    }
+}
@@ -0,0 +1,72 @@
+struct Params {
+    len: u32,
+}
+
+// holds the scanned block totals in add_block_prefixes
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<uniform> params: Params;
+
+// holds one more entry than values, the last entry is the total sum
+@group(0)
+@binding(2)
+var<storage, read_write> prefix_sum: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> block_totals: array<u32>;
+
+var<workgroup> partial_sums: array<u32, 256>;
+
+// Each workgroup scans a block of 256 rows, the row `len` adds nothing so that
+// its prefix is the total
+@compute
+@workgroup_size(256)
+fn scan_blocks(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    var value = 0u;
+    if row < params.len {
+        value = values[row];
+    }
+    partial_sums[local_id.x] = value;
+    workgroupBarrier();
+
+    for (var stride = 1u; stride < 256u; stride <<= 1u) {
+        var sum = partial_sums[local_id.x];
+        if local_id.x >= stride {
+            sum += partial_sums[local_id.x - stride];
+        }
+        workgroupBarrier();
+        partial_sums[local_id.x] = sum;
+        workgroupBarrier();
+    }
+
+    let inclusive = partial_sums[local_id.x];
+    if row <= params.len {
+        prefix_sum[row] = inclusive - value;
+    }
+    if local_id.x == 255u {
+        block_totals[wg_id.x] = inclusive;
+    }
+}
+
+// values holds the exclusive prefix sum of the block totals
+@compute
+@workgroup_size(256)
+fn add_block_prefixes(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let row = global_id.x;
+    if row <= params.len {
+        prefix_sum[row] += values[wg_id.x];
+    }
+}
@@ -0,0 +1,20 @@
+struct Params {
+    start: u32,
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn range(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = params.start + global_id.x;
+    }
+}
@@ -1,8 +1,6 @@
-use std::sync::Arc;
-
 use arrow_gpu_array::{
     array::{BooleanArrayGPU, NullBitBufferGpu, UInt32ArrayGPU},
-    gpu_utils::{ArrowComputePipeline, GpuDevice},
+    gpu_utils::{ArrowComputePipeline, GpuBufferView, GpuDevice},
 };
 use wgpu::Buffer;
 
@@ -14,7 +12,7 @@ const TAKE_SHADER: &str = include_str!("../compute_shaders/bool/take.wgsl");
 
 pub(crate) fn take_bool(
     device: &GpuDevice,
-    data: &Buffer,
+    data: &dyn GpuBufferView,
     indexes: &UInt32ArrayGPU,
     pipeline: &mut ArrowComputePipeline,
 ) -> Buffer {
@@ -23,7 +21,7 @@ pub(crate) fn take_bool(
         data,
         &indexes.data,
         indexes.len as u64,
-        (indexes.len.div_ceil(32) as u64) * 4,
+        (indexes.len.div_ceil(32).max(1) as u64) * 4,
         TAKE_SHADER,
         "take",
         pipeline,
@@ -38,8 +36,8 @@ pub(crate) fn take_null_buffer(
     data.map(|x| {
         let new_bit_bufer = take_bool(&x.gpu_device, &x.bit_buffer, indexes, pipeline);
         NullBitBufferGpu {
-            bit_buffer: Arc::new(new_bit_bufer),
-            len: x.len,
+            bit_buffer: new_bit_bufer.into(),
+            len: indexes.len,
             gpu_device: x.gpu_device.clone(),
         }
     })
@@ -65,21 +63,21 @@ impl Swizzle for BooleanArrayGPU {
             dispatch_size,
         );
 
-        let op1 = self.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
-        let op2 = other.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
-        let mask_null = mask.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
+        let op1 = self.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let op2 = other.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let mask_null = mask.null_buffer.as_ref().map(|x| &x.bit_buffer);
 
         //TODO can be simplified
         let bit_buffer = merge_null_buffers_op(op1, op2, &mask.data, mask_null, pipeline);
 
         let new_null_buffer = bit_buffer.map(|buffer| NullBitBufferGpu {
-            bit_buffer: Arc::new(buffer),
+            bit_buffer: buffer.into(),
             len: self.len,
             gpu_device: self.gpu_device.clone(),
         });
 
         Self {
-            data: Arc::new(new_buffer),
+            data: new_buffer.into(),
             gpu_device: self.gpu_device.clone(),
             len: self.len,
             null_buffer: new_null_buffer,
@@ -92,7 +90,7 @@ impl Swizzle for BooleanArrayGPU {
         let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
 
         Self {
-            data: Arc::new(new_buffer),
+            data: new_buffer.into(),
             gpu_device: self.gpu_device.clone(),
             len: indexes.len,
             null_buffer,
@@ -109,7 +107,7 @@ impl Swizzle for BooleanArrayGPU {
         apply_put_op(
             &self.gpu_device,
             &self.data,
-            &dst.data,
+            &mut dst.data,
             &src_indexes.data,
             &dst_indexes.data,
             (src_indexes.len as u64).div_ceil(32),
@@ -0,0 +1,445 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+const COPY_BITS_SHADER: &str = include_str!("../compute_shaders/concat/copy_bits.wgsl");
+const REBASE_OFFSETS_SHADER: &str = include_str!("../compute_shaders/concat/rebase_offsets.wgsl");
+
+/// Trait for concatenating arrays of the same type
+pub trait Concat: ArrayUtils + Sized {
+    fn concat(arrays: &[&Self]) -> Self {
+        assert!(!arrays.is_empty(), "concat needs at least one array");
+        let mut pipeline = ArrowComputePipeline::new(arrays[0].get_gpu_device(), Some("concat"));
+        let result = Self::concat_op(arrays, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Creates a new array holding the elements of all `arrays` one after the other
+    fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self;
+}
+
+/// Submits commands to write the bits of `views` one after the other into a new buffer.
+/// Views starting at a word boundary of the new buffer are copied, the rest are shifted
+/// into place by a shader.
+pub(crate) fn concat_bits_op(
+    views: &[&dyn GpuBufferView],
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let total_bits = views.iter().map(|x| x.bit_len()).sum::<u64>();
+    let new_buffer = pipeline
+        .device
+        .create_empty_buffer((total_bits.div_ceil(32) * 4).max(4));
+
+    let mut dst_bit_offset = 0;
+    for (index, view) in views.iter().enumerate() {
+        let bit_len = view.bit_len();
+        if bit_len == 0 {
+            continue;
+        }
+        let is_last = index == views.len() - 1;
+        if dst_bit_offset % 32 == 0 && (bit_len % 32 == 0 || is_last) {
+            // bits after the end of the last view only land in the padding of the new buffer
+            pipeline.copy_buffer_to_buffer(
+                *view,
+                0,
+                &new_buffer,
+                dst_bit_offset / 8,
+                bit_len.div_ceil(32) * 4,
+            );
+        } else {
+            let params = pipeline
+                .device
+                .create_uniform_buffer(&[dst_bit_offset as u32, bit_len as u32]);
+            let dispatch_size = (bit_len.div_ceil(32) + 1).div_ceil(256) as u32;
+            pipeline.apply_function(
+                &[*view, &params, &new_buffer],
+                COPY_BITS_SHADER,
+                "copy_bits",
+                dispatch_size,
+            );
+        }
+        dst_bit_offset += bit_len;
+    }
+
+    new_buffer
+}
+
+/// Concatenates the null buffers of arrays, `None` when none of them has nulls
+pub(crate) fn concat_null_buffers_op(
+    null_buffers: &[(Option<&NullBitBufferGpu>, usize)],
+    pipeline: &mut ArrowComputePipeline,
+) -> Option<NullBitBufferGpu> {
+    if null_buffers.iter().all(|(x, _)| x.is_none()) {
+        return None;
+    }
+    let all_set = null_buffers
+        .iter()
+        .map(|(x, len)| match x {
+            Some(_) => None,
+            None => Some(NullBitBufferGpu::new_set_with_capacity(
+                pipeline.device.clone(),
+                *len,
+            )),
+        })
+        .collect::<Vec<_>>();
+    let views = null_buffers
+        .iter()
+        .zip(&all_set)
+        .map(|((x, len), set)| {
+            let null_buffer = x.or(set.as_ref()).unwrap();
+            null_buffer.bit_buffer.slice_bits(0, *len as u64)
+        })
+        .collect::<Vec<_>>();
+    let views = views
+        .iter()
+        .map(|x| x as &dyn GpuBufferView)
+        .collect::<Vec<_>>();
+
+    let len = null_buffers.iter().map(|(_, len)| len).sum();
+    Some(NullBitBufferGpu {
+        bit_buffer: concat_bits_op(&views, pipeline).into(),
+        len,
+        gpu_device: pipeline.device.clone(),
+    })
+}
+
+impl<T: ArrowPrimitiveType> Concat for PrimitiveArrayGpu<T> {
+    fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self {
+        let item_size = std::mem::size_of::<T::NativeType>() as u64;
+        let views = arrays
+            .iter()
+            .map(|x| x.data.slice(0, x.len as u64 * item_size))
+            .collect::<Vec<_>>();
+        let views = views
+            .iter()
+            .map(|x| x as &dyn GpuBufferView)
+            .collect::<Vec<_>>();
+        let new_buffer = concat_bits_op(&views, pipeline);
+
+        let null_buffers = arrays
+            .iter()
+            .map(|x| (x.null_buffer.as_ref(), x.len))
+            .collect::<Vec<_>>();
+        Self {
+            data: new_buffer.into(),
+            gpu_device: pipeline.device.clone(),
+            phantom: Default::default(),
+            len: arrays.iter().map(|x| x.len).sum(),
+            null_buffer: concat_null_buffers_op(&null_buffers, pipeline),
+        }
+    }
+}
+
+impl Concat for BooleanArrayGPU {
+    fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self {
+        let views = arrays
+            .iter()
+            .map(|x| x.data.slice_bits(0, x.len as u64))
+            .collect::<Vec<_>>();
+        let views = views
+            .iter()
+            .map(|x| x as &dyn GpuBufferView)
+            .collect::<Vec<_>>();
+        let new_buffer = concat_bits_op(&views, pipeline);
+
+        let null_buffers = arrays
+            .iter()
+            .map(|x| (x.null_buffer.as_ref(), x.len))
+            .collect::<Vec<_>>();
+        Self {
+            data: new_buffer.into(),
+            gpu_device: pipeline.device.clone(),
+            len: arrays.iter().map(|x| x.len).sum(),
+            null_buffer: concat_null_buffers_op(&null_buffers, pipeline),
+        }
+    }
+}
+
+impl Concat for StringArrayGPU {
+    fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self {
+        let len = arrays.iter().map(|x| x.len).sum::<usize>();
+        let new_offsets = pipeline.device.create_empty_buffer((len as u64 + 1) * 4);
+
+        // the byte range of each array in its value buffer is needed on the host
+        // to size the new value buffer and to rebase the offsets, the first and last
+        // offsets of all the arrays are read back together
+        let bounds = pipeline.device.create_empty_buffer(arrays.len() as u64 * 8);
+        for (i, array) in arrays.iter().enumerate() {
+            let last = array.len as u64 * 4;
+            pipeline.copy_buffer_to_buffer(&array.offsets, 0, &bounds, i as u64 * 8, 4);
+            pipeline.copy_buffer_to_buffer(&array.offsets, last, &bounds, i as u64 * 8 + 4, 4);
+        }
+        let bounds = pipeline.read_buffer(&bounds, 0, arrays.len() as u64 * 8);
+        let bounds = bounds
+            .chunks_exact(4)
+            .map(|x| u32::from_le_bytes(x.try_into().unwrap()) as u64)
+            .collect::<Vec<_>>();
+
+        let mut value_views = Vec::with_capacity(arrays.len());
+        let mut dst_start = 0;
+        let mut base = 0;
+        for (array, bounds) in arrays.iter().zip(bounds.chunks(2)) {
+            let (first, last) = (bounds[0], bounds[1]);
+            value_views.push(array.values.slice(first, last - first));
+
+            let params = pipeline.device.create_uniform_buffer(&[
+                dst_start as u32,
+                array.len as u32 + 1,
+                base as u32,
+                0,
+            ]);
+            pipeline.apply_function(
+                &[&array.offsets, &params, &new_offsets],
+                REBASE_OFFSETS_SHADER,
+                "rebase_offsets",
+                (array.len + 1).div_ceil(256) as u32,
+            );
+            dst_start += array.len;
+            base += last - first;
+        }
+
+        let value_views = value_views
+            .iter()
+            .map(|x| x as &dyn GpuBufferView)
+            .collect::<Vec<_>>();
+        let new_values = concat_bits_op(&value_views, pipeline);
+
+        let null_buffers = arrays
+            .iter()
+            .map(|x| (x.null_buffer.as_ref(), x.len))
+            .collect::<Vec<_>>();
+        Self {
+            offsets: new_offsets.into(),
+            values: new_values.into(),
+            gpu_device: pipeline.device.clone(),
+            len,
+            null_buffer: concat_null_buffers_op(&null_buffers, pipeline),
+        }
+    }
+}
+
+macro_rules! impl_temporal_concat {
+    ($ty: ident) => {
+        impl Concat for $ty {
+            fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self {
+                assert!(arrays.iter().all(|x| x.unit == arrays[0].unit));
+                let values = arrays.iter().map(|x| &x.values).collect::<Vec<_>>();
+                let mut result = arrays[0].slice(0, 0);
+                result.values = Concat::concat_op(&values, pipeline);
+                result.len = result.values.len;
+                result
+            }
+        }
+    };
+}
+
+impl_temporal_concat!(TimestampArrayGPU);
+impl_temporal_concat!(DurationArrayGPU);
+impl_temporal_concat!(Time32ArrayGPU);
+
+impl Concat for Decimal128ArrayGPU {
+    fn concat_op(arrays: &[&Self], pipeline: &mut ArrowComputePipeline) -> Self {
+        let (precision, scale) = (arrays[0].precision, arrays[0].scale);
+        assert!(
+            arrays
+                .iter()
+                .all(|x| x.precision == precision && x.scale == scale)
+        );
+        let values = arrays.iter().map(|x| &x.values).collect::<Vec<_>>();
+        Decimal128ArrayGPU::new(Concat::concat_op(&values, pipeline), precision, scale)
+    }
+}
+
+/// Concatenate arrays of the same type into a new array
+pub fn concat_dyn(arrays: &[&ArrowArrayGPU]) -> ArrowArrayGPU {
+    assert!(!arrays.is_empty(), "concat needs at least one array");
+    let mut pipeline = ArrowComputePipeline::new(arrays[0].get_gpu_device(), Some("concat"));
+    let result = concat_op_dyn(arrays, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! concat_op_dyn_arms {
+    ($arrays: ident, $pipeline: ident, $($arr: ident),*) => {
+        match $arrays[0] {
+            $(ArrowArrayGPU::$arr(_) => {
+                let arrays = $arrays
+                    .iter()
+                    .map(|x| match x {
+                        ArrowArrayGPU::$arr(x) => x,
+                        x => panic!(
+                            "Concat expects arrays of type {:?} found {:?}",
+                            $arrays[0].get_dtype(),
+                            x.get_dtype()
+                        ),
+                    })
+                    .collect::<Vec<_>>();
+                Concat::concat_op(&arrays, $pipeline).into()
+            })*
+            x => panic!("Concat not supported for {:?}", x.get_dtype()),
+        }
+    };
+}
+
+/// Submits commands to concatenate arrays of the same type into a new array
+pub fn concat_op_dyn(
+    arrays: &[&ArrowArrayGPU],
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    assert!(!arrays.is_empty(), "concat needs at least one array");
+    concat_op_dyn_arms!(
+        arrays,
+        pipeline,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU,
+        TimestampArrayGPU,
+        Time32ArrayGPU,
+        DurationArrayGPU,
+        Decimal128ArrayGPU,
+        BooleanArrayGPU,
+        StringArrayGPU
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_concat_u32_copies() {
+        let device = GPU_DEVICE.clone();
+        let array_1 = UInt32ArrayGPU::from_slice(&[1, 2, 3], device.clone());
+        let array_2 = UInt32ArrayGPU::from_optional_slice(&[None, Some(5)], device.clone());
+        let array_3 = UInt32ArrayGPU::from_slice(&[6, 7, 8, 9], device);
+
+        let new_array = UInt32ArrayGPU::concat(&[&array_1, &array_2, &array_3]);
+        assert_eq!(
+            new_array.values(),
+            vec![
+                Some(1),
+                Some(2),
+                Some(3),
+                None,
+                Some(5),
+                Some(6),
+                Some(7),
+                Some(8),
+                Some(9)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concat_u8_packing() {
+        let device = GPU_DEVICE.clone();
+        let values = (0..100).collect::<Vec<u8>>();
+        let array = UInt8ArrayGPU::from_slice(&values, device);
+        let parts = [array.slice(0, 3), array.slice(10, 5), array.slice(50, 50)];
+
+        let new_array = UInt8ArrayGPU::concat(&parts.iter().collect::<Vec<_>>());
+        let expected = [&values[0..3], &values[10..15], &values[50..100]].concat();
+        assert_eq!(new_array.raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_concat_i16_with_nulls() {
+        let device = GPU_DEVICE.clone();
+        let array_1 =
+            Int16ArrayGPU::from_optional_slice(&[Some(-1), None, Some(3)], device.clone());
+        let array_2 = Int16ArrayGPU::from_slice(&[4, -5], device);
+
+        let new_array = Int16ArrayGPU::concat(&[&array_1, &array_2, &array_1]);
+        assert_eq!(
+            new_array.values(),
+            vec![
+                Some(-1),
+                None,
+                Some(3),
+                Some(4),
+                Some(-5),
+                Some(-1),
+                None,
+                Some(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concat_bool_bitmaps() {
+        let device = GPU_DEVICE.clone();
+        let lens = [5, 40, 1, 70, 32];
+        let values = lens
+            .iter()
+            .enumerate()
+            .map(|(i, len)| {
+                (0..*len)
+                    .map(|x| (x % 7 != i).then_some((x + i) % 3 == 0))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let arrays = values
+            .iter()
+            .map(|x| BooleanArrayGPU::from_optional_slice(x, device.clone()))
+            .collect::<Vec<_>>();
+
+        let new_array = BooleanArrayGPU::concat(&arrays.iter().collect::<Vec<_>>());
+        assert_eq!(new_array.values(), values.concat());
+    }
+
+    #[test]
+    fn test_concat_dyn_strings() {
+        let device = GPU_DEVICE.clone();
+        let array_1: ArrowArrayGPU =
+            StringArrayGPU::from_slice(&["a", "bb", "ccc"], device.clone()).into();
+        let array_2: ArrowArrayGPU =
+            StringArrayGPU::from_optional_slice(&[None, Some("dddd"), Some("e")], device)
+                .slice(1, 2)
+                .into();
+
+        let new_array: StringArrayGPU = concat_dyn(&[&array_1, &array_2, &array_1])
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            new_array.raw_values().unwrap(),
+            vec!["a", "bb", "ccc", "dddd", "e", "a", "bb", "ccc"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_dyn_mismatched_types() {
+        let device = GPU_DEVICE.clone();
+        let array_1: ArrowArrayGPU = UInt32ArrayGPU::from_slice(&[1], device.clone()).into();
+        let array_2: ArrowArrayGPU = Int32ArrayGPU::from_slice(&[1], device).into();
+        concat_dyn(&[&array_1, &array_2]);
+    }
+
+    #[test]
+    fn test_concat_timestamps() {
+        let device = GPU_DEVICE.clone();
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[Some(1), None, Some(3)],
+            TimeUnit::Millisecond,
+            Some("UTC".into()),
+            device,
+        );
+        let new_array = TimestampArrayGPU::concat(&[&array, &array.slice(1, 2)]);
+        assert_eq!(new_array.timezone.as_deref(), Some("UTC"));
+        assert_eq!(
+            new_array.values(),
+            vec![Some(1), None, Some(3), None, Some(3)]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::sync::Arc;
+
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::prefix_sum::{exclusive_prefix_sum_op, read_prefix_sum_total};
+use crate::{Swizzle, SwizzleType, take_op_dyn};
+
+const KEYS_SHADER: &str = include_str!("../compute_shaders/dictionary/keys.wgsl");
+const ENCODE_SLOTS_SHADER: &str = include_str!("../compute_shaders/dictionary/encode_slots.wgsl");
+const ENCODE_KEYS_SHADER: &str = include_str!("../compute_shaders/dictionary/encode_keys.wgsl");
+pub(crate) const ENCODE_32BIT_SHADER: &str = concat!(
+    include_str!("../compute_shaders/dictionary/values_32bit.wgsl"),
+    include_str!("../compute_shaders/dictionary/encode.wgsl")
+);
+pub(crate) const ENCODE_64BIT_SHADER: &str = concat!(
+    include_str!("../compute_shaders/dictionary/values_64bit.wgsl"),
+    include_str!("../compute_shaders/dictionary/encode.wgsl")
+);
+
+/// Helper trait for Arrow arrays that support dictionary encoding
+pub trait DictionaryEncodeType {
+    const ENCODE_SHADER: &'static str;
+}
+
+/// Trait for materialising the values of dictionary encoded arrays
+pub trait DictionaryDecode: ArrayUtils {
+    fn decode(&self) -> ArrowArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), None);
+        let result = self.decode_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Creates a new array by taking the value of each key from the dictionary.
+    /// Null keys result in None
+    fn decode_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU;
+}
+
+/// Trait for dictionary encoding arrays
+pub trait DictionaryEncode: ArrayUtils {
+    fn dictionary_encode(&self) -> UInt32DictionaryArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), None);
+        let result = self.dictionary_encode_op(&mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Creates a dictionary array whose dictionary holds the distinct values of self
+    /// in order of first occurrence. Values are compared by their bits and nulls are
+    /// kept as null keys.
+    fn dictionary_encode_op(&self, pipeline: &mut ArrowComputePipeline)
+    -> UInt32DictionaryArrayGPU;
+}
+
+/// Converts the keys of a dictionary array into u32 indexes that can be used with `take`
+pub fn dictionary_indexes_op<K: ArrowDictionaryKeyType>(
+    keys: &PrimitiveArrayGpu<K>,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let data = match K::ITEM_SIZE {
+        4 => pipeline.clone_buffer(&keys.data),
+        item_size => pipeline.apply_unary_function(
+            &keys.data,
+            keys.len as u64 * 4,
+            KEYS_SHADER,
+            if item_size == 1 {
+                "u8_keys"
+            } else {
+                "u16_keys"
+            },
+            keys.len.div_ceil(256) as u32,
+        ),
+    };
+
+    UInt32ArrayGPU {
+        data: data.into(),
+        gpu_device: keys.gpu_device.clone(),
+        phantom: Default::default(),
+        len: keys.len,
+        null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(&keys.null_buffer, pipeline),
+    }
+}
+
+impl<K: ArrowDictionaryKeyType> DictionaryDecode for DictionaryArrayGPU<K> {
+    fn decode_op(&self, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+        let indexes = dictionary_indexes_op(&self.keys, pipeline);
+        let mut decoded = take_op_dyn(&self.values, &indexes, pipeline);
+
+        let null_buffer = NullBitBufferGpu::merge_null_bit_buffer_op(
+            &decoded.get_null_bit_buffer().cloned(),
+            &indexes.null_buffer,
+            pipeline,
+        );
+        decoded.set_null_bit_buffer(null_buffer);
+        decoded
+    }
+}
+
+impl<T: DictionaryEncodeType + SwizzleType + ArrowPrimitiveType> DictionaryEncode
+    for PrimitiveArrayGpu<T>
+where
+    Self: Into<ArrowArrayGPU>,
+{
+    fn dictionary_encode_op(
+        &self,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32DictionaryArrayGPU {
+        let device = pipeline.device.clone();
+        let len = self.len;
+        let dispatch_size = len.div_ceil(256) as u32;
+
+        let all_valid;
+        let validity = match &self.null_buffer {
+            Some(null_buffer) => &null_buffer.bit_buffer,
+            None => {
+                all_valid = NullBitBufferGpu::new_set_with_capacity(device.clone(), len);
+                &all_valid.bit_buffer
+            }
+        };
+
+        // keep the table at most half full so that probe sequences stay short
+        let table_size = (len * 2).next_power_of_two().max(256) as u64;
+        let table = device.create_empty_buffer(table_size * 4);
+        let params = device.create_uniform_buffer(&(len as u32));
+        let slots = device.create_empty_buffer(len as u64 * 4);
+
+        pipeline.apply_function(
+            &[&params, &self.data, validity, &table],
+            T::ENCODE_SHADER,
+            "insert",
+            dispatch_size,
+        );
+        pipeline.apply_function(
+            &[&params, &self.data, validity, &table, &slots],
+            T::ENCODE_SHADER,
+            "find_slot",
+            dispatch_size,
+        );
+
+        let slot_first_rows = device.create_empty_buffer(table_size * 4);
+        let first_rows = device.create_empty_buffer(len as u64 * 4);
+        pipeline.apply_function(
+            &[&slots, &slot_first_rows],
+            ENCODE_SLOTS_SHADER,
+            "slot_first_row",
+            dispatch_size,
+        );
+        pipeline.apply_function(
+            &[&slots, &slot_first_rows, &first_rows],
+            ENCODE_SLOTS_SHADER,
+            "first_row",
+            dispatch_size,
+        );
+
+        let flags = device.create_empty_buffer(len as u64 * 4);
+        pipeline.apply_function(
+            &[&first_rows, &flags],
+            ENCODE_KEYS_SHADER,
+            "first_flags",
+            dispatch_size,
+        );
+        let positions = exclusive_prefix_sum_op(&flags, len, pipeline);
+        let dictionary_len = read_prefix_sum_total(&positions, pipeline) as usize;
+
+        let keys = device.create_empty_buffer(len as u64 * 4);
+        let dictionary_rows = device.create_empty_buffer(dictionary_len.max(1) as u64 * 4);
+        pipeline.apply_function(
+            &[&first_rows, &positions, &keys, &dictionary_rows],
+            ENCODE_KEYS_SHADER,
+            "keys_and_rows",
+            dispatch_size,
+        );
+
+        let dictionary_rows = UInt32ArrayGPU {
+            data: dictionary_rows.into(),
+            gpu_device: device.clone(),
+            phantom: Default::default(),
+            len: dictionary_len,
+            null_buffer: None,
+        };
+        let mut values = self.take_op(&dictionary_rows, pipeline);
+        values.null_buffer = None;
+
+        let keys = UInt32ArrayGPU {
+            data: keys.into(),
+            gpu_device: device.clone(),
+            phantom: Default::default(),
+            len,
+            null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline),
+        };
+
+        DictionaryArrayGPU::new(keys, Arc::new(values.into()))
+    }
+}
+
+/// Materialise the values of a dictionary encoded array
+pub fn decode_dyn(data: &ArrowArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("decode"));
+    let result = decode_op_dyn(data, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to materialise the values of a dictionary encoded array
+pub fn decode_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+    match data {
+        ArrowArrayGPU::UInt8DictionaryArrayGPU(x) => x.decode_op(pipeline),
+        ArrowArrayGPU::UInt16DictionaryArrayGPU(x) => x.decode_op(pipeline),
+        ArrowArrayGPU::UInt32DictionaryArrayGPU(x) => x.decode_op(pipeline),
+        _ => panic!("Decode Operation not supported for {:?}", data.get_dtype()),
+    }
+}
+
+/// Dictionary encode the array
+pub fn dictionary_encode_dyn(data: &ArrowArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("dictionary_encode"));
+    let result = dictionary_encode_op_dyn(data, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! dictionary_encode_op_dyn_arms {
+    ($data: ident, $pipeline:ident, $($arr: ident),*) => {
+        match $data {
+            $(ArrowArrayGPU::$arr(x) => x.dictionary_encode_op($pipeline).into(),)*
+            _ => panic!(
+                "Dictionary Encode Operation not supported for {:?}",
+                $data.get_dtype(),
+            ),
+        }
+    };
+}
+
+/// Submits a command to dictionary encode the array
+pub fn dictionary_encode_op_dyn(
+    data: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    dictionary_encode_op_dyn_arms!(
+        data,
+        pipeline,
+        Date32ArrayGPU,
+        UInt32ArrayGPU,
+        Int32ArrayGPU,
+        Float32ArrayGPU,
+        Int64ArrayGPU,
+        UInt64ArrayGPU
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_decode_u8_keys() {
+        let device = GPU_DEVICE.clone();
+        let values = Int32ArrayGPU::from_slice(&[10, 20, 30], device.clone());
+        let keys =
+            UInt8ArrayGPU::from_optional_slice(&[Some(2), Some(0), None, Some(1), Some(2)], device);
+        let array = DictionaryArrayGPU::new(keys, Arc::new(values.into()));
+        let expected = vec![Some(30), Some(10), None, Some(20), Some(30)];
+
+        let decoded = Int32ArrayGPU::try_from(array.decode()).unwrap();
+        assert_eq!(decoded.values(), expected);
+
+        let decoded = Int32ArrayGPU::try_from(decode_dyn(&array.into())).unwrap();
+        assert_eq!(decoded.values(), expected);
+    }
+
+    #[test]
+    fn test_decode_u16_string_keys() {
+        let device = GPU_DEVICE.clone();
+        let values =
+            StringArrayGPU::from_optional_slice(&[Some("a"), None, Some("ccc")], device.clone());
+        let keys = UInt16ArrayGPU::from_slice(&[2, 1, 0, 2, 0], device);
+        let array = DictionaryArrayGPU::new(keys, Arc::new(values.into()));
+
+        let decoded = StringArrayGPU::try_from(array.decode()).unwrap();
+        assert_eq!(
+            decoded.values(),
+            vec![
+                Some("ccc".to_string()),
+                None,
+                Some("a".to_string()),
+                Some("ccc".to_string()),
+                Some("a".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dictionary_encode_i32() {
+        let device = GPU_DEVICE.clone();
+        let input = [Some(5), Some(-1), None, Some(5), Some(7), Some(-1), None];
+        let array = Int32ArrayGPU::from_optional_slice(&input, device);
+
+        let encoded = array.dictionary_encode();
+        let values = Int32ArrayGPU::try_from(encoded.values.clone_array()).unwrap();
+        assert_eq!(values.raw_values().unwrap(), vec![5, -1, 7]);
+        assert_eq!(
+            encoded.keys.values(),
+            vec![Some(0), Some(1), None, Some(0), Some(2), Some(1), None]
+        );
+
+        let decoded = Int32ArrayGPU::try_from(encoded.decode()).unwrap();
+        assert_eq!(decoded.values(), input);
+    }
+
+    #[test]
+    fn test_dictionary_encode_u64_dyn() {
+        let device = GPU_DEVICE.clone();
+        let input = [u64::MAX, 1, 1 << 32, u64::MAX, 1];
+        let array = UInt64ArrayGPU::from_slice(&input, device);
+
+        let encoded = dictionary_encode_dyn(&array.into());
+        let encoded = UInt32DictionaryArrayGPU::try_from(encoded).unwrap();
+        assert_eq!(encoded.keys.raw_values().unwrap(), vec![0, 1, 2, 0, 1]);
+
+        let decoded = UInt64ArrayGPU::try_from(encoded.decode()).unwrap();
+        assert_eq!(decoded.raw_values().unwrap(), input);
+    }
+
+    #[test]
+    fn test_large_dictionary_encode() {
+        let device = GPU_DEVICE.clone();
+        let input = (0..5000)
+            .map(|x| (x * 7919 % 1013) as f32)
+            .collect::<Vec<_>>();
+        let array = Float32ArrayGPU::from_slice(&input, device);
+
+        let encoded = array.dictionary_encode();
+        let mut expected_values = vec![];
+        for value in &input {
+            if !expected_values.contains(value) {
+                expected_values.push(*value);
+            }
+        }
+        let values = Float32ArrayGPU::try_from(encoded.values.clone_array()).unwrap();
+        assert_eq!(values.raw_values().unwrap(), expected_values);
+
+        let decoded = Float32ArrayGPU::try_from(encoded.decode()).unwrap();
+        assert_eq!(decoded.raw_values().unwrap(), input);
+    }
+}
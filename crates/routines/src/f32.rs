@@ -1,4 +1,7 @@
-use crate::{SwizzleType, merge::U32_MERGE_SHADER, put::U32_PUT_SHADER, take::U32_TAKE_SHADER};
+use crate::{
+    DictionaryEncodeType, SwizzleType, dictionary::ENCODE_32BIT_SHADER, merge::U32_MERGE_SHADER,
+    put::U32_PUT_SHADER, take::U32_TAKE_SHADER,
+};
 
 impl SwizzleType for f32 {
     const MERGE_SHADER: &'static str = U32_MERGE_SHADER;
@@ -6,6 +9,10 @@ impl SwizzleType for f32 {
     const PUT_SHADER: &'static str = U32_PUT_SHADER;
 }
 
+impl DictionaryEncodeType for f32 {
+    const ENCODE_SHADER: &'static str = ENCODE_32BIT_SHADER;
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -0,0 +1,559 @@
+use arrow_gpu_array::array::buffer::ArrowGpuBuffer;
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::bool::{take_bool, take_null_buffer};
+use crate::prefix_sum::{exclusive_prefix_sum_op, read_prefix_sum_total};
+use crate::string::take_strings_op;
+use crate::take::{U32_TAKE_SHADER, U64_TAKE_SHADER, apply_take_op};
+
+const MASK_FLAGS_SHADER: &str = include_str!("../compute_shaders/filter/mask_flags.wgsl");
+const SCATTER_INDEXES_SHADER: &str = include_str!("../compute_shaders/filter/scatter_indexes.wgsl");
+const RANGE_SHADER: &str = include_str!("../compute_shaders/u32/range.wgsl");
+const U8_TAKE_SHADER: &str = include_str!("../compute_shaders/filter/take_8bit.wgsl");
+const U16_TAKE_SHADER: &str = include_str!("../compute_shaders/filter/take_16bit.wgsl");
+const U128_TAKE_SHADER: &str = include_str!("../compute_shaders/filter/take_128bit.wgsl");
+
+/// Trait for dropping the rows of an array not selected by a mask
+pub trait Filter: ArrayUtils + Sized {
+    fn filter(&self, mask: &BooleanArrayGPU) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("filter"));
+        let result = self.filter_op(mask, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Creates a new array with the elements of self whose mask bit is set.
+    /// Rows where the mask is null are dropped. The number of selected rows is
+    /// read back once all the work is recorded.
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self;
+
+    /// Creates a new array with one row per entry of `selected.indexes`, the rows past
+    /// the selected count are unspecified. Nothing is read back, variable size values
+    /// are allocated as large as the values of self.
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+}
+
+/// Index held by the entries of `SelectedIndexes` past the selected rows
+pub const UNSELECTED: u32 = u32::MAX;
+
+/// Indexes of the selected rows, computed without reading anything back
+pub struct SelectedIndexes {
+    /// One entry per row, the selected rows come first in order
+    /// and the remaining entries are `UNSELECTED`
+    pub indexes: UInt32ArrayGPU,
+    /// Number of selected rows, a single u32
+    pub count: ArrowGpuBuffer,
+}
+
+impl SelectedIndexes {
+    /// Reads back the number of selected rows. The commands recorded so far are
+    /// submitted, all the work using the indexes should be recorded before.
+    pub fn read_count(&self, pipeline: &mut ArrowComputePipeline) -> usize {
+        read_prefix_sum_total(&self.count, pipeline) as usize
+    }
+}
+
+/// Helper trait for primitive types that support filter
+pub trait FilterType {
+    /// Shader gathering values by index, packed types fill a whole word per invocation
+    const TAKE_SHADER: &'static str;
+    /// Number of values written by one invocation of `TAKE_SHADER`
+    const VALUES_PER_INVOCATION: usize;
+}
+
+macro_rules! impl_filter_type {
+    ($shader: ident, $values_per_invocation: expr, $($ty: ty),*) => {
+        $(impl FilterType for $ty {
+            const TAKE_SHADER: &'static str = $shader;
+            const VALUES_PER_INVOCATION: usize = $values_per_invocation;
+        })*
+    };
+}
+
+impl_filter_type!(U8_TAKE_SHADER, 4, u8, i8);
+impl_filter_type!(U16_TAKE_SHADER, 2, u16, i16, f16);
+impl_filter_type!(U32_TAKE_SHADER, 1, u32, i32, f32, Date32Type);
+impl_filter_type!(U64_TAKE_SHADER, 1, u64, i64, f64);
+impl_filter_type!(U128_TAKE_SHADER, 1, i128);
+
+/// Gathers the values of array at indexes, 8 and 16 bit values are packed by the kernel
+pub fn take_packed_op<T: FilterType + ArrowPrimitiveType>(
+    array: &PrimitiveArrayGpu<T>,
+    indexes: &UInt32ArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> PrimitiveArrayGpu<T> {
+    let item_size = std::mem::size_of::<T::NativeType>();
+    let new_buffer = apply_take_op(
+        &array.gpu_device,
+        &array.data,
+        &indexes.data,
+        indexes.len.div_ceil(T::VALUES_PER_INVOCATION) as u64,
+        ((indexes.len * item_size).div_ceil(4).max(1) * 4) as u64,
+        T::TAKE_SHADER,
+        "take",
+        pipeline,
+    );
+    let null_buffer = take_null_buffer(array.null_buffer.as_ref(), indexes, pipeline);
+
+    PrimitiveArrayGpu {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: indexes.len,
+        null_buffer,
+    }
+}
+
+impl<T: FilterType + ArrowPrimitiveType> Filter for PrimitiveArrayGpu<T> {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        take_packed_op(self, &selected.indexes, pipeline)
+    }
+}
+
+impl Filter for BooleanArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let indexes = &selected.indexes;
+        let new_buffer = take_bool(&self.gpu_device, &self.data, indexes, pipeline);
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+}
+
+impl Filter for StringArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    // the selected strings fit in the values of self
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        take_strings_op(self, &selected.indexes, Some(self.values.size()), pipeline)
+    }
+}
+
+// Temporal arrays are filtered through their underlying integer values
+macro_rules! impl_temporal_filter {
+    ($ty: ident) => {
+        impl Filter for $ty {
+            fn filter_op(
+                &self,
+                mask: &BooleanArrayGPU,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> Self {
+                let mut result = self.slice(0, 0);
+                result.values = self.values.filter_op(mask, pipeline);
+                result.len = result.values.len;
+                result
+            }
+
+            fn take_selected_op(
+                &self,
+                selected: &SelectedIndexes,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> Self {
+                let mut result = self.slice(0, 0);
+                result.values = self.values.take_selected_op(selected, pipeline);
+                result.len = result.values.len;
+                result
+            }
+        }
+    };
+}
+
+impl_temporal_filter!(TimestampArrayGPU);
+impl_temporal_filter!(DurationArrayGPU);
+impl_temporal_filter!(Time32ArrayGPU);
+
+impl Filter for Decimal128ArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let values = self.values.filter_op(mask, pipeline);
+        Decimal128ArrayGPU::new(values, self.precision, self.scale)
+    }
+
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let values = self.values.take_selected_op(selected, pipeline);
+        Decimal128ArrayGPU::new(values, self.precision, self.scale)
+    }
+}
+
+/// Create a new array with the elements of operand whose mask bit is set
+pub fn filter_dyn(operand: &ArrowArrayGPU, mask: &BooleanArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(operand.get_gpu_device(), Some("filter"));
+    let result = filter_op_dyn(operand, mask, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! filter_op_dyn_arms {
+    ($operand: ident, $mask: ident, $pipeline: ident, $($arr: ident),*) => {
+        match $operand {
+            $(ArrowArrayGPU::$arr(op) => op.filter_op($mask, $pipeline).into(),)*
+            _ => panic!(
+                "Filter Operation not supported for {:?}",
+                $operand.get_dtype(),
+            ),
+        }
+    };
+}
+
+/// Submits a command to create a new array with the elements of operand whose mask bit is set
+pub fn filter_op_dyn(
+    operand: &ArrowArrayGPU,
+    mask: &BooleanArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    filter_op_dyn_arms!(
+        operand,
+        mask,
+        pipeline,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU,
+        TimestampArrayGPU,
+        Time32ArrayGPU,
+        DurationArrayGPU,
+        Decimal128ArrayGPU,
+        BooleanArrayGPU,
+        StringArrayGPU,
+        ListArrayGPU,
+        FixedSizeListArrayGPU,
+        StructArrayGPU
+    )
+}
+
+macro_rules! take_selected_op_dyn_arms {
+    ($operand: ident, $selected: ident, $pipeline: ident, $($arr: ident),*) => {
+        match $operand {
+            $(ArrowArrayGPU::$arr(op) => op.take_selected_op($selected, $pipeline).into(),)*
+            _ => panic!(
+                "Filter Operation not supported for {:?}",
+                $operand.get_dtype(),
+            ),
+        }
+    };
+}
+
+/// Submits commands to create a new array with one row per entry of `selected.indexes`,
+/// the rows past the selected count are unspecified
+pub fn take_selected_op_dyn(
+    operand: &ArrowArrayGPU,
+    selected: &SelectedIndexes,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    take_selected_op_dyn_arms!(
+        operand,
+        selected,
+        pipeline,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU,
+        TimestampArrayGPU,
+        Time32ArrayGPU,
+        DurationArrayGPU,
+        Decimal128ArrayGPU,
+        BooleanArrayGPU,
+        StringArrayGPU,
+        ListArrayGPU,
+        FixedSizeListArrayGPU,
+        StructArrayGPU
+    )
+}
+
+/// Submits commands to compute the indexes of the rows whose mask bit is set.
+/// Null values in mask are not selected.
+pub fn filter_indexes_op(
+    mask: &BooleanArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> SelectedIndexes {
+    let validity = match &mask.null_buffer {
+        Some(null_buffer) => null_buffer.clone(),
+        None => NullBitBufferGpu::new_set_with_capacity(mask.gpu_device.clone(), mask.len),
+    };
+    let flags = pipeline
+        .device
+        .create_empty_buffer(mask.len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&mask.data, &validity.bit_buffer, &flags],
+        MASK_FLAGS_SHADER,
+        "mask_flags",
+        mask.len.div_ceil(256) as u32,
+    );
+    flag_indexes_op(&flags, mask.len, pipeline)
+}
+
+/// Submits commands to create the indexes of the rows whose u32 flag is 1 out of `len` flags
+pub(crate) fn flag_indexes_op(
+    flags: &dyn GpuBufferView,
+    len: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> SelectedIndexes {
+    let prefix_sum = exclusive_prefix_sum_op(flags, len, pipeline);
+
+    let indexes = pipeline.device.create_empty_buffer(len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&prefix_sum, &indexes],
+        SCATTER_INDEXES_SHADER,
+        "scatter_indexes",
+        len.div_ceil(256) as u32,
+    );
+
+    let indexes = UInt32ArrayGPU {
+        data: indexes.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len,
+        null_buffer: None,
+    };
+    let count = ArrowGpuBuffer::from(prefix_sum).slice(len as u64 * 4, 4);
+    SelectedIndexes { indexes, count }
+}
+
+/// Submits a command to create the indexes `start..start + len`
+pub fn range_indexes_op(
+    start: usize,
+    len: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[start as u32, len as u32]);
+    let indexes = pipeline.device.create_empty_buffer(len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&params, &indexes],
+        RANGE_SHADER,
+        "range",
+        len.div_ceil(256) as u32,
+    );
+
+    UInt32ArrayGPU {
+        data: indexes.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len,
+        null_buffer: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_filter_indexes() {
+        let device = GPU_DEVICE.clone();
+        let mut values = vec![Some(false); 600];
+        values[3] = Some(true);
+        values[4] = None;
+        values[299] = Some(true);
+        values[599] = Some(true);
+        let mask = BooleanArrayGPU::from_optional_slice(&values, device.clone());
+
+        let mut pipeline = ArrowComputePipeline::new(device, None);
+        let selected = filter_indexes_op(&mask, &mut pipeline);
+        assert_eq!(selected.read_count(&mut pipeline), 3);
+        pipeline.finish();
+        let mut expected = vec![UNSELECTED; 600];
+        expected[..3].copy_from_slice(&[3, 299, 599]);
+        assert_eq!(selected.indexes.raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_filter_multi_level() {
+        // the flags of more than 256 * 256 rows are scanned in three levels
+        let device = GPU_DEVICE.clone();
+        let values = (0..70_000u32).collect::<Vec<_>>();
+        let mask = (0..70_000).map(|x| Some(x % 3 == 0)).collect::<Vec<_>>();
+        let array = UInt32ArrayGPU::from_slice(&values, device.clone());
+        let mask_array = BooleanArrayGPU::from_optional_slice(&mask, device.clone());
+
+        let new_array = array.filter(&mask_array);
+        assert_eq!(
+            new_array.raw_values().unwrap(),
+            filter_expected(&values, &mask)
+        );
+
+        let strings = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        let strings = strings.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+        let array = StringArrayGPU::from_slice(&strings, device);
+        let new_array = array.filter(&mask_array);
+        let expected = filter_expected(&strings, &mask);
+        assert_eq!(new_array.raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_range_indexes() {
+        let mut pipeline = ArrowComputePipeline::new(GPU_DEVICE.clone(), None);
+        let indexes = range_indexes_op(5, 3, &mut pipeline);
+        pipeline.finish();
+        assert_eq!(indexes.raw_values().unwrap(), vec![5, 6, 7]);
+    }
+
+    fn mask_values(len: usize) -> Vec<Option<bool>> {
+        (0..len)
+            .map(|x| (x % 11 != 3).then_some(x % 3 != 1))
+            .collect()
+    }
+
+    fn filter_expected<T: Clone>(values: &[T], mask: &[Option<bool>]) -> Vec<T> {
+        values
+            .iter()
+            .zip(mask)
+            .filter(|(_, m)| **m == Some(true))
+            .map(|(x, _)| x.clone())
+            .collect()
+    }
+
+    macro_rules! test_filter_primitive {
+        ($fn_name: ident, $ty: ident, $value: expr) => {
+            #[test]
+            fn $fn_name() {
+                let device = GPU_DEVICE.clone();
+                let values = (0..700)
+                    .map(|x| (x % 7 != 0).then(|| $value(x)))
+                    .collect::<Vec<_>>();
+                let mask = mask_values(values.len());
+                let array = $ty::from_optional_slice(&values, device.clone());
+                let mask_array = BooleanArrayGPU::from_optional_slice(&mask, device);
+
+                let new_array = array.filter(&mask_array);
+                assert_eq!(new_array.values(), filter_expected(&values, &mask));
+            }
+        };
+    }
+
+    test_filter_primitive!(test_filter_u8, UInt8ArrayGPU, |x: usize| x as u8);
+    test_filter_primitive!(test_filter_i8, Int8ArrayGPU, |x: usize| (x % 100) as i8
+        - 50);
+    test_filter_primitive!(test_filter_u16, UInt16ArrayGPU, |x: usize| x as u16 * 90);
+    test_filter_primitive!(test_filter_i16, Int16ArrayGPU, |x: usize| 300 - x as i16);
+    test_filter_primitive!(test_filter_f32, Float32ArrayGPU, |x: usize| x as f32 / 4.0);
+    test_filter_primitive!(test_filter_i64, Int64ArrayGPU, |x: usize| x as i64
+        * -1_000_000_007);
+
+    #[test]
+    fn test_filter_bool() {
+        let device = GPU_DEVICE.clone();
+        let values = (0..300)
+            .map(|x| (x % 5 != 0).then_some(x % 2 == 0))
+            .collect::<Vec<_>>();
+        let mask = mask_values(values.len());
+        let array = BooleanArrayGPU::from_optional_slice(&values, device.clone());
+        let mask_array = BooleanArrayGPU::from_optional_slice(&mask, device);
+
+        let new_array = array.filter(&mask_array);
+        assert_eq!(new_array.values(), filter_expected(&values, &mask));
+    }
+
+    #[test]
+    fn test_filter_sliced_u8() {
+        let device = GPU_DEVICE.clone();
+        let values = (0..100).map(|x| x as u8).collect::<Vec<_>>();
+        let array = UInt8ArrayGPU::from_slice(&values, device.clone()).slice(3, 10);
+        let mask = [
+            true, false, false, true, true, true, false, false, false, true,
+        ];
+        let mask_array = BooleanArrayGPU::from_slice(&mask, device);
+
+        let new_array = array.filter(&mask_array);
+        assert_eq!(new_array.raw_values().unwrap(), vec![3, 6, 7, 8, 12]);
+    }
+
+    #[test]
+    fn test_filter_nothing_selected() {
+        let device = GPU_DEVICE.clone();
+        let array = UInt16ArrayGPU::from_slice(&[1, 2, 3], device.clone());
+        let mask_array =
+            BooleanArrayGPU::from_optional_slice(&[Some(false), None, Some(false)], device);
+
+        let new_array = array.filter(&mask_array);
+        assert_eq!(new_array.len, 0);
+        assert_eq!(new_array.values(), vec![]);
+    }
+
+    #[test]
+    fn test_filter_dyn() {
+        let device = GPU_DEVICE.clone();
+        let array: ArrowArrayGPU = StringArrayGPU::from_optional_slice(
+            &[Some("a"), None, Some("ccc"), Some("dd")],
+            device.clone(),
+        )
+        .into();
+        let mask_array = BooleanArrayGPU::from_optional_slice(
+            &[Some(true), Some(true), None, Some(true)],
+            device.clone(),
+        );
+        let new_array: StringArrayGPU = filter_dyn(&array, &mask_array).try_into().unwrap();
+        assert_eq!(
+            new_array.values(),
+            vec![Some("a".to_string()), None, Some("dd".to_string())]
+        );
+
+        let array: ArrowArrayGPU =
+            TimestampArrayGPU::from_slice(&[10, 20, 30, 40], TimeUnit::Second, None, device).into();
+        let new_array: TimestampArrayGPU = filter_dyn(&array, &mask_array).try_into().unwrap();
+        assert_eq!(new_array.unit, TimeUnit::Second);
+        assert_eq!(new_array.raw_values().unwrap(), vec![10, 20, 40]);
+    }
+}
@@ -1,6 +1,9 @@
 use arrow_gpu_array::array::Date32Type;
 
-use crate::{SwizzleType, merge::U32_MERGE_SHADER, put::U32_PUT_SHADER, take::U32_TAKE_SHADER};
+use crate::{
+    DictionaryEncodeType, SwizzleType, dictionary::ENCODE_32BIT_SHADER, merge::U32_MERGE_SHADER,
+    put::U32_PUT_SHADER, take::U32_TAKE_SHADER,
+};
 
 impl SwizzleType for i32 {
     const MERGE_SHADER: &'static str = U32_MERGE_SHADER;
@@ -14,6 +17,14 @@ impl SwizzleType for Date32Type {
     const PUT_SHADER: &'static str = U32_PUT_SHADER;
 }
 
+impl DictionaryEncodeType for i32 {
+    const ENCODE_SHADER: &'static str = ENCODE_32BIT_SHADER;
+}
+
+impl DictionaryEncodeType for Date32Type {
+    const ENCODE_SHADER: &'static str = ENCODE_32BIT_SHADER;
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -5,23 +5,49 @@ use arrow_gpu_array::array::{
 use arrow_gpu_array::gpu_utils::*;
 use bool::take_null_buffer;
 use put::apply_put_op;
-use std::sync::Arc;
 use take::apply_take_op;
 
 pub(crate) mod bool;
+pub(crate) mod concat;
+pub(crate) mod dictionary;
 pub(crate) mod f32;
+pub(crate) mod filter;
 pub(crate) mod i16;
 pub(crate) mod i32;
+pub(crate) mod i64;
 pub(crate) mod i8;
+pub(crate) mod list;
 pub(crate) mod merge;
+pub(crate) mod prefix_sum;
 pub(crate) mod put;
+pub(crate) mod record_batch;
+pub(crate) mod select;
+pub(crate) mod sort;
+pub(crate) mod string;
+pub(crate) mod struct_array;
 pub(crate) mod take;
+pub(crate) mod temporal;
 pub(crate) mod u16;
 pub(crate) mod u32;
+pub(crate) mod u64;
 pub(crate) mod u8;
 
+pub use concat::{Concat, concat_dyn, concat_op_dyn};
+pub use dictionary::*;
+pub use filter::{
+    Filter, FilterType, SelectedIndexes, UNSELECTED, filter_dyn, filter_indexes_op, filter_op_dyn,
+    range_indexes_op, take_packed_op, take_selected_op_dyn,
+};
+pub use list::fixed_size_list_offsets_op;
 pub use merge::*;
 pub use put::{put_dyn, put_op_dyn};
+pub use record_batch::RecordBatchSwizzle;
+pub use select::{first_index_op, top_k_indices_op};
+pub use sort::{
+    Sort, SortOptions, SortType, argsort_dyn, argsort_op_dyn, lexsort_to_indices,
+    lexsort_to_indices_op, sort_dyn, sort_op_dyn,
+};
+pub use string::gather_string_values_op;
 pub use take::{take_dyn, take_op_dyn};
 
 /// Trait for swizzle operations on the array
@@ -98,14 +124,14 @@ impl<T: SwizzleType + ArrowPrimitiveType> Swizzle for PrimitiveArrayGpu<T> {
             dispatch_size,
         );
 
-        let op1 = self.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
-        let op2 = other.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
-        let mask_null = mask.null_buffer.as_ref().map(|x| x.bit_buffer.as_ref());
+        let op1 = self.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let op2 = other.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let mask_null = mask.null_buffer.as_ref().map(|x| &x.bit_buffer);
 
         let bit_buffer = merge_null_buffers_op(op1, op2, &mask.data, mask_null, pipeline);
 
         let new_null_buffer = bit_buffer.map(|buffer| NullBitBufferGpu {
-            bit_buffer: Arc::new(buffer),
+            bit_buffer: buffer.into(),
             len: self.len,
             gpu_device: self.gpu_device.clone(),
         });
@@ -152,7 +178,7 @@ impl<T: SwizzleType + ArrowPrimitiveType> Swizzle for PrimitiveArrayGpu<T> {
         apply_put_op(
             &self.gpu_device,
             &self.data,
-            &dst.data,
+            &mut dst.data,
             &src_indexes.data,
             &dst_indexes.data,
             src_indexes.len as u64,
@@ -0,0 +1,303 @@
+use arrow_gpu_array::array::buffer::ArrowGpuBuffer;
+use arrow_gpu_array::array::{
+    ArrowArrayGPU, BooleanArrayGPU, FixedSizeListArrayGPU, ListArrayGPU, UInt32ArrayGPU,
+};
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::Swizzle;
+use crate::bool::take_null_buffer;
+use crate::filter::{Filter, SelectedIndexes, filter_indexes_op, take_selected_op_dyn};
+use crate::prefix_sum::{exclusive_prefix_sum_op, read_prefix_sum_total};
+use crate::string::TAKE_LENGTHS_SHADER;
+use crate::take::take_op_dyn;
+
+const CHILD_INDEXES_SHADER: &str = include_str!("../compute_shaders/list/child_indexes.wgsl");
+const FIXED_SIZE_OFFSETS_SHADER: &str =
+    include_str!("../compute_shaders/list/fixed_size_offsets.wgsl");
+
+/// Creates the `len + 1` offsets of a list array whose lists all have `value_length` elements
+pub fn fixed_size_list_offsets_op(
+    value_length: usize,
+    len: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[value_length as u32, len as u32]);
+    let offsets = pipeline.device.create_empty_buffer((len as u64 + 1) * 4);
+    pipeline.apply_function(
+        &[&params, &offsets],
+        FIXED_SIZE_OFFSETS_SHADER,
+        "fixed_size_offsets",
+        (len + 1).div_ceil(256) as u32,
+    );
+    offsets
+}
+
+/// Takes the lists at `indexes` out of the lists described by `offsets`.
+/// Returns the new offsets and the child array holding their elements.
+/// With `selected` the indexes are `SelectedIndexes` and the child array is as long
+/// as `values`, the total number of elements is not read back.
+fn take_lists_op(
+    offsets: &dyn GpuBufferView,
+    values: &ArrowArrayGPU,
+    indexes: &UInt32ArrayGPU,
+    selected: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> (ArrowGpuBuffer, ArrowArrayGPU) {
+    let new_lengths = pipeline
+        .device
+        .create_empty_buffer(indexes.len.max(1) as u64 * 4);
+    let starts = pipeline
+        .device
+        .create_empty_buffer(indexes.len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[offsets, &indexes.data, &new_lengths, &starts],
+        TAKE_LENGTHS_SHADER,
+        "take_lengths",
+        indexes.len.div_ceil(256) as u32,
+    );
+
+    let new_offsets: ArrowGpuBuffer =
+        exclusive_prefix_sum_op(&new_lengths, indexes.len, pipeline).into();
+    let total = if selected {
+        values.len()
+    } else {
+        read_prefix_sum_total(&new_offsets, pipeline) as usize
+    };
+
+    let child_indexes = pipeline.device.create_empty_buffer(total.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&starts, &new_offsets, &child_indexes],
+        CHILD_INDEXES_SHADER,
+        "child_indexes_main",
+        total.div_ceil(256) as u32,
+    );
+    let child_indexes = UInt32ArrayGPU {
+        data: child_indexes.into(),
+        gpu_device: indexes.gpu_device.clone(),
+        phantom: Default::default(),
+        len: total,
+        null_buffer: None,
+    };
+
+    let new_values = if selected {
+        let child = SelectedIndexes {
+            indexes: child_indexes,
+            count: new_offsets.slice(indexes.len as u64 * 4, 4),
+        };
+        take_selected_op_dyn(values, &child, pipeline)
+    } else {
+        take_op_dyn(values, &child_indexes, pipeline)
+    };
+    (new_offsets, new_values)
+}
+
+impl Swizzle for ListArrayGPU {
+    fn merge_op(
+        &self,
+        _other: &Self,
+        _mask: &BooleanArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        panic!("Merge Operation not supported for list arrays")
+    }
+
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let (new_offsets, new_values) =
+            take_lists_op(&self.offsets, &self.values, indexes, false, pipeline);
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            offsets: new_offsets,
+            values: Box::new(new_values),
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+
+    fn put_op(
+        &self,
+        _src_indexes: &UInt32ArrayGPU,
+        _dst: &mut Self,
+        _dst_indexes: &UInt32ArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) {
+        panic!("Put Operation not supported for list arrays")
+    }
+}
+
+impl Swizzle for FixedSizeListArrayGPU {
+    fn merge_op(
+        &self,
+        _other: &Self,
+        _mask: &BooleanArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        panic!("Merge Operation not supported for list arrays")
+    }
+
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        let (_, new_values) = take_lists_op(&offsets, &self.values, indexes, false, pipeline);
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            values: Box::new(new_values),
+            value_length: self.value_length,
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+
+    fn put_op(
+        &self,
+        _src_indexes: &UInt32ArrayGPU,
+        _dst: &mut Self,
+        _dst_indexes: &UInt32ArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) {
+        panic!("Put Operation not supported for list arrays")
+    }
+}
+
+impl Filter for ListArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    // the elements of the selected lists fit in a child as long as the child of self
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let indexes = &selected.indexes;
+        let (new_offsets, new_values) =
+            take_lists_op(&self.offsets, &self.values, indexes, true, pipeline);
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            offsets: new_offsets,
+            values: Box::new(new_values),
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+}
+
+impl Filter for FixedSizeListArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let indexes = &selected.indexes;
+        let offsets = fixed_size_list_offsets_op(self.value_length, self.len, pipeline);
+        let (_, new_values) = take_lists_op(&offsets, &self.values, indexes, true, pipeline);
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            values: Box::new(new_values),
+            value_length: self.value_length,
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::{Int32ArrayGPU, StringArrayGPU};
+    use arrow_gpu_array::utils::ScalarArray;
+
+    #[test]
+    fn test_take_list() {
+        let device = GPU_DEVICE.clone();
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5, 6], device.clone());
+        let array = ListArrayGPU::new(&[0, 2, 2, 5, 6], values.into(), None);
+        let indexes = UInt32ArrayGPU::from_slice(&[3, 1, 2, 0, 2], device);
+
+        let new_array = array.take(&indexes);
+        assert_eq!(new_array.raw_offsets(), vec![0, 1, 1, 4, 6, 9]);
+        assert_eq!(
+            new_array.raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![6]),
+                ScalarArray::I32Vec(vec![]),
+                ScalarArray::I32Vec(vec![3, 4, 5]),
+                ScalarArray::I32Vec(vec![1, 2]),
+                ScalarArray::I32Vec(vec![3, 4, 5]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_take_fixed_size_list() {
+        let device = GPU_DEVICE.clone();
+        let values = StringArrayGPU::from_slice(&["a", "bb", "ccc", "", "e", "f"], device.clone());
+        let array = FixedSizeListArrayGPU::new(values.into(), 2, None);
+        let indexes = UInt32ArrayGPU::from_slice(&[2, 0], device);
+
+        let new_array = crate::take_dyn(&array.into(), &indexes);
+        assert_eq!(
+            new_array.get_raw_values(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::StringVec(vec!["e".into(), "f".into()]),
+                ScalarArray::StringVec(vec!["a".into(), "bb".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_list() {
+        let device = GPU_DEVICE.clone();
+        let values =
+            StringArrayGPU::from_slice(&["a", "bb", "ccc", "d", "ee", "f"], device.clone());
+        let array = ListArrayGPU::new(&[0, 2, 2, 5, 6], values.into(), None);
+        let mask = BooleanArrayGPU::from_optional_slice(
+            &[Some(true), Some(true), None, Some(true)],
+            device.clone(),
+        );
+
+        let new_array = array.filter(&mask);
+        assert_eq!(new_array.len, 3);
+        assert_eq!(new_array.raw_offsets(), vec![0, 2, 2, 3]);
+        assert_eq!(
+            new_array.raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::StringVec(vec!["a".into(), "bb".into()]),
+                ScalarArray::StringVec(vec![]),
+                ScalarArray::StringVec(vec!["f".into()]),
+            ])
+        );
+
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5, 6], device.clone());
+        let array = FixedSizeListArrayGPU::new(values.into(), 2, None);
+        let mask = BooleanArrayGPU::from_slice(&[false, true, true], device);
+        assert_eq!(
+            array.filter(&mask).raw_values().unwrap(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![3, 4]),
+                ScalarArray::I32Vec(vec![5, 6]),
+            ])
+        );
+    }
+}
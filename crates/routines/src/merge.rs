@@ -1,7 +1,8 @@
-use arrow_gpu_array::array::{ArrowArrayGPU, BooleanArrayGPU};
+use arrow_gpu_array::array::{ArrowArrayGPU, BooleanArrayGPU, buffer::ArrowGpuBuffer};
 use arrow_gpu_array::gpu_utils::*;
 use wgpu::Buffer;
 
+pub(crate) const U64_MERGE_SHADER: &str = include_str!("../compute_shaders/64bit/merge.wgsl");
 pub(crate) const U32_MERGE_SHADER: &str = include_str!("../compute_shaders/32bit/merge.wgsl");
 pub(crate) const U16_MERGE_SHADER: &str = concat!(
     include_str!("../../../compute_shaders/u16/utils.wgsl"),
@@ -15,20 +16,20 @@ pub(crate) const U8_MERGE_SHADER: &str = concat!(
 use crate::Swizzle;
 
 pub fn merge_null_buffers_op(
-    operand_1_null_buffer: Option<&Buffer>,
-    operand_2_null_buffer: Option<&Buffer>,
-    mask: &Buffer,
-    mask_null_buffer: Option<&Buffer>,
+    operand_1_null_buffer: Option<&ArrowGpuBuffer>,
+    operand_2_null_buffer: Option<&ArrowGpuBuffer>,
+    mask: &dyn GpuBufferView,
+    mask_null_buffer: Option<&ArrowGpuBuffer>,
     pipeline: &mut ArrowComputePipeline,
 ) -> Option<Buffer> {
     const SHADER: &str = include_str!("../compute_shaders/u32/merge_null_buffer.wgsl");
 
     let merged_buffer_1 = if let Some(op1_null_buffer) = operand_1_null_buffer {
-        let dispatch_size = op1_null_buffer.size().div_ceil(4).div_ceil(256) as u32;
+        let dispatch_size = op1_null_buffer.view_size().div_ceil(4).div_ceil(256) as u32;
         Some(pipeline.apply_binary_function(
             op1_null_buffer,
             mask,
-            op1_null_buffer.size(),
+            op1_null_buffer.view_size(),
             SHADER,
             "merge_selected",
             dispatch_size,
@@ -38,11 +39,11 @@ pub fn merge_null_buffers_op(
     };
 
     let merged_buffer_2 = if let Some(op2_null_buffer) = operand_2_null_buffer {
-        let dispatch_size = op2_null_buffer.size().div_ceil(4).div_ceil(256) as u32;
+        let dispatch_size = op2_null_buffer.view_size().div_ceil(4).div_ceil(256) as u32;
         Some(pipeline.apply_binary_function(
             op2_null_buffer,
             mask,
-            op2_null_buffer.size(),
+            op2_null_buffer.view_size(),
             SHADER,
             "merge_not_selected",
             dispatch_size,
@@ -131,14 +132,21 @@ pub fn merge_op_dyn(
         mask,
         pipeline,
         Date32ArrayGPU,
+        TimestampArrayGPU,
+        DurationArrayGPU,
+        Time32ArrayGPU,
         Int32ArrayGPU,
         Int16ArrayGPU,
         Int8ArrayGPU,
         UInt32ArrayGPU,
         UInt16ArrayGPU,
         UInt8ArrayGPU,
+        Int64ArrayGPU,
+        UInt64ArrayGPU,
         Float32ArrayGPU,
-        BooleanArrayGPU
+        BooleanArrayGPU,
+        StringArrayGPU,
+        StructArrayGPU
     )
 }
 
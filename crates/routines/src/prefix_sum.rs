@@ -0,0 +1,47 @@
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+const PREFIX_SUM_SHADER: &str = include_str!("../compute_shaders/u32/prefix_sum.wgsl");
+
+/// Computes the exclusive prefix sum of the first `len` u32 in `values`.
+/// The returned buffer holds `len + 1` u32, the last one being the total.
+/// Blocks of 256 rows are scanned in parallel and their totals are scanned recursively.
+pub(crate) fn exclusive_prefix_sum_op(
+    values: &dyn GpuBufferView,
+    len: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let blocks = (len + 1).div_ceil(256);
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[len as u32, 0, 0, 0]);
+    let prefix_sum = pipeline.device.create_empty_buffer((len as u64 + 1) * 4);
+    let block_totals = pipeline.device.create_empty_buffer(blocks as u64 * 4);
+    pipeline.apply_function(
+        &[values, &params, &prefix_sum, &block_totals],
+        PREFIX_SUM_SHADER,
+        "scan_blocks",
+        blocks as u32,
+    );
+
+    if blocks > 1 {
+        let scanned_totals = exclusive_prefix_sum_op(&block_totals, blocks, pipeline);
+        pipeline.apply_function(
+            &[&scanned_totals, &params, &prefix_sum],
+            PREFIX_SUM_SHADER,
+            "add_block_prefixes",
+            blocks as u32,
+        );
+    }
+
+    prefix_sum
+}
+
+/// Reads back the last entry of a buffer returned by `exclusive_prefix_sum_op`
+pub(crate) fn read_prefix_sum_total(
+    prefix_sum: &dyn GpuBufferView,
+    pipeline: &mut ArrowComputePipeline,
+) -> u32 {
+    let total = pipeline.read_buffer(prefix_sum, prefix_sum.view_size() - 4, 4);
+    u32::from_le_bytes(total[0..4].try_into().unwrap())
+}
@@ -1,47 +1,33 @@
-use arrow_gpu_array::array::{ArrowArrayGPU, UInt32ArrayGPU};
+use arrow_gpu_array::array::{ArrowArrayGPU, UInt32ArrayGPU, buffer::ArrowGpuBuffer};
 use arrow_gpu_array::gpu_utils::*;
-use wgpu::Buffer;
 
 use crate::Swizzle;
 
+pub(crate) const U64_PUT_SHADER: &str = include_str!("../compute_shaders/64bit/put.wgsl");
 pub(crate) const U32_PUT_SHADER: &str = include_str!("../compute_shaders/32bit/put.wgsl");
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_put_op(
     device: &GpuDevice,
-    src_buffer: &Buffer,
-    dst_buffer: &Buffer,
-    src_indexes: &Buffer,
-    dst_indexes: &Buffer,
+    src_buffer: &dyn GpuBufferView,
+    dst_buffer: &mut ArrowGpuBuffer,
+    src_indexes: &dyn GpuBufferView,
+    dst_indexes: &dyn GpuBufferView,
     dispatch_size: u64,
     shader: &str,
     entry_point: &str,
     pipeline: &mut ArrowComputePipeline,
 ) {
+    // the destination is written in place, it can not share its buffer with other arrays
+    dst_buffer.make_unique(pipeline);
+
     let compute_pipeline = device.create_compute_pipeline(shader, entry_point);
 
-    let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-    let bind_group_array = device.device.create_bind_group(&wgpu::BindGroupDescriptor {
-        label: None,
-        layout: &bind_group_layout,
-        entries: &[
-            wgpu::BindGroupEntry {
-                binding: 0,
-                resource: src_buffer.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 1,
-                resource: dst_buffer.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 2,
-                resource: src_indexes.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 3,
-                resource: dst_indexes.as_entire_binding(),
-            },
-        ],
-    });
+    let bind_group_array = device.create_view_bind_group(
+        &compute_pipeline,
+        &[src_buffer, &*dst_buffer, src_indexes, dst_indexes],
+        &mut pipeline.encoder,
+    );
 
     let query = device.compute_pass(
         &mut pipeline.encoder,
@@ -103,6 +89,11 @@ pub fn put_op_dyn(
         Int32ArrayGPU,
         UInt32ArrayGPU,
         Date32ArrayGPU,
+        TimestampArrayGPU,
+        DurationArrayGPU,
+        Time32ArrayGPU,
+        Int64ArrayGPU,
+        UInt64ArrayGPU,
         BooleanArrayGPU
     );
 }
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use arrow_gpu_array::array::{ArrayUtils, BooleanArrayGPU, UInt32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_array::record_batch::RecordBatchGPU;
+
+use crate::filter::{filter_indexes_op, take_selected_op_dyn};
+use crate::take::take_op_dyn;
+
+/// Trait for applying the same row selection to every column of a record batch.
+/// The `_op` variants record the work of all the columns into a single pipeline.
+pub trait RecordBatchSwizzle: ArrayUtils + Sized {
+    fn filter(&self, mask: &BooleanArrayGPU) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("filter"));
+        let result = self.filter_op(mask, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn take(&self, indexes: &UInt32ArrayGPU) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("take"));
+        let result = self.take_op(indexes, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("slice"));
+        let result = self.slice_op(offset, length, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Keeps the rows whose mask bit is set, null values in mask are dropped.
+    /// All the columns are recorded before the number of selected rows is read back.
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self;
+
+    /// Creates a new batch by taking rows using the indexes
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self;
+
+    /// Creates a new batch with `length` rows starting from row `offset`.
+    /// The columns are zero-copy views, nothing is recorded in the pipeline.
+    fn slice_op(&self, offset: usize, length: usize, pipeline: &mut ArrowComputePipeline) -> Self;
+}
+
+impl RecordBatchSwizzle for RecordBatchGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(mask.len, self.num_rows());
+        let selected = filter_indexes_op(mask, pipeline);
+        let columns = self
+            .columns()
+            .iter()
+            .map(|x| take_selected_op_dyn(x, &selected, pipeline))
+            .collect::<Vec<_>>();
+        let count = selected.read_count(pipeline);
+        let columns = columns
+            .iter()
+            .map(|x| Arc::new(x.slice(0, count)))
+            .collect();
+        RecordBatchGPU::try_new(self.schema().clone(), columns)
+            .expect("filter keeps the schema of the batch")
+    }
+
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let columns = self
+            .columns()
+            .iter()
+            .map(|x| Arc::new(take_op_dyn(x, indexes, pipeline)))
+            .collect();
+        RecordBatchGPU::try_new(self.schema().clone(), columns)
+            .expect("take keeps the schema of the batch")
+    }
+
+    fn slice_op(&self, offset: usize, length: usize, _pipeline: &mut ArrowComputePipeline) -> Self {
+        assert!(offset + length <= self.num_rows());
+        let columns = self
+            .columns()
+            .iter()
+            .map(|x| Arc::new(x.slice(offset, length)))
+            .collect();
+        RecordBatchGPU::try_new(self.schema().clone(), columns)
+            .expect("slice keeps the schema of the batch")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::{Float32ArrayGPU, Int32ArrayGPU, ListArrayGPU, StringArrayGPU};
+    use arrow_gpu_array::utils::ScalarArray;
+
+    fn batch() -> RecordBatchGPU {
+        let device = GPU_DEVICE.clone();
+        RecordBatchGPU::try_from_iter([
+            (
+                "id",
+                Int32ArrayGPU::from_slice(&[1, 2, 3, 4], device.clone()).into(),
+            ),
+            (
+                "name",
+                StringArrayGPU::from_slice(&["a", "bb", "ccc", "dddd"], device.clone()).into(),
+            ),
+            (
+                "score",
+                Float32ArrayGPU::from_optional_slice(
+                    &[Some(1.0), None, Some(3.0), Some(4.0)],
+                    device,
+                )
+                .into(),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_batch_filter() {
+        let batch = batch();
+        let mask = BooleanArrayGPU::from_optional_slice(
+            &[Some(false), Some(true), None, Some(true)],
+            GPU_DEVICE.clone(),
+        );
+        let new_batch = batch.filter(&mask);
+
+        assert_eq!(new_batch.num_rows(), 2);
+        assert_eq!(new_batch.schema(), batch.schema());
+        assert_eq!(
+            new_batch.column(0).get_raw_values(),
+            ScalarArray::I32Vec(vec![2, 4])
+        );
+        assert_eq!(
+            new_batch.column(1).get_raw_values(),
+            ScalarArray::StringVec(vec!["bb".into(), "dddd".into()])
+        );
+        let scores: Float32ArrayGPU = new_batch.column(2).clone_array().try_into().unwrap();
+        assert_eq!(scores.values(), vec![None, Some(4.0)]);
+    }
+
+    #[test]
+    fn test_batch_filter_list() {
+        let device = GPU_DEVICE.clone();
+        let values = Int32ArrayGPU::from_slice(&[1, 2, 3, 4, 5], device.clone());
+        let batch = RecordBatchGPU::try_from_iter([
+            (
+                "id",
+                UInt32ArrayGPU::from_slice(&[1, 2, 3], device.clone()).into(),
+            ),
+            (
+                "items",
+                ListArrayGPU::new(&[0, 2, 3, 5], values.into(), None).into(),
+            ),
+        ])
+        .unwrap();
+        let mask = BooleanArrayGPU::from_slice(&[true, false, true], device);
+        let new_batch = batch.filter(&mask);
+
+        assert_eq!(new_batch.num_rows(), 2);
+        assert_eq!(
+            new_batch.column(1).get_raw_values(),
+            ScalarArray::ListVec(vec![
+                ScalarArray::I32Vec(vec![1, 2]),
+                ScalarArray::I32Vec(vec![4, 5]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_batch_take() {
+        let batch = batch();
+        let indexes = UInt32ArrayGPU::from_slice(&[3, 0], GPU_DEVICE.clone());
+        let new_batch = batch.take(&indexes);
+
+        assert_eq!(
+            new_batch.column_by_name("name").unwrap().get_raw_values(),
+            ScalarArray::StringVec(vec!["dddd".into(), "a".into()])
+        );
+    }
+
+    #[test]
+    fn test_batch_slice_and_project() {
+        let batch = batch();
+        let mut pipeline = ArrowComputePipeline::new(GPU_DEVICE.clone(), None);
+        let new_batch = batch
+            .slice_op(1, 2, &mut pipeline)
+            .project(&[1, 0])
+            .unwrap();
+        pipeline.finish();
+
+        assert_eq!(new_batch.num_rows(), 2);
+        assert_eq!(
+            new_batch.column(0).get_raw_values(),
+            ScalarArray::StringVec(vec!["bb".into(), "ccc".into()])
+        );
+        assert_eq!(
+            new_batch.column(1).get_raw_values(),
+            ScalarArray::I32Vec(vec![2, 3])
+        );
+    }
+}
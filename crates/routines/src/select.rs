@@ -0,0 +1,184 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Swizzle;
+use crate::filter::{FilterType, flag_indexes_op, range_indexes_op, take_packed_op};
+use crate::prefix_sum::exclusive_prefix_sum_op;
+use crate::sort::{Sort, SortOptions, SortType, sort_items_op};
+
+const HISTOGRAM_SHADER: &str = include_str!("../compute_shaders/sort/select_histogram.wgsl");
+const DIGIT_SHADER: &str = include_str!("../compute_shaders/sort/select_digit.wgsl");
+const CANDIDATES_SHADER: &str = include_str!("../compute_shaders/sort/select_candidates.wgsl");
+const TIES_SHADER: &str = include_str!("../compute_shaders/sort/select_ties.wgsl");
+const VALIDITY_SHADER: &str = include_str!("../compute_shaders/sort/select_validity.wgsl");
+const FIRST_SHADER: &str = include_str!("../compute_shaders/sort/select_first.wgsl");
+
+/// Submits commands to compute the indexes of the `k` largest values of array when
+/// descending, else of the `k` smallest ones, in sorted order. Null values are never
+/// selected and equal values are taken in the order of their rows.
+///
+/// A radix select finds the key of the k-th value one byte at a time, most significant
+/// first, then only the `k` values up to that key are sorted. The number of selected
+/// values is read back at the end when array has null values, else it is `k` at most.
+pub fn top_k_indices_op<T: SortType + FilterType>(
+    array: &PrimitiveArrayGpu<T>,
+    k: usize,
+    descending: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let options = SortOptions {
+        descending,
+        nulls_first: false,
+    };
+    if k == 0 || array.len == 0 {
+        return range_indexes_op(0, 0, pipeline);
+    }
+
+    let (items, stride) = sort_items_op(array, options, pipeline);
+    let key_words = T::KEY_BYTES.div_ceil(4) as usize;
+    let mut state = [0u32; 4];
+    state[key_words] = k.min(u32::MAX as usize) as u32;
+    let state = pipeline.device.create_scalar_buffer(&state);
+    let dispatch_size = array.len.div_ceil(256) as u32;
+    let create_params = |pipeline: &mut ArrowComputePipeline, word: u32, shift: u32| {
+        pipeline
+            .device
+            .create_uniform_buffer(&[array.len as u32, stride, word, shift])
+    };
+
+    for byte in (0..T::KEY_BYTES).rev() {
+        let params = create_params(pipeline, byte / 4, (byte % 4) * 8);
+        let histogram = pipeline.device.create_empty_buffer(256 * 4);
+        pipeline.apply_function(
+            &[&items, &params, &state, &histogram],
+            HISTOGRAM_SHADER,
+            "select_histogram",
+            dispatch_size,
+        );
+        pipeline.apply_function(
+            &[&histogram, &params, &state],
+            DIGIT_SHADER,
+            "select_digit",
+            1,
+        );
+    }
+
+    let params = create_params(pipeline, 0, 0);
+    let flags = pipeline.device.create_empty_buffer(array.len as u64 * 4);
+    let ties = pipeline.device.create_empty_buffer(array.len as u64 * 4);
+    pipeline.apply_function(
+        &[&items, &params, &state, &flags, &ties],
+        CANDIDATES_SHADER,
+        "select_candidates",
+        dispatch_size,
+    );
+    let tie_prefix = exclusive_prefix_sum_op(&ties, array.len, pipeline);
+    pipeline.apply_function(
+        &[&tie_prefix, &params, &state, &flags],
+        TIES_SHADER,
+        "select_ties",
+        dispatch_size,
+    );
+
+    // exactly min(k, valid values) rows are flagged
+    let selected = flag_indexes_op(&flags, array.len, pipeline);
+    let capacity = k.min(array.len);
+    let candidate_indexes = selected.indexes.slice(0, capacity);
+    let mut candidates = take_packed_op(array, &candidate_indexes, pipeline);
+    candidates.null_buffer = array
+        .null_buffer
+        .as_ref()
+        .map(|_| selected_validity_op(&candidate_indexes, pipeline));
+
+    // the unselected entries are null and sorted last
+    let sorted = candidates.argsort_op(options, pipeline);
+    let indexes = candidate_indexes.take_op(&sorted, pipeline);
+    match &array.null_buffer {
+        Some(_) => indexes.slice(0, selected.read_count(pipeline)),
+        None => indexes,
+    }
+}
+
+/// Null buffer of the selected indexes, unset for the `UNSELECTED` entries
+fn selected_validity_op(
+    indexes: &UInt32ArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> NullBitBufferGpu {
+    let words = indexes.len.div_ceil(32);
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[indexes.len as u32, 0, 0, 0]);
+    let validity = pipeline.device.create_empty_buffer(words.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&indexes.data, &params, &validity],
+        VALIDITY_SHADER,
+        "select_validity",
+        words.div_ceil(256) as u32,
+    );
+    NullBitBufferGpu {
+        bit_buffer: validity.into(),
+        len: indexes.len,
+        gpu_device: pipeline.device.clone(),
+    }
+}
+
+/// Submits commands to compute the index of the largest value of array when descending,
+/// else of the smallest one. Values are ordered like the sort, null values are skipped and
+/// the first row is taken among equal values. The index is null when no value is valid.
+pub fn first_index_op<T: SortType>(
+    array: &PrimitiveArrayGpu<T>,
+    descending: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let options = SortOptions {
+        descending,
+        nulls_first: false,
+    };
+    if array.len == 0 {
+        return UInt32ArrayGPU::from_optional_slice(&[None], pipeline.device.clone());
+    }
+    let (mut items, stride) = sort_items_op(array, options, pipeline);
+    let mut len = array.len;
+    let mut blocks = len.div_ceil(256).max(1);
+    loop {
+        let params = pipeline
+            .device
+            .create_uniform_buffer(&[len as u32, stride, 0, 0]);
+        // the single block of the last level writes the row and its validity
+        let size = if blocks == 1 {
+            8
+        } else {
+            blocks * stride as usize * 4
+        };
+        let output = pipeline.device.create_empty_buffer(size as u64);
+        pipeline.apply_function(
+            &[&items, &params, &output],
+            FIRST_SHADER,
+            "select_first",
+            blocks as u32,
+        );
+        items = output;
+        if blocks == 1 {
+            break;
+        }
+        len = blocks;
+        blocks = len.div_ceil(256);
+    }
+
+    let data = pipeline.device.create_empty_buffer(4);
+    let bit_buffer = pipeline.device.create_empty_buffer(4);
+    pipeline.copy_buffer_to_buffer(&items, 0, &data, 0, 4);
+    pipeline.copy_buffer_to_buffer(&items, 4, &bit_buffer, 0, 4);
+
+    UInt32ArrayGPU {
+        data: data.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len: 1,
+        null_buffer: Some(NullBitBufferGpu {
+            bit_buffer: bit_buffer.into(),
+            len: 1,
+            gpu_device: pipeline.device.clone(),
+        }),
+    }
+}
@@ -0,0 +1,605 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::Swizzle;
+use crate::filter::{FilterType, range_indexes_op, take_packed_op};
+use crate::prefix_sum::exclusive_prefix_sum_op;
+
+const RADIX_SHADER: &str = include_str!("../compute_shaders/sort/radix.wgsl");
+const INDICES_SHADER: &str = include_str!("../compute_shaders/sort/indices.wgsl");
+
+/// Options for sorting arrays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortOptions {
+    pub descending: bool,
+    /// Null values come before the other values
+    pub nulls_first: bool,
+}
+
+impl Default for SortOptions {
+    fn default() -> Self {
+        Self {
+            descending: false,
+            nulls_first: true,
+        }
+    }
+}
+
+/// Trait for sorting the elements of the array
+pub trait Sort: ArrayUtils + Sized {
+    fn sort(&self, options: SortOptions) -> Self {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("sort"));
+        let result = self.sort_op(options, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    fn argsort(&self, options: SortOptions) -> UInt32ArrayGPU {
+        let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("argsort"));
+        let result = self.argsort_op(options, &mut pipeline);
+        pipeline.finish();
+        result
+    }
+
+    /// Creates a new array with the elements of self in sorted order
+    fn sort_op(&self, options: SortOptions, pipeline: &mut ArrowComputePipeline) -> Self;
+
+    /// Computes the indexes that sort self, equal elements keep their order
+    fn argsort_op(
+        &self,
+        options: SortOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU;
+}
+
+/// Helper trait for Arrow arrays that support sort
+pub trait SortType: ArrowPrimitiveType {
+    /// Shader creating the items of the radix sort from the values
+    const ITEMS_SHADER: &'static str;
+    /// Number of bytes of the sort key, one radix pass is run for each of them
+    const KEY_BYTES: u32;
+}
+
+// Prepends the kind of the key to the shader building the items
+macro_rules! items_shader {
+    ($kind: literal, $key_file: literal) => {
+        concat!(
+            "const KEY_KIND = ",
+            $kind,
+            ";\n",
+            include_str!($key_file),
+            include_str!("../compute_shaders/sort/items.wgsl")
+        )
+    };
+}
+
+macro_rules! impl_sort_type {
+    ($ty: ty, $kind: literal, $key_bytes: expr, $key_file: literal) => {
+        impl SortType for $ty {
+            const ITEMS_SHADER: &'static str = items_shader!($kind, $key_file);
+            const KEY_BYTES: u32 = $key_bytes;
+        }
+    };
+}
+
+impl_sort_type!(u8, "0u", 1, "../compute_shaders/sort/key_8bit.wgsl");
+impl_sort_type!(i8, "1u", 1, "../compute_shaders/sort/key_8bit.wgsl");
+impl_sort_type!(u16, "0u", 2, "../compute_shaders/sort/key_16bit.wgsl");
+impl_sort_type!(i16, "1u", 2, "../compute_shaders/sort/key_16bit.wgsl");
+impl_sort_type!(f16, "2u", 2, "../compute_shaders/sort/key_16bit.wgsl");
+impl_sort_type!(u32, "0u", 4, "../compute_shaders/sort/key_32bit.wgsl");
+impl_sort_type!(i32, "1u", 4, "../compute_shaders/sort/key_32bit.wgsl");
+impl_sort_type!(f32, "2u", 4, "../compute_shaders/sort/key_32bit.wgsl");
+impl_sort_type!(
+    Date32Type,
+    "1u",
+    4,
+    "../compute_shaders/sort/key_32bit.wgsl"
+);
+impl_sort_type!(u64, "0u", 8, "../compute_shaders/sort/key_64bit.wgsl");
+impl_sort_type!(i64, "1u", 8, "../compute_shaders/sort/key_64bit.wgsl");
+impl_sort_type!(f64, "2u", 8, "../compute_shaders/sort/key_64bit.wgsl");
+
+/// Sorts `len` items of `stride` words with one stable radix pass for each `(word, shift)`
+/// digit, least significant first, and returns the rows stored in the last word of the items
+pub(crate) fn radix_sort_items_op(
+    items: Buffer,
+    len: usize,
+    stride: u32,
+    digits: &[(u32, u32)],
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let blocks = len.div_ceil(256);
+    let create_params = |pipeline: &mut ArrowComputePipeline, word: u32, shift: u32| {
+        pipeline.device.create_uniform_buffer(&[
+            len as u32,
+            blocks as u32,
+            stride,
+            word,
+            shift,
+            0,
+            0,
+            0,
+        ])
+    };
+
+    let mut items = items;
+    for (word, shift) in digits {
+        let params = create_params(pipeline, *word, *shift);
+        let counts = pipeline
+            .device
+            .create_empty_buffer((blocks.max(1) * 256 * 4) as u64);
+        pipeline.apply_function(
+            &[&items, &params, &counts],
+            RADIX_SHADER,
+            "histogram",
+            blocks as u32,
+        );
+        let offsets = exclusive_prefix_sum_op(&counts, blocks.max(1) * 256, pipeline);
+
+        let new_items = pipeline.device.create_empty_buffer(items.size());
+        pipeline.apply_function(
+            &[&items, &params, &offsets, &new_items],
+            RADIX_SHADER,
+            "scatter",
+            blocks as u32,
+        );
+        items = new_items;
+    }
+
+    let params = create_params(pipeline, 0, 0);
+    let indices = pipeline.device.create_empty_buffer(len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&items, &params, &indices],
+        INDICES_SHADER,
+        "item_indices",
+        len.div_ceil(256) as u32,
+    );
+
+    UInt32ArrayGPU {
+        data: indices.into(),
+        gpu_device: pipeline.device.clone(),
+        phantom: Default::default(),
+        len,
+        null_buffer: None,
+    }
+}
+
+/// Digits of the radix passes sorting keys of `key_bytes` bytes followed by the null flag
+pub(crate) fn key_digits(key_bytes: u32, has_nulls: bool) -> Vec<(u32, u32)> {
+    let key_words = key_bytes.div_ceil(4);
+    let mut digits = (0..key_bytes)
+        .map(|byte| (byte / 4, (byte % 4) * 8))
+        .collect::<Vec<_>>();
+    if has_nulls {
+        digits.push((key_words, 0));
+    }
+    digits
+}
+
+/// Creates the items of the radix sort of array, see `radix_sort_items_op`
+pub(crate) fn sort_items_op<T: SortType>(
+    array: &PrimitiveArrayGpu<T>,
+    options: SortOptions,
+    pipeline: &mut ArrowComputePipeline,
+) -> (Buffer, u32) {
+    let stride = T::KEY_BYTES.div_ceil(4) + 2;
+    let validity = match &array.null_buffer {
+        Some(null_buffer) => null_buffer.clone(),
+        None => NullBitBufferGpu::new_set_with_capacity(array.gpu_device.clone(), array.len.max(1)),
+    };
+    let params = pipeline.device.create_uniform_buffer(&[
+        array.len as u32,
+        options.descending as u32,
+        options.nulls_first as u32,
+        0,
+    ]);
+    let items = pipeline
+        .device
+        .create_empty_buffer((array.len.max(1) as u64) * stride as u64 * 4);
+    pipeline.apply_function(
+        &[&array.data, &validity.bit_buffer, &params, &items],
+        T::ITEMS_SHADER,
+        "make_items",
+        array.len.div_ceil(256) as u32,
+    );
+    (items, stride)
+}
+
+impl<T: SortType + FilterType> Sort for PrimitiveArrayGpu<T> {
+    fn sort_op(&self, options: SortOptions, pipeline: &mut ArrowComputePipeline) -> Self {
+        if self.len == 0 {
+            return self.slice(0, 0);
+        }
+        let indexes = self.argsort_op(options, pipeline);
+        take_packed_op(self, &indexes, pipeline)
+    }
+
+    fn argsort_op(
+        &self,
+        options: SortOptions,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> UInt32ArrayGPU {
+        if self.len == 0 {
+            return range_indexes_op(0, 0, pipeline);
+        }
+        let (items, stride) = sort_items_op(self, options, pipeline);
+        let digits = key_digits(T::KEY_BYTES, self.null_buffer.is_some());
+        radix_sort_items_op(items, self.len, stride, &digits, pipeline)
+    }
+}
+
+/// Creates a new array with the elements of data in sorted order
+pub fn sort_dyn(data: &ArrowArrayGPU, options: SortOptions) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("sort"));
+    let result = sort_op_dyn(data, options, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Computes the indexes that sort data
+pub fn argsort_dyn(data: &ArrowArrayGPU, options: SortOptions) -> UInt32ArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), Some("argsort"));
+    let result = argsort_op_dyn(data, options, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+macro_rules! sort_op_dyn_arms {
+    ($data: ident, $options: ident, $pipeline: ident, $fn: ident, $($arr: ident),*) => {
+        match $data {
+            $(ArrowArrayGPU::$arr(array) => array.$fn($options, $pipeline).into(),)*
+            _ => panic!("Sort not supported for {:?}", $data.get_dtype()),
+        }
+    };
+}
+
+/// Submits commands to create a new array with the elements of data in sorted order
+pub fn sort_op_dyn(
+    data: &ArrowArrayGPU,
+    options: SortOptions,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    sort_op_dyn_arms!(
+        data,
+        options,
+        pipeline,
+        sort_op,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU
+    )
+}
+
+/// Submits commands to compute the indexes that sort data
+pub fn argsort_op_dyn(
+    data: &ArrowArrayGPU,
+    options: SortOptions,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    sort_op_dyn_arms!(
+        data,
+        options,
+        pipeline,
+        argsort_op,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU
+    )
+}
+
+macro_rules! argsort_taken_op_dyn_arms {
+    ($data: ident, $indexes: ident, $options: ident, $pipeline: ident, $($arr: ident),*) => {
+        match $data {
+            $(ArrowArrayGPU::$arr(array) => {
+                take_packed_op(array, $indexes, $pipeline).argsort_op($options, $pipeline)
+            })*
+            _ => panic!("Sort not supported for {:?}", $data.get_dtype()),
+        }
+    };
+}
+
+/// Computes the indexes that sort the elements of data taken at indexes
+fn argsort_taken_op_dyn(
+    data: &ArrowArrayGPU,
+    indexes: &UInt32ArrayGPU,
+    options: SortOptions,
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    argsort_taken_op_dyn_arms!(
+        data,
+        indexes,
+        options,
+        pipeline,
+        Float16ArrayGPU,
+        Float32ArrayGPU,
+        Float64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt16ArrayGPU,
+        UInt8ArrayGPU,
+        Int64ArrayGPU,
+        Int32ArrayGPU,
+        Int16ArrayGPU,
+        Int8ArrayGPU,
+        Date32ArrayGPU
+    )
+}
+
+/// Computes the indexes that sort the rows formed by columns, the first column being
+/// the most significant
+pub fn lexsort_to_indices(columns: &[(ArrowArrayGPU, SortOptions)]) -> UInt32ArrayGPU {
+    assert!(!columns.is_empty(), "lexsort needs at least one column");
+    let mut pipeline = ArrowComputePipeline::new(columns[0].0.get_gpu_device(), Some("lexsort"));
+    let result = lexsort_to_indices_op(columns, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits commands to compute the indexes that sort the rows formed by columns.
+/// Each column from the least significant one is sorted in the order of the previous
+/// columns with a stable radix sort, which keeps the order of its equal rows.
+pub fn lexsort_to_indices_op(
+    columns: &[(ArrowArrayGPU, SortOptions)],
+    pipeline: &mut ArrowComputePipeline,
+) -> UInt32ArrayGPU {
+    let ((last, last_options), rest) = columns
+        .split_last()
+        .expect("lexsort needs at least one column");
+    let mut indexes = argsort_op_dyn(last, *last_options, pipeline);
+    for (column, options) in rest.iter().rev() {
+        assert_eq!(column.len(), indexes.len);
+        let sorted = argsort_taken_op_dyn(column, &indexes, *options, pipeline);
+        indexes = indexes.take_op(&sorted, pipeline);
+    }
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    const DESCENDING_NULLS_LAST: SortOptions = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    // stable sort on the cpu with the same null placement
+    fn expected_argsort<T>(
+        values: &[Option<T>],
+        options: SortOptions,
+        cmp: impl Fn(&T, &T) -> std::cmp::Ordering,
+    ) -> Vec<u32> {
+        let mut indexes = (0..values.len() as u32).collect::<Vec<_>>();
+        indexes.sort_by(|a, b| match (&values[*a as usize], &values[*b as usize]) {
+            (Some(a), Some(b)) if options.descending => cmp(b, a),
+            (Some(a), Some(b)) => cmp(a, b),
+            (None, None) => std::cmp::Ordering::Equal,
+            (None, Some(_)) if options.nulls_first => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (Some(_), None) if options.nulls_first => std::cmp::Ordering::Greater,
+            (Some(_), None) => std::cmp::Ordering::Less,
+        });
+        indexes
+    }
+
+    macro_rules! test_argsort {
+        ($fn_name: ident, $ty: ident, $value: expr, $cmp: expr) => {
+            #[test]
+            fn $fn_name() {
+                let values = (0..1500usize)
+                    .map(|x| (x % 13 != 5).then(|| $value(x)))
+                    .collect::<Vec<_>>();
+                let array = $ty::from_optional_slice(&values, GPU_DEVICE.clone());
+                for options in [SortOptions::default(), DESCENDING_NULLS_LAST] {
+                    assert_eq!(
+                        array.argsort(options).raw_values().unwrap(),
+                        expected_argsort(&values, options, $cmp)
+                    );
+                }
+            }
+        };
+    }
+
+    test_argsort!(
+        test_argsort_u8,
+        UInt8ArrayGPU,
+        |x: usize| (x * 37 % 251) as u8,
+        u8::cmp
+    );
+    test_argsort!(
+        test_argsort_i8,
+        Int8ArrayGPU,
+        |x: usize| (x * 37 % 251) as u8 as i8,
+        i8::cmp
+    );
+    test_argsort!(
+        test_argsort_i16,
+        Int16ArrayGPU,
+        |x: usize| (x * 997 % 65521) as u16 as i16,
+        i16::cmp
+    );
+    test_argsort!(
+        test_argsort_u32,
+        UInt32ArrayGPU,
+        |x: usize| (x as u32).wrapping_mul(2654435761) >> 7,
+        u32::cmp
+    );
+    test_argsort!(
+        test_argsort_i32,
+        Int32ArrayGPU,
+        |x: usize| (x as i32 - 700) * 1234567,
+        i32::cmp
+    );
+    test_argsort!(
+        test_argsort_i64,
+        Int64ArrayGPU,
+        |x: usize| (x as i64 - 700) * 9_876_543_210_123,
+        i64::cmp
+    );
+    test_argsort!(
+        test_argsort_f32,
+        Float32ArrayGPU,
+        |x: usize| ((x * 7919 % 1000) as f32 - 500.0) / 8.0,
+        f32::total_cmp
+    );
+    test_argsort!(
+        test_argsort_f16,
+        Float16ArrayGPU,
+        |x: usize| f16::from_f32(((x * 7919 % 1000) as f32 - 500.0) / 16.0),
+        f16::total_cmp
+    );
+    test_argsort!(
+        test_argsort_f64,
+        Float64ArrayGPU,
+        |x: usize| ((x * 7919 % 1000) as f64 - 500.0) / 3.0,
+        f64::total_cmp
+    );
+
+    #[test]
+    fn test_argsort_many_blocks() {
+        // the digit counts of more than 256 blocks are scanned in several levels
+        let values = (0..100_000u32)
+            .map(|x| Some(x.wrapping_mul(2654435761) % 1000))
+            .collect::<Vec<_>>();
+        let array = UInt32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let options = SortOptions::default();
+        assert_eq!(
+            array.argsort(options).raw_values().unwrap(),
+            expected_argsort(&values, options, u32::cmp)
+        );
+    }
+
+    #[test]
+    fn test_sort_floats_total_order() {
+        let values = [
+            Some(1.0),
+            Some(f32::NAN),
+            None,
+            Some(-0.0),
+            Some(f32::NEG_INFINITY),
+            Some(0.0),
+            Some(-f32::NAN),
+            Some(f32::INFINITY),
+            Some(-2.5),
+        ];
+        let array = Float32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let sorted = array.sort(SortOptions::default()).values();
+        let bits = sorted
+            .iter()
+            .map(|x| x.map(f32::to_bits))
+            .collect::<Vec<_>>();
+        let mut expected = values.iter().flatten().copied().collect::<Vec<_>>();
+        expected.sort_by(f32::total_cmp);
+        let expected = std::iter::once(None)
+            .chain(expected.into_iter().map(|x| Some(x.to_bits())))
+            .collect::<Vec<_>>();
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn test_sort_u16_nulls_last() {
+        let values = [Some(5), None, Some(3), Some(65535), None, Some(0)];
+        let array = UInt16ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        assert_eq!(
+            array.sort(options).values(),
+            vec![Some(0), Some(3), Some(5), Some(65535), None, None]
+        );
+    }
+
+    #[test]
+    fn test_sort_empty() {
+        let array = Int32ArrayGPU::from_slice(&[], GPU_DEVICE.clone());
+        assert_eq!(array.sort(SortOptions::default()).values(), vec![]);
+        assert_eq!(array.argsort(SortOptions::default()).values(), vec![]);
+    }
+
+    #[test]
+    fn test_argsort_take() {
+        let values = [Some(20), Some(-4), None, Some(7)];
+        let array = Int32ArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let indexes = array.argsort(DESCENDING_NULLS_LAST);
+        assert_eq!(
+            array.take(&indexes).values(),
+            vec![Some(20), Some(7), Some(-4), None]
+        );
+    }
+
+    #[test]
+    fn test_sort_dyn_date32() {
+        let array: ArrowArrayGPU =
+            Date32ArrayGPU::from_slice(&[19000, -5, 365, 0], GPU_DEVICE.clone()).into();
+        let sorted: Date32ArrayGPU = sort_dyn(&array, SortOptions::default()).try_into().unwrap();
+        assert_eq!(sorted.raw_values().unwrap(), vec![-5, 0, 365, 19000]);
+        assert_eq!(
+            argsort_dyn(&array, DESCENDING_NULLS_LAST)
+                .raw_values()
+                .unwrap(),
+            vec![0, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_lexsort_to_indices() {
+        let device = GPU_DEVICE.clone();
+        let a = (0..1000u32).map(|x| x % 7).collect::<Vec<_>>();
+        let b = (0..1000)
+            .map(|x| (x % 11 != 0).then_some((x * 31 % 17) as i16))
+            .collect::<Vec<_>>();
+        let c = (0..1000).map(|x| ((x * 13) % 5) as f32).collect::<Vec<_>>();
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let columns = [
+            (
+                UInt32ArrayGPU::from_slice(&a, device.clone()).into(),
+                SortOptions::default(),
+            ),
+            (
+                Int16ArrayGPU::from_optional_slice(&b, device.clone()).into(),
+                descending,
+            ),
+            (
+                Float32ArrayGPU::from_slice(&c, device).into(),
+                SortOptions::default(),
+            ),
+        ];
+
+        let mut expected = (0..1000u32).collect::<Vec<_>>();
+        expected.sort_by(|x, y| {
+            let (x, y) = (*x as usize, *y as usize);
+            // descending with nulls first
+            let b_order = match (b[x], b[y]) {
+                (Some(p), Some(q)) => q.cmp(&p),
+                (p, q) => p.is_some().cmp(&q.is_some()),
+            };
+            a[x].cmp(&a[y]).then(b_order).then(c[x].total_cmp(&c[y]))
+        });
+        assert_eq!(lexsort_to_indices(&columns).raw_values().unwrap(), expected);
+    }
+}
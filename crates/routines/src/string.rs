@@ -0,0 +1,247 @@
+use arrow_gpu_array::array::{BooleanArrayGPU, NullBitBufferGpu, StringArrayGPU, UInt32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::Swizzle;
+use crate::bool::take_null_buffer;
+use crate::merge_null_buffers_op;
+use crate::prefix_sum::{exclusive_prefix_sum_op, read_prefix_sum_total};
+
+pub(crate) const TAKE_LENGTHS_SHADER: &str =
+    include_str!("../compute_shaders/string/take_lengths.wgsl");
+const MERGE_LENGTHS_SHADER: &str = include_str!("../compute_shaders/string/merge_lengths.wgsl");
+const MERGE_STARTS_SHADER: &str = include_str!("../compute_shaders/string/merge_starts.wgsl");
+const GATHER_SHADER: &str = include_str!("../compute_shaders/string/gather.wgsl");
+
+/// Turns the lengths of the new strings into offsets and allocates a value buffer
+/// big enough to hold all of them. The total size is read back unless a capacity is given.
+fn new_offsets_and_values(
+    new_lengths: &dyn GpuBufferView,
+    len: usize,
+    values_capacity: Option<u64>,
+    pipeline: &mut ArrowComputePipeline,
+) -> (Buffer, Buffer) {
+    let new_offsets = exclusive_prefix_sum_op(new_lengths, len, pipeline);
+    let total_bytes = match values_capacity {
+        Some(capacity) => capacity,
+        None => read_prefix_sum_total(&new_offsets, pipeline) as u64,
+    };
+    let new_values = pipeline
+        .device
+        .create_empty_buffer(total_bytes.next_multiple_of(4).max(4));
+    (new_offsets, new_values)
+}
+
+/// Copies the strings starting at `starts` in `values` into `new_values`
+fn gather_op(
+    values: &dyn GpuBufferView,
+    starts: &dyn GpuBufferView,
+    new_offsets: &dyn GpuBufferView,
+    new_values: &dyn GpuBufferView,
+    pipeline: &mut ArrowComputePipeline,
+) {
+    let dispatch_size = new_values.view_size().div_ceil(4).div_ceil(256) as u32;
+    pipeline.apply_function(
+        &[values, starts, new_offsets, new_values],
+        GATHER_SHADER,
+        "gather",
+        dispatch_size,
+    );
+}
+
+/// Builds the offsets and values buffers of a new string array of length `len`
+/// whose ith string is the `lengths[i]` bytes of `values` starting at byte `starts[i]`
+pub fn gather_string_values_op(
+    values: &dyn GpuBufferView,
+    starts: &dyn GpuBufferView,
+    lengths: &dyn GpuBufferView,
+    len: usize,
+    pipeline: &mut ArrowComputePipeline,
+) -> (Buffer, Buffer) {
+    let (new_offsets, new_values) = new_offsets_and_values(lengths, len, None, pipeline);
+    gather_op(values, starts, &new_offsets, &new_values, pipeline);
+    (new_offsets, new_values)
+}
+
+/// Takes the strings of array at indexes, rows taking the index `UNSELECTED` are empty.
+/// When given, `values_capacity` bytes must hold all the taken strings and the
+/// total size of the strings is not read back.
+pub(crate) fn take_strings_op(
+    array: &StringArrayGPU,
+    indexes: &UInt32ArrayGPU,
+    values_capacity: Option<u64>,
+    pipeline: &mut ArrowComputePipeline,
+) -> StringArrayGPU {
+    let new_lengths = pipeline
+        .device
+        .create_empty_buffer(indexes.len.max(1) as u64 * 4);
+    let starts = pipeline
+        .device
+        .create_empty_buffer(indexes.len.max(1) as u64 * 4);
+    pipeline.apply_function(
+        &[&array.offsets, &indexes.data, &new_lengths, &starts],
+        TAKE_LENGTHS_SHADER,
+        "take_lengths",
+        indexes.len.div_ceil(256) as u32,
+    );
+
+    let (new_offsets, new_values) =
+        new_offsets_and_values(&new_lengths, indexes.len, values_capacity, pipeline);
+    gather_op(&array.values, &starts, &new_offsets, &new_values, pipeline);
+
+    let null_buffer = take_null_buffer(array.null_buffer.as_ref(), indexes, pipeline);
+
+    StringArrayGPU {
+        offsets: new_offsets.into(),
+        values: new_values.into(),
+        gpu_device: array.gpu_device.clone(),
+        len: indexes.len,
+        null_buffer,
+    }
+}
+
+impl Swizzle for StringArrayGPU {
+    fn merge_op(
+        &self,
+        other: &Self,
+        mask: &BooleanArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let new_lengths = pipeline.apply_ternary_function(
+            &self.offsets,
+            &other.offsets,
+            &mask.data,
+            self.len as u64 * 4,
+            MERGE_LENGTHS_SHADER,
+            "merge_lengths",
+            self.len as u32,
+        );
+
+        let (new_offsets, new_values) =
+            new_offsets_and_values(&new_lengths, self.len, None, pipeline);
+
+        let dispatch_size = self.len.div_ceil(256) as u32;
+        for (array, entry_point) in [(self, "selected_starts"), (other, "not_selected_starts")] {
+            let starts = pipeline.apply_binary_function(
+                &array.offsets,
+                &mask.data,
+                self.len as u64 * 4,
+                MERGE_STARTS_SHADER,
+                entry_point,
+                dispatch_size,
+            );
+            gather_op(&array.values, &starts, &new_offsets, &new_values, pipeline);
+        }
+
+        let op1 = self.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let op2 = other.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let mask_null = mask.null_buffer.as_ref().map(|x| &x.bit_buffer);
+
+        let bit_buffer = merge_null_buffers_op(op1, op2, &mask.data, mask_null, pipeline);
+
+        let null_buffer = bit_buffer.map(|buffer| NullBitBufferGpu {
+            bit_buffer: buffer.into(),
+            len: self.len,
+            gpu_device: self.gpu_device.clone(),
+        });
+
+        Self {
+            offsets: new_offsets.into(),
+            values: new_values.into(),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        take_strings_op(self, indexes, None, pipeline)
+    }
+
+    fn put_op(
+        &self,
+        _src_indexes: &UInt32ArrayGPU,
+        _dst: &mut Self,
+        _dst_indexes: &UInt32ArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) {
+        panic!("Put Operation not supported for variable length arrays")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::*;
+
+    fn to_owned(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values.iter().map(|x| x.map(String::from)).collect()
+    }
+
+    #[test]
+    fn test_take_string() {
+        let device = GPU_DEVICE.clone();
+        let array = StringArrayGPU::from_optional_slice(
+            &[Some("apple"), None, Some(""), Some("kiwi fruit"), Some("ü")],
+            device.clone(),
+        );
+        let indexes = UInt32ArrayGPU::from_slice(&[3, 0, 1, 4, 2, 3, 4], device);
+        let expected = to_owned(&[
+            Some("kiwi fruit"),
+            Some("apple"),
+            None,
+            Some("ü"),
+            Some(""),
+            Some("kiwi fruit"),
+            Some("ü"),
+        ]);
+
+        assert_eq!(array.take(&indexes).values(), expected);
+
+        let new_array = take_dyn(&array.into(), &indexes);
+        let new_values = StringArrayGPU::try_from(new_array).unwrap().values();
+        assert_eq!(new_values, expected);
+    }
+
+    #[test]
+    fn test_large_take_string() {
+        let device = GPU_DEVICE.clone();
+        let values = (0..2000).map(|x| x.to_string()).collect::<Vec<_>>();
+        let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+        let array = StringArrayGPU::from_slice(&values, device.clone());
+        let indexes = (0..2000).rev().collect::<Vec<u32>>();
+        let indexes = UInt32ArrayGPU::from_slice(&indexes, device);
+
+        let expected = values
+            .iter()
+            .rev()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(array.take(&indexes).raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_merge_string() {
+        let device = GPU_DEVICE.clone();
+        let left = StringArrayGPU::from_optional_slice(
+            &[Some("a"), Some("left"), None, Some("xyz"), Some("q")],
+            device.clone(),
+        );
+        let right = StringArrayGPU::from_optional_slice(
+            &[Some("right"), Some("b"), Some("cc"), None, Some("r")],
+            device.clone(),
+        );
+        let mask = BooleanArrayGPU::from_optional_slice(
+            &[Some(false), Some(true), Some(false), Some(true), None],
+            device,
+        );
+        let expected = to_owned(&[Some("right"), Some("left"), Some("cc"), Some("xyz"), None]);
+
+        assert_eq!(left.merge(&right, &mask).values(), expected);
+
+        let new_array = merge_dyn(&left.into(), &right.into(), &mask);
+        let new_values = StringArrayGPU::try_from(new_array).unwrap().values();
+        assert_eq!(new_values, expected);
+    }
+}
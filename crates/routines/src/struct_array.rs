@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use arrow_gpu_array::array::{BooleanArrayGPU, NullBitBufferGpu, StructArrayGPU, UInt32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Swizzle;
+use crate::bool::take_null_buffer;
+use crate::filter::{Filter, SelectedIndexes, filter_indexes_op, take_selected_op_dyn};
+use crate::merge::{merge_null_buffers_op, merge_op_dyn};
+use crate::take::take_op_dyn;
+
+impl Swizzle for StructArrayGPU {
+    fn merge_op(
+        &self,
+        other: &Self,
+        mask: &BooleanArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        assert_eq!(self.fields, other.fields);
+        let columns = self
+            .columns
+            .iter()
+            .zip(&other.columns)
+            .map(|(x, y)| Arc::new(merge_op_dyn(x, y, mask, pipeline)))
+            .collect();
+
+        let op1 = self.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let op2 = other.null_buffer.as_ref().map(|x| &x.bit_buffer);
+        let mask_null = mask.null_buffer.as_ref().map(|x| &x.bit_buffer);
+
+        let bit_buffer = merge_null_buffers_op(op1, op2, &mask.data, mask_null, pipeline);
+
+        let null_buffer = bit_buffer.map(|buffer| NullBitBufferGpu {
+            bit_buffer: buffer.into(),
+            len: self.len,
+            gpu_device: self.gpu_device.clone(),
+        });
+
+        Self {
+            fields: self.fields.clone(),
+            columns,
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+
+    fn take_op(&self, indexes: &UInt32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|x| Arc::new(take_op_dyn(x, indexes, pipeline)))
+            .collect();
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            fields: self.fields.clone(),
+            columns,
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+
+    fn put_op(
+        &self,
+        _src_indexes: &UInt32ArrayGPU,
+        _dst: &mut Self,
+        _dst_indexes: &UInt32ArrayGPU,
+        _pipeline: &mut ArrowComputePipeline,
+    ) {
+        panic!("Put Operation not supported for struct arrays")
+    }
+}
+
+impl Filter for StructArrayGPU {
+    fn filter_op(&self, mask: &BooleanArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        assert_eq!(self.len, mask.len);
+        let selected = filter_indexes_op(mask, pipeline);
+        let result = self.take_selected_op(&selected, pipeline);
+        result.slice(0, selected.read_count(pipeline))
+    }
+
+    fn take_selected_op(
+        &self,
+        selected: &SelectedIndexes,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let indexes = &selected.indexes;
+        let columns = self
+            .columns
+            .iter()
+            .map(|x| Arc::new(take_selected_op_dyn(x, selected, pipeline)))
+            .collect();
+        let null_buffer = take_null_buffer(self.null_buffer.as_ref(), indexes, pipeline);
+
+        Self {
+            fields: self.fields.clone(),
+            columns,
+            gpu_device: self.gpu_device.clone(),
+            len: indexes.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::{ArrowType, Int32ArrayGPU, StringArrayGPU};
+    use arrow_gpu_array::record_batch::Field;
+    use arrow_gpu_array::utils::ScalarArray;
+
+    fn struct_array(ids: &[i32], names: &[&str]) -> StructArrayGPU {
+        let device = GPU_DEVICE.clone();
+        StructArrayGPU::new(
+            vec![
+                Field::new("id", ArrowType::Int32Type, false),
+                Field::new("name", ArrowType::Utf8Type, false),
+            ],
+            vec![
+                Arc::new(Int32ArrayGPU::from_slice(ids, device.clone()).into()),
+                Arc::new(StringArrayGPU::from_slice(names, device).into()),
+            ],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_take_struct() {
+        let array = struct_array(&[1, 2, 3], &["a", "b", "c"]);
+        let indexes = UInt32ArrayGPU::from_slice(&[2, 0, 2], GPU_DEVICE.clone());
+        assert_eq!(
+            array.take(&indexes).raw_values().unwrap(),
+            ScalarArray::StructVec(vec![
+                ScalarArray::I32Vec(vec![3, 1, 3]),
+                ScalarArray::StringVec(vec!["c".into(), "a".into(), "c".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_struct() {
+        let array_1 = struct_array(&[1, 2, 3], &["a", "b", "c"]);
+        let array_2 = struct_array(&[4, 5, 6], &["d", "e", "f"]);
+        let mask = BooleanArrayGPU::from_slice(&[true, false, true], GPU_DEVICE.clone());
+        assert_eq!(
+            array_1.merge(&array_2, &mask).raw_values().unwrap(),
+            ScalarArray::StructVec(vec![
+                ScalarArray::I32Vec(vec![1, 5, 3]),
+                ScalarArray::StringVec(vec!["a".into(), "e".into(), "c".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_struct() {
+        let array = struct_array(&[1, 2, 3], &["a", "bb", "c"]);
+        let mask = BooleanArrayGPU::from_slice(&[false, true, true], GPU_DEVICE.clone());
+        assert_eq!(
+            array.filter(&mask).raw_values().unwrap(),
+            ScalarArray::StructVec(vec![
+                ScalarArray::I32Vec(vec![2, 3]),
+                ScalarArray::StringVec(vec!["bb".into(), "c".into()]),
+            ])
+        );
+    }
+}
@@ -4,12 +4,14 @@ use wgpu::Buffer;
 
 use crate::Swizzle;
 
+pub(crate) const U64_TAKE_SHADER: &str = include_str!("../compute_shaders/64bit/take.wgsl");
 pub(crate) const U32_TAKE_SHADER: &str = include_str!("../compute_shaders/32bit/take.wgsl");
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_take_op(
     device: &GpuDevice,
-    operand_1: &Buffer,
-    operand_2: &Buffer,
+    operand_1: &dyn GpuBufferView,
+    operand_2: &dyn GpuBufferView,
     dispatch_size: u64,
     output_size: u64,
     shader: &str,
@@ -20,25 +22,11 @@ pub(crate) fn apply_take_op(
 
     let new_values_buffer = device.create_empty_buffer(output_size);
 
-    let bind_group_layout = compute_pipeline.get_bind_group_layout(0);
-    let bind_group_array = device.device.create_bind_group(&wgpu::BindGroupDescriptor {
-        label: None,
-        layout: &bind_group_layout,
-        entries: &[
-            wgpu::BindGroupEntry {
-                binding: 0,
-                resource: operand_1.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 1,
-                resource: operand_2.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 2,
-                resource: new_values_buffer.as_entire_binding(),
-            },
-        ],
-    });
+    let bind_group_array = device.create_view_bind_group(
+        &compute_pipeline,
+        &[operand_1, operand_2, &new_values_buffer],
+        &mut pipeline.encoder,
+    );
 
     let query = device.compute_pass(
         &mut pipeline.encoder,
@@ -87,10 +75,19 @@ pub fn take_op_dyn(
         indexes,
         pipeline,
         Date32ArrayGPU,
+        TimestampArrayGPU,
+        DurationArrayGPU,
+        Time32ArrayGPU,
         UInt32ArrayGPU,
         Int32ArrayGPU,
         Float32ArrayGPU,
-        BooleanArrayGPU
+        Int64ArrayGPU,
+        UInt64ArrayGPU,
+        BooleanArrayGPU,
+        StringArrayGPU,
+        ListArrayGPU,
+        FixedSizeListArrayGPU,
+        StructArrayGPU
     )
 }
 
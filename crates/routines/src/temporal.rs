@@ -0,0 +1,98 @@
+use arrow_gpu_array::array::{
+    BooleanArrayGPU, DurationArrayGPU, Time32ArrayGPU, TimestampArrayGPU, UInt32ArrayGPU,
+};
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Swizzle;
+
+// Temporal arrays are swizzled through their underlying integer values
+macro_rules! impl_temporal_swizzle {
+    ($ty: ident) => {
+        impl Swizzle for $ty {
+            fn merge_op(
+                &self,
+                other: &Self,
+                mask: &BooleanArrayGPU,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> Self {
+                assert_eq!(self.unit, other.unit);
+                let mut result = self.clone_array();
+                result.values = self.values.merge_op(&other.values, mask, pipeline);
+                result
+            }
+
+            fn take_op(
+                &self,
+                indexes: &UInt32ArrayGPU,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> Self {
+                let mut result = self.clone_array();
+                result.values = self.values.take_op(indexes, pipeline);
+                result.len = indexes.len;
+                result
+            }
+
+            fn put_op(
+                &self,
+                src_indexes: &UInt32ArrayGPU,
+                dst: &mut Self,
+                dst_indexes: &UInt32ArrayGPU,
+                pipeline: &mut ArrowComputePipeline,
+            ) {
+                assert_eq!(self.unit, dst.unit);
+                self.values
+                    .put_op(src_indexes, &mut dst.values, dst_indexes, pipeline)
+            }
+        }
+    };
+}
+
+impl_temporal_swizzle!(TimestampArrayGPU);
+impl_temporal_swizzle!(DurationArrayGPU);
+impl_temporal_swizzle!(Time32ArrayGPU);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::take_dyn;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::{ArrowType, TimeUnit};
+
+    #[test]
+    fn test_timestamp_take() {
+        let device = GPU_DEVICE.clone();
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[Some(1), None, Some(1 << 40)],
+            TimeUnit::Nanosecond,
+            Some("+01:00".into()),
+            device.clone(),
+        );
+        let indexes = UInt32ArrayGPU::from_slice(&[2, 1, 2, 0], device);
+        let new_array = take_dyn(&array.into(), &indexes);
+        assert_eq!(
+            new_array.get_dtype(),
+            ArrowType::TimestampType(TimeUnit::Nanosecond, Some("+01:00".into()))
+        );
+        let new_array: TimestampArrayGPU = new_array.try_into().unwrap();
+        assert_eq!(new_array.len, 4);
+        assert_eq!(
+            new_array.values(),
+            vec![Some(1 << 40), None, Some(1 << 40), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_time32_merge_and_put() {
+        let device = GPU_DEVICE.clone();
+        let left = Time32ArrayGPU::from_slice(&[1, 2, 3], TimeUnit::Millisecond, device.clone());
+        let right = Time32ArrayGPU::from_slice(&[4, 5, 6], TimeUnit::Millisecond, device.clone());
+        let mask = BooleanArrayGPU::from_slice(&[true, false, true], device.clone());
+        let mut merged = left.merge(&right, &mask);
+        assert_eq!(merged.raw_values().unwrap(), vec![1, 5, 3]);
+
+        let src_indexes = UInt32ArrayGPU::from_slice(&[0], device.clone());
+        let dst_indexes = UInt32ArrayGPU::from_slice(&[2], device);
+        right.put(&src_indexes, &mut merged, &dst_indexes);
+        assert_eq!(merged.raw_values().unwrap(), vec![1, 5, 4]);
+    }
+}
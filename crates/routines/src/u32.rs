@@ -1,4 +1,7 @@
-use crate::{SwizzleType, merge::U32_MERGE_SHADER, put::U32_PUT_SHADER, take::U32_TAKE_SHADER};
+use crate::{
+    DictionaryEncodeType, SwizzleType, dictionary::ENCODE_32BIT_SHADER, merge::U32_MERGE_SHADER,
+    put::U32_PUT_SHADER, take::U32_TAKE_SHADER,
+};
 
 impl SwizzleType for u32 {
     const MERGE_SHADER: &'static str = U32_MERGE_SHADER;
@@ -6,6 +9,10 @@ impl SwizzleType for u32 {
     const PUT_SHADER: &'static str = U32_PUT_SHADER;
 }
 
+impl DictionaryEncodeType for u32 {
+    const ENCODE_SHADER: &'static str = ENCODE_32BIT_SHADER;
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -96,4 +103,19 @@ mod test {
         [1, 3, 5, 7],
         [100, 0, 101, 1, 102, 2, 103, 3]
     );
+
+    #[test]
+    fn test_take_sliced_u32() {
+        let device = arrow_gpu_array::GPU_DEVICE.clone();
+        let values = (0..30)
+            .map(|x| (x % 4 != 0).then_some(x))
+            .collect::<Vec<_>>();
+        let array = UInt32ArrayGPU::from_optional_slice(&values, device.clone()).slice(9, 10);
+
+        let indexes = UInt32ArrayGPU::from_slice(&[9, 0, 3, 4], device);
+        assert_eq!(
+            array.take(&indexes).values(),
+            vec![Some(18), Some(9), None, Some(13)]
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use crate::{
+    DictionaryEncodeType, SwizzleType, dictionary::ENCODE_64BIT_SHADER, merge::U64_MERGE_SHADER,
+    put::U64_PUT_SHADER, take::U64_TAKE_SHADER,
+};
+
+impl SwizzleType for u64 {
+    const MERGE_SHADER: &'static str = U64_MERGE_SHADER;
+    const TAKE_SHADER: &'static str = U64_TAKE_SHADER;
+    const PUT_SHADER: &'static str = U64_PUT_SHADER;
+}
+
+impl DictionaryEncodeType for u64 {
+    const ENCODE_SHADER: &'static str = ENCODE_64BIT_SHADER;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use arrow_gpu_array::array::*;
+
+    test_merge_op!(
+        test_merge_u64_array_u64,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        UInt64ArrayGPU,
+        merge,
+        merge_dyn,
+        [
+            Some(0),
+            Some(1 << 40),
+            None,
+            None,
+            Some(u64::MAX),
+            Some(4),
+            Some(10)
+        ],
+        [Some(1), Some(2), None, Some(3), None, None, Some(20)],
+        [
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            None
+        ],
+        [
+            Some(0),
+            Some(1 << 40),
+            None,
+            Some(3),
+            Some(u64::MAX),
+            None,
+            None
+        ]
+    );
+
+    test_take_op!(
+        test_take_u64,
+        UInt64ArrayGPU,
+        UInt32ArrayGPU,
+        UInt64ArrayGPU,
+        take,
+        take_dyn,
+        [Some(0), Some(1 << 40), None, Some(u64::MAX)],
+        [3, 1, 2, 0, 0, 1],
+        [
+            Some(u64::MAX),
+            Some(1 << 40),
+            None,
+            Some(0),
+            Some(0),
+            Some(1 << 40)
+        ]
+    );
+
+    test_put_op!(
+        test_put_u64,
+        UInt64ArrayGPU,
+        put,
+        put_dyn,
+        [0, 1 << 40, 3, u64::MAX],
+        [100, 0, 101, 0, 102, 0, 103, 0],
+        [0, 1, 2, 3],
+        [1, 3, 5, 7],
+        [100, 0, 101, 1 << 40, 102, 3, 103, u64::MAX]
+    );
+}
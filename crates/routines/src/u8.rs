@@ -63,4 +63,18 @@ mod test {
             None
         ]
     );
+
+    #[test]
+    fn test_merge_sliced_u8() {
+        let device = arrow_gpu_array::GPU_DEVICE.clone();
+        let values = (0..30).collect::<Vec<u8>>();
+        let array = UInt8ArrayGPU::from_slice(&values, device.clone()).slice(9, 10);
+
+        let other = UInt8ArrayGPU::from_slice(&[100; 10], device.clone());
+        let mask = BooleanArrayGPU::from_slice(&[true, false].repeat(10), device).slice(1, 10);
+        assert_eq!(
+            array.merge(&other, &mask).raw_values().unwrap(),
+            vec![100, 10, 100, 12, 100, 14, 100, 16, 100, 18]
+        );
+    }
 }
@@ -0,0 +1,37 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+// Shifts every byte of the word within [first, first + 26) by delta.
+// Bytes of multi byte utf8 characters are >= 0x80 and are left untouched.
+fn map_ascii(word: u32, first: u32, delta: u32) -> u32 {
+    var new_word = 0u;
+    for (var i = 0u; i < 4u; i++) {
+        var byte = (word >> (i * 8u)) & 0xffu;
+        if byte >= first && byte < first + 26u {
+            byte ^= delta;
+        }
+        new_word |= byte << (i * 8u);
+    }
+    return new_word;
+}
+
+@compute
+@workgroup_size(256)
+fn upper(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = map_ascii(values[global_id.x], 97u, 32u);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn lower(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = map_ascii(values[global_id.x], 65u, 32u);
+    }
+}
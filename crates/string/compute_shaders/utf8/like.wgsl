@@ -0,0 +1,106 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> offsets: array<u32>;
+
+// word 0 holds the number of tokens, word 1 the flags, followed by one token per word
+// a token is either a literal byte, ANY_CHAR for `_` or ANY_STRING for `%`
+@group(0)
+@binding(2)
+var<storage, read> pattern: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_values: array<u32>;
+
+const ANY_CHAR: u32 = 256u;
+const ANY_STRING: u32 = 257u;
+const CASE_INSENSITIVE: u32 = 1u;
+const NEGATE: u32 = 2u;
+const NONE: u32 = 0xffffffffu;
+
+var<workgroup> local_set_bits: array<atomic<u32>, 8>;
+
+fn set_bit(index: u32, value: bool) {
+    let index_by_32 = index / 32u;
+    if value {
+        atomicOr(&local_set_bits[index_by_32], (1u << (index % 32u)));
+    }
+}
+
+fn write_bits(global_id: vec3<u32>, local_id: vec3<u32>, value: bool) {
+    set_bit(local_id.x, value);
+    workgroupBarrier();
+    if global_id.x % 32u == 0u && global_id.x / 32u < arrayLength(&new_values) {
+        new_values[global_id.x / 32u] = atomicLoad(&local_set_bits[local_id.x / 32u]);
+    }
+}
+
+fn get_byte(index: u32) -> u32 {
+    let byte = (values[index / 4u] >> ((index % 4u) * 8u)) & 0xffu;
+    if (pattern[1] & CASE_INSENSITIVE) != 0u && byte >= 65u && byte < 91u {
+        return byte ^ 32u;
+    }
+    return byte;
+}
+
+fn get_token(index: u32) -> u32 {
+    return pattern[2u + index];
+}
+
+// Returns the position of the first byte of the next utf8 character
+fn next_char(position: u32, end: u32) -> u32 {
+    var next = position + 1u;
+    while next < end && (get_byte(next) & 0xc0u) == 0x80u {
+        next++;
+    }
+    return next;
+}
+
+// Wildcard matching which backtracks only to the last seen `%`
+fn like(start: u32, end: u32) -> bool {
+    let tokens = pattern[0];
+    var position = start;
+    var token = 0u;
+    var star_token = NONE;
+    var star_position = 0u;
+
+    while position < end {
+        if token < tokens && get_token(token) == ANY_STRING {
+            star_token = token;
+            star_position = position;
+            token++;
+        } else if token < tokens && get_token(token) == ANY_CHAR {
+            position = next_char(position, end);
+            token++;
+        } else if token < tokens && get_token(token) == get_byte(position) {
+            position++;
+            token++;
+        } else if star_token != NONE {
+            star_position = next_char(star_position, end);
+            position = star_position;
+            token = star_token + 1u;
+        } else {
+            return false;
+        }
+    }
+
+    while token < tokens && get_token(token) == ANY_STRING {
+        token++;
+    }
+    return token == tokens;
+}
+
+@compute
+@workgroup_size(256)
+fn like_match(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    let row = global_id.x;
+    var value = false;
+    if row + 1u < arrayLength(&offsets) {
+        value = like(offsets[row], offsets[row + 1u]) != ((pattern[1] & NEGATE) != 0u);
+    }
+    write_bits(global_id, local_id, value);
+}
@@ -0,0 +1,158 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> offsets: array<u32>;
+
+// first word holds the length of the pattern in bytes, followed by the packed bytes
+@group(0)
+@binding(2)
+var<storage, read> pattern: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> new_values: array<u32>;
+
+var<workgroup> local_set_bits: array<atomic<u32>, 8>;
+
+fn set_bit(index: u32, value: bool) {
+    let index_by_32 = index / 32u;
+    if value {
+        atomicOr(&local_set_bits[index_by_32], (1u << (index % 32u)));
+    }
+}
+
+fn write_bits(global_id: vec3<u32>, local_id: vec3<u32>, value: bool) {
+    set_bit(local_id.x, value);
+    workgroupBarrier();
+    if global_id.x % 32u == 0u && global_id.x / 32u < arrayLength(&new_values) {
+        new_values[global_id.x / 32u] = atomicLoad(&local_set_bits[local_id.x / 32u]);
+    }
+}
+
+fn get_byte(index: u32) -> u32 {
+    return (values[index / 4u] >> ((index % 4u) * 8u)) & 0xffu;
+}
+
+fn get_pattern_byte(index: u32) -> u32 {
+    return (pattern[1u + index / 4u] >> ((index % 4u) * 8u)) & 0xffu;
+}
+
+fn matches_at(position: u32) -> bool {
+    for (var i = 0u; i < pattern[0]; i++) {
+        if get_byte(position + i) != get_pattern_byte(i) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn is_row(row: u32) -> bool {
+    return row + 1u < arrayLength(&offsets);
+}
+
+@compute
+@workgroup_size(256)
+fn starts_with(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    let row = global_id.x;
+    var value = false;
+    if is_row(row) {
+        let start = offsets[row];
+        let end = offsets[row + 1u];
+        value = end - start >= pattern[0] && matches_at(start);
+    }
+    write_bits(global_id, local_id, value);
+}
+
+@compute
+@workgroup_size(256)
+fn ends_with(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    let row = global_id.x;
+    var value = false;
+    if is_row(row) {
+        let start = offsets[row];
+        let end = offsets[row + 1u];
+        value = end - start >= pattern[0] && matches_at(end - pattern[0]);
+    }
+    write_bits(global_id, local_id, value);
+}
+
+@compute
+@workgroup_size(256)
+fn contains(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    let row = global_id.x;
+    var value = false;
+    if is_row(row) {
+        let start = offsets[row];
+        let end = offsets[row + 1u];
+        if end - start >= pattern[0] {
+            for (var position = start; position <= end - pattern[0]; position++) {
+                if matches_at(position) {
+                    value = true;
+                    break;
+                }
+            }
+        }
+    }
+    write_bits(global_id, local_id, value);
+}
+
+// compares the bytes of the row with the pattern, the byte order of utf8 is the order of
+// the code points. Returns 0 when the row is smaller, 1 when equal and 2 when larger
+fn compare_row(start: u32, end: u32) -> u32 {
+    let length = end - start;
+    for (var i = 0u; i < pattern[0]; i++) {
+        // the row is a proper prefix of the pattern
+        if i == length {
+            return 0u;
+        }
+        let byte = get_byte(start + i);
+        let pattern_byte = get_pattern_byte(i);
+        if byte != pattern_byte {
+            return select(2u, 0u, byte < pattern_byte);
+        }
+    }
+    return select(1u, 2u, length > pattern[0]);
+}
+
+// `accepted` holds the result for a smaller, an equal and a larger row
+fn write_compare(global_id: vec3<u32>, local_id: vec3<u32>, accepted: vec3<bool>) {
+    let row = global_id.x;
+    var value = false;
+    if is_row(row) {
+        value = accepted[compare_row(offsets[row], offsets[row + 1u])];
+    }
+    write_bits(global_id, local_id, value);
+}
+
+@compute
+@workgroup_size(256)
+fn eq_scalar(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    write_compare(global_id, local_id, vec3<bool>(false, true, false));
+}
+
+@compute
+@workgroup_size(256)
+fn gt_scalar(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    write_compare(global_id, local_id, vec3<bool>(false, false, true));
+}
+
+@compute
+@workgroup_size(256)
+fn gteq_scalar(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    write_compare(global_id, local_id, vec3<bool>(false, true, true));
+}
+
+@compute
+@workgroup_size(256)
+fn lt_scalar(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    write_compare(global_id, local_id, vec3<bool>(true, false, false));
+}
+
+@compute
+@workgroup_size(256)
+fn lteq_scalar(@builtin(global_invocation_id) global_id: vec3<u32>, @builtin(local_invocation_id) local_id: vec3<u32>) {
+    write_compare(global_id, local_id, vec3<bool>(true, true, false));
+}
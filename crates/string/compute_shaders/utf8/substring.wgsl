@@ -0,0 +1,55 @@
+struct SubstringParams {
+    start: u32,
+    length: u32,
+}
+
+@group(0)
+@binding(0)
+var<storage, read> values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read> offsets: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_lengths: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> starts: array<u32>;
+
+@group(0)
+@binding(4)
+var<uniform> params: SubstringParams;
+
+fn get_byte(index: u32) -> u32 {
+    return (values[index / 4u] >> ((index % 4u) * 8u)) & 0xffu;
+}
+
+// returns the byte position after skipping `count` characters from `position`
+fn skip_chars(position: u32, end: u32, count: u32) -> u32 {
+    var new_position = position;
+    var chars = 0u;
+    while new_position < end && chars < count {
+        new_position += 1u;
+        // continuation bytes of a multi byte character are of the form 0b10xxxxxx
+        while new_position < end && (get_byte(new_position) & 0xc0u) == 0x80u {
+            new_position += 1u;
+        }
+        chars += 1u;
+    }
+    return new_position;
+}
+
+@compute
+@workgroup_size(256)
+fn substring(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let row = global_id.x;
+    if row < arrayLength(&new_lengths) {
+        let end = offsets[row + 1u];
+        let start = skip_chars(offsets[row], end, params.start);
+        starts[row] = start;
+        new_lengths[row] = skip_chars(start, end, params.length) - start;
+    }
+}
@@ -0,0 +1,330 @@
+use arrow_gpu_array::array::{ArrayUtils, ArrowArrayGPU, BooleanArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+
+pub(crate) mod utf8;
+
+const LENGTH_ENTRY_POINT: &str = "length";
+const UPPER_ENTRY_POINT: &str = "upper";
+const LOWER_ENTRY_POINT: &str = "lower";
+const SUBSTRING_ENTRY_POINT: &str = "substring";
+const STARTS_WITH_ENTRY_POINT: &str = "starts_with";
+const ENDS_WITH_ENTRY_POINT: &str = "ends_with";
+const CONTAINS_ENTRY_POINT: &str = "contains";
+const LIKE_ENTRY_POINT: &str = "like_match";
+const EQ_SCALAR_ENTRY_POINT: &str = "eq_scalar";
+const GT_SCALAR_ENTRY_POINT: &str = "gt_scalar";
+const GTEQ_SCALAR_ENTRY_POINT: &str = "gteq_scalar";
+const LT_SCALAR_ENTRY_POINT: &str = "lt_scalar";
+const LTEQ_SCALAR_ENTRY_POINT: &str = "lteq_scalar";
+
+macro_rules! default_impl {
+    ($self: ident, $fn: ident $(, $arg: ident)*) => {
+        let mut pipeline = ArrowComputePipeline::new($self.get_gpu_device(), None);
+        let output = Self::$fn(&$self, $($arg,)* &mut pipeline);
+        pipeline.finish();
+        return output;
+    };
+}
+
+/// Trait for string functions on each element of the array
+pub trait StringUnary: ArrayUtils + Sized {
+    type LengthType;
+
+    fn length(&self) -> Self::LengthType {
+        default_impl!(self, length_op);
+    }
+    fn upper(&self) -> Self {
+        default_impl!(self, upper_op);
+    }
+    fn lower(&self) -> Self {
+        default_impl!(self, lower_op);
+    }
+    fn substring(&self, start: u32, length: u32) -> Self {
+        default_impl!(self, substring_op, start, length);
+    }
+
+    /// Compute the length in bytes of each string in array
+    fn length_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::LengthType;
+    /// Convert the ASCII characters of each string in array to upper case
+    fn upper_op(&self, pipeline: &mut ArrowComputePipeline) -> Self;
+    /// Convert the ASCII characters of each string in array to lower case
+    fn lower_op(&self, pipeline: &mut ArrowComputePipeline) -> Self;
+    /// Take `length` characters starting from the character at `start` of each string in array
+    fn substring_op(&self, start: u32, length: u32, pipeline: &mut ArrowComputePipeline) -> Self;
+}
+
+/// Trait for matching each element of the array against a pattern
+pub trait StringMatch: ArrayUtils {
+    fn starts_with(&self, pattern: &str) -> BooleanArrayGPU {
+        default_impl!(self, starts_with_op, pattern);
+    }
+    fn ends_with(&self, pattern: &str) -> BooleanArrayGPU {
+        default_impl!(self, ends_with_op, pattern);
+    }
+    fn contains(&self, pattern: &str) -> BooleanArrayGPU {
+        default_impl!(self, contains_op, pattern);
+    }
+
+    /// Returns a boolean array indicating which strings start with `pattern`
+    fn starts_with_op(&self, pattern: &str, pipeline: &mut ArrowComputePipeline)
+    -> BooleanArrayGPU;
+    /// Returns a boolean array indicating which strings end with `pattern`
+    fn ends_with_op(&self, pattern: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating which strings contain `pattern`
+    fn contains_op(&self, pattern: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+}
+
+/// Trait for comparing each element of the array against a string.
+/// Strings are ordered by their utf8 bytes, which is the order of their code points.
+/// Null strings stay null in the output.
+pub trait StringCompare: ArrayUtils {
+    fn eq_scalar(&self, value: &str) -> BooleanArrayGPU {
+        default_impl!(self, eq_scalar_op, value);
+    }
+    fn gt_scalar(&self, value: &str) -> BooleanArrayGPU {
+        default_impl!(self, gt_scalar_op, value);
+    }
+    fn gteq_scalar(&self, value: &str) -> BooleanArrayGPU {
+        default_impl!(self, gteq_scalar_op, value);
+    }
+    fn lt_scalar(&self, value: &str) -> BooleanArrayGPU {
+        default_impl!(self, lt_scalar_op, value);
+    }
+    fn lteq_scalar(&self, value: &str) -> BooleanArrayGPU {
+        default_impl!(self, lteq_scalar_op, value);
+    }
+
+    /// Returns a boolean array indicating where `self` == `value`
+    fn eq_scalar_op(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` > `value`
+    fn gt_scalar_op(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` >= `value`
+    fn gteq_scalar_op(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` < `value`
+    fn lt_scalar_op(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating where `self` <= `value`
+    fn lteq_scalar_op(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU;
+}
+
+/// Trait for SQL `LIKE` matching of each element of the array.
+/// `%` matches any sequence of characters, `_` matches exactly one character
+/// and `escape` makes the following character match literally, a trailing escape matches itself.
+/// Null strings stay null in the output.
+pub trait StringLike: ArrayUtils {
+    fn like(&self, pattern: &str, escape: Option<char>) -> BooleanArrayGPU {
+        default_impl!(self, like_op, pattern, escape);
+    }
+    fn ilike(&self, pattern: &str, escape: Option<char>) -> BooleanArrayGPU {
+        default_impl!(self, ilike_op, pattern, escape);
+    }
+    fn not_like(&self, pattern: &str, escape: Option<char>) -> BooleanArrayGPU {
+        default_impl!(self, not_like_op, pattern, escape);
+    }
+    fn not_ilike(&self, pattern: &str, escape: Option<char>) -> BooleanArrayGPU {
+        default_impl!(self, not_ilike_op, pattern, escape);
+    }
+
+    /// Returns a boolean array indicating which strings match `pattern`
+    fn like_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating which strings match `pattern`, ignoring ASCII case
+    fn ilike_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating which strings do not match `pattern`
+    fn not_like_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+    /// Returns a boolean array indicating which strings do not match `pattern`, ignoring ASCII case
+    fn not_ilike_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU;
+}
+
+macro_rules! dyn_unary_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident, $($arr:ident),* ]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU) -> ArrowArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+                match data {
+                    $(ArrowArrayGPU::$arr(arr_1) => arr_1.$array_op(pipeline).into(),)*
+                    _ => panic!("Operation {} not supported for type {:?}", stringify!($dyn_op), data.get_dtype())
+                }
+            }
+        )+
+    }
+}
+
+dyn_unary_fn!(
+    [
+        length_dyn,
+        "Compute the length in bytes of each string in array",
+        length_op_dyn,
+        length_op,
+        StringArrayGPU
+    ],
+    [
+        upper_dyn,
+        "Convert the ASCII characters of each string in array to upper case",
+        upper_op_dyn,
+        upper_op,
+        StringArrayGPU
+    ],
+    [
+        lower_dyn,
+        "Convert the ASCII characters of each string in array to lower case",
+        lower_op_dyn,
+        lower_op,
+        StringArrayGPU
+    ]
+);
+
+/// Take `length` characters starting from the character at `start` of each string in array
+pub fn substring_dyn(data: &ArrowArrayGPU, start: u32, length: u32) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+    let result = substring_op_dyn(data, start, length, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to the pipeline to take `length` characters
+/// starting from the character at `start` of each string in array
+pub fn substring_op_dyn(
+    data: &ArrowArrayGPU,
+    start: u32,
+    length: u32,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    match data {
+        ArrowArrayGPU::StringArrayGPU(arr_1) => arr_1.substring_op(start, length, pipeline).into(),
+        _ => panic!(
+            "Operation substring_op_dyn not supported for type {:?}",
+            data.get_dtype()
+        ),
+    }
+}
+
+macro_rules! dyn_match_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident, $($arr:ident),* ]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU, pattern: &str) -> BooleanArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, pattern, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(data: &ArrowArrayGPU, pattern: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+                match data {
+                    $(ArrowArrayGPU::$arr(arr_1) => arr_1.$array_op(pattern, pipeline),)*
+                    _ => panic!("Operation {} not supported for type {:?}", stringify!($dyn_op), data.get_dtype())
+                }
+            }
+        )+
+    }
+}
+
+dyn_match_fn!(
+    [
+        starts_with_dyn,
+        "Construct bool array indicating which strings start with pattern",
+        starts_with_op_dyn,
+        starts_with_op,
+        StringArrayGPU
+    ],
+    [
+        ends_with_dyn,
+        "Construct bool array indicating which strings end with pattern",
+        ends_with_op_dyn,
+        ends_with_op,
+        StringArrayGPU
+    ],
+    [
+        contains_dyn,
+        "Construct bool array indicating which strings contain pattern",
+        contains_op_dyn,
+        contains_op,
+        StringArrayGPU
+    ]
+);
+
+macro_rules! dyn_like_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident, $($arr:ident),* ]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU, pattern: &str, escape: Option<char>) -> BooleanArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, pattern, escape, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(
+                data: &ArrowArrayGPU,
+                pattern: &str,
+                escape: Option<char>,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> BooleanArrayGPU {
+                match data {
+                    $(ArrowArrayGPU::$arr(arr_1) => arr_1.$array_op(pattern, escape, pipeline),)*
+                    _ => panic!("Operation {} not supported for type {:?}", stringify!($dyn_op), data.get_dtype())
+                }
+            }
+        )+
+    }
+}
+
+dyn_like_fn!(
+    [
+        like_dyn,
+        "Construct bool array indicating which strings match the LIKE pattern",
+        like_op_dyn,
+        like_op,
+        StringArrayGPU
+    ],
+    [
+        ilike_dyn,
+        "Construct bool array indicating which strings match the case insensitive LIKE pattern",
+        ilike_op_dyn,
+        ilike_op,
+        StringArrayGPU
+    ],
+    [
+        not_like_dyn,
+        "Construct bool array indicating which strings do not match the LIKE pattern",
+        not_like_op_dyn,
+        not_like_op,
+        StringArrayGPU
+    ],
+    [
+        not_ilike_dyn,
+        "Construct bool array indicating which strings do not match the case insensitive LIKE pattern",
+        not_ilike_op_dyn,
+        not_ilike_op,
+        StringArrayGPU
+    ]
+);
@@ -0,0 +1,766 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_routines::gather_string_values_op;
+use wgpu::Buffer;
+
+use crate::*;
+
+const LENGTH_SHADER: &str = include_str!("../compute_shaders/utf8/length.wgsl");
+const CASE_SHADER: &str = include_str!("../compute_shaders/utf8/case.wgsl");
+const SUBSTRING_SHADER: &str = include_str!("../compute_shaders/utf8/substring.wgsl");
+const MATCH_SHADER: &str = include_str!("../compute_shaders/utf8/match.wgsl");
+const LIKE_SHADER: &str = include_str!("../compute_shaders/utf8/like.wgsl");
+
+const LIKE_ANY_CHAR: u32 = 256;
+const LIKE_ANY_STRING: u32 = 257;
+const LIKE_CASE_INSENSITIVE: u32 = 1;
+const LIKE_NEGATE: u32 = 2;
+
+/// Packs the pattern as its length in bytes followed by its bytes
+pub(crate) fn create_pattern_buffer(pattern: &[u8], device: &GpuDevice) -> Buffer {
+    let mut words = Vec::with_capacity(1 + pattern.len().div_ceil(4));
+    words.push(pattern.len() as u32);
+    words.extend(pattern.chunks(4).map(|chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        u32::from_le_bytes(word)
+    }));
+    device.create_gpu_buffer_with_data(&words)
+}
+
+/// Packs the number of tokens and the flags followed by one token per word.
+/// Escaped and literal characters become one token per utf8 byte,
+/// an escape character ending the pattern matches itself.
+pub(crate) fn create_like_pattern_buffer(
+    pattern: &str,
+    escape: Option<char>,
+    flags: u32,
+    device: &GpuDevice,
+) -> Buffer {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+    while let Some(char) = chars.next() {
+        let literal = match char {
+            _ if Some(char) == escape => chars.next().unwrap_or(char),
+            '%' => {
+                if tokens.last() != Some(&LIKE_ANY_STRING) {
+                    tokens.push(LIKE_ANY_STRING);
+                }
+                continue;
+            }
+            '_' => {
+                tokens.push(LIKE_ANY_CHAR);
+                continue;
+            }
+            _ => char,
+        };
+        let literal = match flags & LIKE_CASE_INSENSITIVE {
+            0 => literal,
+            _ => literal.to_ascii_lowercase(),
+        };
+        let mut bytes = [0u8; 4];
+        tokens.extend(literal.encode_utf8(&mut bytes).bytes().map(u32::from));
+    }
+
+    let mut words = vec![tokens.len() as u32, flags];
+    words.extend(tokens);
+    device.create_gpu_buffer_with_data(&words)
+}
+
+fn apply_case_op(
+    array: &StringArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> StringArrayGPU {
+    let dispatch_size = array.values.size().div_ceil(4).div_ceil(256) as u32;
+    let new_values = pipeline.apply_unary_function(
+        &array.values,
+        array.values.size(),
+        CASE_SHADER,
+        entry_point,
+        dispatch_size,
+    );
+    let new_offsets = pipeline.clone_buffer(&array.offsets);
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+
+    StringArrayGPU {
+        offsets: new_offsets.into(),
+        values: new_values.into(),
+        gpu_device: array.gpu_device.clone(),
+        len: array.len,
+        null_buffer,
+    }
+}
+
+fn apply_match_op(
+    array: &StringArrayGPU,
+    pattern: &Buffer,
+    shader: &str,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> BooleanArrayGPU {
+    let new_values = pipeline
+        .device
+        .create_empty_buffer(array.len.div_ceil(32).max(1) as u64 * 4);
+
+    pipeline.apply_function(
+        &[&array.values, &array.offsets, pattern, &new_values],
+        shader,
+        entry_point,
+        array.len.div_ceil(256) as u32,
+    );
+
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+
+    BooleanArrayGPU {
+        data: new_values.into(),
+        gpu_device: array.gpu_device.clone(),
+        len: array.len,
+        null_buffer,
+    }
+}
+
+impl StringUnary for StringArrayGPU {
+    type LengthType = Int32ArrayGPU;
+
+    fn length_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::LengthType {
+        let new_buffer = pipeline.apply_unary_function(
+            &self.offsets,
+            self.len as u64 * 4,
+            LENGTH_SHADER,
+            LENGTH_ENTRY_POINT,
+            self.len.div_ceil(256) as u32,
+        );
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Int32ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+
+    fn upper_op(&self, pipeline: &mut ArrowComputePipeline) -> Self {
+        apply_case_op(self, UPPER_ENTRY_POINT, pipeline)
+    }
+
+    fn lower_op(&self, pipeline: &mut ArrowComputePipeline) -> Self {
+        apply_case_op(self, LOWER_ENTRY_POINT, pipeline)
+    }
+
+    fn substring_op(&self, start: u32, length: u32, pipeline: &mut ArrowComputePipeline) -> Self {
+        let params = pipeline.device.create_uniform_buffer(&[start, length]);
+        let new_lengths = pipeline.device.create_empty_buffer(self.len as u64 * 4);
+        let starts = pipeline.device.create_empty_buffer(self.len as u64 * 4);
+
+        pipeline.apply_function(
+            &[&self.values, &self.offsets, &new_lengths, &starts, &params],
+            SUBSTRING_SHADER,
+            SUBSTRING_ENTRY_POINT,
+            self.len.div_ceil(256) as u32,
+        );
+
+        let (new_offsets, new_values) =
+            gather_string_values_op(&self.values, &starts, &new_lengths, self.len, pipeline);
+        let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&self.null_buffer, pipeline);
+
+        Self {
+            offsets: new_offsets.into(),
+            values: new_values.into(),
+            gpu_device: self.gpu_device.clone(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+impl StringMatch for StringArrayGPU {
+    fn starts_with_op(
+        &self,
+        pattern: &str,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        let pattern = create_pattern_buffer(pattern.as_bytes(), &pipeline.device);
+        apply_match_op(
+            self,
+            &pattern,
+            MATCH_SHADER,
+            STARTS_WITH_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn ends_with_op(&self, pattern: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        let pattern = create_pattern_buffer(pattern.as_bytes(), &pipeline.device);
+        apply_match_op(
+            self,
+            &pattern,
+            MATCH_SHADER,
+            ENDS_WITH_ENTRY_POINT,
+            pipeline,
+        )
+    }
+
+    fn contains_op(&self, pattern: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+        let pattern = create_pattern_buffer(pattern.as_bytes(), &pipeline.device);
+        apply_match_op(self, &pattern, MATCH_SHADER, CONTAINS_ENTRY_POINT, pipeline)
+    }
+}
+
+macro_rules! impl_string_compare_op {
+    ($($fn: ident, $entry_point: ident),*) => {
+        impl StringCompare for StringArrayGPU {
+            $(
+                fn $fn(&self, value: &str, pipeline: &mut ArrowComputePipeline) -> BooleanArrayGPU {
+                    let pattern = create_pattern_buffer(value.as_bytes(), &pipeline.device);
+                    apply_match_op(self, &pattern, MATCH_SHADER, $entry_point, pipeline)
+                }
+            )*
+        }
+    };
+}
+
+impl_string_compare_op!(
+    eq_scalar_op,
+    EQ_SCALAR_ENTRY_POINT,
+    gt_scalar_op,
+    GT_SCALAR_ENTRY_POINT,
+    gteq_scalar_op,
+    GTEQ_SCALAR_ENTRY_POINT,
+    lt_scalar_op,
+    LT_SCALAR_ENTRY_POINT,
+    lteq_scalar_op,
+    LTEQ_SCALAR_ENTRY_POINT
+);
+
+impl StringLike for StringArrayGPU {
+    fn like_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        let pattern = create_like_pattern_buffer(pattern, escape, 0, &pipeline.device);
+        apply_match_op(self, &pattern, LIKE_SHADER, LIKE_ENTRY_POINT, pipeline)
+    }
+
+    fn ilike_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        let flags = LIKE_CASE_INSENSITIVE;
+        let pattern = create_like_pattern_buffer(pattern, escape, flags, &pipeline.device);
+        apply_match_op(self, &pattern, LIKE_SHADER, LIKE_ENTRY_POINT, pipeline)
+    }
+
+    fn not_like_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        let flags = LIKE_NEGATE;
+        let pattern = create_like_pattern_buffer(pattern, escape, flags, &pipeline.device);
+        apply_match_op(self, &pattern, LIKE_SHADER, LIKE_ENTRY_POINT, pipeline)
+    }
+
+    fn not_ilike_op(
+        &self,
+        pattern: &str,
+        escape: Option<char>,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> BooleanArrayGPU {
+        let flags = LIKE_CASE_INSENSITIVE | LIKE_NEGATE;
+        let pattern = create_like_pattern_buffer(pattern, escape, flags, &pipeline.device);
+        apply_match_op(self, &pattern, LIKE_SHADER, LIKE_ENTRY_POINT, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    const INPUT: [Option<&str>; 7] = [
+        Some("Hello World"),
+        None,
+        Some(""),
+        Some("straße"),
+        Some("ab"),
+        Some("world hello"),
+        Some("ABC xyz 123"),
+    ];
+
+    fn to_owned(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values.iter().map(|x| x.map(String::from)).collect()
+    }
+
+    macro_rules! test_string_op {
+        ($fn_name: ident, $input: expr, $operation: ident, $operation_dyn: ident, $output_ty: ident, $output: expr $(, $arg: expr)*) => {
+            #[test]
+            fn $fn_name() {
+                let array = StringArrayGPU::from_optional_slice(&$input, GPU_DEVICE.clone());
+                let new_array = array.$operation($($arg),*);
+                assert_eq!(new_array.values(), $output);
+
+                let new_array = $operation_dyn(&array.into(), $($arg),*);
+                let new_values = $output_ty::try_from(new_array).unwrap().values();
+                assert_eq!(new_values, $output);
+            }
+        };
+    }
+
+    macro_rules! test_match_op {
+        ($fn_name: ident, $input: expr, $operation: ident, $operation_dyn: ident, $pattern: expr, $output: expr) => {
+            #[test]
+            fn $fn_name() {
+                let array = StringArrayGPU::from_optional_slice(&$input, GPU_DEVICE.clone());
+                assert_eq!(array.$operation($pattern).values(), $output);
+                assert_eq!($operation_dyn(&array.into(), $pattern).values(), $output);
+            }
+        };
+    }
+
+    test_string_op!(
+        test_length,
+        INPUT,
+        length,
+        length_dyn,
+        Int32ArrayGPU,
+        vec![
+            Some(11),
+            None,
+            Some(0),
+            Some(7),
+            Some(2),
+            Some(11),
+            Some(11)
+        ]
+    );
+
+    test_string_op!(
+        test_upper,
+        INPUT,
+        upper,
+        upper_dyn,
+        StringArrayGPU,
+        to_owned(&[
+            Some("HELLO WORLD"),
+            None,
+            Some(""),
+            Some("STRAßE"),
+            Some("AB"),
+            Some("WORLD HELLO"),
+            Some("ABC XYZ 123")
+        ])
+    );
+
+    test_string_op!(
+        test_lower,
+        INPUT,
+        lower,
+        lower_dyn,
+        StringArrayGPU,
+        to_owned(&[
+            Some("hello world"),
+            None,
+            Some(""),
+            Some("straße"),
+            Some("ab"),
+            Some("world hello"),
+            Some("abc xyz 123")
+        ])
+    );
+
+    test_string_op!(
+        test_substring,
+        INPUT,
+        substring,
+        substring_dyn,
+        StringArrayGPU,
+        to_owned(&[
+            Some("llo "),
+            None,
+            Some(""),
+            Some("raße"),
+            Some(""),
+            Some("rld "),
+            Some("C xy")
+        ]),
+        2,
+        4
+    );
+
+    test_string_op!(
+        test_substring_past_end,
+        INPUT,
+        substring,
+        substring_dyn,
+        StringArrayGPU,
+        to_owned(&[
+            Some("Hello World"),
+            None,
+            Some(""),
+            Some("straße"),
+            Some("ab"),
+            Some("world hello"),
+            Some("ABC xyz 123")
+        ]),
+        0,
+        100
+    );
+
+    test_match_op!(
+        test_starts_with,
+        INPUT,
+        starts_with,
+        starts_with_dyn,
+        "Hello",
+        vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_match_op!(
+        test_ends_with,
+        INPUT,
+        ends_with,
+        ends_with_dyn,
+        "ße",
+        vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_match_op!(
+        test_contains,
+        INPUT,
+        contains,
+        contains_dyn,
+        "o",
+        vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false)
+        ]
+    );
+
+    test_match_op!(
+        test_contains_empty_pattern,
+        INPUT,
+        contains,
+        contains_dyn,
+        "",
+        vec![
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true)
+        ]
+    );
+
+    macro_rules! test_like_op {
+        ($fn_name: ident, $operation: ident, $operation_dyn: ident, $pattern: expr, $escape: expr, $output: expr) => {
+            #[test]
+            fn $fn_name() {
+                let input = [
+                    Some("apple pie"),
+                    None,
+                    Some(""),
+                    Some("Apple"),
+                    Some("grüße"),
+                    Some("100%"),
+                    Some("a_b"),
+                    Some("axb"),
+                ];
+                let array = StringArrayGPU::from_optional_slice(&input, GPU_DEVICE.clone());
+                assert_eq!(array.$operation($pattern, $escape).values(), $output);
+                assert_eq!(
+                    $operation_dyn(&array.into(), $pattern, $escape).values(),
+                    $output
+                );
+            }
+        };
+    }
+
+    test_like_op!(
+        test_like_prefix,
+        like,
+        like_dyn,
+        "app%",
+        None,
+        vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_wildcards,
+        like,
+        like_dyn,
+        "%p_e%",
+        None,
+        vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_utf8_char,
+        like,
+        like_dyn,
+        "gr__e",
+        None,
+        vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_empty,
+        like,
+        like_dyn,
+        "",
+        None,
+        vec![
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_escape,
+        like,
+        like_dyn,
+        "a\\_b",
+        Some('\\'),
+        vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_custom_escape,
+        like,
+        like_dyn,
+        "%!%",
+        Some('!'),
+        vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_like_trailing_escape,
+        like,
+        like_dyn,
+        "100%",
+        Some('%'),
+        vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_ilike,
+        ilike,
+        ilike_dyn,
+        "APP%",
+        None,
+        vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_not_like,
+        not_like,
+        not_like_dyn,
+        "a_b",
+        None,
+        vec![
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(false)
+        ]
+    );
+
+    test_like_op!(
+        test_not_ilike,
+        not_ilike,
+        not_ilike_dyn,
+        "%E",
+        None,
+        vec![
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true)
+        ]
+    );
+
+    #[test]
+    fn test_like_merge() {
+        use arrow_gpu_routines::Swizzle;
+
+        let device = GPU_DEVICE.clone();
+        let array = StringArrayGPU::from_optional_slice(
+            &[Some("keep me"), Some("drop"), None, Some("keep")],
+            device.clone(),
+        );
+        let other =
+            StringArrayGPU::from_optional_slice(&[Some("a"), Some("b"), Some("c"), None], device);
+        let mask = array.like("keep%", None);
+        let expected = vec![
+            Some("keep me".to_string()),
+            Some("b".to_string()),
+            None,
+            Some("keep".to_string()),
+        ];
+        assert_eq!(array.merge(&other, &mask).values(), expected);
+    }
+
+    #[test]
+    fn test_large_like() {
+        let values = (0..1000).map(|x| format!("row {x}")).collect::<Vec<_>>();
+        let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+        let array = StringArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        let expected = values
+            .iter()
+            .map(|x| x.starts_with("row 9") && x.ends_with('1'))
+            .collect::<Vec<_>>();
+        assert_eq!(array.like("row 9%1", None).raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_large_contains() {
+        let values = (0..1000).map(|x| format!("row {x}")).collect::<Vec<_>>();
+        let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+        let array = StringArrayGPU::from_slice(&values, GPU_DEVICE.clone());
+        let expected = values.iter().map(|x| x.contains("99")).collect::<Vec<_>>();
+        assert_eq!(array.contains("99").raw_values().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_compare_scalar() {
+        let values = [
+            Some("straße"),
+            None,
+            Some(""),
+            Some("strasse"),
+            Some("straß"),
+            Some("straßen"),
+            Some("Straße"),
+            Some("zz"),
+        ];
+        let array = StringArrayGPU::from_optional_slice(&values, GPU_DEVICE.clone());
+        let expected = |op: fn(&str, &str) -> bool| {
+            values
+                .iter()
+                .map(|x| x.map(|x| op(x, "straße")))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(array.eq_scalar("straße").values(), expected(|x, y| x == y));
+        assert_eq!(array.gt_scalar("straße").values(), expected(|x, y| x > y));
+        assert_eq!(
+            array.gteq_scalar("straße").values(),
+            expected(|x, y| x >= y)
+        );
+        assert_eq!(array.lt_scalar("straße").values(), expected(|x, y| x < y));
+        assert_eq!(
+            array.lteq_scalar("straße").values(),
+            expected(|x, y| x <= y)
+        );
+        assert_eq!(
+            array.lteq_scalar("").values(),
+            values.map(|x| x.map(str::is_empty)).to_vec()
+        );
+    }
+}
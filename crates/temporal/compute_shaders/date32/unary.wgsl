@@ -0,0 +1,76 @@
+@group(0)
+@binding(0)
+var<storage, read> values: array<i32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<i32>;
+
+@compute
+@workgroup_size(256)
+fn year(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(values[global_id.x]).x;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn month(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(values[global_id.x]).y;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn day(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(values[global_id.x]).z;
+    }
+}
+
+// 0 is sunday, 1970-01-01 was a thursday.
+// The remainder is computed by hand as `%` of negative values is undefined on some backends.
+@compute
+@workgroup_size(256)
+fn day_of_week(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let days = values[global_id.x];
+        new_values[global_id.x] = (days - days / 7 * 7 + 11) % 7;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn day_of_year(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let days = values[global_id.x];
+        new_values[global_id.x] = days - days_from_civil(civil_from_days(days).x, 1, 1) + 1;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn quarter(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = (civil_from_days(values[global_id.x]).y - 1) / 3 + 1;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn trunc_month(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let date = civil_from_days(values[global_id.x]);
+        new_values[global_id.x] = days_from_civil(date.x, date.y, 1);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn trunc_year(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = days_from_civil(civil_from_days(values[global_id.x]).x, 1, 1);
+    }
+}
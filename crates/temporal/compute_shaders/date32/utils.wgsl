@@ -0,0 +1,27 @@
+// Civil calendar conversions for the proleptic gregorian calendar,
+// see http://howardhinnant.github.io/date_algorithms.html
+
+// Returns (year, month, day) of the date `days` after 1970-01-01
+fn civil_from_days(days: i32) -> vec3<i32> {
+    let z = days + 719468;
+    let era = select(z, z - 146096, z < 0) / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = select(mp - 9, mp + 3, mp < 10);
+    let year = yoe + era * 400;
+    return vec3<i32>(select(year, year + 1, month <= 2), month, day);
+}
+
+// Returns the number of days from 1970-01-01 to the given date
+fn days_from_civil(year: i32, month: i32, day: i32) -> i32 {
+    let y = select(year, year - 1, month <= 2);
+    let era = select(y, y - 399, y < 0) / 400;
+    let yoe = y - era * 400;
+    let mp = select(month - 3, month + 9, month <= 2);
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    return era * 146097 + doe - 719468;
+}
@@ -0,0 +1,45 @@
+struct Params {
+    per_second: u32,
+    len: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> values: array<i32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<i32>;
+
+// times are always positive, the number of seconds since midnight
+fn seconds_of_day(index: u32) -> i32 {
+    return values[index] / i32(params.per_second);
+}
+
+@compute
+@workgroup_size(256)
+fn hour(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = seconds_of_day(global_id.x) / 3600;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn minute(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = seconds_of_day(global_id.x) / 60 % 60;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn second(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        new_values[global_id.x] = seconds_of_day(global_id.x) % 60;
+    }
+}
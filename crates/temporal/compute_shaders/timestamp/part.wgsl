@@ -0,0 +1,122 @@
+struct Params {
+    per_second: u32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> params: Params;
+
+@group(0)
+@binding(1)
+var<storage, read> values: array<vec2<u32>>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> new_values: array<i32>;
+
+// (first second as i64, offset from UTC in seconds, unused) sorted by first second,
+// each offset applies until the next one and the first one starts at i64::MIN
+@group(0)
+@binding(3)
+var<storage, read> transitions: array<vec4<u32>>;
+
+// Offset from UTC in seconds of the timezone at the UTC second `seconds`
+fn offset_seconds(seconds: vec2<u32>) -> i32 {
+    var low = 0u;
+    var high = arrayLength(&transitions);
+    while high - low > 1u {
+        let mid = (low + high) / 2u;
+        if lt_i64(seconds, transitions[mid].xy) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    return bitcast<i32>(transitions[low].z);
+}
+
+// Returns (days since 1970-01-01, seconds since midnight) of the timestamp in local time
+fn local_time(value: vec2<u32>) -> vec2<i32> {
+    let utc_seconds = floor_div_rem_i64_u32(value, params.per_second).quotient;
+    let local_offset = offset_seconds(utc_seconds);
+    let offset = vec2<u32>(bitcast<u32>(local_offset), select(0u, 0xffffffffu, local_offset < 0));
+    let seconds = add_64(utc_seconds, offset);
+    let days = floor_div_rem_i64_u32(seconds, 86400u);
+    return vec2<i32>(bitcast<i32>(days.quotient.x), i32(days.remainder));
+}
+
+@compute
+@workgroup_size(256)
+fn hour(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = local_time(values[global_id.x]).y / 3600;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn minute(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = local_time(values[global_id.x]).y / 60 % 60;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn second(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = local_time(values[global_id.x]).y % 60;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn year(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(local_time(values[global_id.x]).x).x;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn month(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(local_time(values[global_id.x]).x).y;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn day(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = civil_from_days(local_time(values[global_id.x]).x).z;
+    }
+}
+
+// 0 is sunday, see date32/unary.wgsl for the remainder
+@compute
+@workgroup_size(256)
+fn day_of_week(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let days = local_time(values[global_id.x]).x;
+        new_values[global_id.x] = (days - days / 7 * 7 + 11) % 7;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn day_of_year(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let days = local_time(values[global_id.x]).x;
+        new_values[global_id.x] = days - days_from_civil(civil_from_days(days).x, 1, 1) + 1;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn quarter(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        let days = local_time(values[global_id.x]).x;
+        new_values[global_id.x] = (civil_from_days(days).y - 1) / 3 + 1;
+    }
+}
@@ -0,0 +1,238 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use wgpu::Buffer;
+
+use crate::*;
+
+const DATE32_UNARY_SHADER: &str = concat!(
+    include_str!("../compute_shaders/date32/utils.wgsl"),
+    include_str!("../compute_shaders/date32/unary.wgsl")
+);
+const DATE32_BINARY_SHADER: &str = include_str!("../compute_shaders/date32/binary.wgsl");
+
+fn apply_date32_unary_op(
+    array: &Date32ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> (Buffer, Option<NullBitBufferGpu>) {
+    let new_buffer = pipeline.apply_unary_function(
+        &array.data,
+        array.len as u64 * 4,
+        DATE32_UNARY_SHADER,
+        entry_point,
+        array.len.div_ceil(256) as u32,
+    );
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+    (new_buffer, null_buffer)
+}
+
+fn apply_date32_binary_op<T: ArrowPrimitiveType>(
+    left: &Date32ArrayGPU,
+    right: &PrimitiveArrayGpu<T>,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> (Buffer, Option<NullBitBufferGpu>) {
+    assert_eq!(left.len, right.len);
+    let new_buffer = pipeline.apply_binary_function(
+        &left.data,
+        &right.data,
+        left.len as u64 * 4,
+        DATE32_BINARY_SHADER,
+        entry_point,
+        left.len.div_ceil(256) as u32,
+    );
+    let null_buffer =
+        NullBitBufferGpu::merge_null_bit_buffer_op(&left.null_buffer, &right.null_buffer, pipeline);
+    (new_buffer, null_buffer)
+}
+
+fn date_part_op(
+    array: &Date32ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Int32ArrayGPU {
+    let (new_buffer, null_buffer) = apply_date32_unary_op(array, entry_point, pipeline);
+    Int32ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer,
+    }
+}
+
+impl DatePart for Date32ArrayGPU {
+    fn year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, YEAR_ENTRY_POINT, pipeline)
+    }
+
+    fn month_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, MONTH_ENTRY_POINT, pipeline)
+    }
+
+    fn day_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, DAY_ENTRY_POINT, pipeline)
+    }
+
+    fn day_of_week_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, DAY_OF_WEEK_ENTRY_POINT, pipeline)
+    }
+
+    fn day_of_year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, DAY_OF_YEAR_ENTRY_POINT, pipeline)
+    }
+
+    fn quarter_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        date_part_op(self, QUARTER_ENTRY_POINT, pipeline)
+    }
+}
+
+impl DateArithmetic for Date32ArrayGPU {
+    fn add_days_op(&self, days: &Int32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self {
+        let (new_buffer, null_buffer) =
+            apply_date32_binary_op(self, days, ADD_DAYS_ENTRY_POINT, pipeline);
+        Self {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+
+    fn sub_dates_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        let (new_buffer, null_buffer) =
+            apply_date32_binary_op(self, other, SUB_DATES_ENTRY_POINT, pipeline);
+        Int32ArrayGPU {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+
+    fn date_trunc_op(&self, unit: DateTruncUnit, pipeline: &mut ArrowComputePipeline) -> Self {
+        let entry_point = match unit {
+            DateTruncUnit::Month => TRUNC_MONTH_ENTRY_POINT,
+            DateTruncUnit::Year => TRUNC_YEAR_ENTRY_POINT,
+        };
+        let (new_buffer, null_buffer) = apply_date32_unary_op(self, entry_point, pipeline);
+        Self {
+            data: new_buffer.into(),
+            gpu_device: self.gpu_device.clone(),
+            phantom: Default::default(),
+            len: self.len,
+            null_buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_test_macros::*;
+
+    // 1970-01-01, 1969-12-31, 2000-02-29, 2024-02-29, 2024-12-31, 1900-01-01, 1600-03-01, 2023-11-15
+    const DATES: [i32; 8] = [0, -1, 11016, 19782, 20088, -25567, -135080, 19676];
+
+    test_unary_op!(
+        test_date32_year,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        year,
+        year_dyn,
+        vec![1970, 1969, 2000, 2024, 2024, 1900, 1600, 2023]
+    );
+
+    test_unary_op!(
+        test_date32_month,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        month,
+        month_dyn,
+        vec![1, 12, 2, 2, 12, 1, 3, 11]
+    );
+
+    test_unary_op!(
+        test_date32_day,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        day,
+        day_dyn,
+        vec![1, 31, 29, 29, 31, 1, 1, 15]
+    );
+
+    test_unary_op!(
+        test_date32_day_of_week,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        day_of_week,
+        day_of_week_dyn,
+        vec![4, 3, 2, 4, 2, 1, 3, 3]
+    );
+
+    test_unary_op!(
+        test_date32_day_of_year,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        day_of_year,
+        day_of_year_dyn,
+        vec![1, 365, 60, 60, 366, 1, 61, 319]
+    );
+
+    test_unary_op!(
+        test_date32_quarter,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        DATES,
+        quarter,
+        quarter_dyn,
+        vec![1, 4, 1, 1, 4, 1, 1, 4]
+    );
+
+    test_array_op!(
+        test_date32_add_days,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        Date32ArrayGPU,
+        add_days,
+        add_days_dyn,
+        [Some(0), Some(19782), None, Some(-1)],
+        [Some(31), Some(-365), Some(1), None],
+        vec![Some(31), Some(19417), None, None]
+    );
+
+    test_array_op!(
+        test_date32_sub_dates,
+        Date32ArrayGPU,
+        Date32ArrayGPU,
+        Int32ArrayGPU,
+        sub_dates,
+        sub_dates_dyn,
+        [Some(19782), Some(0), None],
+        [Some(11016), Some(20088), Some(1)],
+        vec![Some(8766), Some(-20088), None]
+    );
+
+    #[test]
+    fn test_date32_trunc() {
+        use arrow_gpu_array::GPU_DEVICE;
+        let array = Date32ArrayGPU::from_slice(&DATES, GPU_DEVICE.clone());
+        assert_eq!(
+            array.date_trunc(DateTruncUnit::Month).raw_values().unwrap(),
+            vec![0, -31, 10988, 19754, 20058, -25567, -135080, 19662]
+        );
+        assert_eq!(
+            date_trunc_dyn(&array.into(), DateTruncUnit::Year).get_raw_values(),
+            arrow_gpu_array::utils::ScalarArray::I32Vec(vec![
+                0, -365, 10957, 19723, 19723, -25567, -135140, 19358
+            ])
+        );
+    }
+}
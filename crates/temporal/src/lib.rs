@@ -0,0 +1,393 @@
+use arrow_gpu_array::array::{ArrayUtils, ArrowArrayGPU, DurationArrayGPU, Int32ArrayGPU};
+use arrow_gpu_array::gpu_utils::*;
+
+pub(crate) mod date32;
+pub(crate) mod time32;
+pub(crate) mod timestamp;
+
+const YEAR_ENTRY_POINT: &str = "year";
+const MONTH_ENTRY_POINT: &str = "month";
+const DAY_ENTRY_POINT: &str = "day";
+const DAY_OF_WEEK_ENTRY_POINT: &str = "day_of_week";
+const DAY_OF_YEAR_ENTRY_POINT: &str = "day_of_year";
+const QUARTER_ENTRY_POINT: &str = "quarter";
+const TRUNC_MONTH_ENTRY_POINT: &str = "trunc_month";
+const TRUNC_YEAR_ENTRY_POINT: &str = "trunc_year";
+const ADD_DAYS_ENTRY_POINT: &str = "add_days";
+const SUB_DATES_ENTRY_POINT: &str = "sub_dates";
+const HOUR_ENTRY_POINT: &str = "hour";
+const MINUTE_ENTRY_POINT: &str = "minute";
+const SECOND_ENTRY_POINT: &str = "second";
+
+macro_rules! default_impl {
+    ($self: ident, $fn: ident $(, $arg: ident)*) => {
+        let mut pipeline = ArrowComputePipeline::new($self.get_gpu_device(), None);
+        let output = Self::$fn(&$self, $($arg,)* &mut pipeline);
+        pipeline.finish();
+        return output;
+    };
+}
+
+/// Unit to truncate dates to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTruncUnit {
+    Month,
+    Year,
+}
+
+/// Trait for extracting a calendar field from each date of the array
+pub trait DatePart: ArrayUtils {
+    fn year(&self) -> Int32ArrayGPU {
+        default_impl!(self, year_op);
+    }
+    fn month(&self) -> Int32ArrayGPU {
+        default_impl!(self, month_op);
+    }
+    fn day(&self) -> Int32ArrayGPU {
+        default_impl!(self, day_op);
+    }
+    fn day_of_week(&self) -> Int32ArrayGPU {
+        default_impl!(self, day_of_week_op);
+    }
+    fn day_of_year(&self) -> Int32ArrayGPU {
+        default_impl!(self, day_of_year_op);
+    }
+    fn quarter(&self) -> Int32ArrayGPU {
+        default_impl!(self, quarter_op);
+    }
+
+    /// Extract the year of each date
+    fn year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the month of each date, starting from 1 for january
+    fn month_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the day of the month of each date, starting from 1
+    fn day_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the day of the week of each date, 0 for sunday to 6 for saturday
+    fn day_of_week_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the day of the year of each date, starting from 1 for january 1st
+    fn day_of_year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the quarter of each date, from 1 to 4
+    fn quarter_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+}
+
+/// Trait for calendar arithmetic on dates
+pub trait DateArithmetic: ArrayUtils + Sized {
+    fn add_days(&self, days: &Int32ArrayGPU) -> Self {
+        default_impl!(self, add_days_op, days);
+    }
+    fn sub_dates(&self, other: &Self) -> Int32ArrayGPU {
+        default_impl!(self, sub_dates_op, other);
+    }
+    fn date_trunc(&self, unit: DateTruncUnit) -> Self {
+        default_impl!(self, date_trunc_op, unit);
+    }
+
+    /// Add the given number of days to each date
+    fn add_days_op(&self, days: &Int32ArrayGPU, pipeline: &mut ArrowComputePipeline) -> Self;
+    /// Compute the number of days between each pair of dates
+    fn sub_dates_op(&self, other: &Self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Truncate each date to the first day of its month or year
+    fn date_trunc_op(&self, unit: DateTruncUnit, pipeline: &mut ArrowComputePipeline) -> Self;
+}
+
+/// Trait for extracting the time of day from each element of the array
+pub trait TimePart: ArrayUtils {
+    fn hour(&self) -> Int32ArrayGPU {
+        default_impl!(self, hour_op);
+    }
+    fn minute(&self) -> Int32ArrayGPU {
+        default_impl!(self, minute_op);
+    }
+    fn second(&self) -> Int32ArrayGPU {
+        default_impl!(self, second_op);
+    }
+
+    /// Extract the hour of each element, from 0 to 23
+    fn hour_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the minute of each element, from 0 to 59
+    fn minute_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+    /// Extract the second of each element, from 0 to 59
+    fn second_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU;
+}
+
+/// Trait for arithmetic between timestamps and durations.
+/// When units differ the values are converted to the finer unit first,
+/// the rows whose value overflows an i64 in that unit are null.
+pub trait TimestampArithmetic: ArrayUtils + Sized {
+    fn add_duration(&self, duration: &DurationArrayGPU) -> Self {
+        default_impl!(self, add_duration_op, duration);
+    }
+    fn sub_duration(&self, duration: &DurationArrayGPU) -> Self {
+        default_impl!(self, sub_duration_op, duration);
+    }
+    fn sub_timestamps(&self, other: &Self) -> DurationArrayGPU {
+        default_impl!(self, sub_timestamps_op, other);
+    }
+
+    /// Add each duration to the timestamp, the timezone of self is kept
+    fn add_duration_op(
+        &self,
+        duration: &DurationArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+    /// Subtract each duration from the timestamp, the timezone of self is kept
+    fn sub_duration_op(
+        &self,
+        duration: &DurationArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+    /// Compute the duration elapsed from each timestamp of `other` to the timestamp of self
+    fn sub_timestamps_op(
+        &self,
+        other: &Self,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> DurationArrayGPU;
+}
+
+macro_rules! dyn_date_part_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident, $($arr:ident),* ]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU) -> ArrowArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+                match data {
+                    $(ArrowArrayGPU::$arr(arr_1) => arr_1.$array_op(pipeline).into(),)*
+                    _ => panic!("Operation {} not supported for type {:?}", stringify!($dyn_op), data.get_dtype())
+                }
+            }
+        )+
+    }
+}
+
+dyn_date_part_fn!(
+    [
+        year_dyn,
+        "Extract the year of each date",
+        year_op_dyn,
+        year_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        month_dyn,
+        "Extract the month of each date",
+        month_op_dyn,
+        month_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        day_dyn,
+        "Extract the day of the month of each date",
+        day_op_dyn,
+        day_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        day_of_week_dyn,
+        "Extract the day of the week of each date",
+        day_of_week_op_dyn,
+        day_of_week_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        day_of_year_dyn,
+        "Extract the day of the year of each date",
+        day_of_year_op_dyn,
+        day_of_year_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        quarter_dyn,
+        "Extract the quarter of each date",
+        quarter_op_dyn,
+        quarter_op,
+        Date32ArrayGPU,
+        TimestampArrayGPU
+    ],
+    [
+        hour_dyn,
+        "Extract the hour of each element",
+        hour_op_dyn,
+        hour_op,
+        TimestampArrayGPU,
+        Time32ArrayGPU
+    ],
+    [
+        minute_dyn,
+        "Extract the minute of each element",
+        minute_op_dyn,
+        minute_op,
+        TimestampArrayGPU,
+        Time32ArrayGPU
+    ],
+    [
+        second_dyn,
+        "Extract the second of each element",
+        second_op_dyn,
+        second_op,
+        TimestampArrayGPU,
+        Time32ArrayGPU
+    ]
+);
+
+/// Add the number of days in `days` to each date of `data`
+pub fn add_days_dyn(data: &ArrowArrayGPU, days: &ArrowArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+    let result = add_days_op_dyn(data, days, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to the pipeline to add the number of days in `days` to each date of `data`
+pub fn add_days_op_dyn(
+    data: &ArrowArrayGPU,
+    days: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    match (data, days) {
+        (ArrowArrayGPU::Date32ArrayGPU(arr_1), ArrowArrayGPU::Int32ArrayGPU(arr_2)) => {
+            arr_1.add_days_op(arr_2, pipeline).into()
+        }
+        _ => panic!(
+            "Operation add_days_op_dyn not supported for type {:?} {:?}",
+            data.get_dtype(),
+            days.get_dtype()
+        ),
+    }
+}
+
+/// Compute the number of days from each date of `right` to the date of `left`
+pub fn sub_dates_dyn(left: &ArrowArrayGPU, right: &ArrowArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(left.get_gpu_device(), None);
+    let result = sub_dates_op_dyn(left, right, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to the pipeline to compute the number of days
+/// from each date of `right` to the date of `left`
+pub fn sub_dates_op_dyn(
+    left: &ArrowArrayGPU,
+    right: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    match (left, right) {
+        (ArrowArrayGPU::Date32ArrayGPU(arr_1), ArrowArrayGPU::Date32ArrayGPU(arr_2)) => {
+            arr_1.sub_dates_op(arr_2, pipeline).into()
+        }
+        _ => panic!(
+            "Operation sub_dates_op_dyn not supported for type {:?} {:?}",
+            left.get_dtype(),
+            right.get_dtype()
+        ),
+    }
+}
+
+/// Truncate each date to the first day of its month or year
+pub fn date_trunc_dyn(data: &ArrowArrayGPU, unit: DateTruncUnit) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+    let result = date_trunc_op_dyn(data, unit, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to the pipeline to truncate each date to the first day of its month or year
+pub fn date_trunc_op_dyn(
+    data: &ArrowArrayGPU,
+    unit: DateTruncUnit,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    match data {
+        ArrowArrayGPU::Date32ArrayGPU(arr_1) => arr_1.date_trunc_op(unit, pipeline).into(),
+        _ => panic!(
+            "Operation date_trunc_op_dyn not supported for type {:?}",
+            data.get_dtype()
+        ),
+    }
+}
+
+macro_rules! dyn_timestamp_duration_fn {
+    ($([$dyn: ident, $doc: expr, $dyn_op: ident, $array_op: ident]),*) => {
+        $(
+            #[doc=$doc]
+            pub fn $dyn(data: &ArrowArrayGPU, duration: &ArrowArrayGPU) -> ArrowArrayGPU {
+                let mut pipeline = ArrowComputePipeline::new(data.get_gpu_device(), None);
+                let result = $dyn_op(data, duration, &mut pipeline);
+                pipeline.finish();
+                result
+            }
+
+            #[doc=concat!("Submits a command to the pipeline to ", $doc)]
+            pub fn $dyn_op(
+                data: &ArrowArrayGPU,
+                duration: &ArrowArrayGPU,
+                pipeline: &mut ArrowComputePipeline,
+            ) -> ArrowArrayGPU {
+                match (data, duration) {
+                    (ArrowArrayGPU::TimestampArrayGPU(arr_1), ArrowArrayGPU::DurationArrayGPU(arr_2)) => {
+                        arr_1.$array_op(arr_2, pipeline).into()
+                    }
+                    _ => panic!(
+                        "Operation {} not supported for type {:?} {:?}",
+                        stringify!($dyn_op),
+                        data.get_dtype(),
+                        duration.get_dtype()
+                    ),
+                }
+            }
+        )+
+    }
+}
+
+dyn_timestamp_duration_fn!(
+    [
+        add_duration_dyn,
+        "Add each duration of `duration` to the timestamp of `data`",
+        add_duration_op_dyn,
+        add_duration_op
+    ],
+    [
+        sub_duration_dyn,
+        "Subtract each duration of `duration` from the timestamp of `data`",
+        sub_duration_op_dyn,
+        sub_duration_op
+    ]
+);
+
+/// Compute the duration elapsed from each timestamp of `right` to the timestamp of `left`
+pub fn sub_timestamps_dyn(left: &ArrowArrayGPU, right: &ArrowArrayGPU) -> ArrowArrayGPU {
+    let mut pipeline = ArrowComputePipeline::new(left.get_gpu_device(), None);
+    let result = sub_timestamps_op_dyn(left, right, &mut pipeline);
+    pipeline.finish();
+    result
+}
+
+/// Submits a command to the pipeline to compute the duration elapsed
+/// from each timestamp of `right` to the timestamp of `left`
+pub fn sub_timestamps_op_dyn(
+    left: &ArrowArrayGPU,
+    right: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    match (left, right) {
+        (ArrowArrayGPU::TimestampArrayGPU(arr_1), ArrowArrayGPU::TimestampArrayGPU(arr_2)) => {
+            arr_1.sub_timestamps_op(arr_2, pipeline).into()
+        }
+        _ => panic!(
+            "Operation sub_timestamps_op_dyn not supported for type {:?} {:?}",
+            left.get_dtype(),
+            right.get_dtype()
+        ),
+    }
+}
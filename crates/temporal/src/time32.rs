@@ -0,0 +1,84 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::*;
+
+const TIME32_PART_SHADER: &str = include_str!("../compute_shaders/time32/part.wgsl");
+
+fn time32_part_op(
+    array: &Time32ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Int32ArrayGPU {
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[array.unit.per_second(), array.len as u32]);
+    let new_buffer = pipeline.device.create_empty_buffer(array.len as u64 * 4);
+    pipeline.apply_function(
+        &[&params, &array.values.data, &new_buffer],
+        TIME32_PART_SHADER,
+        entry_point,
+        array.len.div_ceil(256) as u32,
+    );
+
+    Int32ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(
+            &array.values.null_buffer,
+            pipeline,
+        ),
+    }
+}
+
+impl TimePart for Time32ArrayGPU {
+    fn hour_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        time32_part_op(self, HOUR_ENTRY_POINT, pipeline)
+    }
+
+    fn minute_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        time32_part_op(self, MINUTE_ENTRY_POINT, pipeline)
+    }
+
+    fn second_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        time32_part_op(self, SECOND_ENTRY_POINT, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    #[test]
+    fn test_time32_part() {
+        let device = GPU_DEVICE.clone();
+        // 00:00:00, 13:45:30.250, 23:59:59.999
+        let array = Time32ArrayGPU::from_optional_slice(
+            &[Some(0), Some(49_530_250), None, Some(86_399_999)],
+            TimeUnit::Millisecond,
+            device.clone(),
+        );
+        assert_eq!(
+            array.hour().values(),
+            vec![Some(0), Some(13), None, Some(23)]
+        );
+        assert_eq!(
+            array.minute().values(),
+            vec![Some(0), Some(45), None, Some(59)]
+        );
+        assert_eq!(
+            array.second().values(),
+            vec![Some(0), Some(30), None, Some(59)]
+        );
+
+        let array = Time32ArrayGPU::from_slice(&[3_661], TimeUnit::Second, device);
+        let hours = hour_dyn(&array.into());
+        assert_eq!(
+            hours.get_raw_values(),
+            arrow_gpu_array::utils::ScalarArray::I32Vec(vec![1])
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use arrow_gpu_arithmetic::{ArrowAdd, ArrowSub};
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+use arrow_gpu_cast::with_finer_unit_op;
+use chrono::{DateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+use crate::*;
+
+const TIMESTAMP_PART_SHADER: &str = concat!(
+    include_str!("../../../compute_shaders/u64/utils.wgsl"),
+    include_str!("../../../compute_shaders/i64/utils.wgsl"),
+    include_str!("../compute_shaders/date32/utils.wgsl"),
+    include_str!("../compute_shaders/timestamp/part.wgsl")
+);
+
+/// First second of 1900-01-01 and of 2100-01-01, the range where the transitions
+/// of named timezones are looked for
+const TRANSITIONS_START: i64 = -2_208_988_800;
+const TRANSITIONS_END: i64 = 4_102_444_800;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Parses fixed offsets such as `+05:30`, `-0800` or `+01` into seconds
+fn fixed_offset_seconds(timezone: &str) -> Option<i32> {
+    let sign = match timezone.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits = timezone[1..].replace(':', "");
+    if !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    (hours < 24 && minutes < 60).then_some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Returns the offsets from UTC in seconds of `timezone` along with the UTC second
+/// they start from, sorted by that second. The first offset starts from `i64::MIN`.
+/// UTC, fixed offsets and IANA names such as `Europe/Paris` are supported, the offsets
+/// of IANA timezones change at most once a day between 1900 and 2100.
+fn timezone_transitions(timezone: Option<&str>) -> Vec<(i64, i32)> {
+    let timezone = match timezone {
+        None | Some("UTC") | Some("Z") => return vec![(i64::MIN, 0)],
+        Some(timezone) => timezone,
+    };
+    if let Some(offset) = fixed_offset_seconds(timezone) {
+        return vec![(i64::MIN, offset)];
+    }
+
+    let tz = timezone.parse::<Tz>().unwrap_or_else(|_| {
+        panic!(
+            "Timezone {} is not supported, only UTC, fixed offsets and IANA names are",
+            timezone
+        )
+    });
+    let offset_at = |second: i64| {
+        let utc = DateTime::from_timestamp(second, 0).unwrap().naive_utc();
+        tz.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+    };
+
+    let mut transitions = vec![(i64::MIN, offset_at(TRANSITIONS_START))];
+    let mut second = TRANSITIONS_START;
+    while second < TRANSITIONS_END {
+        let next_second = second + SECONDS_PER_DAY;
+        let offset = offset_at(next_second);
+        if offset != transitions.last().unwrap().1 {
+            // first second of the day with the new offset
+            let (mut low, mut high) = (second, next_second);
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                if offset_at(mid) == offset {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            transitions.push((high, offset));
+        }
+        second = next_second;
+    }
+    transitions
+}
+
+fn timestamp_part_op(
+    array: &TimestampArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Int32ArrayGPU {
+    let transitions = timezone_transitions(array.timezone.as_deref())
+        .into_iter()
+        .flat_map(|(second, offset)| [second as u32, (second >> 32) as u32, offset as u32, 0])
+        .collect::<Vec<_>>();
+    let transitions = pipeline.device.create_gpu_buffer_with_data(&transitions);
+    let params = pipeline
+        .device
+        .create_uniform_buffer(&[array.unit.per_second(), 0]);
+    let new_buffer = pipeline.device.create_empty_buffer(array.len as u64 * 4);
+    pipeline.apply_function(
+        &[&params, &array.values.data, &new_buffer, &transitions],
+        TIMESTAMP_PART_SHADER,
+        entry_point,
+        array.len.div_ceil(256) as u32,
+    );
+
+    Int32ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: Default::default(),
+        len: array.len,
+        null_buffer: NullBitBufferGpu::clone_null_bit_buffer_op(
+            &array.values.null_buffer,
+            pipeline,
+        ),
+    }
+}
+
+impl DatePart for TimestampArrayGPU {
+    fn year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, YEAR_ENTRY_POINT, pipeline)
+    }
+
+    fn month_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, MONTH_ENTRY_POINT, pipeline)
+    }
+
+    fn day_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, DAY_ENTRY_POINT, pipeline)
+    }
+
+    fn day_of_week_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, DAY_OF_WEEK_ENTRY_POINT, pipeline)
+    }
+
+    fn day_of_year_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, DAY_OF_YEAR_ENTRY_POINT, pipeline)
+    }
+
+    fn quarter_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, QUARTER_ENTRY_POINT, pipeline)
+    }
+}
+
+impl TimePart for TimestampArrayGPU {
+    fn hour_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, HOUR_ENTRY_POINT, pipeline)
+    }
+
+    fn minute_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, MINUTE_ENTRY_POINT, pipeline)
+    }
+
+    fn second_op(&self, pipeline: &mut ArrowComputePipeline) -> Int32ArrayGPU {
+        timestamp_part_op(self, SECOND_ENTRY_POINT, pipeline)
+    }
+}
+
+impl TimestampArithmetic for TimestampArrayGPU {
+    fn add_duration_op(
+        &self,
+        duration: &DurationArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        with_finer_unit_op(self, duration, pipeline, |left, right, unit, pipeline| {
+            let values = left.values.add_op(&right.values, pipeline);
+            TimestampArrayGPU::new(values, unit, self.timezone.clone())
+        })
+    }
+
+    fn sub_duration_op(
+        &self,
+        duration: &DurationArrayGPU,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        with_finer_unit_op(self, duration, pipeline, |left, right, unit, pipeline| {
+            let values = left.values.sub_op(&right.values, pipeline);
+            TimestampArrayGPU::new(values, unit, self.timezone.clone())
+        })
+    }
+
+    fn sub_timestamps_op(
+        &self,
+        other: &Self,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> DurationArrayGPU {
+        with_finer_unit_op(self, other, pipeline, |left, right, unit, pipeline| {
+            DurationArrayGPU::new(left.values.sub_op(&right.values, pipeline), unit)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_gpu_array::GPU_DEVICE;
+
+    // 1970-01-01 00:00:00, 1969-12-31 23:59:59.999, 2024-02-29 13:45:30.250,
+    // 1900-01-01 06:07:08, 2023-11-15 23:30:00
+    const TIMESTAMPS: [i64; 5] = [
+        0,
+        -1,
+        1_709_214_330_250,
+        -2_208_966_772_000,
+        1_700_091_000_000,
+    ];
+
+    fn timestamps(timezone: Option<&str>) -> TimestampArrayGPU {
+        TimestampArrayGPU::from_slice(
+            &TIMESTAMPS,
+            TimeUnit::Millisecond,
+            timezone.map(String::from),
+            GPU_DEVICE.clone(),
+        )
+    }
+
+    #[test]
+    fn test_timestamp_time_part() {
+        let array = timestamps(None);
+        assert_eq!(array.hour().raw_values().unwrap(), vec![0, 23, 13, 6, 23]);
+        assert_eq!(array.minute().raw_values().unwrap(), vec![0, 59, 45, 7, 30]);
+        assert_eq!(
+            second_dyn(&array.into()).get_raw_values(),
+            arrow_gpu_array::utils::ScalarArray::I32Vec(vec![0, 59, 30, 8, 0])
+        );
+    }
+
+    #[test]
+    fn test_timestamp_date_part() {
+        let array = timestamps(Some("UTC"));
+        assert_eq!(
+            array.year().raw_values().unwrap(),
+            vec![1970, 1969, 2024, 1900, 2023]
+        );
+        assert_eq!(array.month().raw_values().unwrap(), vec![1, 12, 2, 1, 11]);
+        assert_eq!(array.day().raw_values().unwrap(), vec![1, 31, 29, 1, 15]);
+        assert_eq!(
+            array.day_of_week().raw_values().unwrap(),
+            vec![4, 3, 4, 1, 3]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_timezone() {
+        let array = timestamps(Some("+05:30"));
+        assert_eq!(array.hour().raw_values().unwrap(), vec![5, 5, 19, 11, 5]);
+        assert_eq!(
+            array.minute().raw_values().unwrap(),
+            vec![30, 29, 15, 37, 0]
+        );
+        assert_eq!(array.day().raw_values().unwrap(), vec![1, 1, 29, 1, 16]);
+
+        let array = timestamps(Some("-08"));
+        assert_eq!(array.hour().raw_values().unwrap(), vec![16, 15, 5, 22, 15]);
+        assert_eq!(
+            year_dyn(&array.into()).get_raw_values(),
+            arrow_gpu_array::utils::ScalarArray::I32Vec(vec![1969, 1969, 2024, 1899, 2023])
+        );
+    }
+
+    #[test]
+    fn test_timestamp_named_timezone() {
+        // Paris is at +01:00 in winter, +02:00 in summer and was at +00:09:21 in 1900
+        let array = timestamps(Some("Europe/Paris"));
+        assert_eq!(array.hour().raw_values().unwrap(), vec![1, 0, 14, 6, 0]);
+        assert_eq!(
+            array.minute().raw_values().unwrap(),
+            vec![0, 59, 45, 16, 30]
+        );
+        assert_eq!(array.day().raw_values().unwrap(), vec![1, 1, 29, 1, 16]);
+
+        // 2024-03-31 00:59:59 and 01:00:00 UTC, on both sides of the switch to summer time
+        let array = TimestampArrayGPU::from_slice(
+            &[1_711_846_799, 1_711_846_800],
+            TimeUnit::Second,
+            Some("Europe/Paris".into()),
+            GPU_DEVICE.clone(),
+        );
+        assert_eq!(array.hour().raw_values().unwrap(), vec![1, 3]);
+
+        let array = TimestampArrayGPU::from_slice(
+            &[1_700_091_000],
+            TimeUnit::Second,
+            Some("America/New_York".into()),
+            GPU_DEVICE.clone(),
+        );
+        assert_eq!(array.hour().raw_values().unwrap(), vec![18]);
+    }
+
+    #[test]
+    fn test_timestamp_add_duration() {
+        let device = GPU_DEVICE.clone();
+        let array = TimestampArrayGPU::from_optional_slice(
+            &[Some(1_000), None, Some(-5)],
+            TimeUnit::Second,
+            Some("UTC".into()),
+            device.clone(),
+        );
+        let duration = DurationArrayGPU::from_optional_slice(
+            &[Some(1_500), Some(1), Some(-1)],
+            TimeUnit::Millisecond,
+            device,
+        );
+        let new_array = array.add_duration(&duration);
+        assert_eq!(new_array.unit, TimeUnit::Millisecond);
+        assert_eq!(new_array.timezone.as_deref(), Some("UTC"));
+        assert_eq!(
+            new_array.values(),
+            vec![Some(1_001_500), None, Some(-5_001)]
+        );
+
+        let new_array: TimestampArrayGPU = sub_duration_dyn(&new_array.into(), &duration.into())
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            new_array.values(),
+            vec![Some(1_000_000), None, Some(-5_000)]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_add_duration_overflow() {
+        let device = GPU_DEVICE.clone();
+        // the largest timestamp in seconds that still fits in nanoseconds
+        let limit = i64::MAX / 1_000_000_000;
+        let array = TimestampArrayGPU::from_slice(
+            &[limit, limit + 1, -limit - 1],
+            TimeUnit::Second,
+            None,
+            device.clone(),
+        );
+        let duration = DurationArrayGPU::from_slice(&[1, 0, 0], TimeUnit::Nanosecond, device);
+        let new_array = array.add_duration(&duration);
+        assert_eq!(new_array.unit, TimeUnit::Nanosecond);
+        assert_eq!(
+            new_array.values(),
+            vec![Some(limit * 1_000_000_000 + 1), None, None]
+        );
+        assert_eq!(
+            array.sub_duration(&duration).values(),
+            vec![Some(limit * 1_000_000_000 - 1), None, None]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_sub_timestamps() {
+        let device = GPU_DEVICE.clone();
+        let left = TimestampArrayGPU::from_slice(
+            &[1 << 40, 10],
+            TimeUnit::Microsecond,
+            None,
+            device.clone(),
+        );
+        let right =
+            TimestampArrayGPU::from_slice(&[(1 << 40) + 7, 20], TimeUnit::Nanosecond, None, device);
+        let duration = sub_timestamps_dyn(&left.into(), &right.into());
+        assert_eq!(
+            duration.get_dtype(),
+            ArrowType::DurationType(TimeUnit::Nanosecond)
+        );
+        let duration: DurationArrayGPU = duration.try_into().unwrap();
+        assert_eq!(
+            duration.raw_values().unwrap(),
+            vec![(1 << 40) * 1000 - (1 << 40) - 7, 9_980]
+        );
+    }
+}
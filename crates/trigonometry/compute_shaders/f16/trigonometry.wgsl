@@ -0,0 +1,34 @@
+// half2, load_half2 and store_half2 are defined by a prelude in compute_shaders/f16,
+// each invocation computes the two values packed in a word
+
+@group(0)
+@binding(0)
+var<storage, read> original_values: array<u32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> new_values: array<u32>;
+
+@compute
+@workgroup_size(256)
+fn cos_f16(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = store_half2(cos(load_half2(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn acos_f16(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = store_half2(acos(load_half2(original_values[global_id.x])));
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sin_f16(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&new_values) {
+        new_values[global_id.x] = store_half2(sin(load_half2(original_values[global_id.x])));
+    }
+}
@@ -0,0 +1,94 @@
+use arrow_gpu_array::array::*;
+use arrow_gpu_array::gpu_utils::*;
+
+use crate::Trigonometric;
+
+const TRIGONOMETRY_SHADER: Float16Shader = Float16Shader {
+    native: concat!(
+        include_str!("../../../compute_shaders/f16/native.wgsl"),
+        include_str!("../compute_shaders/f16/trigonometry.wgsl")
+    ),
+    packed: concat!(
+        include_str!("../../../compute_shaders/f16/packed.wgsl"),
+        include_str!("../compute_shaders/f16/trigonometry.wgsl")
+    ),
+};
+
+fn apply_f16_trigonometry_op(
+    array: &Float16ArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float16ArrayGPU {
+    let new_buffer = pipeline.apply_unary_function(
+        &array.data,
+        array.data.size(),
+        TRIGONOMETRY_SHADER.select(&pipeline.device),
+        entry_point,
+        array.len.div_ceil(2).div_ceil(256) as u32,
+    );
+    let null_buffer = NullBitBufferGpu::clone_null_bit_buffer_op(&array.null_buffer, pipeline);
+
+    Float16ArrayGPU {
+        data: new_buffer.into(),
+        gpu_device: array.gpu_device.clone(),
+        phantom: std::marker::PhantomData,
+        len: array.len,
+        null_buffer,
+    }
+}
+
+impl Trigonometric for Float16ArrayGPU {
+    type Output = Float16ArrayGPU;
+
+    fn cos_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::Output {
+        apply_f16_trigonometry_op(self, "cos_f16", pipeline)
+    }
+
+    fn sin_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::Output {
+        apply_f16_trigonometry_op(self, "sin_f16", pipeline)
+    }
+
+    fn acos_op(&self, pipeline: &mut ArrowComputePipeline) -> Self::Output {
+        apply_f16_trigonometry_op(self, "acos_f16", pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use arrow_gpu_array::GPU_DEVICE;
+    use arrow_gpu_array::array::f16;
+
+    #[test]
+    fn test_f16_trigonometry() {
+        let values = [0.0f32, 1.0, -0.5, 3.0];
+        let array = Float16ArrayGPU::from_optional_slice(
+            &values
+                .iter()
+                .map(|x| Some(f16::from_f32(*x)))
+                .chain([None])
+                .collect::<Vec<_>>(),
+            GPU_DEVICE.clone(),
+        );
+
+        let assert_close = |result: Float16ArrayGPU, function: fn(f32) -> f32| {
+            let result = result.values();
+            assert_eq!(result[4], None);
+            for (value, result) in values.iter().zip(result) {
+                let expected = function(*value);
+                assert!((result.unwrap().to_f32() - expected).abs() < 2e-3);
+            }
+        };
+        assert_close(array.cos(), f32::cos);
+        assert_close(array.sin(), f32::sin);
+        assert_close(
+            sin_dyn(&array.clone_array().into()).try_into().unwrap(),
+            f32::sin,
+        );
+
+        let acos = array.acos().raw_values().unwrap();
+        assert!((acos[2].to_f32() - (-0.5f32).acos()).abs() < 2e-3);
+        assert!(acos[3].is_nan());
+    }
+}
@@ -3,6 +3,7 @@ use arrow_gpu_array::array::*;
 use arrow_gpu_array::gpu_utils::*;
 use std::sync::Arc;
 
+pub(crate) mod f16_kernel;
 pub(crate) mod f32_kernel;
 pub(crate) mod i16_kernel;
 pub(crate) mod i8_kernel;
@@ -175,6 +176,7 @@ dyn_fn!(
         "Compute cos(x) for each x in array",
         cos_op_dyn,
         cos_op,
+        Float16ArrayGPU,
         Float32ArrayGPU,
         UInt16ArrayGPU,
         UInt8ArrayGPU,
@@ -186,6 +188,7 @@ dyn_fn!(
         "Compute sin(x) for each x in array",
         sin_op_dyn,
         sin_op,
+        Float16ArrayGPU,
         Float32ArrayGPU,
         UInt16ArrayGPU,
         UInt8ArrayGPU,
@@ -197,6 +200,7 @@ dyn_fn!(
         "Compute acos(x) for each x in array",
         acos_op_dyn,
         acos_op,
+        Float16ArrayGPU,
         Float32ArrayGPU
     ]
 );